@@ -0,0 +1,55 @@
+// Service worker of Leontine
+// Caches the application shell, so that the application loads offline: pages are
+// fetched from the network first, static files are served from the cache and
+// refreshed in the background. API requests are never cached.
+
+const CACHE = 'leontine-shell-v1';
+const SHELL = ['./', './manifest.webmanifest', './icon.svg'];
+const STATIC_FILE = /\.(js|wasm|css|svg|ico|png|webmanifest)$/;
+
+self.addEventListener('install', (event) => {
+    event.waitUntil(caches.open(CACHE).then((cache) => cache.addAll(SHELL)));
+    self.skipWaiting();
+});
+
+self.addEventListener('activate', (event) => {
+    event.waitUntil(
+        caches
+            .keys()
+            .then((keys) => Promise.all(keys.filter((key) => key !== CACHE).map((key) => caches.delete(key))))
+            .then(() => self.clients.claim())
+    );
+});
+
+self.addEventListener('fetch', (event) => {
+    const request = event.request;
+    const url = new URL(request.url);
+    if (request.method !== 'GET' || url.origin !== self.location.origin) return;
+
+    if (request.mode === 'navigate') {
+        event.respondWith(
+            fetch(request)
+                .then((response) => {
+                    const copy = response.clone();
+                    caches.open(CACHE).then((cache) => cache.put('./', copy));
+                    return response;
+                })
+                .catch(() => caches.match('./'))
+        );
+        return;
+    }
+
+    if (!STATIC_FILE.test(url.pathname)) return;
+    event.respondWith(
+        caches.open(CACHE).then(async (cache) => {
+            const cached = await cache.match(request);
+            const refreshed = fetch(request)
+                .then((response) => {
+                    if (response.ok) cache.put(request, response.clone());
+                    return response;
+                })
+                .catch(() => cached);
+            return cached || refreshed;
+        })
+    );
+});
@@ -0,0 +1,85 @@
+//! Server administration endpoints
+//! Operators running their own WhisperX server can list and cancel the jobs of the queue.
+//! These endpoints require the admin token of the endpoint profile, sent as a bearer token.
+
+use super::{http, http_error, parse_json, ApiError, JobStatus};
+use crate::config::API_ADMIN_JOBS_ENDPOINT;
+use crate::profile::EndpointProfile;
+use chrono::{DateTime, Utc};
+use gloo_net::http::RequestBuilder;
+use log::{error, info};
+use serde::Deserialize;
+
+/// A job of the server queue, as listed by the admin endpoint.
+/// Only anonymous fields are kept: file names or client details are never read.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct QueuedJob {
+    pub job_id: String,
+    pub status: JobStatus,
+    /// When the job was queued.
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+    /// Size of the uploaded file, in bytes.
+    #[serde(default)]
+    pub file_size: Option<u64>,
+}
+
+/// Response of the queue listing endpoint.
+#[derive(Debug, Deserialize)]
+struct QueueListing {
+    jobs: Vec<QueuedJob>,
+}
+
+/// Adds the admin token of the profile to a request.
+fn with_admin_token(builder: RequestBuilder, profile: &EndpointProfile) -> RequestBuilder {
+    match &profile.admin_token {
+        Some(token) => builder.header("Authorization", &format!("Bearer {}", token.0)),
+        None => builder,
+    }
+}
+
+/// Lists the jobs queued or processing on the server.
+/// A 404 means the server does not expose the queue listing.
+pub async fn list_jobs(
+    api_url: &str,
+    profile: &EndpointProfile,
+) -> Result<Vec<QueuedJob>, ApiError> {
+    if api_url.is_empty() {
+        return Err(ApiError::NotConfigured);
+    }
+    let url = format!("{}{}", api_url, API_ADMIN_JOBS_ENDPOINT);
+    info!("Fetching server queue from: {}", url);
+
+    let response = http::send(with_admin_token(http::get(&url, profile), profile)).await?;
+    if !response.ok() {
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        error!("Queue listing returned error status {}: {}", status, text);
+        return Err(http_error(status, text));
+    }
+
+    let listing: QueueListing = parse_json(response).await?;
+    Ok(listing.jobs)
+}
+
+/// Cancels a job of the server queue.
+pub async fn cancel_job(
+    api_url: &str,
+    profile: &EndpointProfile,
+    job_id: &str,
+) -> Result<(), ApiError> {
+    let url = format!("{}{}/{}", api_url, API_ADMIN_JOBS_ENDPOINT, job_id);
+    info!("Cancelling job {} at: {}", job_id, url);
+
+    let response = http::send(with_admin_token(http::delete(&url, profile), profile)).await?;
+    if !response.ok() {
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        error!(
+            "Cancelling job {} returned error status {}: {}",
+            job_id, status, text
+        );
+        return Err(http_error(status, text));
+    }
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! API capabilities
+//! Derives the features the client can use with a server from its status response,
+//! so that the interface only offers what the server supports. Servers predating a
+//! feature do not mention it: each flag tells what its absence means. Whether jobs can
+//! be cancelled, when not advertised, is probed with an OPTIONS request.
+
+use super::{http, ApiStatus, NotificationSupport};
+use crate::config::API_TRANSCRIPTION_ENDPOINT;
+use crate::profile::EndpointProfile;
+use log::info;
+
+/// The features of a server the client can use.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Capabilities {
+    /// Speaker diarization, assumed available unless the server says otherwise.
+    pub diarization: bool,
+    /// Job status pushed through Server-Sent Events.
+    pub sse: bool,
+    /// Status of several jobs in one request.
+    pub batch_status: bool,
+    /// Cancellation of jobs, `None` while neither advertised nor probed.
+    pub cancellation: Option<bool>,
+    /// A list of the models the server can run.
+    pub models: bool,
+    /// Notification targets the server can register with a submission.
+    pub notifications: NotificationSupport,
+}
+
+impl Capabilities {
+    /// The capabilities advertised by a status response.
+    pub fn from_status(status: &ApiStatus) -> Self {
+        let features = status.features.clone().unwrap_or_default();
+        Capabilities {
+            diarization: features.diarization.unwrap_or(true),
+            sse: status
+                .streaming
+                .as_ref()
+                .is_some_and(|streaming| streaming.sse),
+            batch_status: status.batch_job_status,
+            cancellation: features.cancellation,
+            models: features.models.unwrap_or(false),
+            notifications: status.notifications.clone().unwrap_or_default(),
+        }
+    }
+
+    /// Whether jobs can be cancelled. Unknown support is assumed, as the
+    /// server answers a refused cancellation with an error anyway.
+    pub fn can_cancel(&self) -> bool {
+        self.cancellation.unwrap_or(true)
+    }
+
+    /// Names of the supported features, for display.
+    pub fn labels(&self) -> Vec<&'static str> {
+        [
+            (self.diarization, "Speaker diarization"),
+            (self.sse, "Live job updates"),
+            (self.batch_status, "Batch job status"),
+            (self.can_cancel(), "Job cancellation"),
+            (self.models, "Model list"),
+            (self.notifications.email, "Email notifications"),
+            (self.notifications.webhook, "Webhook notifications"),
+        ]
+        .into_iter()
+        .filter_map(|(supported, label)| supported.then_some(label))
+        .collect()
+    }
+}
+
+/// Probes whether jobs can be cancelled, from the methods the transcription endpoint
+/// allows. Returns `None` when the server does not tell, or the `Allow` header is
+/// not exposed to the browser.
+pub async fn probe_cancellation(api_url: &str, profile: &EndpointProfile) -> Option<bool> {
+    let url = format!("{}{}", api_url, API_TRANSCRIPTION_ENDPOINT);
+    let response = http::send(http::options(&url, profile)).await.ok()?;
+    let allow = response.headers().get("allow")?;
+    let allowed = allow
+        .split(',')
+        .any(|method| method.trim().eq_ignore_ascii_case("DELETE"));
+    info!(
+        "Probed job cancellation support of {}: {}",
+        api_url, allowed
+    );
+    Some(allowed)
+}
@@ -0,0 +1,116 @@
+//! API URL discovery helpers
+//! Detects when the configured URL is not the API base (e.g. the web UI URL was
+//! pasted instead) and suggests likely corrections.
+
+use super::{get_status, ApiError};
+use crate::config::{API_DISCOVERY_PATHS, API_STATUS_ENDPOINT};
+use crate::profile::EndpointProfile;
+use futures_util::future::join_all;
+
+/// Whether an error suggests that the URL does not point at the API base path.
+pub fn is_wrong_url_error(err: &ApiError) -> bool {
+    matches!(
+        err,
+        ApiError::NotAnApi(_) | ApiError::HttpError(404, _) | ApiError::UnexpectedResponse(_)
+    )
+}
+
+/// Splits a URL into its origin (`scheme://host[:port]`) and its path segments.
+fn split_url(url: &str) -> (String, Vec<String>) {
+    let url = url.trim();
+    let url = url.split(['?', '#']).next().unwrap_or(url);
+    let after_scheme = url.find("://").map(|i| i + 3).unwrap_or(0);
+    let path_start = url[after_scheme..]
+        .find('/')
+        .map(|i| i + after_scheme)
+        .unwrap_or(url.len());
+
+    let origin = url[..path_start].to_string();
+    let segments = url[path_start..]
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_string)
+        .collect();
+    (origin, segments)
+}
+
+/// Joins an origin and path segments back into a URL without trailing slash.
+fn join_url(origin: &str, segments: &[String]) -> String {
+    if segments.is_empty() {
+        origin.to_string()
+    } else {
+        format!("{}/{}", origin, segments.join("/"))
+    }
+}
+
+/// Suggests likely API base URLs for a URL that does not look like the API:
+/// strips the status endpoint, trailing page paths (`index.html`, `#/...`),
+/// and appends `/api` to the remaining paths.
+pub fn suggest_corrections(url: &str) -> Vec<String> {
+    let (origin, mut segments) = split_url(url);
+    if origin.is_empty() {
+        return Vec::new();
+    }
+
+    // A pasted status URL: the base is what comes before the endpoint.
+    let status_segment = API_STATUS_ENDPOINT.trim_start_matches('/');
+    if segments.last().map(String::as_str) == Some(status_segment) {
+        segments.pop();
+    }
+
+    let mut candidates = Vec::new();
+    // A page (e.g. `index.html`) is never part of the API base path.
+    if segments.last().is_some_and(|segment| segment.contains('.')) {
+        segments.pop();
+    }
+    candidates.push(join_url(&origin, &segments));
+
+    // Try `/api` below every ancestor of the path, closest first.
+    for depth in (0..=segments.len()).rev() {
+        let mut with_api = segments[..depth].to_vec();
+        if with_api.last().map(String::as_str) != Some("api") {
+            with_api.push("api".to_string());
+            candidates.push(join_url(&origin, &with_api));
+        }
+    }
+
+    let original = url.trim().trim_end_matches('/');
+    let mut suggestions: Vec<String> = Vec::new();
+    for candidate in candidates {
+        if candidate != original && !suggestions.contains(&candidate) {
+            suggestions.push(candidate);
+        }
+    }
+    suggestions
+}
+
+/// Probes the given candidate URLs concurrently and returns those that answer
+/// with a valid API status, in the order of the candidates.
+/// The profile of the endpoint being corrected is used for every probe.
+pub async fn probe_candidates(candidates: &[String], profile: &EndpointProfile) -> Vec<String> {
+    let probes = candidates.iter().map(|candidate| async move {
+        get_status(candidate, profile)
+            .await
+            .ok()
+            .map(|_| candidate.clone())
+    });
+    join_all(probes).await.into_iter().flatten().collect()
+}
+
+/// Probes the conventional API base paths on the origin of `url` concurrently
+/// and returns the preferred one that answers with a valid API status.
+pub async fn detect_api(url: &str, profile: &EndpointProfile) -> Option<String> {
+    let (origin, _) = split_url(url);
+    if origin.is_empty() {
+        return None;
+    }
+
+    let candidates: Vec<String> = API_DISCOVERY_PATHS
+        .iter()
+        .map(|path| format!("{}{}", origin, path))
+        .collect();
+    probe_candidates(&candidates, profile)
+        .await
+        .into_iter()
+        .next()
+}
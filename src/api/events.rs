@@ -0,0 +1,84 @@
+//! Job status events
+//! Servers advertising Server-Sent Events push the status of a job as it changes,
+//! instead of being polled for it. Each message of the stream is a job state, as
+//! returned by the status endpoint. `EventSource` cannot send headers: the stream is
+//! only used for endpoints reachable without credentials or custom headers.
+
+use super::{job_status_url, JobState};
+use crate::config::JOB_EVENTS_SUFFIX;
+use crate::document::{eval, Eval};
+use log::warn;
+use serde::Deserialize;
+
+/// Opens the stream of a job, kept in a global by job id so that it can be closed.
+const SUBSCRIBE_SCRIPT: &str = r#"
+    const [jobId, url] = await dioxus.recv();
+    window.leontineJobEvents = window.leontineJobEvents || {};
+    const source = new EventSource(url);
+    window.leontineJobEvents[jobId] = source;
+    source.onmessage = (event) => dioxus.send({ message: event.data });
+    source.onerror = () => {
+        // The browser reconnects on its own, unless the server refused the stream.
+        if (source.readyState === EventSource.CLOSED) {
+            delete window.leontineJobEvents[jobId];
+            dioxus.send('closed');
+        }
+    };
+"#;
+
+/// Events sent by the subscription script.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum StreamEvent {
+    Message(String),
+    Closed,
+}
+
+/// An open stream of the status of a job, closed when dropped.
+pub struct JobStatusSubscription {
+    job_id: String,
+    script: Eval,
+}
+
+/// Subscribes to the status events of a job.
+/// Returns `None` if the stream cannot be opened.
+pub fn subscribe_job_status(api_url: &str, job_id: &str) -> Option<JobStatusSubscription> {
+    let url = format!("{}{}", job_status_url(api_url, job_id), JOB_EVENTS_SUFFIX);
+    let script = eval(SUBSCRIBE_SCRIPT);
+    if let Err(e) = script.send((job_id, url)) {
+        warn!(
+            "Failed to subscribe to the events of job {}: {:?}",
+            job_id, e
+        );
+        return None;
+    }
+    Some(JobStatusSubscription {
+        job_id: job_id.to_string(),
+        script,
+    })
+}
+
+impl JobStatusSubscription {
+    /// Waits for the next state of the job. Returns `None` once the stream is closed.
+    /// Messages which are not a job state are skipped.
+    pub async fn next(&mut self) -> Option<JobState> {
+        loop {
+            match self.script.recv::<StreamEvent>().await {
+                Ok(StreamEvent::Message(data)) => match serde_json::from_str(&data) {
+                    Ok(state) => return Some(state),
+                    Err(e) => warn!("Ignoring an event of job {}: {}", self.job_id, e),
+                },
+                Ok(StreamEvent::Closed) | Err(_) => return None,
+            }
+        }
+    }
+}
+
+impl Drop for JobStatusSubscription {
+    fn drop(&mut self) {
+        let _ = eval(&format!(
+            "const events = window.leontineJobEvents || {{}}; const source = events[{:?}]; if (source) {{ source.close(); delete events[{:?}]; }}",
+            self.job_id, self.job_id
+        ));
+    }
+}
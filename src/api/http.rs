@@ -0,0 +1,320 @@
+//! HTTP layer of the API client
+//! Every request to the API is built here, so that the options of the endpoint
+//! profile (credentials, custom headers...) are applied consistently.
+//! Credentials and header values are never logged.
+//! Requests are sent through [`send`], [`send_with_clock`], [`send_body`] and
+//! [`send_form_with_progress`], which keep count of the requests in flight for the
+//! activity indicator.
+
+use crate::clock;
+use crate::profile::EndpointProfile;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::Utc;
+use dioxus::prelude::*;
+use gloo_net::http::{RequestBuilder, Response};
+use web_sys::js_sys::{Function, Promise};
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+use web_sys::{FormData, ProgressEvent, XmlHttpRequest};
+
+/// Number of requests currently in flight.
+static IN_FLIGHT: GlobalSignal<usize> = Signal::global(|| 0);
+
+/// Returns the number of requests currently in flight.
+pub fn in_flight() -> usize {
+    IN_FLIGHT()
+}
+
+/// Counts a request as in flight for as long as it lives,
+/// including when the sending future is dropped before completion.
+struct InFlightGuard;
+
+impl InFlightGuard {
+    fn start() -> Self {
+        *IN_FLIGHT.write() += 1;
+        InFlightGuard
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        let mut count = IN_FLIGHT.write();
+        *count = count.saturating_sub(1);
+    }
+}
+
+/// A request in flight that can be aborted.
+pub trait Abort {
+    fn abort(&mut self);
+}
+
+/// Aborts a request when dropped before it is answered, as when the task awaiting it
+/// is cancelled: the server stops receiving it, and creates no job nobody would monitor.
+pub struct AbortOnDrop<R: Abort> {
+    request: R,
+    answered: bool,
+}
+
+impl<R: Abort> AbortOnDrop<R> {
+    pub fn new(request: R) -> Self {
+        AbortOnDrop {
+            request,
+            answered: false,
+        }
+    }
+
+    /// Marks the request as answered: dropping the guard no longer aborts it.
+    pub fn answered(&mut self) {
+        self.answered = true;
+    }
+
+    pub fn request(&self) -> &R {
+        &self.request
+    }
+}
+
+impl<R: Abort> Drop for AbortOnDrop<R> {
+    fn drop(&mut self) {
+        if !self.answered {
+            self.request.abort();
+        }
+    }
+}
+
+/// An upload through `XMLHttpRequest`, with the progress handler it calls back.
+struct XhrUpload {
+    xhr: XmlHttpRequest,
+    _on_progress: Closure<dyn Fn(ProgressEvent)>,
+}
+
+impl Abort for XhrUpload {
+    fn abort(&mut self) {
+        log::info!("Aborting the upload in flight.");
+        let _ = self.xhr.abort();
+    }
+}
+
+/// The handlers are removed before the progress closure is dropped,
+/// as the browser would otherwise call a freed closure.
+impl Drop for XhrUpload {
+    fn drop(&mut self) {
+        if let Ok(upload) = self.xhr.upload() {
+            upload.set_onprogress(None);
+        }
+        self.xhr.set_onload(None);
+        self.xhr.set_onerror(None);
+        self.xhr.set_onabort(None);
+        self.xhr.set_ontimeout(None);
+    }
+}
+
+/// Builds a GET request to an endpoint of the API.
+pub fn get(url: &str, profile: &EndpointProfile) -> RequestBuilder {
+    apply_profile(RequestBuilder::new(url), profile)
+}
+
+/// Builds a POST request to an endpoint of the API.
+pub fn post(url: &str, profile: &EndpointProfile) -> RequestBuilder {
+    apply_profile(
+        RequestBuilder::new(url).method(gloo_net::http::Method::POST),
+        profile,
+    )
+}
+
+/// Builds a GET request to an endpoint outside the API, such as a metrics exporter.
+/// The profile options are left out, as for [`post_external`].
+pub fn get_external(url: &str) -> RequestBuilder {
+    RequestBuilder::new(url)
+}
+
+/// Builds a POST request to an endpoint outside the API, such as a feedback collector.
+/// The profile options are left out: credentials and custom headers are only
+/// meant for the API.
+pub fn post_external(url: &str) -> RequestBuilder {
+    RequestBuilder::new(url).method(gloo_net::http::Method::POST)
+}
+
+/// Builds a DELETE request to an endpoint of the API.
+pub fn delete(url: &str, profile: &EndpointProfile) -> RequestBuilder {
+    apply_profile(
+        RequestBuilder::new(url).method(gloo_net::http::Method::DELETE),
+        profile,
+    )
+}
+
+/// Builds an OPTIONS request to an endpoint of the API.
+pub fn options(url: &str, profile: &EndpointProfile) -> RequestBuilder {
+    apply_profile(
+        RequestBuilder::new(url).method(gloo_net::http::Method::OPTIONS),
+        profile,
+    )
+}
+
+/// Returns the origin of a URL (scheme, host and port), lowercased.
+pub fn origin(url: &str) -> Option<String> {
+    let (scheme, rest) = url.trim().split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    // Credentials embedded in the URL are not part of the origin.
+    let host = authority.rsplit('@').next()?;
+    (!scheme.is_empty() && !host.is_empty())
+        .then(|| format!("{}://{}", scheme, host).to_lowercase())
+}
+
+/// Whether two URLs share the same origin, in which case the options of the profile
+/// of one can be sent to the other.
+pub fn same_origin(url: &str, other: &str) -> bool {
+    matches!((origin(url), origin(other)), (Some(a), Some(b)) if a == b)
+}
+
+/// Returns the headers derived from the profile options, meant for the API only:
+/// requests to other origins are built with [`get_external`] or [`post_external`].
+/// The API token takes precedence over Basic credentials, as both use the
+/// `Authorization` header; the settings disable the credentials while a token is set.
+/// Custom headers come last, so they can override the generated ones.
+fn profile_headers(profile: &EndpointProfile) -> Vec<(String, String)> {
+    let mut headers = Vec::new();
+    if let Some(token) = &profile.api_token {
+        headers.push(("Authorization".to_string(), format!("Bearer {}", token.0)));
+    } else if let Some(auth) = profile
+        .basic_auth
+        .as_ref()
+        .filter(|auth| !auth.username.is_empty())
+    {
+        let credentials = STANDARD.encode(format!("{}:{}", auth.username, auth.password));
+        headers.push((
+            "Authorization".to_string(),
+            format!("Basic {}", credentials),
+        ));
+    }
+    headers.extend(profile.sendable_headers());
+    headers
+}
+
+/// Whether requests to the endpoint carry headers from the profile, which rules out
+/// the browser APIs unable to send headers, such as `EventSource`.
+pub fn has_profile_headers(profile: &EndpointProfile) -> bool {
+    !profile_headers(profile).is_empty()
+}
+
+/// Applies the profile options to a request.
+fn apply_profile(mut builder: RequestBuilder, profile: &EndpointProfile) -> RequestBuilder {
+    for (name, value) in profile_headers(profile) {
+        builder = builder.header(&name, &value);
+    }
+    builder
+}
+
+/// Sends a request without body.
+pub async fn send(builder: RequestBuilder) -> Result<Response, gloo_net::Error> {
+    let _guard = InFlightGuard::start();
+    builder.send().await
+}
+
+/// Sends a request to an API without body, and feeds the `Date` header of the
+/// response to the clock estimate of its origin. Only meant for short status requests:
+/// uploads would skew the estimate.
+pub async fn send_with_clock(builder: RequestBuilder) -> Result<Response, gloo_net::Error> {
+    let _guard = InFlightGuard::start();
+    let sent_at = Utc::now();
+    let response = builder.send().await?;
+    if let Some(date) = response.headers().get("date") {
+        clock::record_response_date(&response.url(), &date, sent_at, Utc::now());
+    }
+    Ok(response)
+}
+
+/// Sends a request with a body.
+pub async fn send_body(
+    builder: RequestBuilder,
+    body: impl Into<JsValue>,
+) -> Result<Response, gloo_net::Error> {
+    let request = builder.body(body)?;
+    let _guard = InFlightGuard::start();
+    request.send().await
+}
+
+/// Posts a form, reporting the upload progress as `(sent bytes, total bytes)`.
+/// The Fetch API does not expose upload progress, so the request goes through
+/// `XMLHttpRequest`. Returns the status code and the body of the response.
+/// The upload is aborted if the future is dropped before the response, e.g. when
+/// the submission is cancelled.
+pub async fn send_form_with_progress(
+    url: &str,
+    profile: &EndpointProfile,
+    headers: &[(&str, &str)],
+    form_data: FormData,
+    on_progress: impl Fn(u64, u64) + 'static,
+) -> Result<(u16, String), JsValue> {
+    let xhr = XmlHttpRequest::new()?;
+    xhr.open("POST", url)?;
+    for (name, value) in profile_headers(profile) {
+        xhr.set_request_header(&name, &value)?;
+    }
+    for (name, value) in headers {
+        xhr.set_request_header(name, value)?;
+    }
+
+    let on_progress = Closure::<dyn Fn(ProgressEvent)>::new(move |event: ProgressEvent| {
+        if event.length_computable() {
+            on_progress(event.loaded() as u64, event.total() as u64);
+        }
+    });
+    xhr.upload()?
+        .set_onprogress(Some(on_progress.as_ref().unchecked_ref()));
+
+    // The request settles the promise: loaded on any HTTP response, rejected on network errors.
+    let completion = Promise::new(&mut |resolve: Function, reject: Function| {
+        xhr.set_onload(Some(&resolve));
+        xhr.set_onerror(Some(&reject));
+        xhr.set_onabort(Some(&reject));
+        xhr.set_ontimeout(Some(&reject));
+    });
+
+    // The closure must live until the request is over, which the guard ensures.
+    let mut upload = AbortOnDrop::new(XhrUpload {
+        xhr,
+        _on_progress: on_progress,
+    });
+    let _guard = InFlightGuard::start();
+    upload
+        .request()
+        .xhr
+        .send_with_opt_form_data(Some(&form_data))?;
+    let outcome = wasm_bindgen_futures::JsFuture::from(completion).await;
+    upload.answered();
+    outcome.map_err(|_| JsValue::from_str("network error during upload"))?;
+
+    let xhr = &upload.request().xhr;
+    let status = xhr.status()?;
+    let body = xhr.response_text()?.unwrap_or_default();
+    Ok((status, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_origin_compares_scheme_host_and_port() {
+        let api = "https://whisper.example.org/api";
+        assert!(same_origin(api, "https://whisper.example.org/metrics"));
+        assert!(same_origin(api, "HTTPS://Whisper.example.org?x=1"));
+        assert!(!same_origin(api, "http://whisper.example.org/metrics"));
+        assert!(!same_origin(
+            api,
+            "https://whisper.example.org:9090/metrics"
+        ));
+        assert!(!same_origin(api, "https://metrics.example.org/metrics"));
+        assert!(!same_origin(api, "https://whisper.example.org.evil.net/"));
+    }
+
+    #[test]
+    fn same_origin_ignores_embedded_credentials() {
+        assert!(!same_origin(
+            "https://whisper.example.org",
+            "https://whisper.example.org@evil.net/metrics"
+        ));
+        assert!(!same_origin("not a url", "not a url"));
+    }
+}
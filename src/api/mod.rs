@@ -1,40 +1,199 @@
 //! API client module for interacting with the WhisperX API
 
-use crate::config::{API_STATUS_ENDPOINT, API_TRANSCRIPTION_ENDPOINT};
+pub mod admin;
+pub mod capabilities;
+pub mod discovery;
+pub mod events;
+pub mod http;
+
+use crate::checksum;
+use crate::config::{
+    API_STATUS_ENDPOINT, API_TRANSCRIPTION_ENDPOINT, BATCH_JOB_STATUS_PATH, CHECKSUM_HEADER,
+    RETRY_BASE_DELAY_MS, RETRY_MAX_ATTEMPTS, RETRY_MAX_DELAY_MS,
+};
 use crate::dioxus_elements::FileEngine;
-use gloo_net::http::Request;
+use crate::media::channels::StereoChannel;
+use crate::profile::{EndpointProfile, UploadLimitProbe};
+use chrono::{DateTime, Utc};
+use gloo_net::http::Response;
+use gloo_timers::future::TimeoutFuture;
 use log::{error, info, warn};
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
+use std::future::Future;
 use std::sync::Arc;
-use web_sys::js_sys::{Array, Uint8Array};
+use web_sys::js_sys::{Array, Math, Uint8Array};
 use web_sys::wasm_bindgen::JsValue;
 use web_sys::{Blob, FormData};
 
 /// Error type for API operations.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ApiError {
+    /// No API URL has been configured.
+    NotConfigured,
     /// Network or request error from `gloo_net` or JS interop.
     RequestFailed(String),
     /// Server returned a non-200 response.
     HttpError(u16, String),
-    /// Error parsing the response JSON.
+    /// Error serializing or parsing JSON.
     ParseError(String),
+    /// The response body does not match the expected schema.
+    UnexpectedResponse(ResponseMismatch),
+    /// The URL serves a web page (HTML) instead of the API.
+    NotAnApi(String),
     /// The file from the file engine was not available or couldn't be read.
     FileNotAvailable,
+    /// The server is under maintenance (503 response with a maintenance body).
+    Maintenance(MaintenanceInfo),
 }
 
 impl fmt::Display for ApiError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            ApiError::NotConfigured => write!(f, "API URL is not configured"),
             ApiError::RequestFailed(s) => write!(f, "Request failed: {}", s),
             ApiError::HttpError(status, text) => write!(f, "Server error {}: {}", status, text),
             ApiError::ParseError(s) => write!(f, "Failed to parse response: {}", s),
+            ApiError::UnexpectedResponse(mismatch) => write!(
+                f,
+                "Unexpected response at `{}`: {}",
+                mismatch.path, mismatch.reason
+            ),
+            ApiError::NotAnApi(content_type) => {
+                write!(f, "The URL returned {} instead of JSON", content_type)
+            }
             ApiError::FileNotAvailable => write!(f, "File is not available."),
+            ApiError::Maintenance(_) => write!(f, "The server is under maintenance"),
+        }
+    }
+}
+
+impl ApiError {
+    /// Whether the request may succeed if sent again: network failures, server errors
+    /// and rate limiting. A maintenance is not transient enough to be retried.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ApiError::RequestFailed(_) | ApiError::HttpError(429 | 500..=599, _)
+        )
+    }
+}
+
+/// How an idempotent request is retried after a transient failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one.
+    pub max_attempts: u32,
+    pub base_delay_ms: u32,
+    pub max_delay_ms: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: RETRY_MAX_ATTEMPTS,
+            base_delay_ms: RETRY_BASE_DELAY_MS,
+            max_delay_ms: RETRY_MAX_DELAY_MS,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before the given retry (1 for the first one): exponential, capped,
+    /// with a random half so that clients failing together do not retry together.
+    fn delay_ms(&self, retry: u32) -> u32 {
+        let exponential = self
+            .base_delay_ms
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)));
+        let capped = exponential.min(self.max_delay_ms);
+        capped / 2 + (Math::random() * f64::from(capped / 2)) as u32
+    }
+}
+
+/// Runs a request, running it again after a delay while it fails with a retryable error,
+/// up to the attempts of the policy. The last error is returned.
+pub async fn with_retry<T, F, Fut>(policy: RetryPolicy, mut request: F) -> Result<T, ApiError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, ApiError>>,
+{
+    let mut attempt = 1;
+    loop {
+        match request().await {
+            Err(e) if e.is_retryable() && attempt < policy.max_attempts => {
+                let delay_ms = policy.delay_ms(attempt);
+                warn!(
+                    "Attempt {} failed ({}), retrying in {} ms",
+                    attempt, e, delay_ms
+                );
+                TimeoutFuture::new(delay_ms).await;
+                attempt += 1;
+            }
+            result => return result,
         }
     }
 }
 
+/// Describes where and why a response body did not match the expected schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseMismatch {
+    /// Path of the offending field (e.g. `queue_state.queued_jobs`), `.` for the root.
+    pub path: String,
+    /// What was expected and what was found instead.
+    pub reason: String,
+    /// The part of the response body around the failure.
+    pub excerpt: String,
+}
+
+/// Maximum number of characters kept on each side of a parse failure in an excerpt.
+const EXCERPT_RADIUS: usize = 40;
+
+/// Extracts the part of `text` around the given 1-based line and column.
+fn excerpt_around(text: &str, line: usize, column: usize) -> String {
+    let line_text = text.lines().nth(line.saturating_sub(1)).unwrap_or(text);
+    let chars: Vec<char> = line_text.chars().collect();
+    let position = column.saturating_sub(1).min(chars.len());
+    let start = position.saturating_sub(EXCERPT_RADIUS);
+    let end = (position + EXCERPT_RADIUS).min(chars.len());
+
+    let mut excerpt: String = chars[start..end].iter().collect();
+    if start > 0 {
+        excerpt.insert(0, '…');
+    }
+    if end < chars.len() {
+        excerpt.push('…');
+    }
+    excerpt
+}
+
+/// Parses a JSON response body, reporting the failing field path and an excerpt
+/// of the body when it does not match the expected type.
+async fn parse_json<T: DeserializeOwned>(response: Response) -> Result<T, ApiError> {
+    let text = response.text().await?;
+    parse_json_text(&text)
+}
+
+/// Same as [`parse_json`], for a response body already read.
+fn parse_json_text<T: DeserializeOwned>(text: &str) -> Result<T, ApiError> {
+    let deserializer = &mut serde_json::Deserializer::from_str(text);
+
+    serde_path_to_error::deserialize(deserializer).map_err(|err| {
+        let path = err.path().to_string();
+        let inner = err.into_inner();
+        let mismatch = ResponseMismatch {
+            path,
+            reason: inner.to_string(),
+            excerpt: excerpt_around(text, inner.line(), inner.column()),
+        };
+        error!(
+            "Response does not match the expected schema: {:?}",
+            mismatch
+        );
+        ApiError::UnexpectedResponse(mismatch)
+    })
+}
+
 // --- From Trait Implementations ---
 
 /// Allows for the use of `?` on `gloo_net::Error` to convert it into our `ApiError`.
@@ -67,17 +226,76 @@ pub enum JobStatus {
     Processing,
     Completed,
     Failed,
+    /// A status this client does not know, kept as sent by the server. The job is
+    /// considered still running.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Represents the JSON response from a successful asynchronous transcription submission.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
 pub struct TranscriptionJob {
     pub job_id: String,
     pub status_url: String,
+    /// API the job was submitted to (local metadata). Jobs saved before it was recorded
+    /// belong to the API in use.
+    #[serde(default)]
+    pub api_url: Option<String>,
+    /// Endpoint profile the job was submitted with (local metadata).
+    #[serde(default)]
+    pub profile_id: Option<String>,
+    /// Acknowledgment message of the server, if any.
+    #[serde(default)]
+    pub message: Option<String>,
+    /// When the server queued the job, as reported by the server.
+    #[serde(default)]
+    pub queued_at: Option<String>,
+    /// URL of the result, if the server announces it at submission.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Extra form fields sent with the submission (local metadata, not returned by the server).
+    #[serde(default)]
+    pub extra_form_fields: Vec<(String, String)>,
+    /// Whether the server verified the checksum of the upload, if it reports it.
+    #[serde(default)]
+    pub checksum_verified: Option<bool>,
+    /// SHA-256 checksum of the uploaded file (local metadata).
+    #[serde(default)]
+    pub sha256: Option<String>,
+    /// Whether the checksum was sent to the server with the upload (local metadata).
+    #[serde(default)]
+    pub checksum_sent: bool,
+    /// Name of the submitted file (local metadata).
+    #[serde(default)]
+    pub file_name: Option<String>,
+    /// When the job was submitted, by the local clock (local metadata).
+    #[serde(default)]
+    pub submitted_at: Option<DateTime<Utc>>,
+    /// Format requested for the result (local metadata).
+    #[serde(default)]
+    pub output_format: Option<OutputFormat>,
+    /// Job parameters sent with the submission, as key and value (local metadata).
+    #[serde(default)]
+    pub params: Vec<(String, String)>,
+    /// Notification target registered with the submission (local metadata).
+    #[serde(default)]
+    pub notification: Option<NotificationTarget>,
+    /// Duration of the submitted media in seconds, if it could be probed (local metadata).
+    #[serde(default)]
+    pub media_duration: Option<f64>,
+    /// When the job was first seen processing, by the local clock (local metadata).
+    #[serde(default)]
+    pub processing_since: Option<DateTime<Utc>>,
+    /// Channel of a stereo recording transcribed on its own (local metadata).
+    #[serde(default)]
+    pub channel: Option<StereoChannel>,
+    /// SHA-256 checksum of the stereo recording the channel was taken from (local metadata).
+    #[serde(default)]
+    pub source_sha256: Option<String>,
 }
 
 /// Represents the state of a transcription job, returned by the status endpoint.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct JobState {
     pub status: JobStatus,
     /// The position in the queue, if the job is queued.
@@ -88,10 +306,117 @@ pub struct JobState {
     pub data: Option<String>,
 }
 
+/// Format in which the API returns the transcription result.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Txt,
+    Srt,
+    Vtt,
+    /// Segments with timestamps, speakers and scores, on which the transcript tools rely.
+    #[default]
+    Json,
+    Tsv,
+}
+
+impl OutputFormat {
+    /// Every format offered for selection.
+    pub const ALL: [OutputFormat; 5] = [
+        OutputFormat::Txt,
+        OutputFormat::Srt,
+        OutputFormat::Vtt,
+        OutputFormat::Json,
+        OutputFormat::Tsv,
+    ];
+
+    /// The value sent to the API, also used as file extension.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Txt => "txt",
+            OutputFormat::Srt => "srt",
+            OutputFormat::Vtt => "vtt",
+            OutputFormat::Json => "json",
+            OutputFormat::Tsv => "tsv",
+        }
+    }
+
+    /// Parses the value sent to the API.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|format| format.extension() == extension)
+    }
+
+    /// Name shown in the format selector.
+    pub fn label(&self) -> &'static str {
+        match self {
+            OutputFormat::Txt => "Plain text",
+            OutputFormat::Srt => "SubRip subtitles (SRT)",
+            OutputFormat::Vtt => "WebVTT subtitles",
+            OutputFormat::Json => "JSON with timestamps",
+            OutputFormat::Tsv => "Tab-separated values (TSV)",
+        }
+    }
+
+    /// MIME type of the downloaded result.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            OutputFormat::Txt | OutputFormat::Srt => "text/plain",
+            OutputFormat::Vtt => "text/vtt",
+            OutputFormat::Json => "application/json",
+            OutputFormat::Tsv => "text/tab-separated-values",
+        }
+    }
+}
+
+/// Speaker diarization options of a transcription job.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DiarizationOptions {
+    /// Whether segments are labeled with their speaker.
+    pub enabled: bool,
+    /// Minimum number of speakers, when known.
+    #[serde(default)]
+    pub min_speakers: Option<u32>,
+    /// Maximum number of speakers, when known.
+    #[serde(default)]
+    pub max_speakers: Option<u32>,
+}
+
+/// Options of a transcription job, as chosen on submission.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobOptions {
+    pub output_format: OutputFormat,
+    pub diarization: DiarizationOptions,
+    /// Target notified of the end of the job, for servers supporting callbacks.
+    pub notification: Option<NotificationTarget>,
+}
+
 /// Represents the parameters for a transcription job, to be serialized as JSON.
 #[derive(Serialize)]
 struct TranscriptionParams {
     sync: bool,
+    output_format: OutputFormat,
+    diarize: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_speakers: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_speakers: Option<u32>,
+}
+
+impl TranscriptionParams {
+    /// The parameters as key and value, sorted by key, to record them with the job.
+    fn entries(&self) -> Vec<(String, String)> {
+        let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(self) else {
+            return Vec::new();
+        };
+        fields
+            .into_iter()
+            .map(|(key, value)| match value {
+                serde_json::Value::String(text) => (key, text),
+                other => (key, other.to_string()),
+            })
+            .collect()
+    }
 }
 
 /// API status response structure
@@ -103,6 +428,192 @@ pub struct ApiStatus {
     pub security: SecurityConfig,
     pub queue_state: QueueState,
     pub error: Option<String>,
+    /// Maintenance flag, absent from servers that do not advertise maintenance.
+    #[serde(default)]
+    pub maintenance: Option<MaintenanceFlag>,
+    /// Device utilization, absent from servers that do not expose it.
+    #[serde(default)]
+    pub resource_usage: Option<ResourceUsage>,
+    /// Notification callbacks, absent from servers that cannot notify job completion.
+    #[serde(default)]
+    pub notifications: Option<NotificationSupport>,
+    /// Push channels for job status, absent from servers that can only be polled.
+    #[serde(default)]
+    pub streaming: Option<StreamingSupport>,
+    /// Whether the server gives the status of several jobs in one request.
+    #[serde(default)]
+    pub batch_job_status: bool,
+    /// Optional features, absent from servers that do not advertise them.
+    #[serde(default)]
+    pub features: Option<ServerFeatures>,
+}
+
+/// Optional features the server advertises; `None` when it does not say.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct ServerFeatures {
+    #[serde(default)]
+    pub diarization: Option<bool>,
+    #[serde(default)]
+    pub cancellation: Option<bool>,
+    /// Whether the server lists the models it can run.
+    #[serde(default)]
+    pub models: Option<bool>,
+}
+
+/// Push channels the server offers for job status.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct StreamingSupport {
+    /// Server-Sent Events at the status URL of a job, followed by `/events`.
+    #[serde(default)]
+    pub sse: bool,
+}
+
+impl ApiStatus {
+    /// The maintenance in progress, if the server reports one.
+    pub fn maintenance_info(&self) -> Option<MaintenanceInfo> {
+        self.maintenance
+            .clone()
+            .and_then(MaintenanceFlag::into_info)
+    }
+}
+
+/// Notification callbacks the server can register along with a submission.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct NotificationSupport {
+    #[serde(default)]
+    pub email: bool,
+    #[serde(default)]
+    pub webhook: bool,
+}
+
+/// Target the server notifies once a job is finished, registered with its submission.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase", tag = "kind", content = "target")]
+pub enum NotificationTarget {
+    Email(String),
+    Webhook(String),
+}
+
+impl NotificationTarget {
+    /// Parses a target entered in the settings: an HTTP(S) URL is a webhook,
+    /// anything else containing an `@` an email address.
+    pub fn parse(target: &str) -> Option<Self> {
+        let target = target.trim();
+        if target.starts_with("https://") || target.starts_with("http://") {
+            Some(NotificationTarget::Webhook(target.to_string()))
+        } else if target.contains('@') && !target.contains(char::is_whitespace) {
+            Some(NotificationTarget::Email(target.to_string()))
+        } else {
+            None
+        }
+    }
+
+    /// Whether the server can notify this kind of target.
+    pub fn is_supported_by(&self, support: &NotificationSupport) -> bool {
+        match self {
+            NotificationTarget::Email(_) => support.email,
+            NotificationTarget::Webhook(_) => support.webhook,
+        }
+    }
+
+    /// The form field registering the target, and its value.
+    fn form_field(&self) -> (&'static str, &str) {
+        match self {
+            NotificationTarget::Email(address) => ("notify_email", address),
+            NotificationTarget::Webhook(url) => ("callback_url", url),
+        }
+    }
+}
+
+impl std::fmt::Display for NotificationTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationTarget::Email(address) => write!(f, "Email to {}", address),
+            NotificationTarget::Webhook(url) => write!(f, "Webhook {}", url),
+        }
+    }
+}
+
+/// Device utilization reported by the server, in percent.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct ResourceUsage {
+    #[serde(default)]
+    pub cpu_percent: Option<f64>,
+    #[serde(default)]
+    pub memory_percent: Option<f64>,
+    #[serde(default)]
+    pub gpu_percent: Option<f64>,
+    #[serde(default)]
+    pub gpu_memory_percent: Option<f64>,
+}
+
+impl ResourceUsage {
+    /// The reported measures, labelled, in display order.
+    pub fn gauges(&self) -> Vec<(&'static str, f64)> {
+        [
+            ("GPU", self.gpu_percent),
+            ("GPU memory", self.gpu_memory_percent),
+            ("CPU", self.cpu_percent),
+            ("Memory", self.memory_percent),
+        ]
+        .into_iter()
+        .filter_map(|(label, value)| value.map(|value| (label, value.clamp(0.0, 100.0))))
+        .collect()
+    }
+}
+
+/// Maintenance flag of the API status: either a plain boolean or a detailed object.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum MaintenanceFlag {
+    Enabled(bool),
+    Details(MaintenanceInfo),
+}
+
+impl MaintenanceFlag {
+    /// The maintenance details, if maintenance is in progress.
+    pub fn into_info(self) -> Option<MaintenanceInfo> {
+        match self {
+            MaintenanceFlag::Enabled(true) => Some(MaintenanceInfo::default()),
+            MaintenanceFlag::Enabled(false) => None,
+            MaintenanceFlag::Details(info) => Some(info),
+        }
+    }
+}
+
+/// Details of a maintenance advertised by the server.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct MaintenanceInfo {
+    /// Explanation given by the server.
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Advertised end of the maintenance, as sent by the server (usually RFC 3339).
+    #[serde(default, alias = "until", alias = "end_time")]
+    pub ends_at: Option<String>,
+}
+
+/// Body of a 503 response sent during maintenance. The details may be nested under
+/// `maintenance` or sit next to a `"maintenance": true` flag.
+#[derive(Deserialize)]
+struct MaintenanceBody {
+    maintenance: MaintenanceFlag,
+    #[serde(flatten)]
+    details: MaintenanceInfo,
+}
+
+/// Builds the error for a failed response. A 503 whose body describes a maintenance
+/// is reported as such rather than as a server failure.
+fn http_error(status: u16, text: String) -> ApiError {
+    if status == 503 {
+        if let Ok(body) = serde_json::from_str::<MaintenanceBody>(&text) {
+            match body.maintenance {
+                MaintenanceFlag::Enabled(true) => return ApiError::Maintenance(body.details),
+                MaintenanceFlag::Details(info) => return ApiError::Maintenance(info),
+                MaintenanceFlag::Enabled(false) => {}
+            }
+        }
+    }
+    ApiError::HttpError(status, text)
 }
 
 /// Server configuration section of the API status response
@@ -148,28 +659,38 @@ pub struct QueueState {
     pub processing_jobs: u32,
 }
 
-/// Fetches the API status from the server.
-pub async fn get_status(api_url: &str) -> Result<ApiStatus, ApiError> {
+/// Fetches the API status from the server, retrying transient failures.
+pub async fn get_status(api_url: &str, profile: &EndpointProfile) -> Result<ApiStatus, ApiError> {
+    with_retry(RetryPolicy::default(), || fetch_status(api_url, profile)).await
+}
+
+async fn fetch_status(api_url: &str, profile: &EndpointProfile) -> Result<ApiStatus, ApiError> {
     if api_url.is_empty() {
         warn!("API URL is empty, cannot check status");
-        return Err(ApiError::RequestFailed(
-            "API URL is not configured".to_string(),
-        ));
+        return Err(ApiError::NotConfigured);
     }
 
     let url = format!("{}{}", api_url, API_STATUS_ENDPOINT);
     info!("Fetching API status from: {}", url);
 
-    let response = Request::get(&url).send().await?;
+    let response = http::send_with_clock(http::get(&url, profile)).await?;
+
+    // A web page instead of the API is a common configuration mistake:
+    // report it explicitly so that corrections can be suggested.
+    let content_type = response.headers().get("content-type").unwrap_or_default();
+    if content_type.contains("text/html") {
+        warn!("API status URL returned an HTML page: {}", url);
+        return Err(ApiError::NotAnApi(content_type));
+    }
 
     if !response.ok() {
         let status = response.status();
         let text = response.text().await.unwrap_or_default();
         error!("API returned error status {}: {}", status, text);
-        return Err(ApiError::HttpError(status, text));
+        return Err(http_error(status, text));
     }
 
-    let status: ApiStatus = response.json().await?;
+    let status: ApiStatus = parse_json(response).await?;
 
     info!(
         "API status parsed successfully: {:?} jobs queued, {:?} jobs processing",
@@ -179,15 +700,25 @@ pub async fn get_status(api_url: &str) -> Result<ApiStatus, ApiError> {
 }
 
 /// Submits an audio file for asynchronous transcription.
+/// The extra form fields of the endpoint profile are appended to the form data.
+/// `sha256` is the checksum of the file when already known, computed here otherwise.
+/// `on_progress` receives the number of bytes sent and the total size of the upload.
 pub async fn submit_transcription(
     api_url: &str,
+    profile: &EndpointProfile,
     file_engine: &Arc<dyn FileEngine>,
+    sha256: Option<String>,
+    options: JobOptions,
+    on_progress: impl Fn(u64, u64) + 'static,
 ) -> Result<TranscriptionJob, ApiError> {
     if api_url.is_empty() {
-        return Err(ApiError::RequestFailed(
-            "API URL is not configured".to_string(),
-        ));
+        return Err(ApiError::NotConfigured);
     }
+    let JobOptions {
+        output_format,
+        diarization,
+        notification,
+    } = options;
 
     let file_name = file_engine
         .files()
@@ -195,64 +726,242 @@ pub async fn submit_transcription(
         .cloned()
         .ok_or(ApiError::FileNotAvailable)?;
 
-    let file_bytes = file_engine
-        .read_file(&file_name)
+    // The native file is appended as is, so the browser streams it from disk rather
+    // than through copies in WASM memory. Engines without a native file are read in full.
+    let native_file = file_engine
+        .get_native_file(&file_name)
         .await
-        .ok_or(ApiError::FileNotAvailable)?;
-
-    // Create a JS-compatible byte array (Uint8Array) from the Rust byte slice.
-    let uint8_array = Uint8Array::from(file_bytes.as_slice());
+        .and_then(|file| file.downcast::<web_sys::File>().ok());
+    let blob: Blob = match native_file {
+        Some(file) => (*file).into(),
+        None => {
+            let file_bytes = file_engine
+                .read_file(&file_name)
+                .await
+                .ok_or(ApiError::FileNotAvailable)?;
+            // The web_sys::Blob constructor needs a sequence (JS Array) of blob parts.
+            let array = Array::new();
+            array.push(&Uint8Array::from(file_bytes.as_slice()).into());
+            Blob::new_with_blob_sequence(&array)?
+        }
+    };
 
-    // The web_sys::Blob constructor needs a sequence (JS Array) of blob parts.
-    let array = Array::new();
-    array.push(&uint8_array.into());
-    let blob = Blob::new_with_blob_sequence(&array)?;
+    // The checksum is always kept locally, to recognize resubmissions of the same file.
+    let sha256 = match sha256 {
+        Some(sha256) => Some(sha256),
+        None => checksum::file_sha256_hex(file_engine).await,
+    };
+    let send_checksum = profile.send_checksum && sha256.is_some();
 
     // --- Correctly structure the form data ---
-    let params = TranscriptionParams { sync: false };
+    // Speaker counts only apply to diarization.
+    let speakers = |count: Option<u32>| count.filter(|_| diarization.enabled);
+    let params = TranscriptionParams {
+        sync: false,
+        output_format,
+        diarize: diarization.enabled,
+        min_speakers: speakers(diarization.min_speakers),
+        max_speakers: speakers(diarization.max_speakers),
+    };
     let params_json = serde_json::to_string(&params)?;
+    let sent_params = params.entries();
 
     let form_data = FormData::new()?;
     form_data.append_with_blob_and_filename("file", &blob, &file_name)?;
     form_data.append_with_str("params", &params_json)?;
     // --- End of correction ---
 
+    let extra_form_fields = profile.sendable_form_fields();
+    for (key, value) in &extra_form_fields {
+        form_data.append_with_str(key, value)?;
+    }
+    if let Some(target) = &notification {
+        let (key, value) = target.form_field();
+        form_data.append_with_str(key, value)?;
+    }
+
     let url = format!("{}{}", api_url, API_TRANSCRIPTION_ENDPOINT);
     info!("Submitting transcription to: {}", url);
 
-    let response = Request::post(&url)
-        .header("Accept", "application/json")
-        .body(form_data)?
-        .send()
-        .await?;
+    let mut headers = vec![("Accept", "application/json")];
+    if let Some(sha256) = sha256.as_deref().filter(|_| send_checksum) {
+        headers.push((CHECKSUM_HEADER, sha256));
+    }
+    let (status, text) =
+        http::send_form_with_progress(&url, profile, &headers, form_data, on_progress).await?;
+
+    if !(200..300).contains(&status) {
+        return Err(http_error(status, text));
+    }
+
+    let mut job: TranscriptionJob = parse_json_text(&text)?;
+    job.extra_form_fields = extra_form_fields;
+    job.file_name = Some(file_name);
+    job.sha256 = sha256;
+    job.checksum_sent = send_checksum;
+    job.submitted_at = Some(Utc::now());
+    job.output_format = Some(output_format);
+    job.params = sent_params;
+    job.notification = notification;
+    info!("Transcription job submitted successfully: {:?}", job);
+    Ok(job)
+}
+
+/// Probes the effective upload limit of the transcription endpoint, which a reverse
+/// proxy may set below the API's advertised `max_file_size`.
+/// Bodies of increasing sizes are posted without any audio file, so the API itself
+/// rejects them (e.g. with 400); a 413 or a dropped connection reveals a proxy limit.
+/// Any other answer (authorization, server or proxy errors) tells nothing of the
+/// limit: the probe stops with an error.
+pub async fn probe_upload_limit(
+    api_url: &str,
+    profile: &EndpointProfile,
+    sizes: &[u64],
+) -> Result<UploadLimitProbe, ApiError> {
+    if api_url.is_empty() {
+        return Err(ApiError::NotConfigured);
+    }
+
+    let url = format!("{}{}", api_url, API_TRANSCRIPTION_ENDPOINT);
+    let mut probe = UploadLimitProbe {
+        accepted_up_to: None,
+        rejected_at: None,
+        probed_at: Utc::now(),
+    };
+
+    for &size in sizes {
+        info!("Probing upload limit of {} with {} bytes", url, size);
+        let array = Array::new();
+        array.push(&Uint8Array::new_with_length(size as u32).into());
+        let form_data = FormData::new()?;
+        form_data.append_with_blob("probe", &Blob::new_with_blob_sequence(&array)?)?;
+
+        match http::send_body(http::post(&url, profile), form_data).await {
+            Ok(response) if response.status() == 413 => {
+                probe.rejected_at = Some(size);
+                break;
+            }
+            // The body reached the API, which answered about its content.
+            Ok(response) if response.ok() || matches!(response.status(), 400 | 422) => {
+                probe.accepted_up_to = Some(size)
+            }
+            Ok(response) => {
+                let status = response.status();
+                let text = response.text().await.unwrap_or_default();
+                warn!(
+                    "Upload limit probe stopped by an unexpected status {}",
+                    status
+                );
+                return Err(http_error(status, text));
+            }
+            // Some proxies drop the connection instead of answering 413.
+            Err(_) if probe.accepted_up_to.is_some() => {
+                probe.rejected_at = Some(size);
+                break;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    info!("Upload limit probe result: {:?}", probe);
+    Ok(probe)
+}
+
+/// Transcription quality feedback, as posted to a feedback endpoint.
+#[derive(Debug, Serialize)]
+pub struct FeedbackReport<'a> {
+    pub job_id: &'a str,
+    pub rating: u8,
+    pub comment: &'a str,
+}
+
+/// Posts transcription quality feedback to the feedback endpoint of the profile.
+/// The endpoint may belong to a third party, so the request carries none of the
+/// credentials or custom headers of the profile.
+pub async fn send_feedback(
+    feedback_url: &str,
+    report: &FeedbackReport<'_>,
+) -> Result<(), ApiError> {
+    info!(
+        "Sending feedback on job {} to: {}",
+        report.job_id, feedback_url
+    );
+    let body = serde_json::to_string(report)?;
+    let response = http::send_body(
+        http::post_external(feedback_url).header("Content-Type", "application/json"),
+        body,
+    )
+    .await?;
 
     if !response.ok() {
         let status = response.status();
         let text = response.text().await.unwrap_or_default();
-        return Err(ApiError::HttpError(status, text));
+        return Err(http_error(status, text));
     }
+    Ok(())
+}
 
-    let job: TranscriptionJob = response.json().await?;
-    info!("Transcription job submitted successfully: {:?}", job);
-    Ok(job)
+/// Fetches the Prometheus metrics exposed at `metrics_url`, as text.
+/// The options of the profile are only sent when the metrics are served from the
+/// origin of the API: an exporter elsewhere gets neither credentials nor custom headers.
+pub async fn get_metrics(
+    metrics_url: &str,
+    api_url: &str,
+    profile: &EndpointProfile,
+) -> Result<String, ApiError> {
+    info!("Fetching metrics from: {}", metrics_url);
+    let request = if http::same_origin(metrics_url, api_url) {
+        http::get(metrics_url, profile)
+    } else {
+        http::get_external(metrics_url)
+    };
+    let response = http::send(request.header("Accept", "text/plain")).await?;
+
+    if !response.ok() {
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        error!(
+            "Metrics endpoint returned error status {}: {}",
+            status, text
+        );
+        return Err(http_error(status, text));
+    }
+    Ok(response.text().await?)
+}
+
+/// Returns the URL of the status endpoint of a job.
+pub fn job_status_url(api_url: &str, job_id: &str) -> String {
+    format!("{}/transcription/{}", api_url, job_id)
+}
+
+/// Fetches the status of a specific transcription job from the API, retrying transient failures.
+pub async fn get_job_status(
+    api_url: &str,
+    profile: &EndpointProfile,
+    job_id: &str,
+) -> Result<JobState, ApiError> {
+    with_retry(RetryPolicy::default(), || {
+        fetch_job_status(api_url, profile, job_id)
+    })
+    .await
 }
 
-/// Fetches the status of a specific transcription job from the API.
-pub async fn get_job_status(api_url: &str, job_id: &str) -> Result<JobState, ApiError> {
+async fn fetch_job_status(
+    api_url: &str,
+    profile: &EndpointProfile,
+    job_id: &str,
+) -> Result<JobState, ApiError> {
     if api_url.is_empty() {
         warn!("API URL is empty, cannot check job status");
-        return Err(ApiError::RequestFailed(
-            "API URL is not configured".to_string(),
-        ));
+        return Err(ApiError::NotConfigured);
     }
 
-    let url = format!("{}/transcription/{}", api_url, job_id);
+    let url = job_status_url(api_url, job_id);
     info!("Fetching job status from: {}", url);
 
-    let response = Request::get(&url)
-        .header("Accept", "application/json")
-        .send()
-        .await?;
+    let response =
+        http::send_with_clock(http::get(&url, profile).header("Accept", "application/json"))
+            .await?;
 
     if !response.ok() {
         let status = response.status();
@@ -261,10 +970,10 @@ pub async fn get_job_status(api_url: &str, job_id: &str) -> Result<JobState, Api
             "API returned error status {} for job {}: {}",
             status, job_id, text
         );
-        return Err(ApiError::HttpError(status, text));
+        return Err(http_error(status, text));
     }
 
-    let state: JobState = response.json().await?;
+    let state: JobState = parse_json(response).await?;
 
     info!(
         "Successfully parsed status for job {}: {:?}",
@@ -272,3 +981,89 @@ pub async fn get_job_status(api_url: &str, job_id: &str) -> Result<JobState, Api
     );
     Ok(state)
 }
+
+/// Answer of the batch status endpoint: the state of each known job, by job id.
+#[derive(Debug, Deserialize)]
+struct BatchJobStatus {
+    #[serde(default)]
+    jobs: HashMap<String, JobState>,
+}
+
+/// Fetches the status of several jobs in one request, retrying transient failures.
+/// Jobs unknown to the server are missing from the returned states.
+pub async fn get_jobs_status(
+    api_url: &str,
+    profile: &EndpointProfile,
+    job_ids: &[String],
+) -> Result<HashMap<String, JobState>, ApiError> {
+    with_retry(RetryPolicy::default(), || {
+        fetch_jobs_status(api_url, profile, job_ids)
+    })
+    .await
+}
+
+async fn fetch_jobs_status(
+    api_url: &str,
+    profile: &EndpointProfile,
+    job_ids: &[String],
+) -> Result<HashMap<String, JobState>, ApiError> {
+    if api_url.is_empty() {
+        warn!("API URL is empty, cannot check job status");
+        return Err(ApiError::NotConfigured);
+    }
+
+    let url = format!("{}{}", api_url, BATCH_JOB_STATUS_PATH);
+    info!(
+        "Fetching the status of {} jobs from: {}",
+        job_ids.len(),
+        url
+    );
+
+    let request = http::get(&url, profile)
+        .query([("ids", job_ids.join(","))])
+        .header("Accept", "application/json");
+    let response = http::send_with_clock(request).await?;
+
+    if !response.ok() {
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        error!(
+            "API returned error status {} for the batch status: {}",
+            status, text
+        );
+        return Err(http_error(status, text));
+    }
+
+    let batch: BatchJobStatus = parse_json(response).await?;
+    Ok(batch.jobs)
+}
+
+/// Cancels a queued or processing transcription job, through the DELETE method
+/// of its status endpoint.
+pub async fn cancel_job(
+    api_url: &str,
+    profile: &EndpointProfile,
+    job_id: &str,
+) -> Result<(), ApiError> {
+    if api_url.is_empty() {
+        warn!("API URL is empty, cannot cancel job");
+        return Err(ApiError::NotConfigured);
+    }
+
+    let url = job_status_url(api_url, job_id);
+    info!("Cancelling job {} at: {}", job_id, url);
+
+    let response = http::send(http::delete(&url, profile)).await?;
+    if !response.ok() {
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        error!(
+            "API returned error status {} while cancelling job {}: {}",
+            status, job_id, text
+        );
+        return Err(http_error(status, text));
+    }
+
+    info!("Job {} cancelled", job_id);
+    Ok(())
+}
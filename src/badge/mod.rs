@@ -0,0 +1,27 @@
+//! Application badge
+//! Shows a count on the icon of the installed application with the App Badging API,
+//! where the browser supports it. Elsewhere, setting the badge does nothing.
+
+use crate::document::eval;
+use log::warn;
+
+/// Sets the badge to `count`, or clears it when `count` is zero.
+pub fn set(count: usize) {
+    let badge = eval(
+        r#"
+        const count = await dioxus.recv();
+        if ("setAppBadge" in navigator) {
+            try {
+                if (count > 0) {
+                    await navigator.setAppBadge(count);
+                } else {
+                    await navigator.clearAppBadge();
+                }
+            } catch (e) {}
+        }
+        "#,
+    );
+    if let Err(e) = badge.send(count) {
+        warn!("Failed to send the count to the badge script: {:?}", e);
+    }
+}
@@ -0,0 +1,50 @@
+//! Content checksums
+//! Hashes uploaded content with the browser's Web Crypto API, which is
+//! asynchronous and does not block the interface on large files.
+
+use crate::dioxus_elements::FileEngine;
+use log::warn;
+use std::sync::Arc;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::js_sys::{ArrayBuffer, Object, Promise, Uint8Array};
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+
+/// Returns the SHA-256 digest of `data`, hex-encoded, or `None` when Web Crypto
+/// is unavailable (e.g. on pages not served over HTTPS).
+pub async fn sha256_hex(data: &[u8]) -> Option<String> {
+    digest_hex(|subtle| subtle.digest_with_str_and_u8_array("SHA-256", data)).await
+}
+
+/// Returns the SHA-256 digest of the first file of the file engine, hex-encoded.
+/// The file is read into a browser buffer rather than into WASM memory when the
+/// engine exposes the native file, which matters for large recordings.
+pub async fn file_sha256_hex(file_engine: &Arc<dyn FileEngine>) -> Option<String> {
+    let file_name = file_engine.files().first().cloned()?;
+    let native_file = file_engine
+        .get_native_file(&file_name)
+        .await
+        .and_then(|file| file.downcast::<web_sys::File>().ok());
+    let Some(file) = native_file else {
+        let bytes = file_engine.read_file(&file_name).await?;
+        return sha256_hex(&bytes).await;
+    };
+    let buffer = JsFuture::from(file.array_buffer()).await.ok()?;
+    let buffer = buffer.dyn_into::<Object>().ok()?;
+    digest_hex(|subtle| subtle.digest_with_str_and_buffer_source("SHA-256", &buffer)).await
+}
+
+async fn digest_hex(
+    digest: impl FnOnce(&web_sys::SubtleCrypto) -> Result<Promise, JsValue>,
+) -> Option<String> {
+    let subtle = web_sys::window()?.crypto().ok()?.subtle();
+    let promise = match digest(&subtle) {
+        Ok(promise) => promise,
+        Err(e) => {
+            warn!("SHA-256 digest is unavailable: {:?}", e);
+            return None;
+        }
+    };
+    let digest = JsFuture::from(promise).await.ok()?;
+    let bytes = Uint8Array::new(digest.dyn_ref::<ArrayBuffer>()?).to_vec();
+    Some(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
@@ -0,0 +1,27 @@
+//! Clipboard access for the Leontine application
+//! Wraps the browser's asynchronous Clipboard API.
+
+use crate::document::eval;
+use log::warn;
+
+/// Copies the given text to the system clipboard.
+/// Returns `true` if the browser accepted the write.
+pub async fn copy_text(text: &str) -> bool {
+    let mut copy = eval(
+        r#"
+        const text = await dioxus.recv();
+        try {
+            await navigator.clipboard.writeText(text);
+            dioxus.send(true);
+        } catch (e) {
+            dioxus.send(false);
+        }
+        "#,
+    );
+
+    if let Err(e) = copy.send(text) {
+        warn!("Failed to send text to the clipboard script: {:?}", e);
+        return false;
+    }
+    copy.recv::<bool>().await.unwrap_or(false)
+}
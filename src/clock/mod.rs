@@ -0,0 +1,132 @@
+//! Server clock
+//! Timestamps sent by the server follow its clock, local ones the clock of the browser;
+//! a skew between both shows jobs queued in the future, or expired early. The offset of
+//! the server clock is estimated from the `Date` header of its responses, and applied to
+//! server timestamps before they are compared with local ones or displayed.
+//! Each origin has its own offset, so that checking another server never skews the
+//! times of the API in use.
+//! Cross-origin servers must expose the header (`Access-Control-Expose-Headers: Date`),
+//! otherwise both clocks are assumed to agree.
+
+use crate::api::http;
+use crate::config::CLOCK_SKEW_TOLERANCE_MS;
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// Estimated offset of each server clock from the local one, in milliseconds,
+/// by origin.
+static OFFSETS_MS: GlobalSignal<HashMap<String, i64>> = Signal::global(HashMap::new);
+
+/// Updates the offset of the origin of `url` from the `Date` header of its response,
+/// given when the request was sent and its response received, by the local clock.
+pub fn record_response_date(
+    url: &str,
+    date: &str,
+    sent_at: DateTime<Utc>,
+    received_at: DateTime<Utc>,
+) {
+    let (Some(origin), Some(offset_ms)) = (
+        http::origin(url),
+        estimate_offset_ms(date, sent_at, received_at),
+    ) else {
+        return;
+    };
+    let known_ms = OFFSETS_MS.peek().get(&origin).copied().unwrap_or(0);
+    // Only a significant change is published, to spare renders.
+    if (offset_ms - known_ms).abs() >= CLOCK_SKEW_TOLERANCE_MS {
+        log::info!("Clock offset of {} estimated at {} ms", origin, offset_ms);
+        OFFSETS_MS.write().insert(origin, offset_ms);
+    }
+}
+
+/// Estimates the offset of the server clock from the `Date` header of a response,
+/// taken at the midpoint of the round trip. Round trips longer than the tolerance
+/// leave too much doubt on when the header was written, and give no estimate.
+/// The header has a one-second resolution: offsets within the tolerance count as none.
+fn estimate_offset_ms(
+    date: &str,
+    sent_at: DateTime<Utc>,
+    received_at: DateTime<Utc>,
+) -> Option<i64> {
+    let round_trip = received_at - sent_at;
+    if round_trip.num_milliseconds() > CLOCK_SKEW_TOLERANCE_MS {
+        return None;
+    }
+    let server_time = DateTime::parse_from_rfc2822(date).ok()?;
+    let offset_ms =
+        (server_time.with_timezone(&Utc) - (sent_at + round_trip / 2)).num_milliseconds();
+    Some(if offset_ms.abs() < CLOCK_SKEW_TOLERANCE_MS {
+        0
+    } else {
+        offset_ms
+    })
+}
+
+/// The estimated offset of the clock of the server at `api_url`.
+pub fn offset(api_url: &str) -> Duration {
+    let offset_ms = http::origin(api_url)
+        .and_then(|origin| OFFSETS_MS.read().get(&origin).copied())
+        .unwrap_or(0);
+    Duration::milliseconds(offset_ms)
+}
+
+/// The current time by the clock of the server at `api_url`.
+pub fn server_now(api_url: &str) -> DateTime<Utc> {
+    Utc::now() + offset(api_url)
+}
+
+/// Converts a timestamp of the server at `api_url` to the local clock.
+pub fn to_local(api_url: &str, server_time: DateTime<Utc>) -> DateTime<Utc> {
+    server_time - offset(api_url)
+}
+
+/// Parses a timestamp sent by the server: RFC 3339, or a date and time without
+/// time zone, taken as UTC.
+pub fn parse_server_time(text: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(text)
+        .map(|at| at.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f")
+                .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f"))
+                .ok()
+                .map(|at| at.and_utc())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const DATE: &str = "Sat, 17 Oct 2026 10:00:30 GMT";
+
+    fn at(secs: u32, millis: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 17, 10, 0, secs).unwrap()
+            + Duration::milliseconds(millis as i64)
+    }
+
+    #[test]
+    fn offset_is_taken_at_the_midpoint_of_the_round_trip() {
+        assert_eq!(estimate_offset_ms(DATE, at(20, 0), at(20, 400)), Some(9800));
+        assert_eq!(
+            estimate_offset_ms(DATE, at(40, 0), at(40, 200)),
+            Some(-10100)
+        );
+    }
+
+    #[test]
+    fn small_offsets_count_as_none() {
+        assert_eq!(estimate_offset_ms(DATE, at(29, 500), at(29, 700)), Some(0));
+    }
+
+    #[test]
+    fn long_round_trips_and_invalid_dates_give_no_estimate() {
+        assert_eq!(estimate_offset_ms(DATE, at(0, 0), at(10, 0)), None);
+        assert_eq!(
+            estimate_offset_ms("yesterday", at(20, 0), at(20, 100)),
+            None
+        );
+    }
+}
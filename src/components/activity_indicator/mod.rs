@@ -0,0 +1,25 @@
+//! Activity indicator component
+//! A slim bar at the top of the page, animated while requests to the API are in flight.
+
+use crate::api::http;
+use dioxus::prelude::*;
+
+/// Shows that the application is talking to the server.
+#[component]
+pub fn ActivityIndicator() -> Element {
+    let in_flight = http::in_flight();
+    let title = match in_flight {
+        0 => String::new(),
+        1 => "1 request in progress".to_string(),
+        n => format!("{} requests in progress", n),
+    };
+
+    rsx! {
+        div {
+            class: if in_flight > 0 { "activity-indicator active" } else { "activity-indicator" },
+            role: "progressbar",
+            aria_busy: in_flight > 0,
+            title: "{title}",
+        }
+    }
+}
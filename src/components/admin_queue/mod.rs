@@ -0,0 +1,132 @@
+//! Admin queue panel component
+//! Lists the jobs of the server queue for operators holding an admin token,
+//! with their age and size only: job contents and file names are never shown.
+
+use crate::api::admin::{self, QueuedJob};
+use crate::api::{ApiError, JobStatus};
+use crate::clock;
+use crate::components::api_status::format_elapsed;
+use crate::components::error_message::ErrorMessage;
+use crate::components::toast;
+use crate::errors::{AppError, Severity};
+use crate::i18n::use_translator;
+use crate::media;
+use crate::state::AppState;
+use dioxus::prelude::*;
+
+/// Number of characters of the job IDs shown in the listing.
+const SHORT_ID_LENGTH: usize = 8;
+
+/// The server queue, shown when the endpoint profile holds an admin token
+/// and the server exposes the queue listing.
+#[component]
+pub fn AdminQueuePanel() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+
+    // Runs again whenever the URL or the profiles change.
+    let mut listing = use_resource(move || async move {
+        let api_url = app_state.api_url.get();
+        let profile = app_state.current_profile();
+        profile.admin_token.as_ref()?;
+        Some(admin::list_jobs(&api_url, &profile).await)
+    });
+
+    let jobs = match &*listing.read() {
+        None | Some(None) => return rsx! {},
+        // The server does not expose the queue listing.
+        Some(Some(Err(ApiError::HttpError(404, _)))) => return rsx! {},
+        Some(Some(result)) => result.clone(),
+    };
+
+    let cancel = move |job_id: String| {
+        spawn(async move {
+            let api_url = app_state.api_url.get();
+            let profile = app_state.current_profile();
+            match admin::cancel_job(&api_url, &profile, &job_id).await {
+                Ok(()) => toast::show("Job cancelled.", Severity::Info),
+                Err(err) => {
+                    toast::show_with("Could not cancel the job: {}", &[&err], Severity::Error)
+                }
+            }
+            listing.restart();
+        });
+    };
+
+    rsx! {
+        div {
+            class: "admin-queue",
+            h2 { {i18n.t("Server Queue")} }
+            button { onclick: move |_| listing.restart(), {i18n.t("Refresh")} }
+            match jobs {
+                Err(err) => rsx! {
+                    ErrorMessage { error: AppError::from(err) }
+                },
+                Ok(jobs) if jobs.is_empty() => rsx! {
+                    p { {i18n.t("The queue is empty.")} }
+                },
+                Ok(jobs) => rsx! {
+                    table {
+                        thead {
+                            tr {
+                                th { {i18n.t("Job")} }
+                                th { {i18n.t("Status")} }
+                                th { {i18n.t("Age")} }
+                                th { {i18n.t("Size")} }
+                                th {}
+                            }
+                        }
+                        tbody {
+                            for job in jobs {
+                                QueuedJobRow {
+                                    key: "{job.job_id}",
+                                    job: job.clone(),
+                                    oncancel: cancel,
+                                }
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// A row of the queue listing.
+#[component]
+fn QueuedJobRow(job: QueuedJob, oncancel: EventHandler<String>) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let short_id: String = job.job_id.chars().take(SHORT_ID_LENGTH).collect();
+    let status = match &job.status {
+        JobStatus::Queued => i18n.t("Queued"),
+        JobStatus::Processing => i18n.t("Processing"),
+        JobStatus::Completed => i18n.t("Completed"),
+        JobStatus::Failed => i18n.t("Failed"),
+        JobStatus::Unknown(status) => status.as_str(),
+    };
+    let age = job
+        .created_at
+        .map(|at| format_elapsed(i18n, at, clock::server_now(&app_state.api_url.get())))
+        .unwrap_or_default();
+    let size = job.file_size.map(media::format_size).unwrap_or_default();
+    let cancellable = matches!(job.status, JobStatus::Queued | JobStatus::Processing);
+    let job_id = job.job_id.clone();
+
+    rsx! {
+        tr {
+            td { code { title: "{job.job_id}", "{short_id}" } }
+            td { "{status}" }
+            td { "{age}" }
+            td { "{size}" }
+            td {
+                if cancellable {
+                    button {
+                        onclick: move |_| oncancel.call(job_id.clone()),
+                        {i18n.t("Cancel")}
+                    }
+                }
+            }
+        }
+    }
+}
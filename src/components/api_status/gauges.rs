@@ -0,0 +1,38 @@
+//! Resource gauges
+//! Shows the device utilization reported by the server, which helps understand slow processing.
+
+use crate::api::ResourceUsage;
+use crate::i18n::use_translator;
+use dioxus::prelude::*;
+
+/// Small gauges of the GPU, CPU and memory usage of the server.
+#[component]
+pub fn ResourceGauges(usage: ResourceUsage) -> Element {
+    let i18n = use_translator();
+    let gauges = usage.gauges();
+    if gauges.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "resource-gauges",
+            for (label, value) in gauges {
+                div {
+                    key: "{label}",
+                    class: "resource-gauge",
+                    span { class: "gauge-label", {i18n.t(label)} }
+                    meter {
+                        min: "0",
+                        max: "100",
+                        low: "70",
+                        high: "90",
+                        optimum: "0",
+                        value: "{value}",
+                    }
+                    span { class: "gauge-value", "{value:.0}%" }
+                }
+            }
+        }
+    }
+}
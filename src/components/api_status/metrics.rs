@@ -0,0 +1,122 @@
+//! Metrics dashboard
+//! Scrapes the Prometheus metrics endpoint of the profile and draws the trends
+//! of the queue length and of the job durations.
+
+use crate::api::{self, ApiError};
+use crate::i18n::use_translator;
+use crate::media;
+use crate::metrics::{self, Snapshot};
+use crate::state::AppState;
+use chrono::Utc;
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+
+/// Trends of the server metrics, shown when the profile has a metrics endpoint.
+#[component]
+pub fn MetricsDashboard() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut trend = use_signal(Vec::<Snapshot>::new);
+    let mut last_error = use_signal(|| None::<ApiError>);
+    let mut scraper: Signal<Option<Task>> = use_signal(|| None);
+    let metrics_url = use_memo(move || app_state.current_profile().metrics_url);
+
+    // Restart scraping from an empty trend whenever the endpoint changes.
+    use_effect(move || {
+        let url = metrics_url();
+        if let Some(task) = scraper.write().take() {
+            task.cancel();
+        }
+        trend.write().clear();
+        last_error.set(None);
+        let Some(url) = url else {
+            return;
+        };
+        let task = spawn(async move {
+            loop {
+                match api::get_metrics(&url, &app_state.api_url.get(), &app_state.current_profile())
+                    .await
+                {
+                    Ok(text) => {
+                        let snapshot = Snapshot::from_samples(&metrics::parse(&text), Utc::now());
+                        metrics::push(&mut trend.write(), snapshot);
+                        last_error.set(None);
+                    }
+                    Err(err) => last_error.set(Some(err)),
+                }
+                TimeoutFuture::new(app_state.status_check_interval_ms()).await;
+            }
+        });
+        scraper.set(Some(task));
+    });
+
+    if metrics_url().is_none() {
+        return rsx! {};
+    }
+
+    let trend = trend.read();
+    let queue_lengths: Vec<f64> = trend.iter().filter_map(|s| s.queue_length).collect();
+    let durations: Vec<f64> = trend
+        .iter()
+        .enumerate()
+        .filter_map(|(i, s)| s.mean_job_duration(i.checked_sub(1).map(|i| &trend[i])))
+        .collect();
+
+    rsx! {
+        div {
+            class: "metrics-dashboard",
+            if let Some(err) = last_error() {
+                p { class: "timestamp", {i18n.format("Metrics unavailable: {}", &[&err])} }
+            }
+            if let Some(current) = queue_lengths.last() {
+                MetricTrend {
+                    label: "Queue length",
+                    current: format!("{current:.0}"),
+                    values: queue_lengths.clone(),
+                }
+            }
+            if let Some(current) = durations.last() {
+                MetricTrend {
+                    label: "Mean job duration",
+                    current: media::format_duration(*current),
+                    values: durations.clone(),
+                }
+            }
+        }
+    }
+}
+
+/// A metric with its current value and a sparkline of its recent values.
+#[component]
+fn MetricTrend(label: &'static str, current: String, values: Vec<f64>) -> Element {
+    let i18n = use_translator();
+    let max = values.iter().copied().fold(0.0, f64::max);
+    let step = 100.0 / (values.len().max(2) - 1) as f64;
+    let points = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let y = if max > 0.0 {
+                24.0 - value / max * 22.0
+            } else {
+                23.0
+            };
+            format!("{:.1},{:.1}", i as f64 * step, y)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    rsx! {
+        div {
+            class: "metric-trend",
+            span { class: "gauge-label", {i18n.t(label)} }
+            svg {
+                class: "sparkline",
+                view_box: "0 0 100 24",
+                preserve_aspect_ratio: "none",
+                polyline { points: "{points}" }
+            }
+            span { class: "gauge-value", "{current}" }
+        }
+    }
+}
@@ -1,39 +1,87 @@
 //! API Status display component.
 //! This component renders the API status based on the shared `ApiConnectionStatus` state.
 
+mod gauges;
+mod metrics;
+
+use crate::components::copy_button::CopyButton;
+use crate::components::error_message::ErrorMessage;
+use crate::embed::{self, EmbedView};
+use crate::errors::AppError;
+use crate::i18n::{use_translator, Translator};
+use crate::services::status_monitor::use_api_status;
 use crate::state::{ApiConnectionStatus, AppState};
+use chrono::{DateTime, Utc};
 use dioxus::prelude::*;
+use gauges::ResourceGauges;
+use metrics::MetricsDashboard;
 
 /// A component to display the API status. It gets its data from the shared context.
 #[component]
 #[allow(non_snake_case)]
 pub fn ApiStatusDisplay() -> Element {
     let app_state = use_context::<AppState>();
-    // Read the connection status from the global state.
-    let connection_status = app_state.api_connection_status.read();
+    let i18n = use_translator();
+    // Subscribe to the connection status checked by the status monitor.
+    let connection_status = use_api_status();
+    let connection_status = connection_status.read();
 
     // Determine the display properties based on the current connection status.
-    let (flag_color, status_message, queue_info, last_checked) = match &*connection_status {
+    let (flag_color, status_message, queue_info, last_checked, error) = match &*connection_status {
         ApiConnectionStatus::Available(status, timestamp) => (
             "green",
-            "API Online".to_string(),
-            format!(
+            i18n.t("API Online"),
+            i18n.format(
+                "{} jobs in queue, {} jobs processing",
+                &[
+                    &status.queue_state.queued_jobs,
+                    &status.queue_state.processing_jobs,
+                ],
+            ),
+            i18n.format("Last checked at {}", &[&timestamp.format("%H:%M:%S")]),
+            None,
+        ),
+        ApiConnectionStatus::Stale {
+            status,
+            last_success,
+            error,
+            failures,
+        } => (
+            "amber",
+            i18n.t("API Not Responding"),
+            i18n.format(
                 "{} jobs in queue, {} jobs processing",
-                status.queue_state.queued_jobs, status.queue_state.processing_jobs
+                &[
+                    &status.queue_state.queued_jobs,
+                    &status.queue_state.processing_jobs,
+                ],
+            ),
+            i18n.format(
+                "Last successful check {} ({} failed since) — data may be stale",
+                &[&format_elapsed(i18n, *last_success, Utc::now()), failures],
             ),
-            format!("Last checked at {}", timestamp.format("%H:%M:%S")),
+            Some(AppError::from(error.clone())),
         ),
         ApiConnectionStatus::Unavailable(err, timestamp) => (
             "red",
-            err.to_string(),
+            i18n.t("API Unavailable"),
+            "".to_string(),
+            i18n.format("Last check failed at {}", &[&timestamp.format("%H:%M:%S")]),
+            Some(AppError::from(err.clone())),
+        ),
+        ApiConnectionStatus::Maintenance(_, timestamp) => (
+            "amber",
+            i18n.t("API Under Maintenance"),
             "".to_string(),
-            format!("Last check failed at {}", timestamp.format("%H:%M:%S")),
+            i18n.format("Last checked at {}", &[&timestamp.format("%H:%M:%S")]),
+            None,
         ),
         ApiConnectionStatus::Pending => (
             "yellow",
-            "Checking API status...".to_string(),
+            i18n.t("Checking API status..."),
             "".to_string(),
             "".to_string(), // No timestamp when pending
+            None,
         ),
     };
 
@@ -53,6 +101,74 @@ pub fn ApiStatusDisplay() -> Element {
                     }
                 }
             }
+            if connection_status.api_status().is_some() {
+                div {
+                    class: "capabilities",
+                    {i18n.t("Features: ")}
+                    {
+                        app_state
+                            .capabilities
+                            .read()
+                            .labels()
+                            .into_iter()
+                            .map(|label| i18n.t(label))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    }
+                }
+            }
+            if let Some(usage) = connection_status.api_status().and_then(|status| status.resource_usage.clone()) {
+                ResourceGauges { usage }
+            }
+            MetricsDashboard {}
+            if let Some(error) = error {
+                ErrorMessage { error }
+            }
+            EmbedSnippet { api_url: app_state.api_url.get() }
         }
     }
 }
+
+/// The HTML snippets embedding the status badge of the API, or the uploader, in another page.
+#[component]
+fn EmbedSnippet(api_url: String) -> Element {
+    let i18n = use_translator();
+    let Some(link) = embed::view_link(EmbedView::StatusBadge, &api_url) else {
+        return rsx! {};
+    };
+    let snippet = format!(
+        r#"<iframe src="{}" title="Transcription status" width="360" height="40" style="border: none;"></iframe>"#,
+        link
+    );
+    let upload_snippet = embed::view_link(EmbedView::Upload, &api_url).map(|link| {
+        format!(
+            r#"<iframe src="{}&format=json" title="Transcription" width="640" height="480" style="border: none;"></iframe>"#,
+            link
+        )
+    });
+
+    rsx! {
+        details {
+            class: "embed-snippet",
+            summary { {i18n.t("Embed in another page")} }
+            p { class: "probe-note", {i18n.t("A badge with the availability and queue of the API, for a dashboard page:")} }
+            textarea { readonly: true, rows: 3, value: "{snippet}" }
+            CopyButton { text: snippet.clone(), label: "Copy snippet" }
+            if let Some(upload_snippet) = upload_snippet {
+                p { class: "probe-note", {i18n.t("The uploader alone, for another tool (the format, diarize, min_speakers and max_speakers parameters fix the options):")} }
+                textarea { readonly: true, rows: 3, value: "{upload_snippet}" }
+                CopyButton { text: upload_snippet.clone(), label: "Copy snippet" }
+            }
+        }
+    }
+}
+
+/// Formats the time elapsed since `since`, e.g. "4 min ago".
+pub fn format_elapsed(i18n: Translator, since: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let seconds = (now - since).num_seconds().max(0);
+    match seconds {
+        0..60 => i18n.format("{} s ago", &[&seconds]),
+        60..3600 => i18n.format("{} min ago", &[&(seconds / 60)]),
+        _ => i18n.format("{} h ago", &[&(seconds / 3600)]),
+    }
+}
@@ -0,0 +1,115 @@
+//! Bilingual view component
+//! Shows a transcript side by side with another transcription of the same file,
+//! typically its translation, and exports the pair as a two-column document
+//! or as subtitles with both languages.
+
+use crate::history::{self, HistoryEntry};
+use crate::i18n::{use_translator, Translator};
+use crate::media::{data_url, text_data_url};
+use crate::state::AppState;
+use crate::transcript::bilingual::{self, SegmentPair};
+use crate::transcript::{self, TranscriptSegment};
+use dioxus::prelude::*;
+
+/// Number of characters of the job identifiers shown to tell jobs apart.
+const SHORT_ID_LENGTH: usize = 8;
+
+/// Paired view of the segments of a job and of another completed job of the same file.
+/// Renders nothing when the history holds no such job with segments.
+#[component]
+pub fn BilingualView(job_id: String, segments: Vec<TranscriptSegment>) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut partner_id: Signal<Option<String>> = use_signal(|| None);
+
+    let history = app_state.history.get();
+    let partners: Vec<(&HistoryEntry, Vec<TranscriptSegment>)> =
+        history::find_same_file(&history, &job_id)
+            .into_iter()
+            .filter_map(|entry| {
+                let segments = transcript::parse_segments(entry.result.as_deref()?)?;
+                Some((entry, segments))
+            })
+            .collect();
+    let Some((partner, partner_segments)) = partners
+        .iter()
+        .find(|(entry, _)| Some(&entry.job.job_id) == partner_id.read().as_ref())
+        .or(partners.first())
+    else {
+        return rsx! {};
+    };
+
+    let pairs = bilingual::align(&segments, partner_segments);
+    let original_label = job_label(i18n, &job_id, &[]);
+    let partner_label = job_label(i18n, &partner.job.job_id, &partner.job.extra_form_fields);
+    let html_href = data_url(
+        "text/html",
+        &bilingual::to_two_column_html(&pairs, &original_label, &partner_label),
+    );
+    let srt_href = text_data_url(&bilingual::to_interleaved_srt(&pairs));
+
+    rsx! {
+        details {
+            class: "bilingual-view",
+            summary { {i18n.t("Bilingual View")} }
+            label {
+                {i18n.t("Paired with ")}
+                select {
+                    onchange: move |evt| partner_id.set(Some(evt.value())),
+                    for (entry, _) in partners.iter() {
+                        option {
+                            value: "{entry.job.job_id}",
+                            selected: entry.job.job_id == partner.job.job_id,
+                            {job_label(i18n, &entry.job.job_id, &entry.job.extra_form_fields)}
+                        }
+                    }
+                }
+            }
+            table {
+                thead {
+                    tr {
+                        th { {i18n.t("Time")} }
+                        th { "{original_label}" }
+                        th { "{partner_label}" }
+                    }
+                }
+                tbody {
+                    for (index, pair) in pairs.iter().enumerate() {
+                        PairRow { key: "{index}", pair: pair.clone() }
+                    }
+                }
+            }
+            div {
+                class: "action-buttons",
+                a { class: "download-button", href: "{html_href}", download: "{job_id}-bilingual.html", {i18n.t("Download Two-Column Document")} }
+                a { class: "download-button", href: "{srt_href}", download: "{job_id}-bilingual.srt", {i18n.t("Download Bilingual SRT")} }
+            }
+        }
+    }
+}
+
+/// A row of the paired view.
+#[component]
+fn PairRow(pair: SegmentPair) -> Element {
+    rsx! {
+        tr {
+            td { class: "segment-time", "{transcript::format_timestamp(pair.start)}" }
+            td { "{pair.original}" }
+            td { "{pair.translation}" }
+        }
+    }
+}
+
+/// Tells a job apart by its short identifier and the extra fields it was sent with,
+/// such as the task.
+fn job_label(i18n: Translator, job_id: &str, extra_form_fields: &[(String, String)]) -> String {
+    let short_id: String = job_id.chars().take(SHORT_ID_LENGTH).collect();
+    if extra_form_fields.is_empty() {
+        return i18n.format("Job {}", &[&short_id]);
+    }
+    let fields: Vec<String> = extra_form_fields
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+    i18n.format("Job {} ({})", &[&short_id, &fields.join(", ")])
+}
@@ -0,0 +1,95 @@
+//! Capability banner component
+//! Tells the user which of the features they set up the server cannot provide,
+//! and what happens instead, rather than letting a submission fail or silently
+//! ignore an option. The banner can be dismissed until another feature is missing.
+
+use crate::api::NotificationTarget;
+use crate::embed::FixedOptions;
+use crate::i18n::use_translator;
+use crate::state::AppState;
+use dioxus::prelude::*;
+
+/// A feature the user set up, which the server does not support.
+#[derive(Debug, Clone, PartialEq)]
+struct UnsupportedFeature {
+    name: String,
+    consequence: &'static str,
+}
+
+/// Banner listing the features in use that the server does not support.
+#[component]
+pub fn CapabilityBanner() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let fixed_options = try_use_context::<FixedOptions>().unwrap_or_default();
+    // Names of the features listed when the banner was dismissed.
+    let mut dismissed: Signal<Vec<String>> = use_signal(Vec::new);
+
+    if app_state
+        .api_connection_status
+        .read()
+        .api_status()
+        .is_none()
+    {
+        return rsx! {};
+    }
+    let capabilities = app_state.capabilities.read();
+    let mut unsupported = Vec::new();
+
+    let diarization = fixed_options
+        .diarization
+        .unwrap_or_else(|| app_state.diarization.get());
+    if diarization.enabled && !capabilities.diarization {
+        unsupported.push(UnsupportedFeature {
+            name: i18n.t("Speaker diarization").to_string(),
+            consequence: "transcripts will not be labeled with speakers.",
+        });
+    }
+    if let Some(target) = app_state
+        .current_profile()
+        .notification_target
+        .as_deref()
+        .and_then(NotificationTarget::parse)
+        .filter(|target| !target.is_supported_by(&capabilities.notifications))
+    {
+        unsupported.push(UnsupportedFeature {
+            name: i18n.format("Notification ({})", &[&target]),
+            consequence: "the target is not registered with new submissions.",
+        });
+    }
+    if capabilities.cancellation == Some(false) && !app_state.active_jobs.get().is_empty() {
+        unsupported.push(UnsupportedFeature {
+            name: i18n.t("Job cancellation").to_string(),
+            consequence: "jobs run until they finish.",
+        });
+    }
+
+    let names: Vec<String> = unsupported
+        .iter()
+        .map(|feature| feature.name.clone())
+        .collect();
+    if unsupported.is_empty() || names.iter().all(|name| dismissed.read().contains(name)) {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "capability-banner severity-info",
+            strong { {i18n.t("This server does not support some of the features you set up:")} }
+            ul {
+                for feature in unsupported {
+                    li {
+                        key: "{feature.name}",
+                        strong { "{feature.name}" }
+                        ": "
+                        {i18n.t(feature.consequence)}
+                    }
+                }
+            }
+            button {
+                onclick: move |_| dismissed.set(names.clone()),
+                {i18n.t("Dismiss")}
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Channel view component
+//! Shows the transcripts of the two channels of a stereo recording, transcribed
+//! separately, as a single conversation with a speaker per channel, and exports it.
+
+use crate::history::{self, HistoryEntry};
+use crate::i18n::use_translator;
+use crate::media::{data_url, text_data_url};
+use crate::state::AppState;
+use crate::transcript::{self, channels, export, TranscriptSegment};
+use dioxus::prelude::*;
+
+/// The conversation of a channel transcribed by `job_id` with the other channel.
+/// Renders nothing until the other channel is transcribed with segments.
+#[component]
+pub fn ChannelView(job_id: String, segments: Vec<TranscriptSegment>) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let history = app_state.history.get();
+    let Some(entry) = history::find(&history, &job_id) else {
+        return rsx! {};
+    };
+    let Some(channel) = entry.job.channel else {
+        return rsx! {};
+    };
+    let Some((other, other_segments)) =
+        history::find_other_channel(&history, &job_id).and_then(|other: &HistoryEntry| {
+            Some((other, transcript::parse_segments(other.result.as_deref()?)?))
+        })
+    else {
+        return rsx! {};
+    };
+
+    let mut parts = [
+        (channel, segments.as_slice()),
+        (channel.other(), other_segments.as_slice()),
+    ];
+    parts.sort_by_key(|(channel, _)| channel.index());
+    let merged =
+        channels::interleave(&parts.map(|(channel, segments)| (channel.label(), segments)));
+    let turns = transcript::speaker_turns(&merged).unwrap_or_default();
+    let json = transcript::segments_to_json(merged);
+    let title = entry.title();
+    let markdown_href = data_url("text/markdown", &export::markdown(&title, &json, None));
+    let json_href = text_data_url(&json);
+    let base_name = format!("{}-{}", job_id, other.job.job_id);
+
+    rsx! {
+        details {
+            class: "channel-view",
+            summary { {i18n.t("Both Channels")} }
+            p {
+                class: "hint",
+                {
+                    i18n.format(
+                        "The {} channel interleaved with the {} one, by time.",
+                        &[
+                            &i18n.t(channel.label()).to_lowercase(),
+                            &i18n.t(channel.other().label()).to_lowercase(),
+                        ],
+                    )
+                }
+            }
+            div {
+                class: "channel-turns",
+                for (index, turn) in turns.iter().enumerate() {
+                    p {
+                        key: "{index}",
+                        class: "channel-turn",
+                        span { class: "segment-time", "{transcript::format_timestamp(turn.start)} " }
+                        strong { "{turn.speaker}: " }
+                        "{turn.text}"
+                    }
+                }
+            }
+            div {
+                class: "action-buttons",
+                a { class: "download-button", href: "{markdown_href}", download: "{base_name}-channels.md", {i18n.t("Download Conversation")} }
+                a { class: "download-button", href: "{json_href}", download: "{base_name}-channels.json", {i18n.t("Download JSON")} }
+            }
+        }
+    }
+}
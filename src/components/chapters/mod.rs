@@ -0,0 +1,95 @@
+//! Table of contents component
+//! Lists the chapters detected in a transcript, with editable titles, and exports
+//! the transcript as text split into chapters.
+//! Edited titles are kept in the history entry of the job.
+
+use crate::history;
+use crate::i18n::use_translator;
+use crate::media::text_data_url;
+use crate::state::AppState;
+use crate::transcript::chapters::{self, Chapter};
+use crate::transcript::{self, TranscriptSegment};
+use dioxus::prelude::*;
+
+/// The chapters of a transcript; selecting one sends the index of its first segment to `onselect`.
+#[component]
+pub fn TableOfContents(
+    job_id: String,
+    segments: Vec<TranscriptSegment>,
+    onselect: EventHandler<usize>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut include_contents = use_signal(|| true);
+
+    let edited =
+        history::find(&app_state.history.get(), &job_id).and_then(|entry| entry.chapters.clone());
+    let is_edited = edited.is_some();
+    let chapters = edited.unwrap_or_else(|| chapters::detect(&segments));
+    let text_href = text_data_url(&chapters::to_text(&segments, &chapters, include_contents()));
+
+    // Records the chapters of the job, or forgets the edits with `None`.
+    let save = {
+        let job_id = job_id.clone();
+        move |chapters: Option<Vec<Chapter>>| {
+            let mut history = app_state.history;
+            history.set(history::update(&history.get(), &job_id, |entry| {
+                entry.chapters = chapters;
+            }));
+        }
+    };
+
+    rsx! {
+        details {
+            class: "table-of-contents",
+            summary { {i18n.format("Chapters ({})", &[&chapters.len()])} }
+            if chapters.len() < 2 {
+                p { class: "hint", {i18n.t("No topic change detected in this transcript.")} }
+            }
+            ol {
+                for (index, chapter) in chapters.iter().enumerate() {
+                    li {
+                        key: "{index}",
+                        button {
+                            class: "chapter-time",
+                            onclick: {
+                                let first_segment = chapter.first_segment;
+                                move |_| onselect.call(first_segment)
+                            },
+                            "{transcript::format_timestamp(chapter.start)}"
+                        }
+                        input {
+                            r#type: "text",
+                            value: "{chapter.title}",
+                            onchange: {
+                                let chapters = chapters.clone();
+                                let save = save.clone();
+                                move |evt: Event<FormData>| {
+                                    let mut chapters = chapters.clone();
+                                    chapters[index].title = evt.value();
+                                    save(Some(chapters));
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+            div {
+                class: "action-buttons",
+                label {
+                    input {
+                        r#type: "checkbox",
+                        checked: include_contents(),
+                        onchange: move |evt| include_contents.set(evt.checked()),
+                    }
+                    " "
+                    {i18n.t("Include table of contents")}
+                }
+                if is_edited {
+                    button { onclick: move |_| save(None), {i18n.t("Restore Detected Titles")} }
+                }
+                a { class: "download-button", href: "{text_href}", download: "{job_id}.txt", {i18n.t("Download Text")} }
+            }
+        }
+    }
+}
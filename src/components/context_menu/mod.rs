@@ -0,0 +1,120 @@
+//! Context menu component
+//! Opens a menu of actions on right-click, or on a long press on touch screens,
+//! over any content wrapped in a `ContextMenuArea`. The owner of the content
+//! decides which actions are offered and performs them.
+
+use crate::api::OutputFormat;
+use crate::config::LONG_PRESS_DURATION_MS;
+use crate::i18n::{use_translator, Translator};
+use dioxus::prelude::*;
+use gloo_timers::callback::Timeout;
+
+/// An action offered by a context menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Open,
+    Rename,
+    Download(OutputFormat),
+    Rerun,
+    CopyJobId,
+    CopyText,
+    Delete,
+}
+
+impl Action {
+    /// Text of the menu item.
+    pub fn label(&self, i18n: Translator) -> String {
+        match self {
+            Action::Open => i18n.t("Open Result").to_string(),
+            Action::Rename => i18n.t("Rename").to_string(),
+            Action::Download(format) => i18n.format("Download as {}", &[&format.label()]),
+            Action::Rerun => i18n.t("Re-run with the Same Settings").to_string(),
+            Action::CopyJobId => i18n.t("Copy Job ID").to_string(),
+            Action::CopyText => i18n.t("Copy Text").to_string(),
+            Action::Delete => i18n.t("Delete").to_string(),
+        }
+    }
+
+    /// Whether the item is set apart as destructive.
+    fn is_destructive(&self) -> bool {
+        matches!(self, Action::Delete)
+    }
+}
+
+/// Content with a context menu offering `actions`; the chosen one is sent to `onaction`.
+#[component]
+pub fn ContextMenuArea(
+    actions: Vec<Action>,
+    onaction: EventHandler<Action>,
+    children: Element,
+) -> Element {
+    let i18n = use_translator();
+    // Viewport coordinates of the open menu.
+    let mut position: Signal<Option<(f64, f64)>> = use_signal(|| None);
+    let mut long_press: Signal<Option<Timeout>> = use_signal(|| None);
+
+    let mut cancel_long_press = move || {
+        if let Some(timeout) = long_press.write().take() {
+            timeout.cancel();
+        }
+    };
+
+    rsx! {
+        div {
+            class: "context-menu-area",
+            oncontextmenu: move |evt| {
+                evt.prevent_default();
+                let point = evt.client_coordinates();
+                position.set(Some((point.x, point.y)));
+            },
+            ontouchstart: move |evt| {
+                let Some(touch) = evt.touches().into_iter().next() else {
+                    return;
+                };
+                let point = touch.client_coordinates();
+                let timeout = Timeout::new(LONG_PRESS_DURATION_MS, move || {
+                    position.set(Some((point.x, point.y)));
+                });
+                long_press.set(Some(timeout));
+            },
+            ontouchend: move |_| cancel_long_press(),
+            ontouchmove: move |_| cancel_long_press(),
+            {children}
+        }
+        if let Some((x, y)) = position() {
+            // The backdrop closes the menu on any click outside of it.
+            div {
+                class: "context-menu-backdrop",
+                onclick: move |_| position.set(None),
+                oncontextmenu: move |evt| {
+                    evt.prevent_default();
+                    position.set(None);
+                },
+            }
+            ul {
+                class: "context-menu",
+                role: "menu",
+                style: "left: {x}px; top: {y}px;",
+                onkeydown: move |evt| {
+                    if evt.key() == Key::Escape {
+                        position.set(None);
+                    }
+                },
+                for action in actions.iter().copied() {
+                    li {
+                        key: "{action:?}",
+                        button {
+                            class: if action.is_destructive() { "context-menu-item destructive" } else { "context-menu-item" },
+                            role: "menuitem",
+                            onclick: move |_| {
+                                position.set(None);
+                                onaction.call(action);
+                            },
+                            "{action.label(i18n)}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
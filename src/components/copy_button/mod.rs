@@ -0,0 +1,33 @@
+//! Copy button component
+//! Copies a text to the clipboard and briefly confirms it.
+
+use crate::clipboard;
+use crate::i18n::use_translator;
+use dioxus::prelude::*;
+
+/// A small button copying `text` to the clipboard. `label` is translated.
+#[component]
+pub fn CopyButton(text: String, #[props(default = "Copy")] label: &'static str) -> Element {
+    let i18n = use_translator();
+    let mut copied = use_signal(|| None::<bool>);
+
+    let copy = move |_| {
+        let text = text.clone();
+        spawn(async move {
+            copied.set(Some(clipboard::copy_text(&text).await));
+        });
+    };
+
+    rsx! {
+        button {
+            class: "copy-button",
+            onclick: copy,
+            onmouseleave: move |_| copied.set(None),
+            match copied() {
+                Some(true) => i18n.t("Copied!"),
+                Some(false) => i18n.t("Copy failed"),
+                None => i18n.t(label),
+            }
+        }
+    }
+}
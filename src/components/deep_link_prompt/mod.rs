@@ -0,0 +1,78 @@
+//! Deep link confirmation
+//! A job link names the API it was created with. Opening it never changes the API
+//! in use silently, as a crafted link could otherwise send every later upload to any
+//! server: a link to another API is only followed once the user confirms the switch.
+
+use crate::api::{self, TranscriptionJob};
+use crate::deeplink::JobLink;
+use crate::i18n::use_translator;
+use crate::state::AppState;
+use dioxus::prelude::*;
+
+/// Opens the job of a link, if it belongs to the API in use. Returns the link otherwise,
+/// to be confirmed.
+pub fn open_or_confirm(app_state: AppState, link: JobLink) -> Option<JobLink> {
+    if link.api_url.trim_end_matches('/') != app_state.api_url.get().trim_end_matches('/') {
+        log::warn!(
+            "The job link of {} names another API: {}",
+            link.job_id,
+            link.api_url
+        );
+        return Some(link);
+    }
+    open_job(app_state, link);
+    None
+}
+
+/// Makes the job of a link the focused job.
+fn open_job(app_state: AppState, link: JobLink) {
+    log::info!("Opening job {} from a deep link", link.job_id);
+    let mut active_jobs = app_state.active_jobs;
+    let mut jobs = active_jobs.get();
+    jobs.retain(|job| job.job_id != link.job_id);
+    jobs.push(TranscriptionJob {
+        status_url: api::job_status_url(&link.api_url, &link.job_id),
+        api_url: Some(link.api_url),
+        job_id: link.job_id,
+        ..Default::default()
+    });
+    active_jobs.set(jobs);
+}
+
+/// Asks whether to switch to the API of a job link.
+#[component]
+pub fn DeepLinkPrompt(link: Signal<Option<JobLink>>) -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let Some(pending) = link() else {
+        return rsx! {};
+    };
+
+    let confirm = {
+        let pending = pending.clone();
+        move |_| {
+            app_state.api_url.set(pending.api_url.clone());
+            open_job(app_state, pending.clone());
+            link.set(None);
+        }
+    };
+
+    rsx! {
+        div {
+            class: "deep-link-prompt severity-warning",
+            p {
+                {i18n.t("This link opens job ")}
+                code { "{pending.job_id}" }
+                {i18n.t(" of another API. Switch the API to ")}
+                strong { "{pending.api_url}" }
+                {i18n.t("?")}
+            }
+            p { class: "hint", {i18n.t("Your next uploads will be sent to that server. Only switch if you trust the link.")} }
+            div {
+                class: "action-buttons",
+                button { onclick: confirm, {i18n.t("Switch API and Open Job")} }
+                button { onclick: move |_| link.set(None), {i18n.t("Ignore Link")} }
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Transcript editor component
+//! Corrects the transcript in a text area. The draft is saved in the browser shortly
+//! after typing stops, so that it survives a closed page, until it is applied or
+//! reverted to the transcript returned by the server.
+
+use crate::config::EDITOR_AUTOSAVE_DELAY_MS;
+use crate::drafts;
+use crate::i18n::use_translator;
+use crate::state::AppState;
+use chrono::{DateTime, Local, Utc};
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+
+/// Editor of the transcript of a job. `text` is the current transcript and `original`
+/// the one returned by the server; `onsave` receives the corrected transcript.
+#[component]
+pub fn TranscriptEditor(
+    job_id: String,
+    text: String,
+    original: String,
+    onsave: EventHandler<String>,
+    onclose: EventHandler<()>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut transcript_drafts = app_state.transcript_drafts;
+    let stored = transcript_drafts.get().get(&job_id).cloned();
+    let mut restored_at = use_signal(|| stored.as_ref().map(|draft| draft.saved_at));
+    let mut draft = use_signal(|| stored.map_or(text.clone(), |draft| draft.text));
+    let mut saved_at: Signal<Option<DateTime<Utc>>> = use_signal(|| None);
+    // Incremented on each change, so that only the last one within the delay is saved.
+    let mut generation = use_signal(|| 0u64);
+    let mut confirming_revert = use_signal(|| false);
+
+    let on_input = {
+        let job_id = job_id.clone();
+        move |evt: Event<FormData>| {
+            draft.set(evt.value());
+            generation += 1;
+            let current = *generation.peek();
+            let job_id = job_id.clone();
+            spawn(async move {
+                TimeoutFuture::new(EDITOR_AUTOSAVE_DELAY_MS).await;
+                if *generation.peek() != current {
+                    return;
+                }
+                let now = Utc::now();
+                transcript_drafts.set(drafts::save(
+                    &transcript_drafts.get(),
+                    &job_id,
+                    draft.peek().clone(),
+                    now,
+                ));
+                saved_at.set(Some(now));
+            });
+        }
+    };
+
+    let apply = {
+        let job_id = job_id.clone();
+        move |_| {
+            generation += 1;
+            transcript_drafts.set(drafts::discard(&transcript_drafts.get(), &job_id));
+            onsave.call(draft.peek().clone());
+        }
+    };
+
+    let revert = move |_| {
+        generation += 1;
+        transcript_drafts.set(drafts::discard(&transcript_drafts.get(), &job_id));
+        draft.set(original.clone());
+        restored_at.set(None);
+        saved_at.set(None);
+        confirming_revert.set(false);
+    };
+
+    let is_changed = *draft.read() != text;
+    let restored_on = restored_at().map(|at| {
+        at.with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string()
+    });
+    let saved_on = saved_at().map(|at| at.with_timezone(&Local).format("%H:%M:%S").to_string());
+
+    rsx! {
+        div {
+            class: "transcript-editor",
+            if let Some(restored_on) = restored_on {
+                p { class: "hint", {i18n.format("Restored your draft from {}.", &[&restored_on])} }
+            }
+            textarea {
+                class: "transcript-editor-text",
+                rows: "16",
+                value: "{draft}",
+                oninput: on_input,
+            }
+            p {
+                class: "probe-note",
+                match saved_on {
+                    Some(saved_on) => i18n.format("Draft saved at {}.", &[&saved_on]),
+                    None => i18n.t("Drafts are saved in this browser as you type.").to_string(),
+                }
+            }
+            if confirming_revert() {
+                div {
+                    class: "bulk-confirmation",
+                    p { {i18n.t("Revert to the transcript returned by the server? Your corrections will be lost.")} }
+                    div {
+                        class: "action-buttons",
+                        button { onclick: move |_| confirming_revert.set(false), {i18n.t("Cancel")} }
+                        button { onclick: revert, {i18n.t("Confirm")} }
+                    }
+                }
+            }
+            div {
+                class: "action-buttons",
+                button { class: "button-transcribe", disabled: !is_changed, onclick: apply, {i18n.t("Apply Corrections")} }
+                button { onclick: move |_| confirming_revert.set(true), {i18n.t("Revert to Original")} }
+                button { onclick: move |_| onclose.call(()), {i18n.t("Close")} }
+            }
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Error message component
+//! Renders any `AppError` from the error catalog with its code, remediation and details.
+//! The message and remediation are translated; the technical details are not.
+
+use crate::errors::AppError;
+use crate::i18n::use_translator;
+use dioxus::prelude::*;
+
+/// Displays an error consistently across the application.
+#[component]
+pub fn ErrorMessage(error: AppError) -> Element {
+    let i18n = use_translator();
+    let info = error.info();
+    let severity_class = info.severity.css_class();
+
+    rsx! {
+        div {
+            class: "error-message {severity_class}",
+            p {
+                strong { {i18n.t(info.message)} }
+                span { class: "error-code", " ({info.code})" }
+            }
+            p { class: "error-remediation", {i18n.t(info.remediation)} }
+            if let Some(excerpt) = info.excerpt {
+                pre { class: "response-excerpt", "{excerpt}" }
+            }
+            if let Some(detail) = info.detail {
+                details {
+                    summary { {i18n.t("Technical details")} }
+                    code { "{detail}" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Feedback widget component
+//! Lets the user rate a completed transcription and describe its issues.
+//! Feedback is stored in the history entry of the job, and posted to the
+//! feedback endpoint of the profile when one is configured.
+
+use crate::api::{self, FeedbackReport};
+use crate::components::error_message::ErrorMessage;
+use crate::errors::AppError;
+use crate::history::{self, Feedback};
+use crate::i18n::use_translator;
+use crate::state::AppState;
+use chrono::Utc;
+use dioxus::prelude::*;
+
+/// Rating and free-text feedback on the transcription of a job.
+#[component]
+pub fn FeedbackWidget(job_id: String) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut rating = use_signal(|| 0u8);
+    let mut comment = use_signal(String::new);
+    let mut send_error: Signal<Option<AppError>> = use_signal(|| None);
+
+    let history = app_state.history.get();
+    if let Some(feedback) =
+        history::find(&history, &job_id).and_then(|entry| entry.feedback.clone())
+    {
+        return rsx! {
+            div {
+                class: "feedback-widget",
+                p { {i18n.format("Thanks for your feedback: rated {}/5.", &[&feedback.rating])} }
+                if let Some(error) = send_error() {
+                    ErrorMessage { error }
+                }
+            }
+        };
+    }
+
+    let submit = move |_| {
+        let job_id = job_id.clone();
+        let feedback = Feedback {
+            rating: rating(),
+            comment: comment.read().trim().to_string(),
+            given_at: Utc::now(),
+            sent: false,
+        };
+        let mut history = app_state.history;
+        history.set(history::update(&history.get(), &job_id, |entry| {
+            entry.feedback = Some(feedback.clone());
+        }));
+
+        let Some(feedback_url) = app_state.current_profile().feedback_url else {
+            return;
+        };
+        spawn(async move {
+            let report = FeedbackReport {
+                job_id: &job_id,
+                rating: feedback.rating,
+                comment: &feedback.comment,
+            };
+            match api::send_feedback(&feedback_url, &report).await {
+                Ok(()) => history.set(history::update(&history.get(), &job_id, |entry| {
+                    if let Some(feedback) = entry.feedback.as_mut() {
+                        feedback.sent = true;
+                    }
+                })),
+                Err(e) => send_error.set(Some(e.into())),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "feedback-widget",
+            p { {i18n.t("Rate this transcript:")} }
+            div {
+                class: "rating",
+                for value in 1..=5u8 {
+                    button {
+                        key: "{value}",
+                        class: if value <= rating() { "rating-star selected" } else { "rating-star" },
+                        title: "{value}/5",
+                        onclick: move |_| rating.set(value),
+                        "★"
+                    }
+                }
+            }
+            textarea {
+                placeholder: i18n.t("Describe any issue (optional)"),
+                value: "{comment}",
+                oninput: move |evt| comment.set(evt.value()),
+            }
+            button {
+                disabled: rating() == 0,
+                onclick: submit,
+                {i18n.t("Send Feedback")}
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Find and replace dialog component
+//! Previews the matches of a plain or regex query in a transcript before
+//! replacing them all at once.
+
+use crate::find_replace::{Mode, Query};
+use crate::i18n::use_translator;
+use dioxus::prelude::*;
+
+/// Maximum number of matches listed in the preview.
+const MAX_PREVIEWS: usize = 20;
+
+/// Find and replace over `text`; `onapply` receives the text with every match replaced.
+#[component]
+pub fn FindReplace(text: String, onapply: EventHandler<String>) -> Element {
+    let i18n = use_translator();
+    let mut query = use_signal(Query::default);
+
+    let previews = query.read().preview(&text);
+    let count = previews.as_ref().map(Vec::len).unwrap_or(0);
+
+    let apply = move |_| match query.read().replace_all(&text) {
+        Ok(replaced) => onapply.call(replaced),
+        Err(err) => log::warn!("Find and replace failed: {}", err),
+    };
+
+    rsx! {
+        details {
+            class: "find-replace",
+            summary { {i18n.t("Find and Replace")} }
+            div {
+                class: "form-field-row",
+                input {
+                    r#type: "text",
+                    placeholder: i18n.t("Find"),
+                    value: "{query.read().find}",
+                    oninput: move |evt| query.write().find = evt.value(),
+                }
+                input {
+                    r#type: "text",
+                    placeholder: i18n.t("Replace with"),
+                    value: "{query.read().replace}",
+                    oninput: move |evt| query.write().replace = evt.value(),
+                }
+            }
+            div {
+                class: "form-field-row",
+                label {
+                    input {
+                        r#type: "checkbox",
+                        checked: query.read().mode == Mode::Regex,
+                        onchange: move |evt| {
+                            query.write().mode = if evt.checked() { Mode::Regex } else { Mode::Plain };
+                        },
+                    }
+                    " "
+                    {i18n.t("Regular expression")}
+                }
+                label {
+                    input {
+                        r#type: "checkbox",
+                        checked: query.read().case_sensitive,
+                        onchange: move |evt| query.write().case_sensitive = evt.checked(),
+                    }
+                    " "
+                    {i18n.t("Match case")}
+                }
+            }
+            match previews {
+                Err(err) => rsx! {
+                    span { class: "status-text red", {i18n.format("Invalid pattern: {}", &[&err])} }
+                },
+                Ok(previews) => rsx! {
+                    if !query.read().find.is_empty() {
+                        p { class: "hint", {i18n.format("{} matches", &[&count])} }
+                    }
+                    ul {
+                        class: "find-replace-preview",
+                        for (index, preview) in previews.into_iter().take(MAX_PREVIEWS).enumerate() {
+                            li {
+                                key: "{index}",
+                                "…{preview.before}"
+                                del { "{preview.matched}" }
+                                ins { "{preview.replacement}" }
+                                "{preview.after}…"
+                            }
+                        }
+                    }
+                    if count > MAX_PREVIEWS {
+                        p { class: "hint", {i18n.format("and {} more.", &[&(count - MAX_PREVIEWS)])} }
+                    }
+                },
+            }
+            button {
+                disabled: count == 0,
+                onclick: apply,
+                {i18n.t("Replace All")}
+            }
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! Bulk actions on the history
+//! Applies an action to the selected entries, after a confirmation summarizing its impact.
+
+use super::{move_to_trash, restore_settings};
+use crate::components::toast;
+use crate::config::TRASH_RETENTION_DAYS;
+use crate::errors::Severity;
+use crate::history::HistoryEntry;
+use crate::i18n::{use_translator, Translator};
+use crate::media;
+use crate::state::AppState;
+use chrono::Local;
+use dioxus::prelude::*;
+use std::collections::BTreeSet;
+
+/// An action applied to every selected entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BulkAction {
+    Delete,
+    /// The transcripts, one after the other, in a text file.
+    ExportText,
+    /// The entries themselves, in a JSON file.
+    ExportJson,
+    Rerun,
+}
+
+impl BulkAction {
+    fn label(&self) -> &'static str {
+        match self {
+            BulkAction::Delete => "Delete",
+            BulkAction::ExportText => "Export Transcripts",
+            BulkAction::ExportJson => "Export Entries",
+            BulkAction::Rerun => "Re-run",
+        }
+    }
+
+    /// What confirming the action will do to the entries.
+    fn impact(&self, entries: &[&HistoryEntry], i18n: Translator) -> String {
+        let count = entries.len();
+        let completed = entries
+            .iter()
+            .filter(|entry| entry.result.is_some())
+            .count();
+        match self {
+            BulkAction::Delete => {
+                let starred = entries.iter().filter(|entry| entry.starred).count();
+                let mut impact = i18n.format("Move {} entries to the trash", &[&count]);
+                if starred > 0 {
+                    impact.push_str(&i18n.format(", {} of them starred", &[&starred]));
+                }
+                if completed > 0 {
+                    impact.push_str(&i18n.format(
+                        ". Their {} transcripts will be deleted permanently after {} days: export them first to keep them",
+                        &[&completed, &TRASH_RETENTION_DAYS],
+                    ));
+                }
+                impact + "."
+            }
+            BulkAction::ExportText => i18n.format(
+                "Export the {} transcripts of the {} entries in a text file; entries without result are skipped.",
+                &[&completed, &count],
+            ),
+            BulkAction::ExportJson => i18n.format(
+                "Export the {} entries, with their results, in a JSON file.",
+                &[&count],
+            ),
+            BulkAction::Rerun => i18n.format(
+                "Restore the settings of the newest entry. The {} files must then be transcribed again, one by one.",
+                &[&count],
+            ),
+        }
+    }
+}
+
+/// Selection controls and the actions on the selected entries.
+/// `matching` are the job ids of the entries shown with the current filter.
+#[component]
+pub fn BulkActions(selected: Signal<BTreeSet<String>>, matching: Vec<String>) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut pending: Signal<Option<BulkAction>> = use_signal(|| None);
+
+    let all_selected = !matching.is_empty()
+        && matching
+            .iter()
+            .all(|job_id| selected.read().contains(job_id));
+    // Entries removed since their selection no longer count.
+    let count = app_state
+        .history
+        .get()
+        .iter()
+        .filter(|entry| selected.read().contains(&entry.job.job_id))
+        .count();
+
+    let mut confirm = move |action: BulkAction| {
+        let history = app_state.history.get();
+        let entries: Vec<&HistoryEntry> = history
+            .iter()
+            .filter(|entry| selected.read().contains(&entry.job.job_id))
+            .collect();
+        let stamp = Local::now().format("%Y%m%d-%H%M");
+        match action {
+            BulkAction::Delete => {
+                move_to_trash(app_state, |entry| {
+                    selected.read().contains(&entry.job.job_id)
+                });
+                selected.write().clear();
+            }
+            BulkAction::ExportText => {
+                let text = entries
+                    .iter()
+                    .filter_map(|entry| {
+                        let transcript =
+                            entry.processed_result.as_ref().or(entry.result.as_ref())?;
+                        Some(format!("{}\n\n{}\n", entry.title(), transcript))
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                media::download(&format!("transcripts-{}.txt", stamp), "text/plain", &text);
+            }
+            BulkAction::ExportJson => match serde_json::to_string_pretty(&entries) {
+                Ok(json) => media::download(
+                    &format!("history-{}.json", stamp),
+                    "application/json",
+                    &json,
+                ),
+                Err(e) => log::error!("Failed to export the history entries: {}", e),
+            },
+            BulkAction::Rerun => {
+                if let Some(newest) = entries.first() {
+                    restore_settings(app_state, newest);
+                    let titles: Vec<String> = entries.iter().map(|entry| entry.title()).collect();
+                    toast::show_with(
+                        "Settings restored: transcribe again {}.",
+                        &[&titles.join(", ")],
+                        Severity::Info,
+                    );
+                }
+            }
+        }
+        pending.set(None);
+    };
+
+    let impact = pending().map(|action| {
+        let history = app_state.history.get();
+        let entries: Vec<&HistoryEntry> = history
+            .iter()
+            .filter(|entry| selected.read().contains(&entry.job.job_id))
+            .collect();
+        action.impact(&entries, i18n)
+    });
+
+    rsx! {
+        div {
+            class: "bulk-actions",
+            label {
+                input {
+                    r#type: "checkbox",
+                    checked: all_selected,
+                    onchange: move |evt| {
+                        let mut selected = selected.write();
+                        for job_id in &matching {
+                            if evt.checked() {
+                                selected.insert(job_id.clone());
+                            } else {
+                                selected.remove(job_id);
+                            }
+                        }
+                    },
+                }
+                " "
+                {i18n.t("Select all shown")}
+            }
+            if count > 0 {
+                span { class: "bulk-count", {i18n.format("{} selected", &[&count])} }
+                for action in [BulkAction::ExportText, BulkAction::ExportJson, BulkAction::Rerun, BulkAction::Delete] {
+                    button {
+                        class: if action == BulkAction::Delete { "button-clear" } else { "" },
+                        onclick: move |_| pending.set(Some(action)),
+                        {i18n.t(action.label())}
+                    }
+                }
+            }
+        }
+        if let (Some(action), Some(impact)) = (pending(), impact) {
+            div {
+                class: "bulk-confirmation",
+                p { "{impact}" }
+                div {
+                    class: "action-buttons",
+                    button { onclick: move |_| pending.set(None), {i18n.t("Cancel")} }
+                    button { onclick: move |_| confirm(action), {i18n.t("Confirm")} }
+                }
+            }
+        }
+    }
+}
+
+/// Selection checkbox of an entry.
+#[component]
+pub fn SelectBox(job_id: String, selected: Signal<BTreeSet<String>>) -> Element {
+    let checked = selected.read().contains(&job_id);
+    rsx! {
+        input {
+            class: "history-select",
+            r#type: "checkbox",
+            checked,
+            // Keep the entry folded: the checkbox sits in its summary.
+            onclick: move |evt| evt.stop_propagation(),
+            onchange: move |evt| {
+                if evt.checked() {
+                    selected.write().insert(job_id.clone());
+                } else {
+                    selected.write().remove(&job_id);
+                }
+            },
+        }
+    }
+}
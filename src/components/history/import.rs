@@ -0,0 +1,116 @@
+//! Import into the history
+//! Adds transcripts produced elsewhere to the history, and restores the entries
+//! exported from another browser with "Export Entries".
+
+use crate::api::TranscriptionJob;
+use crate::components::toast;
+use crate::document::eval;
+use crate::errors::Severity;
+use crate::history::{self, HistoryEntry};
+use crate::i18n::use_translator;
+use crate::state::AppState;
+use crate::transcript::import;
+use chrono::Utc;
+use dioxus::prelude::*;
+use dioxus_elements::FileEngine;
+use std::sync::Arc;
+
+/// File types offered by the import dialog.
+const ACCEPTED_FILES: &str = ".srt,.vtt,.tsv,.txt,.json";
+
+/// Button importing transcript files, or history exports, into the history.
+#[component]
+pub fn ImportButton() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+
+    let import_files = move |file_engine: Arc<dyn FileEngine>| {
+        spawn(async move {
+            let mut imported = 0;
+            for (index, file_name) in file_engine.files().into_iter().enumerate() {
+                let Some(content) = file_engine.read_file_to_string(&file_name).await else {
+                    toast::show_with("Could not read {}.", &[&file_name], Severity::Error);
+                    continue;
+                };
+                match import_file(app_state, &file_name, &content, index) {
+                    Ok(count) => imported += count,
+                    Err(message) => toast::show_with("{}", &[&message], Severity::Error),
+                }
+            }
+            if imported > 0 {
+                toast::show_with(
+                    "{} entries imported into the history.",
+                    &[&imported],
+                    Severity::Info,
+                );
+            }
+        });
+    };
+
+    rsx! {
+        input {
+            r#type: "file",
+            id: "history-import-input",
+            accept: ACCEPTED_FILES,
+            multiple: true,
+            style: "display: none;",
+            onchange: move |evt| {
+                if let Some(file_engine) = evt.files() {
+                    import_files(file_engine);
+                }
+                // Allow importing the same file again.
+                let _ = eval(r#"document.getElementById('history-import-input').value = '';"#);
+            },
+        }
+        button {
+            class: "history-import",
+            title: i18n.t("Transcripts made elsewhere (SRT, VTT, TSV, TXT, JSON) or an export of entries"),
+            onclick: move |_| {
+                let _ = eval(r#"document.getElementById('history-import-input').click();"#);
+            },
+            {i18n.t("Import Transcripts")}
+        }
+    }
+}
+
+/// Adds the content of a file to the history. Returns the number of entries added,
+/// or the message explaining why the file could not be imported.
+/// `index` keeps the ids of the files imported at once distinct.
+fn import_file(
+    app_state: AppState,
+    file_name: &str,
+    content: &str,
+    index: usize,
+) -> Result<usize, String> {
+    let mut history = app_state.history;
+
+    // History exports are restored as they were.
+    if let Ok(entries) = serde_json::from_str::<Vec<HistoryEntry>>(content) {
+        let current = history.get();
+        let merged = history::merge(&current, entries);
+        let added = merged.len() - current.len();
+        history.set(merged);
+        return Ok(added);
+    }
+
+    let transcript =
+        import::import(file_name, content).map_err(|e| format!("{}: {}.", file_name, e))?;
+    let now = Utc::now();
+    let job = TranscriptionJob {
+        job_id: format!("import-{}-{}", now.timestamp_millis(), index),
+        file_name: Some(file_name.to_string()),
+        submitted_at: Some(now),
+        output_format: Some(transcript.output_format),
+        ..Default::default()
+    };
+    let mut entry = HistoryEntry::new(String::new(), job);
+    entry.imported = true;
+    entry.completed_at = Some(now);
+    entry.result = Some(transcript.result);
+    history.set(history::record(
+        &history.get(),
+        entry,
+        &app_state.history_retention.get(),
+    ));
+    Ok(1)
+}
@@ -0,0 +1,291 @@
+//! History panel component
+//! Lists the receipts of past submissions.
+//! Entries offer their actions (open, rename, download, re-run, copy id, delete) in a context menu,
+//! and several entries can be selected to act on them at once.
+//! Deleted entries go to the trash, from which they can be restored for a while.
+//! Transcripts produced elsewhere can be imported to use the same tools on them.
+
+mod bulk;
+mod import;
+mod trash;
+
+use crate::api::{DiarizationOptions, OutputFormat};
+use crate::clipboard;
+use crate::components::context_menu::{Action, ContextMenuArea};
+use crate::components::receipt::SubmissionReceipt;
+use crate::components::toast;
+use crate::errors::Severity;
+use crate::history::{self, HistoryEntry};
+use crate::i18n::use_translator;
+use crate::state::{AppState, TranscriptionUiStatus};
+use crate::transcript::export;
+use bulk::{BulkActions, SelectBox};
+use chrono::{Local, Utc};
+use dioxus::prelude::*;
+use import::ImportButton;
+use std::collections::BTreeSet;
+use trash::Trash;
+
+/// Format of the submission dates in the list.
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// The list of past submissions, newest first.
+#[component]
+pub fn HistoryPanel() -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut starred_only = use_signal(|| false);
+    // Job ids of the selected entries.
+    let selected: Signal<BTreeSet<String>> = use_signal(BTreeSet::new);
+    let history = app_state.history.get();
+
+    let has_entries = !history.is_empty();
+    let has_unstarred = history.iter().any(|entry| !entry.starred);
+    let entries: Vec<HistoryEntry> = history
+        .into_iter()
+        .filter(|entry| !starred_only() || entry.starred)
+        .collect();
+    let matching: Vec<String> = entries
+        .iter()
+        .map(|entry| entry.job.job_id.clone())
+        .collect();
+
+    let mut toggle_star = move |job_id: String| {
+        let updated = history::update(&app_state.history.get(), &job_id, |entry| {
+            entry.starred = !entry.starred;
+        });
+        app_state.history.set(updated);
+    };
+
+    // Job id of the entry being renamed.
+    let mut renaming: Signal<Option<String>> = use_signal(|| None);
+    let mut rename = move |job_id: String, label: String| {
+        let label = Some(label.trim().to_string()).filter(|label| !label.is_empty());
+        let updated = history::update(&app_state.history.get(), &job_id, |entry| {
+            entry.label = label;
+        });
+        app_state.history.set(updated);
+        renaming.set(None);
+    };
+
+    let mut run_action = move |entry: HistoryEntry, action: Action| {
+        let job_id = entry.job.job_id.clone();
+        match action {
+            Action::Open => open_result(app_state, &entry),
+            Action::Rename => renaming.set(Some(job_id)),
+            Action::Download(format) => {
+                if let Some(result) = entry.result.as_deref() {
+                    export::download(
+                        &job_id,
+                        result,
+                        entry.processed_result.as_deref(),
+                        entry.job.output_format,
+                        format,
+                        &app_state.subtitle_constraints.get(),
+                        &app_state.postprocess_pipeline.get(),
+                    );
+                }
+            }
+            Action::Rerun => rerun(app_state, &entry),
+            Action::CopyJobId => {
+                spawn(async move {
+                    if !clipboard::copy_text(&job_id).await {
+                        toast::show("Could not access the clipboard.", Severity::Warning);
+                    }
+                });
+            }
+            Action::Delete => {
+                move_to_trash(app_state, |entry| entry.job.job_id == job_id);
+                toast::show_with("{} moved to the trash.", &[&entry.title()], Severity::Info);
+            }
+            Action::CopyText => {}
+        }
+    };
+
+    rsx! {
+        div {
+            class: "history-panel",
+            h2 { {i18n.t("History")} }
+            ImportButton {}
+            if has_entries {
+                label {
+                    class: "history-filter",
+                    input {
+                        r#type: "checkbox",
+                        checked: starred_only(),
+                        onchange: move |evt| starred_only.set(evt.checked()),
+                    }
+                    " "
+                    {i18n.t("Starred only")}
+                }
+                BulkActions { selected, matching }
+            }
+            for entry in entries {
+                ContextMenuArea {
+                    key: "{entry.job.job_id}",
+                    actions: entry_actions(&entry),
+                    onaction: {
+                        let entry = entry.clone();
+                        move |action| run_action(entry.clone(), action)
+                    },
+                    details {
+                        class: "history-entry",
+                        summary {
+                            SelectBox { job_id: entry.job.job_id.clone(), selected }
+                            StarButton {
+                                starred: entry.starred,
+                                ontoggle: {
+                                    let job_id = entry.job.job_id.clone();
+                                    move |_| toggle_star(job_id.clone())
+                                },
+                            }
+                            if renaming().as_ref() == Some(&entry.job.job_id) {
+                                input {
+                                    class: "history-rename",
+                                    r#type: "text",
+                                    value: "{entry.title()}",
+                                    autofocus: true,
+                                    // Typing a space must not fold the entry.
+                                    onclick: move |evt| evt.prevent_default(),
+                                    onkeyup: move |evt| evt.prevent_default(),
+                                    onchange: {
+                                        let job_id = entry.job.job_id.clone();
+                                        move |evt: Event<FormData>| rename(job_id.clone(), evt.value())
+                                    },
+                                }
+                            } else {
+                                "{entry.title()}"
+                            }
+                            if let Some(submitted_at) = entry.job.submitted_at {
+                                span {
+                                    class: "history-date",
+                                    " — {submitted_at.with_timezone(&Local).format(DATE_FORMAT)}"
+                                }
+                            }
+                        }
+                        SubmissionReceipt { job: entry.job.clone() }
+                        if entry.imported {
+                            p { class: "history-api", {i18n.t("Imported from a file")} }
+                        } else {
+                            p { class: "history-api", {i18n.format("Submitted to {}", &[&entry.api_url])} }
+                        }
+                    }
+                }
+            }
+            if has_unstarred {
+                button {
+                    class: "button-clear",
+                    title: i18n.t("Starred entries are kept"),
+                    onclick: move |_| move_to_trash(app_state, |entry| !entry.starred),
+                    {i18n.t("Clear History")}
+                }
+            }
+            Trash {}
+        }
+    }
+}
+
+/// Toggles the star of a history entry; starred entries are exempt from cleanups.
+#[component]
+fn StarButton(starred: bool, ontoggle: EventHandler<()>) -> Element {
+    let i18n = use_translator();
+
+    rsx! {
+        button {
+            class: if starred { "star-button starred" } else { "star-button" },
+            title: if starred { i18n.t("Unstar (allow cleanup)") } else { i18n.t("Star (keep during cleanups)") },
+            onclick: move |evt| {
+                // Keep the entry folded: the button sits in its summary.
+                evt.prevent_default();
+                ontoggle.call(());
+            },
+            if starred { "★" } else { "☆" }
+        }
+    }
+}
+
+/// Moves the entries matching `is_deleted` to the trash.
+fn move_to_trash(app_state: AppState, is_deleted: impl Fn(&HistoryEntry) -> bool) {
+    let (mut history, mut trash) = (app_state.history, app_state.trash);
+    let (kept, trashed) =
+        history::move_to_trash(&history.get(), &trash.get(), is_deleted, Utc::now());
+    history.set(kept);
+    trash.set(trashed);
+}
+
+/// The context menu actions of an entry; opening and downloads are offered once it has a result,
+/// and imported transcripts cannot be re-run.
+fn entry_actions(entry: &HistoryEntry) -> Vec<Action> {
+    let mut actions = Vec::new();
+    if entry.result.is_some() {
+        actions.push(Action::Open);
+    }
+    actions.push(Action::Rename);
+    if let Some(result) = entry.result.as_deref() {
+        actions.extend(
+            export::available_formats(result, entry.job.output_format)
+                .into_iter()
+                .map(Action::Download),
+        );
+    }
+    if !entry.imported {
+        actions.push(Action::Rerun);
+    }
+    actions.extend([Action::CopyJobId, Action::Delete]);
+    actions
+}
+
+/// Shows the result of an entry in the transcription panel, unless a file is being submitted.
+fn open_result(app_state: AppState, entry: &HistoryEntry) {
+    let mut ui_status = app_state.transcription_ui_status;
+    let Some(result) = entry.result.clone() else {
+        return;
+    };
+    if *ui_status.read() == TranscriptionUiStatus::Submitting {
+        toast::show(
+            "Wait for the submission to finish before opening a result.",
+            Severity::Warning,
+        );
+        return;
+    }
+    ui_status.set(TranscriptionUiStatus::Completed {
+        job_id: entry.job.job_id.clone(),
+        result,
+        processed: entry.processed_result.clone(),
+    });
+}
+
+/// Restores the settings a job was submitted with.
+/// Browsers do not keep the files between sessions, so the user selects the file again.
+fn rerun(app_state: AppState, entry: &HistoryEntry) {
+    restore_settings(app_state, entry);
+    toast::show_with(
+        "Settings of {} restored: transcribe the file again to re-run it.",
+        &[&entry.title()],
+        Severity::Info,
+    );
+}
+
+/// Restores the output format and diarization options a job was submitted with.
+fn restore_settings(app_state: AppState, entry: &HistoryEntry) {
+    let param = |key: &str| {
+        entry
+            .job
+            .params
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    };
+    let mut output_format = app_state.output_format;
+    if let Some(format) = param("output_format").and_then(OutputFormat::from_extension) {
+        output_format.set(format);
+    }
+    if let Some(diarize) = param("diarize") {
+        let mut diarization = app_state.diarization;
+        diarization.set(DiarizationOptions {
+            enabled: diarize == "true",
+            min_speakers: param("min_speakers").and_then(|count| count.parse().ok()),
+            max_speakers: param("max_speakers").and_then(|count| count.parse().ok()),
+        });
+    }
+}
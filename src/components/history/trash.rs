@@ -0,0 +1,91 @@
+//! Trash of the history
+//! Lists the deleted entries until the cleanup service deletes them permanently,
+//! so that an entry deleted by mistake can be restored.
+
+use crate::config::TRASH_RETENTION_DAYS;
+use crate::history;
+use crate::i18n::use_translator;
+use crate::state::AppState;
+use chrono::Local;
+use dioxus::prelude::*;
+
+/// Format of the deletion dates in the trash.
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// The deleted entries, with their restore and permanent deletion actions.
+#[component]
+pub fn Trash() -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let trash = app_state.trash.get();
+
+    if trash.is_empty() {
+        return rsx! {};
+    }
+
+    let mut restore = move |job_id: String| {
+        let (history, trash) =
+            history::restore(&app_state.history.get(), &app_state.trash.get(), &job_id);
+        app_state.history.set(history);
+        app_state.trash.set(trash);
+    };
+    let mut delete_now = move |job_id: String| {
+        let mut trash = app_state.trash.get();
+        trash.retain(|trashed| trashed.entry.job.job_id != job_id);
+        app_state.trash.set(trash);
+    };
+
+    rsx! {
+        details {
+            class: "history-trash",
+            summary { {i18n.format("Trash ({})", &[&trash.len()])} }
+            p {
+                class: "trash-hint",
+                {
+                    i18n.format(
+                        "Deleted entries are kept {} days before they are deleted permanently.",
+                        &[&TRASH_RETENTION_DAYS],
+                    )
+                }
+            }
+            ul {
+                for trashed in trash {
+                    li {
+                        key: "{trashed.entry.job.job_id}",
+                        class: "trash-entry",
+                        span { class: "trash-title", "{trashed.entry.title()}" }
+                        span {
+                            class: "trash-date",
+                            {
+                                i18n.format(
+                                    "Deleted permanently on {}",
+                                    &[&trashed.purge_at().with_timezone(&Local).format(DATE_FORMAT)],
+                                )
+                            }
+                        }
+                        button {
+                            onclick: {
+                                let job_id = trashed.entry.job.job_id.clone();
+                                move |_| restore(job_id.clone())
+                            },
+                            {i18n.t("Restore")}
+                        }
+                        button {
+                            class: "button-clear",
+                            onclick: {
+                                let job_id = trashed.entry.job.job_id.clone();
+                                move |_| delete_now(job_id.clone())
+                            },
+                            {i18n.t("Delete Now")}
+                        }
+                    }
+                }
+            }
+            button {
+                class: "button-clear",
+                onclick: move |_| app_state.trash.set(Vec::new()),
+                {i18n.t("Empty Trash")}
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Install prompt component
+//! Offers to install Leontine as an application, once the browser allows it.
+
+use crate::i18n::use_translator;
+use crate::pwa;
+use dioxus::prelude::*;
+
+/// An "Install app" button, shown while the application can be installed.
+#[component]
+pub fn InstallPrompt() -> Element {
+    let i18n = use_translator();
+    let mut installable = use_signal(|| false);
+
+    use_hook(move || {
+        spawn(async move {
+            let mut watch = pwa::watch_install_prompt();
+            while let Ok(available) = watch.recv::<bool>().await {
+                installable.set(available);
+            }
+        });
+    });
+
+    let install = move |_| {
+        installable.set(false);
+        spawn(async move {
+            if pwa::prompt_install().await {
+                log::info!("Leontine was installed as an application.");
+            }
+        });
+    };
+
+    if !installable() {
+        return rsx! {};
+    }
+
+    rsx! {
+        button {
+            class: "install-prompt",
+            title: i18n.t("Install Leontine to open it like an application, even offline"),
+            onclick: install,
+            {i18n.t("Install app")}
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Job list component
+//! Lists the transcription jobs being monitored, with their last known state,
+//! so several files can be transcribed at once.
+
+use crate::api::{JobStatus, TranscriptionJob};
+use crate::components::toast;
+use crate::errors::Severity;
+use crate::i18n::use_translator;
+use crate::services::job_manager;
+use crate::state::AppState;
+use dioxus::prelude::*;
+
+/// The jobs being monitored, oldest first. Renders nothing when there is none.
+#[component]
+pub fn JobList() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let jobs = app_state.active_jobs.get();
+
+    if jobs.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "job-list",
+            h2 { {i18n.t("Jobs in Progress")} }
+            ul {
+                for job in jobs {
+                    JobRow { key: "{job.job_id}", job }
+                }
+            }
+        }
+    }
+}
+
+/// A monitored job, with its state and the actions on it.
+#[component]
+fn JobRow(job: TranscriptionJob) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut cancelling = use_signal(|| false);
+    let stalled = app_state.stalled_jobs.read().contains(&job.job_id);
+    let state = match app_state.job_states.read().get(&job.job_id) {
+        _ if stalled => i18n
+            .t("Monitoring stopped after repeated errors")
+            .to_string(),
+        Some(Ok(state)) => match state.status {
+            JobStatus::Queued => match state.queue_position {
+                Some(position) => i18n.format("Queued at position {}", &[&position]),
+                None => i18n.t("Queued").to_string(),
+            },
+            JobStatus::Processing => i18n.t("Processing").to_string(),
+            JobStatus::Unknown(ref status) => i18n.format("Server status: {}", &[status]),
+            _ => i18n.t("Finishing").to_string(),
+        },
+        Some(Err(_)) => i18n.t("Status check failed, retrying").to_string(),
+        None => i18n.t("Checking status").to_string(),
+    };
+    let title = job_manager::job_title(&job);
+    let job_id = job.job_id.clone();
+
+    let cancel = move |_| {
+        let job_id = job_id.clone();
+        cancelling.set(true);
+        spawn(async move {
+            if let Err(e) = job_manager::cancel(app_state, &job_id).await {
+                log::error!("Cancelling job {} failed: {:?}", job_id, e);
+                toast::show_with("The job could not be cancelled: {}", &[&e], Severity::Error);
+            }
+            cancelling.set(false);
+        });
+    };
+
+    rsx! {
+        li {
+            class: if stalled { "job-row stalled" } else { "job-row" },
+            span { class: "job-title", title: "{job.job_id}", "{title}" }
+            span { class: "job-state", "{state}" }
+            if stalled {
+                button {
+                    onclick: {
+                        let job_id = job.job_id.clone();
+                        move |_| job_manager::resume(app_state, &job_id)
+                    },
+                    {i18n.t("Resume")}
+                }
+            }
+            button {
+                class: "button-cancel",
+                disabled: cancelling() || !(app_state.is_api_available)(),
+                onclick: cancel,
+                if cancelling() { {i18n.t("Cancelling...")} } else { {i18n.t("Cancel")} }
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Three-panel layout component
+//! Places the history and jobs in a left sidebar, the transcription in the middle
+//! and the settings in a right panel. Sidebars collapse to a button, and are resized
+//! by dragging their inner edge. On narrow screens, the panels stack below the main content.
+
+use crate::i18n::use_translator;
+use crate::layout::{PanelLayout, Side};
+use crate::state::AppState;
+use dioxus::prelude::*;
+
+/// An ongoing resize: the sidebar, the pointer position and the width when it started.
+#[derive(Clone, Copy, PartialEq)]
+struct Drag {
+    side: Side,
+    start_x: f64,
+    start_width: f64,
+}
+
+/// The page layout, with the sidebar contents around the main content.
+#[component]
+pub fn ThreePanelLayout(left: Element, main: Element, right: Element) -> Element {
+    let i18n = use_translator();
+    let mut panel_layout = use_context::<AppState>().panel_layout;
+    let mut drag: Signal<Option<Drag>> = use_signal(|| None);
+    // Layout while dragging, persisted once the drag ends.
+    let mut preview: Signal<Option<PanelLayout>> = use_signal(|| None);
+    let layout = preview().unwrap_or_else(|| panel_layout.get());
+
+    let column = |side: Side| {
+        if layout.is_open(side) {
+            format!("{}px", layout.width(side))
+        } else {
+            "max-content".to_string()
+        }
+    };
+    let style = format!(
+        "--left-panel-width: {}; --right-panel-width: {};",
+        column(Side::Left),
+        column(Side::Right)
+    );
+
+    let onmousemove = move |evt: MouseEvent| {
+        let Some(drag) = drag() else {
+            return;
+        };
+        let delta = evt.client_coordinates().x - drag.start_x;
+        // The left sidebar grows rightwards, the right panel leftwards.
+        let width = match drag.side {
+            Side::Left => drag.start_width + delta,
+            Side::Right => drag.start_width - delta,
+        };
+        preview.set(Some(panel_layout.get().resized(drag.side, width)));
+    };
+    let end_drag = move |_| {
+        drag.set(None);
+        if let Some(layout) = preview.write().take() {
+            panel_layout.set(layout);
+        }
+    };
+
+    rsx! {
+        div {
+            class: if drag().is_some() { "three-panel-layout resizing" } else { "three-panel-layout" },
+            style: "{style}",
+            onmousemove,
+            onmouseup: end_drag,
+            onmouseleave: end_drag,
+            Sidebar {
+                side: Side::Left,
+                title: i18n.t("History & Jobs"),
+                layout,
+                ontoggle: move |side| panel_layout.set(panel_layout.get().toggled(side)),
+                onresizestart: move |start: Drag| drag.set(Some(start)),
+                {left}
+            }
+            main { class: "main-panel", {main} }
+            Sidebar {
+                side: Side::Right,
+                title: i18n.t("Settings"),
+                layout,
+                ontoggle: move |side| panel_layout.set(panel_layout.get().toggled(side)),
+                onresizestart: move |start: Drag| drag.set(Some(start)),
+                {right}
+            }
+        }
+    }
+}
+
+/// A collapsible, resizable sidebar.
+#[component]
+fn Sidebar(
+    side: Side,
+    title: String,
+    layout: PanelLayout,
+    ontoggle: EventHandler<Side>,
+    onresizestart: EventHandler<Drag>,
+    children: Element,
+) -> Element {
+    let i18n = use_translator();
+    let open = layout.is_open(side);
+    let side_class = match side {
+        Side::Left => "left",
+        Side::Right => "right",
+    };
+    let width = layout.width(side) as f64;
+
+    rsx! {
+        aside {
+            class: if open { "sidebar sidebar-{side_class}" } else { "sidebar sidebar-{side_class} collapsed" },
+            button {
+                class: "sidebar-toggle",
+                title: if open { i18n.format("Collapse {}", &[&title]) } else { i18n.format("Expand {}", &[&title]) },
+                aria_expanded: "{open}",
+                onclick: move |_| ontoggle.call(side),
+                if open { "{title} ×" } else { "{title}" }
+            }
+            if open {
+                div { class: "sidebar-content", {children} }
+                div {
+                    class: "resize-handle",
+                    title: i18n.t("Drag to resize"),
+                    onmousedown: move |evt: MouseEvent| {
+                        evt.prevent_default();
+                        onresizestart.call(Drag {
+                            side,
+                            start_x: evt.client_coordinates().x,
+                            start_width: width,
+                        });
+                    },
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Maintenance banner component
+//! Informs the user that the server is under maintenance and submissions are paused.
+
+use crate::clock;
+use crate::i18n::use_translator;
+use crate::state::{ApiConnectionStatus, AppState};
+use chrono::Local;
+use dioxus::prelude::*;
+
+/// Banner shown while the server reports a maintenance, with the advertised end time.
+#[component]
+pub fn MaintenanceBanner() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let connection_status = app_state.api_connection_status.read();
+
+    let ApiConnectionStatus::Maintenance(info, _) = &*connection_status else {
+        return rsx! {};
+    };
+    let api_url = app_state.api_url.get();
+    let ends_at = info
+        .ends_at
+        .as_deref()
+        .map(|ends_at| format_end_time(&api_url, ends_at));
+
+    rsx! {
+        div {
+            class: "maintenance-banner severity-warning",
+            strong { {i18n.t("The server is under maintenance")} }
+            if let Some(ends_at) = ends_at {
+                {i18n.format(" until {}", &[&ends_at])}
+            }
+            "."
+            if let Some(message) = info.message.as_deref() {
+                p { "{message}" }
+            }
+            p {
+                class: "hint",
+                {i18n.t("New submissions are paused. Jobs already submitted are still monitored, less often.")}
+            }
+        }
+    }
+}
+
+/// Formats the advertised end time in local time, corrected for the server clock offset,
+/// or returns it as sent when it is not a date.
+fn format_end_time(api_url: &str, ends_at: &str) -> String {
+    clock::parse_server_time(ends_at)
+        .map(|at| {
+            clock::to_local(api_url, at)
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_else(|| ends_at.to_string())
+}
@@ -1,3 +1,31 @@
+pub mod activity_indicator;
+pub mod admin_queue;
 pub mod api_status;
+pub mod bilingual;
+pub mod capability_banner;
+pub mod channel_view;
+pub mod chapters;
+pub mod context_menu;
+pub mod copy_button;
+pub mod deep_link_prompt;
+pub mod editor;
+pub mod error_message;
+pub mod feedback;
+pub mod find_replace;
+pub mod history;
+pub mod install_prompt;
+pub mod job_list;
+pub mod layout;
+pub mod maintenance_banner;
+pub mod output_device;
+pub mod profile_switcher;
+pub mod proofreading;
+pub mod receipt;
+pub mod recorder;
 pub mod settings;
+pub mod status_badge;
+pub mod storage_banner;
+pub mod subtitle_export;
+pub mod theme_toggle;
+pub mod toast;
 pub mod transcription;
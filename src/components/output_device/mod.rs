@@ -0,0 +1,60 @@
+//! Output device selector component
+//! Picks the device a player plays through, on the browsers able to route playback.
+//! The choice is shared by the players and persisted.
+
+use crate::i18n::use_translator;
+use crate::media::output;
+use crate::state::AppState;
+use dioxus::prelude::*;
+
+/// The selector of the output device of the audio element `audio_id`.
+/// Renders nothing when the browser cannot route playback.
+#[component]
+pub fn OutputDeviceSelect(audio_id: &'static str) -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let devices = use_resource(output::list_devices);
+
+    // The saved device is applied to the player, then again whenever it changes.
+    use_effect(move || {
+        let label = app_state.output_device.get();
+        spawn(async move {
+            if !output::apply(audio_id, label.clone()).await && label.is_some() {
+                log::warn!("The output device {:?} is not available.", label);
+            }
+        });
+    });
+
+    let Some(Some(devices)) = devices.read().clone() else {
+        return rsx! {};
+    };
+    let selected = app_state.output_device.get();
+
+    rsx! {
+        label {
+            class: "output-device-select",
+            {i18n.t("Output ")}
+            select {
+                onchange: move |evt| {
+                    let label = evt.value();
+                    app_state.output_device.set(Some(label).filter(|label| !label.is_empty()));
+                },
+                option { value: "", selected: selected.is_none(), {i18n.t("Default device")} }
+                for label in devices.iter().cloned() {
+                    option {
+                        key: "{label}",
+                        value: "{label}",
+                        selected: selected.as_deref() == Some(label.as_str()),
+                        "{label}"
+                    }
+                }
+                if let Some(missing) = selected.clone().filter(|label| !devices.contains(label)) {
+                    option { value: "{missing}", selected: true, disabled: true, {i18n.format("{} (unavailable)", &[&missing])} }
+                }
+            }
+            if devices.is_empty() {
+                span { class: "probe-note", {i18n.t(" Device names are shown once the microphone is allowed.")} }
+            }
+        }
+    }
+}
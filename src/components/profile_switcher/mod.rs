@@ -0,0 +1,55 @@
+//! Profile switcher component
+//! A quick switch between the named endpoint profiles, in the header, with the
+//! connection status of the one in use. Hidden until a profile is saved.
+
+use crate::i18n::use_translator;
+use crate::profile;
+use crate::state::AppState;
+use dioxus::prelude::*;
+
+/// The list of the named profiles, selecting the profile and the API URL in use.
+#[component]
+pub fn ProfileSwitcher() -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let named = profile::named_profiles(&app_state.endpoint_profiles.get());
+    if named.is_empty() {
+        return rsx! {};
+    }
+    let current_url = app_state.api_url.get();
+    let current_id = app_state.current_profile_id();
+    let is_named = named.iter().any(|(id, _)| *id == current_id);
+    let color = app_state.api_connection_status.read().color();
+
+    rsx! {
+        div {
+            class: "profile-switcher",
+            span { class: "status-badge-dot {color}" }
+            select {
+                "aria-label": i18n.t("Endpoint profile"),
+                onchange: move |evt| {
+                    let id = evt.value();
+                    let profiles = app_state.endpoint_profiles.get();
+                    if let Some((_, profile)) = profile::named_profiles(&profiles)
+                        .into_iter()
+                        .find(|(other, _)| *other == id)
+                    {
+                        log::info!("Switching to profile {} at {}", id, profile.api_url);
+                        app_state.select_profile(&id, &profile.api_url);
+                    }
+                },
+                if !is_named {
+                    option { value: "", selected: true, "{current_url}" }
+                }
+                for (id, profile) in named {
+                    option {
+                        key: "{id}",
+                        value: "{id}",
+                        selected: id == current_id,
+                        {profile.name.unwrap_or_default()}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Audio locator
+//! Finds the recording of a transcript again once the submitted file is no longer
+//! selected: from the handle kept in the browser, or by selecting the file, which
+//! is checked against what the history knows of it.
+
+use crate::checksum;
+use crate::components::toast;
+use crate::dioxus_elements::FileEngine;
+use crate::document::eval;
+use crate::errors::Severity;
+use crate::history::{self, AudioReference};
+use crate::i18n::use_translator;
+use crate::media::{self, handles};
+use crate::state::AppState;
+use dioxus::prelude::*;
+use std::sync::Arc;
+
+/// Identifier of the file input used when handles are not supported.
+const INPUT_ID: &str = "proofreading-audio-input";
+
+/// Buttons to re-open or locate the recording of a job.
+/// `onlocated` receives an object URL of the recording.
+#[component]
+pub fn AudioLocator(job_id: String, onlocated: EventHandler<String>) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut opening = use_signal(|| false);
+    let entry = history::find(&app_state.history.get(), &job_id).cloned();
+    let reference = entry.as_ref().and_then(|entry| entry.audio.clone());
+    let sha256 = entry.and_then(|entry| entry.job.sha256);
+    let handle_stored = reference.as_ref().is_some_and(|audio| audio.handle_stored);
+    let file_name = reference.as_ref().map(|audio| audio.file_name.clone());
+
+    // Records what is now known of the recording, keeping the original file name.
+    let remember = {
+        let job_id = job_id.clone();
+        move |name: String, size: u64, handle_stored: bool| {
+            let mut history = app_state.history;
+            history.set(history::update(&history.get(), &job_id, |entry| {
+                let file_name = entry
+                    .audio
+                    .as_ref()
+                    .map_or(name, |audio| audio.file_name.clone());
+                entry.audio = Some(AudioReference {
+                    file_name,
+                    size: Some(size),
+                    handle_stored,
+                });
+            }));
+        }
+    };
+
+    let reopen = {
+        let job_id = job_id.clone();
+        move |_| {
+            let job_id = job_id.clone();
+            opening.set(true);
+            spawn(async move {
+                match handles::reopen(&job_id).await {
+                    Some(file) => onlocated.call(file.url),
+                    None => toast::show(
+                        "The recording could not be re-opened: locate the file again.",
+                        Severity::Warning,
+                    ),
+                }
+                opening.set(false);
+            });
+        }
+    };
+
+    let locate = {
+        let job_id = job_id.clone();
+        let reference = reference.clone();
+        let remember = remember.clone();
+        move |_| {
+            if !handles::is_supported() {
+                let _ = eval(&format!("document.getElementById('{}').click();", INPUT_ID));
+                return;
+            }
+            let job_id = job_id.clone();
+            let reference = reference.clone();
+            let remember = remember.clone();
+            opening.set(true);
+            spawn(async move {
+                if let Some(file) = handles::pick(&job_id).await {
+                    warn_if_different(reference.as_ref(), &file.name, file.size, None);
+                    remember(file.name, file.size, true);
+                    onlocated.call(file.url);
+                }
+                opening.set(false);
+            });
+        }
+    };
+
+    let select_file = move |file_engine: Arc<dyn FileEngine>| {
+        let reference = reference.clone();
+        let sha256 = sha256.clone();
+        let remember = remember.clone();
+        spawn(async move {
+            let Some(name) = file_engine.files().first().cloned() else {
+                return;
+            };
+            let size = file_engine.file_size(&name).await.unwrap_or_default();
+            // The checksum is only computed when the size already matches.
+            let same_content = match (&sha256, reference.as_ref().and_then(|audio| audio.size)) {
+                (Some(sha256), Some(expected)) if expected == size => {
+                    checksum::file_sha256_hex(&file_engine)
+                        .await
+                        .map(|actual| actual == *sha256)
+                }
+                _ => None,
+            };
+            warn_if_different(reference.as_ref(), &name, size, same_content);
+            if let Some(url) = media::object_url(&file_engine).await {
+                remember(name, size, false);
+                onlocated.call(url);
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "audio-locator",
+            p {
+                class: "hint",
+                match &file_name {
+                    Some(file_name) => i18n.format("The recording ({}) is no longer selected.", &[file_name]),
+                    None => i18n.t("No recording is attached to this transcript.").to_string(),
+                }
+            }
+            input {
+                r#type: "file",
+                id: INPUT_ID,
+                accept: "audio/*,video/*",
+                style: "display: none;",
+                onchange: move |evt| {
+                    if let Some(file_engine) = evt.files() {
+                        select_file(file_engine);
+                    }
+                },
+            }
+            div {
+                class: "action-buttons",
+                if handle_stored {
+                    button { disabled: opening(), onclick: reopen, {i18n.t("Re-open Recording")} }
+                }
+                button {
+                    disabled: opening(),
+                    title: if handles::is_supported() { i18n.t("The file will be re-opened directly next time") } else { "" },
+                    onclick: locate,
+                    {i18n.t("Locate Audio File")}
+                }
+            }
+        }
+    }
+}
+
+/// Warns when a located file does not look like the transcribed one.
+/// `same_content` is the checksum comparison, when it could be made.
+fn warn_if_different(
+    reference: Option<&AudioReference>,
+    name: &str,
+    size: u64,
+    same_content: Option<bool>,
+) {
+    let Some(reference) = reference else {
+        return;
+    };
+    let different = match same_content {
+        Some(same) => !same,
+        None => reference.size.is_some_and(|expected| expected != size),
+    };
+    if different {
+        toast::show_with(
+            "{} differs from the transcribed file ({}): timestamps may not match.",
+            &[&name, &reference.file_name],
+            Severity::Warning,
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! Media Session integration
+//! Lets the media keys of the system and the lock screen control the proofreading
+//! player, the lock screen showing the title of the reviewed file.
+
+use crate::document::eval;
+
+/// Offset of the seek backward and forward actions, when the system gives none, in seconds.
+const SEEK_OFFSET_SECS: f64 = 10.0;
+
+/// Sets the metadata and the action handlers, driving the audio element by id.
+const REGISTER_SCRIPT: &str = r#"
+    const [title, audioId, seekOffset] = await dioxus.recv();
+    if (!('mediaSession' in navigator)) return;
+    const audio = () => document.getElementById(audioId);
+    navigator.mediaSession.metadata = new MediaMetadata({ title, artist: 'Leontine', album: 'Proofreading' });
+    const handlers = {
+        play: () => audio()?.play(),
+        pause: () => audio()?.pause(),
+        stop: () => audio()?.pause(),
+        seekbackward: (details) => {
+            const player = audio();
+            if (player) player.currentTime = Math.max(0, player.currentTime - (details.seekOffset || seekOffset));
+        },
+        seekforward: (details) => {
+            const player = audio();
+            if (player) player.currentTime = Math.min(player.duration || Infinity, player.currentTime + (details.seekOffset || seekOffset));
+        },
+        seekto: (details) => {
+            const player = audio();
+            if (player) player.currentTime = details.seekTime;
+        },
+    };
+    for (const [action, handler] of Object.entries(handlers)) {
+        try {
+            navigator.mediaSession.setActionHandler(action, handler);
+        } catch (e) {
+            // The browser does not support this action.
+        }
+    }
+"#;
+
+/// Removes the metadata and the action handlers.
+const CLEAR_SCRIPT: &str = r#"
+    if (!('mediaSession' in navigator)) return;
+    navigator.mediaSession.metadata = null;
+    for (const action of ['play', 'pause', 'stop', 'seekbackward', 'seekforward', 'seekto']) {
+        try {
+            navigator.mediaSession.setActionHandler(action, null);
+        } catch (e) {
+            // The browser does not support this action.
+        }
+    }
+"#;
+
+/// Hands the media keys over to the audio element `audio_id`, playing `title`.
+pub fn register(title: &str, audio_id: &str) {
+    let session = eval(REGISTER_SCRIPT);
+    if let Err(e) = session.send((title, audio_id, SEEK_OFFSET_SECS)) {
+        log::warn!("Failed to register the media session: {:?}", e);
+    }
+}
+
+/// Gives the media keys back to the system.
+pub fn clear() {
+    let _ = eval(CLEAR_SCRIPT);
+}
@@ -0,0 +1,262 @@
+//! Proofreading view component
+//! Combines the audio player, the read-only timestamped transcript and an edit pane.
+//! Keyboard bindings: Tab / Shift+Tab jump to the next / previous segment,
+//! Ctrl+Space plays or pauses the audio.
+//! When the result reports confidence scores, segments can be colored by confidence
+//! and the least confident ones reviewed first.
+//! Long transcripts get a table of contents to jump between chapters.
+//! Once the submitted file is no longer selected, the recording can be located again.
+//! The media keys of the system control the player while the view is open.
+//! Quiet recordings can be made louder for playback.
+
+mod audio_locator;
+mod media_session;
+mod normalization;
+
+use crate::components::chapters::TableOfContents;
+use crate::components::output_device::OutputDeviceSelect;
+use crate::config::LOWEST_CONFIDENCE_SEGMENTS;
+use crate::document::eval;
+use crate::history;
+use crate::i18n::use_translator;
+use crate::media;
+use crate::state::AppState;
+use crate::transcript::{self, ConfidenceLevel, TranscriptSegment};
+use audio_locator::AudioLocator;
+use dioxus::prelude::*;
+use normalization::LoudnessNormalization;
+
+/// Identifier of the audio element, driven through JS for seeking and speed changes.
+const AUDIO_ID: &str = "proofreading-audio";
+
+/// Playback speeds offered by the player.
+const PLAYBACK_RATES: &[f64] = &[0.5, 0.75, 1.0, 1.25, 1.5, 2.0];
+
+/// Split view to proofread a transcript while listening to the recording.
+/// `result` is the raw result, from which the segments are read; `text` is the
+/// transcript being corrected, sent to `onsave` once edited.
+#[component]
+pub fn ProofreadingView(
+    job_id: String,
+    audio_url: Option<String>,
+    result: String,
+    text: String,
+    onsave: EventHandler<String>,
+    onclose: EventHandler<()>,
+) -> Element {
+    let i18n = use_translator();
+    let segments = use_signal(|| transcript::parse_segments(&result).unwrap_or_default());
+    let mut draft = use_signal(|| text.clone());
+    let mut current: Signal<Option<usize>> = use_signal(|| None);
+    let mut rate = use_signal(|| 1.0);
+    let mut heatmap = use_signal(|| false);
+    // Position in the list of least confident segments, while reviewing them.
+    let mut review_position: Signal<Option<usize>> = use_signal(|| None);
+    let lowest = use_memo(move || {
+        transcript::lowest_confidence(&segments.read(), LOWEST_CONFIDENCE_SEGMENTS)
+    });
+    // Object URL of the recording located from the history, released on close.
+    let mut located_url: Signal<Option<String>> = use_signal(|| None);
+    use_drop(move || {
+        if let Some(url) = located_url.peek().as_deref() {
+            media::revoke_object_url(url);
+        }
+    });
+    let audio_url = audio_url.or(located_url());
+    use_drop(media_session::clear);
+    let app_state = use_context::<AppState>();
+    let title = history::find(&app_state.history.get(), &job_id)
+        .map(|entry| entry.title())
+        .unwrap_or_else(|| job_id.clone());
+
+    let mut seek = move |index: usize| {
+        let Some(start) = segments.read().get(index).map(|segment| segment.start) else {
+            return;
+        };
+        current.set(Some(index));
+        let _ = eval(&format!(
+            "document.getElementById('{}').currentTime = {};",
+            AUDIO_ID, start
+        ));
+    };
+
+    let track_playback = move |_| {
+        spawn(async move {
+            let time = eval(&format!(
+                "return document.getElementById('{}').currentTime;",
+                AUDIO_ID
+            ))
+            .join::<f64>()
+            .await;
+            if let Ok(time) = time {
+                current.set(transcript::segment_at(&segments.read(), time));
+            }
+        });
+    };
+
+    let segment_count = segments.read().len();
+    let review_next = move |_| {
+        let lowest = lowest.read();
+        if lowest.is_empty() {
+            return;
+        }
+        let position = review_position().map_or(0, |position| (position + 1) % lowest.len());
+        review_position.set(Some(position));
+        seek(lowest[position]);
+    };
+    let onkeydown = move |evt: KeyboardEvent| {
+        let modifiers = evt.modifiers();
+        match evt.key() {
+            Key::Tab if segment_count > 0 => {
+                evt.prevent_default();
+                let index = match (current(), modifiers.shift()) {
+                    (None, _) => 0,
+                    (Some(index), false) => (index + 1).min(segment_count - 1),
+                    (Some(index), true) => index.saturating_sub(1),
+                };
+                seek(index);
+            }
+            Key::Character(c) if c == " " && modifiers.ctrl() => {
+                evt.prevent_default();
+                let _ = eval(&format!(
+                    "const audio = document.getElementById('{}'); if (audio.paused) audio.play(); else audio.pause();",
+                    AUDIO_ID
+                ));
+            }
+            _ => {}
+        }
+    };
+
+    rsx! {
+        div {
+            class: "proofreading-view",
+            onkeydown,
+            div {
+                class: "proofreading-player",
+                if let Some(audio_url) = audio_url {
+                    audio {
+                        id: AUDIO_ID,
+                        controls: true,
+                        src: "{audio_url}",
+                        ontimeupdate: track_playback,
+                        onloadedmetadata: move |_| media_session::register(&title, AUDIO_ID),
+                    }
+                    label {
+                        {i18n.t("Speed ")}
+                        select {
+                            onchange: move |evt| {
+                                if let Ok(value) = evt.value().parse::<f64>() {
+                                    rate.set(value);
+                                    let _ = eval(&format!(
+                                        "document.getElementById('{}').playbackRate = {};",
+                                        AUDIO_ID, value
+                                    ));
+                                }
+                            },
+                            for value in PLAYBACK_RATES.iter().copied() {
+                                option { value: "{value}", selected: value == rate(), "{value}×" }
+                            }
+                        }
+                    }
+                    OutputDeviceSelect { audio_id: AUDIO_ID }
+                    LoudnessNormalization { audio_url: audio_url.clone() }
+                } else {
+                    AudioLocator {
+                        job_id: job_id.clone(),
+                        onlocated: move |url| located_url.set(Some(url)),
+                    }
+                }
+                p { class: "hint", {i18n.t("Tab / Shift+Tab: next / previous segment. Ctrl+Space: play or pause.")} }
+            }
+            div {
+                class: "proofreading-transcript",
+                if !lowest.read().is_empty() {
+                    div {
+                        class: "confidence-tools",
+                        label {
+                            input {
+                                r#type: "checkbox",
+                                checked: heatmap(),
+                                onchange: move |evt| heatmap.set(evt.checked()),
+                            }
+                            " "
+                            {i18n.t("Confidence heatmap")}
+                        }
+                        button {
+                            onclick: review_next,
+                            match review_position() {
+                                Some(position) => i18n.format(
+                                    "Next Low-Confidence Segment ({}/{})",
+                                    &[&(position + 1), &lowest.read().len()],
+                                ),
+                                None => i18n.t("Jump to Lowest-Confidence Segments").to_string(),
+                            }
+                        }
+                    }
+                }
+                if segment_count > 0 {
+                    TableOfContents { job_id, segments: segments(), onselect: seek }
+                }
+                if segment_count == 0 {
+                    p { class: "hint", {i18n.t("This result has no timestamps.")} }
+                    p { "{result}" }
+                }
+                for (index, segment) in segments.read().iter().enumerate() {
+                    SegmentLine {
+                        key: "{index}",
+                        segment: segment.clone(),
+                        current: current() == Some(index),
+                        heatmap: heatmap(),
+                        onselect: move |_| seek(index),
+                    }
+                }
+            }
+            div {
+                class: "proofreading-editor",
+                textarea {
+                    value: "{draft}",
+                    oninput: move |evt| draft.set(evt.value()),
+                }
+                div {
+                    class: "action-buttons",
+                    button { onclick: move |_| onclose.call(()), {i18n.t("Close")} }
+                    button {
+                        disabled: draft() == text,
+                        onclick: move |_| onsave.call(draft()),
+                        {i18n.t("Save Corrections")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A segment of the read-only transcript; selecting it seeks the audio.
+/// With the heatmap on, its background reflects its confidence.
+#[component]
+fn SegmentLine(
+    segment: TranscriptSegment,
+    current: bool,
+    heatmap: bool,
+    onselect: EventHandler<()>,
+) -> Element {
+    let i18n = use_translator();
+    let timestamp = transcript::format_timestamp(segment.start);
+    let confidence = segment.confidence();
+    let level = confidence
+        .filter(|_| heatmap)
+        .map(|confidence| ConfidenceLevel::of(confidence).css_class())
+        .unwrap_or_default();
+    let title = confidence
+        .map(|confidence| i18n.format("Confidence: {}%", &[&format!("{:.0}", confidence * 100.0)]))
+        .unwrap_or_default();
+    rsx! {
+        p {
+            class: if current { "segment current {level}" } else { "segment {level}" },
+            title: "{title}",
+            onclick: move |_| onselect.call(()),
+            span { class: "segment-time", "[{timestamp}]" }
+            " {segment.text.trim()}"
+        }
+    }
+}
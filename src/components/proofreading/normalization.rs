@@ -0,0 +1,71 @@
+//! Loudness normalization
+//! Makes a quiet recording louder while proofreading it. The recording is measured
+//! the first time the normalization is turned on; the uploaded file is not altered.
+
+use super::AUDIO_ID;
+use crate::i18n::use_translator;
+use crate::media::loudness;
+use dioxus::prelude::*;
+
+/// A checkbox to normalize the loudness of the player, with the gain applied.
+#[component]
+pub fn LoudnessNormalization(audio_url: String) -> Element {
+    let i18n = use_translator();
+    let mut enabled = use_signal(|| false);
+    // The measured peak: `None` until measured, `Some(None)` when it cannot be.
+    let mut peak: Signal<Option<Option<f64>>> = use_signal(|| None);
+    let mut measuring = use_signal(|| false);
+    use_drop(|| loudness::release(AUDIO_ID));
+
+    // A new recording has to be measured again.
+    use_effect(use_reactive!(|audio_url| {
+        let _ = audio_url;
+        peak.set(None);
+    }));
+
+    // The player goes through the gain node only once the normalization was turned on.
+    use_effect(move || {
+        let gain = match (enabled(), peak()) {
+            (true, Some(Some(peak))) => loudness::normalization_gain(peak),
+            (false, Some(Some(_))) => 1.0,
+            _ => return,
+        };
+        loudness::set_gain(AUDIO_ID, gain);
+    });
+
+    let toggle = move |evt: FormEvent| {
+        let checked = evt.checked();
+        enabled.set(checked);
+        if checked && peak.peek().is_none() && !measuring() {
+            measuring.set(true);
+            let audio_url = audio_url.clone();
+            spawn(async move {
+                peak.set(Some(loudness::measure_peak(&audio_url).await));
+                measuring.set(false);
+            });
+        }
+    };
+
+    let note = match (enabled(), peak()) {
+        (false, _) => None,
+        (true, _) if measuring() => Some(i18n.t("Measuring the recording...").to_string()),
+        (true, Some(None)) => Some(i18n.t("This recording cannot be measured.").to_string()),
+        (true, Some(Some(peak))) => match loudness::normalization_gain(peak) {
+            gain if gain > 1.0 => Some(format!("+{:.1} dB", loudness::gain_db(gain))),
+            _ => Some(i18n.t("Already at a comfortable level.").to_string()),
+        },
+        (true, None) => None,
+    };
+
+    rsx! {
+        label {
+            class: "loudness-normalization",
+            input { r#type: "checkbox", checked: enabled(), onchange: toggle }
+            " "
+            {i18n.t("Boost quiet recording")}
+            if let Some(note) = note {
+                span { class: "probe-note", " {note}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Submission receipt component
+//! Displays the acknowledgment returned by the server when a job is submitted,
+//! with the parameters it was submitted with.
+
+use crate::api::{self, TranscriptionJob};
+use crate::clock;
+use crate::components::copy_button::CopyButton;
+use crate::deeplink;
+use crate::i18n::use_translator;
+use crate::state::AppState;
+use dioxus::prelude::*;
+use qrcode::render::svg;
+use qrcode::QrCode;
+
+/// A card summarizing the server acknowledgment of a submission.
+#[component]
+pub fn SubmissionReceipt(job: TranscriptionJob) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let submitted_at = job.submitted_at.map(|at| {
+        at.with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string()
+    });
+
+    // The server clock may be off: its time is shown by the local clock.
+    let api_url = job
+        .api_url
+        .clone()
+        .unwrap_or_else(|| app_state.api_url.get());
+    let queued_at = job.queued_at.as_deref().map(|queued_at| {
+        clock::parse_server_time(queued_at).map_or_else(
+            || queued_at.to_string(),
+            |at| {
+                clock::to_local(&api_url, at)
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            },
+        )
+    });
+
+    rsx! {
+        dl {
+            class: "submission-receipt",
+            dt { {i18n.t("Job ID")} }
+            dd {
+                code { "{job.job_id}" }
+                " "
+                CopyButton { text: job.job_id.clone() }
+            }
+            if let Some(file_name) = &job.file_name {
+                dt { {i18n.t("File")} }
+                dd { "{file_name}" }
+            }
+            if let Some(submitted_at) = submitted_at {
+                dt { {i18n.t("Submitted")} }
+                dd { "{submitted_at}" }
+            }
+            if let Some(queued_at) = queued_at {
+                dt { {i18n.t("Queued")} }
+                dd { "{queued_at}" }
+            }
+            if let Some(message) = &job.message {
+                dt { {i18n.t("Server message")} }
+                dd { "{message}" }
+            }
+            if !job.params.is_empty() || !job.extra_form_fields.is_empty() {
+                dt { {i18n.t("Parameters")} }
+                dd {
+                    ul {
+                        class: "job-parameters",
+                        for (key, value) in job.params.iter().chain(job.extra_form_fields.iter()) {
+                            li { code { "{key}" } " = {value}" }
+                        }
+                    }
+                }
+            }
+            if let Some(notification) = &job.notification {
+                dt { {i18n.t("Notification")} }
+                dd { "{notification}" }
+            }
+            if let Some(sha256) = job.sha256.as_ref().filter(|_| job.checksum_sent) {
+                dt { {i18n.t("Integrity")} }
+                dd {
+                    match job.checksum_verified {
+                        Some(true) => rsx! { span { class: "badge badge-verified", {i18n.t("Verified by the server")} } },
+                        Some(false) => rsx! { span { class: "badge badge-mismatch", {i18n.t("Checksum mismatch")} } },
+                        None => rsx! { span { class: "badge", {i18n.t("Not acknowledged")} } },
+                    }
+                    " "
+                    code { class: "checksum", title: "SHA-256", "{sha256}" }
+                }
+            }
+            if let Some(url) = &job.url {
+                dt { {i18n.t("Result URL")} }
+                dd { a { href: "{url}", target: "_blank", "{url}" } }
+            }
+        }
+    }
+}
+
+/// The active job id, displayed prominently with a link to its raw status endpoint,
+/// so the job can be checked or shared outside the application.
+#[component]
+pub fn JobLink(api_url: String, job_id: String) -> Element {
+    let i18n = use_translator();
+    let status_url = api::job_status_url(&api_url, &job_id);
+    let deep_link = deeplink::job_link(&api_url, &job_id);
+
+    rsx! {
+        div {
+            class: "job-link",
+            p {
+                {i18n.t("Job ")}
+                code { class: "job-id", "{job_id}" }
+                " "
+                CopyButton { text: job_id.clone(), label: "Copy ID" }
+            }
+            p {
+                a {
+                    href: "{status_url}",
+                    target: "_blank",
+                    rel: "noopener",
+                    title: i18n.t("Raw status of the job, as returned by the API"),
+                    {i18n.t("Status endpoint")}
+                }
+                " "
+                CopyButton { text: status_url.clone(), label: "Copy link" }
+            }
+            if let Some(link) = deep_link {
+                details {
+                    class: "job-qr-code",
+                    summary { {i18n.t("Continue on another device")} }
+                    JobQrCode { link }
+                }
+            }
+        }
+    }
+}
+
+/// A QR code encoding the deep link of a job, to hand monitoring over to a phone.
+#[component]
+fn JobQrCode(link: String) -> Element {
+    let i18n = use_translator();
+    let svg = match QrCode::new(link.as_bytes()) {
+        Ok(code) => code
+            .render::<svg::Color>()
+            .min_dimensions(200, 200)
+            .quiet_zone(true)
+            .build(),
+        Err(e) => {
+            log::warn!("Failed to generate the job QR code: {}", e);
+            return rsx! {};
+        }
+    };
+
+    rsx! {
+        div { class: "qr-code", dangerous_inner_html: svg }
+        p {
+            class: "probe-note",
+            {i18n.t("Scan this code to follow the job from your phone. ")}
+            CopyButton { text: link.clone(), label: "Copy app link" }
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Microphone recorder component
+//! Records audio in the browser with MediaRecorder, as an alternative to selecting
+//! a file. The recording is handed over as a file engine once stopped. The screen is
+//! kept on during the recording.
+
+mod wake_lock;
+
+use crate::components::error_message::ErrorMessage;
+use crate::dioxus_elements::FileEngine;
+use crate::document::eval;
+use crate::errors::AppError;
+use crate::i18n::use_translator;
+use crate::media::native_file::NativeFile;
+use crate::transcript::format_timestamp;
+use chrono::Local;
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use serde::Deserialize;
+use std::sync::Arc;
+use web_sys::js_sys::Date;
+
+/// Global under which the recorder script leaves the recorded file.
+const RECORDING_GLOBAL: &str = "leontineRecording";
+
+/// Interval between two refreshes of the elapsed time, in milliseconds.
+const CLOCK_INTERVAL_MS: u32 = 500;
+
+/// Opens the microphone and starts recording. The session is kept in a global,
+/// so that the pause, resume and stop commands reach it; the recorded file is
+/// left in another global once stopped. A generation counter lets the recorder
+/// abandon a session still waiting for the microphone permission.
+const START_SCRIPT: &str = r#"
+    const name = await dioxus.recv();
+    const generation = (window.leontineRecorderGeneration || 0) + 1;
+    window.leontineRecorderGeneration = generation;
+    try {
+        if (!navigator.mediaDevices || !window.MediaRecorder) {
+            throw new Error('Recording is not supported by this browser, or the page is not served over HTTPS.');
+        }
+        const stream = await navigator.mediaDevices.getUserMedia({ audio: true });
+        if (window.leontineRecorderGeneration !== generation) {
+            stream.getTracks().forEach((track) => track.stop());
+            return;
+        }
+        const types = ['audio/webm;codecs=opus', 'audio/ogg;codecs=opus', 'audio/mp4'];
+        const mimeType = types.find((type) => MediaRecorder.isTypeSupported(type));
+        const recorder = new MediaRecorder(stream, mimeType ? { mimeType } : {});
+        const session = { recorder, discarded: false };
+        const chunks = [];
+        recorder.ondataavailable = (event) => {
+            if (event.data.size > 0) chunks.push(event.data);
+        };
+        recorder.onstop = () => {
+            stream.getTracks().forEach((track) => track.stop());
+            if (window.leontineRecorder === session) delete window.leontineRecorder;
+            if (session.discarded) {
+                dioxus.send('discarded');
+                return;
+            }
+            const type = recorder.mimeType || 'audio/webm';
+            const extension = type.includes('ogg') ? 'ogg' : type.includes('mp4') ? 'm4a' : 'webm';
+            window.leontineRecording = new File(chunks, `${name}.${extension}`, { type });
+            dioxus.send('stopped');
+        };
+        window.leontineRecorder = session;
+        recorder.start(1000);
+        dioxus.send('started');
+    } catch (error) {
+        dioxus.send({ error: error.message || String(error) });
+    }
+"#;
+
+/// Events sent by the recorder script.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum RecorderEvent {
+    Started,
+    Stopped,
+    Discarded,
+    Error(String),
+}
+
+/// A command sent to the running recorder, ignored once it is inactive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Pause,
+    Resume,
+    Stop,
+    Discard,
+}
+
+impl Command {
+    fn send(self) {
+        let action = match self {
+            Command::Pause => "session.recorder.pause();",
+            Command::Resume => "session.recorder.resume();",
+            Command::Stop => "session.recorder.stop();",
+            Command::Discard => "session.discarded = true; session.recorder.stop();",
+        };
+        let _ = eval(&format!(
+            "const session = window.leontineRecorder; if (session && session.recorder.state !== 'inactive') {{ {} }}",
+            action
+        ));
+    }
+}
+
+/// State of the recorder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RecorderStatus {
+    Idle,
+    /// Waiting for the microphone permission.
+    Starting,
+    Recording,
+    Paused,
+}
+
+/// Start, pause and stop controls with the elapsed time.
+/// `onrecorded` receives the recording once stopped.
+#[component]
+pub fn Recorder(disabled: bool, onrecorded: EventHandler<Arc<dyn FileEngine>>) -> Element {
+    let mut status = use_signal(|| RecorderStatus::Idle);
+    let mut error: Signal<Option<AppError>> = use_signal(|| None);
+    // Recorded time before the last resume, and when recording last resumed, in milliseconds.
+    let mut recorded_ms = use_signal(|| 0.0);
+    let mut resumed_at: Signal<Option<f64>> = use_signal(|| None);
+    let mut clock = use_signal(Date::now);
+
+    // The screen stays on while a recording is in progress, even paused.
+    use_effect(move || match status() {
+        RecorderStatus::Recording | RecorderStatus::Paused => wake_lock::acquire(),
+        RecorderStatus::Idle | RecorderStatus::Starting => wake_lock::release(),
+    });
+
+    // Leaving the page section drops the recording in progress, or about to start.
+    use_drop(|| {
+        wake_lock::release();
+        Command::Discard.send();
+        let _ = eval(
+            "window.leontineRecorderGeneration = (window.leontineRecorderGeneration || 0) + 1;",
+        );
+    });
+
+    let start = move |_| {
+        error.set(None);
+        recorded_ms.set(0.0);
+        status.set(RecorderStatus::Starting);
+        spawn(async move {
+            let mut session = eval(START_SCRIPT);
+            let name = format!("recording-{}", Local::now().format("%Y%m%d-%H%M%S"));
+            if let Err(e) = session.send(name) {
+                log::warn!("Failed to start the recorder: {:?}", e);
+                status.set(RecorderStatus::Idle);
+                return;
+            }
+            loop {
+                match session.recv::<RecorderEvent>().await {
+                    Ok(RecorderEvent::Started) => {
+                        resumed_at.set(Some(Date::now()));
+                        status.set(RecorderStatus::Recording);
+                        spawn(async move {
+                            while matches!(
+                                *status.peek(),
+                                RecorderStatus::Recording | RecorderStatus::Paused
+                            ) {
+                                clock.set(Date::now());
+                                TimeoutFuture::new(CLOCK_INTERVAL_MS).await;
+                            }
+                        });
+                    }
+                    Ok(RecorderEvent::Stopped) => {
+                        status.set(RecorderStatus::Idle);
+                        // File engines are shared as `Arc`, even on the single-threaded web target.
+                        #[allow(clippy::arc_with_non_send_sync)]
+                        match NativeFile::take(RECORDING_GLOBAL) {
+                            Some(file) => onrecorded.call(Arc::new(file)),
+                            None => log::warn!("The recorder stopped without a recording."),
+                        }
+                        break;
+                    }
+                    Ok(RecorderEvent::Discarded) => {
+                        status.set(RecorderStatus::Idle);
+                        break;
+                    }
+                    Ok(RecorderEvent::Error(message)) => {
+                        error.set(Some(AppError::Recording(message)));
+                        status.set(RecorderStatus::Idle);
+                        break;
+                    }
+                    Err(e) => {
+                        log::warn!("Lost the recorder session: {:?}", e);
+                        status.set(RecorderStatus::Idle);
+                        break;
+                    }
+                }
+            }
+        });
+    };
+
+    let pause = move |_| {
+        if let Some(at) = resumed_at.take() {
+            recorded_ms += Date::now() - at;
+        }
+        status.set(RecorderStatus::Paused);
+        Command::Pause.send();
+    };
+    let resume = move |_| {
+        resumed_at.set(Some(Date::now()));
+        status.set(RecorderStatus::Recording);
+        Command::Resume.send();
+    };
+
+    let i18n = use_translator();
+    let elapsed_ms = recorded_ms() + resumed_at().map_or(0.0, |at| (clock() - at).max(0.0));
+
+    rsx! {
+        div {
+            class: "recorder",
+            match status() {
+                RecorderStatus::Idle => rsx! {
+                    button { disabled, onclick: start, {i18n.t("Record Audio")} }
+                },
+                RecorderStatus::Starting => rsx! {
+                    p { class: "hint", {i18n.t("Allow the use of the microphone to start recording.")} }
+                },
+                RecorderStatus::Recording | RecorderStatus::Paused => rsx! {
+                    span {
+                        class: if status() == RecorderStatus::Recording { "recorder-time recording" } else { "recorder-time" },
+                        "{format_timestamp(elapsed_ms / 1000.0)}"
+                    }
+                    if status() == RecorderStatus::Recording {
+                        button { onclick: pause, {i18n.t("Pause")} }
+                    } else {
+                        button { onclick: resume, {i18n.t("Resume")} }
+                    }
+                    button { class: "button-transcribe", onclick: move |_| Command::Stop.send(), {i18n.t("Stop")} }
+                    button { class: "button-clear", onclick: move |_| Command::Discard.send(), {i18n.t("Discard")} }
+                },
+            }
+            if let Some(error) = error() {
+                ErrorMessage { error }
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Screen wake lock
+//! Keeps the screen on while recording, as a sleeping mobile device stops the recording.
+//! The browser releases the lock whenever the page is hidden: it is requested again
+//! when the page becomes visible, for as long as it is wanted.
+
+use crate::document::eval;
+
+/// Requests the lock, then again each time the page becomes visible, until released.
+/// Does nothing when the browser has no Screen Wake Lock API.
+const ACQUIRE_SCRIPT: &str = r#"
+    if (!('wakeLock' in navigator)) return;
+    const lock = window.leontineWakeLock || (window.leontineWakeLock = { sentinel: null, wanted: false, onVisibility: null });
+    lock.wanted = true;
+    const request = async () => {
+        if (!lock.wanted || lock.sentinel || document.visibilityState !== 'visible') return;
+        try {
+            const sentinel = await navigator.wakeLock.request('screen');
+            if (!lock.wanted) {
+                sentinel.release();
+                return;
+            }
+            lock.sentinel = sentinel;
+            sentinel.addEventListener('release', () => {
+                if (lock.sentinel === sentinel) lock.sentinel = null;
+            });
+        } catch (e) {
+            console.warn('Screen wake lock refused:', e);
+        }
+    };
+    if (!lock.onVisibility) {
+        lock.onVisibility = () => request();
+        document.addEventListener('visibilitychange', lock.onVisibility);
+    }
+    await request();
+"#;
+
+/// Releases the lock and stops requesting it again.
+const RELEASE_SCRIPT: &str = r#"
+    const lock = window.leontineWakeLock;
+    if (!lock) return;
+    lock.wanted = false;
+    if (lock.onVisibility) {
+        document.removeEventListener('visibilitychange', lock.onVisibility);
+        lock.onVisibility = null;
+    }
+    if (lock.sentinel) {
+        lock.sentinel.release();
+        lock.sentinel = null;
+    }
+"#;
+
+/// Keeps the screen on from now on.
+pub fn acquire() {
+    let _ = eval(ACQUIRE_SCRIPT);
+}
+
+/// Lets the screen sleep again.
+pub fn release() {
+    let _ = eval(RELEASE_SCRIPT);
+}
@@ -0,0 +1,381 @@
+//! Endpoint profile editor
+//! Edits the options of the profile in use for the saved API URL.
+
+use crate::api::{NotificationSupport, NotificationTarget};
+use crate::config::CHECKSUM_HEADER;
+use crate::i18n::use_translator;
+use crate::profile::{update_profile, EndpointProfile, Token, RESERVED_FORM_FIELDS};
+use crate::state::AppState;
+use dioxus::prelude::*;
+
+/// A list of key/value pairs of the endpoint profile.
+#[derive(Clone, Copy, PartialEq)]
+pub enum KeyValueList {
+    /// Extra form fields appended to submissions.
+    ExtraFormFields,
+    /// Custom headers added to every request.
+    CustomHeaders,
+}
+
+impl KeyValueList {
+    fn entries(self, profile: &mut EndpointProfile) -> &mut Vec<(String, String)> {
+        match self {
+            KeyValueList::ExtraFormFields => &mut profile.extra_form_fields,
+            KeyValueList::CustomHeaders => &mut profile.custom_headers,
+        }
+    }
+
+    fn legend(self) -> &'static str {
+        match self {
+            KeyValueList::ExtraFormFields => "Extra form fields",
+            KeyValueList::CustomHeaders => "Custom headers",
+        }
+    }
+
+    fn hint(self) -> &'static str {
+        match self {
+            KeyValueList::ExtraFormFields => {
+                "Sent with every transcription submitted to this endpoint (e.g. project tag, cost center)."
+            }
+            KeyValueList::CustomHeaders => {
+                "Added to every request sent to this endpoint (e.g. X-Api-Key, tenant ID)."
+            }
+        }
+    }
+
+    fn reserved(self) -> &'static [&'static str] {
+        match self {
+            KeyValueList::ExtraFormFields => RESERVED_FORM_FIELDS,
+            KeyValueList::CustomHeaders => &[],
+        }
+    }
+}
+
+/// Editor for a key/value list of the current endpoint profile.
+#[component]
+pub fn KeyValueEditor(list: KeyValueList) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let profiles = app_state.endpoint_profiles;
+    let profile_id = app_state.current_profile_id();
+    let mut profile = app_state.current_profile();
+    let entries = list.entries(&mut profile).clone();
+
+    let add_id = profile_id.clone();
+
+    rsx! {
+        fieldset {
+            class: "profile-section",
+            legend { {i18n.t(list.legend())} }
+            p { class: "hint", {i18n.t(list.hint())} }
+
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                div {
+                    key: "{index}",
+                    class: "form-field-row",
+                    input {
+                        r#type: "text",
+                        placeholder: i18n.t("Name"),
+                        value: "{key}",
+                        oninput: {
+                            let profile_id = profile_id.clone();
+                            move |evt: FormEvent| update_profile(profiles, &profile_id, |profile| {
+                                list.entries(profile)[index].0 = evt.value();
+                            })
+                        }
+                    }
+                    input {
+                        r#type: "text",
+                        placeholder: i18n.t("Value"),
+                        value: "{value}",
+                        oninput: {
+                            let profile_id = profile_id.clone();
+                            move |evt: FormEvent| update_profile(profiles, &profile_id, |profile| {
+                                list.entries(profile)[index].1 = evt.value();
+                            })
+                        }
+                    }
+                    button {
+                        onclick: {
+                            let profile_id = profile_id.clone();
+                            move |_| update_profile(profiles, &profile_id, |profile| {
+                                list.entries(profile).remove(index);
+                            })
+                        },
+                        {i18n.t("Remove")}
+                    }
+                    if list.reserved().contains(&key.trim()) {
+                        span { class: "status-text red", {i18n.t("Reserved name, this entry will be ignored.")} }
+                    }
+                }
+            }
+
+            button {
+                onclick: move |_| update_profile(profiles, &add_id, |profile| {
+                    list.entries(profile).push((String::new(), String::new()));
+                }),
+                {i18n.t("Add entry")}
+            }
+        }
+    }
+}
+
+/// Editor for the API token of the current endpoint, sent as a bearer token.
+/// The token and the Basic credentials both use the `Authorization` header:
+/// setting a token disables the Basic credentials, which the editors tell.
+#[component]
+pub fn ApiTokenEditor() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let profiles = app_state.endpoint_profiles;
+    let profile_id = app_state.current_profile_id();
+    let profile = app_state.current_profile();
+    let has_basic_auth = has_basic_auth(&profile);
+    let token = profile.api_token.map(|token| token.0).unwrap_or_default();
+
+    rsx! {
+        fieldset {
+            class: "profile-section",
+            legend { {i18n.t("API token")} }
+            p { class: "hint", {i18n.t("For servers with authorization enabled. Sent as a bearer token with every request to the API, and never to other servers. Leave empty to disable.")} }
+            if has_basic_auth {
+                p { class: "hint severity-warning", {i18n.t("HTTP Basic credentials are set for this endpoint. Setting a token disables them, as both use the same header.")} }
+            }
+            input {
+                r#type: "password",
+                placeholder: i18n.t("Token"),
+                autocomplete: "off",
+                value: "{token}",
+                oninput: move |evt| update_profile(profiles, &profile_id, |profile| {
+                    profile.api_token = Some(evt.value().trim().to_string())
+                        .filter(|token| !token.is_empty())
+                        .map(Token);
+                })
+            }
+        }
+    }
+}
+
+/// Editor for the HTTP Basic credentials of the current endpoint.
+/// Leaving the username empty disables Basic authentication.
+#[component]
+pub fn BasicAuthEditor() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let profiles = app_state.endpoint_profiles;
+    let profile_id = app_state.current_profile_id();
+    let profile = app_state.current_profile();
+    let has_token = profile.api_token.is_some();
+    let auth = profile.basic_auth.unwrap_or_default();
+
+    let password_id = profile_id.clone();
+
+    rsx! {
+        fieldset {
+            class: "profile-section",
+            legend { {i18n.t("HTTP Basic authentication")} }
+            p { class: "hint", {i18n.t("For endpoints behind a reverse proxy requiring a login. Leave empty to disable.")} }
+            if has_token {
+                p { class: "hint severity-warning", {i18n.t("Disabled while an API token is set: both use the same header. Clear the token to send these credentials.")} }
+            }
+            div {
+                class: "form-field-row",
+                input {
+                    r#type: "text",
+                    placeholder: i18n.t("Username"),
+                    disabled: has_token,
+                    autocomplete: "username",
+                    value: "{auth.username}",
+                    oninput: move |evt| update_profile(profiles, &profile_id, |profile| {
+                        let mut auth = profile.basic_auth.take().unwrap_or_default();
+                        auth.username = evt.value();
+                        profile.basic_auth = Some(auth).filter(|auth| !auth.username.is_empty() || !auth.password.is_empty());
+                    })
+                }
+                input {
+                    r#type: "password",
+                    placeholder: i18n.t("Password"),
+                    disabled: has_token,
+                    autocomplete: "current-password",
+                    value: "{auth.password}",
+                    oninput: move |evt| update_profile(profiles, &password_id, |profile| {
+                        let mut auth = profile.basic_auth.take().unwrap_or_default();
+                        auth.password = evt.value();
+                        profile.basic_auth = Some(auth).filter(|auth| !auth.username.is_empty() || !auth.password.is_empty());
+                    })
+                }
+            }
+        }
+    }
+}
+
+/// Whether Basic credentials would be sent, were it not for an API token.
+fn has_basic_auth(profile: &EndpointProfile) -> bool {
+    profile
+        .basic_auth
+        .as_ref()
+        .is_some_and(|auth| !auth.username.is_empty())
+}
+
+/// Toggles the SHA-256 checksum sent with uploads.
+#[component]
+pub fn ChecksumToggle() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let profiles = app_state.endpoint_profiles;
+    let profile_id = app_state.current_profile_id();
+    let send_checksum = app_state.current_profile().send_checksum;
+
+    rsx! {
+        fieldset {
+            class: "profile-section",
+            legend { {i18n.t("Upload integrity")} }
+            label {
+                input {
+                    r#type: "checkbox",
+                    checked: send_checksum,
+                    onchange: move |evt| update_profile(profiles, &profile_id, |profile| {
+                        profile.send_checksum = evt.checked();
+                    })
+                }
+                {i18n.t(" Send a SHA-256 checksum of uploaded files")}
+            }
+            p { class: "hint", {i18n.format("Sent in the {} header, for servers verifying uploads. The header must be allowed by the server CORS policy.", &[&CHECKSUM_HEADER])} }
+        }
+    }
+}
+
+/// Edits the endpoint receiving transcription quality feedback.
+#[component]
+pub fn FeedbackEndpointEditor() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let profiles = app_state.endpoint_profiles;
+    let profile_id = app_state.current_profile_id();
+    let feedback_url = app_state.current_profile().feedback_url.unwrap_or_default();
+
+    rsx! {
+        fieldset {
+            class: "profile-section",
+            legend { {i18n.t("Feedback endpoint")} }
+            p { class: "hint", {i18n.t("Ratings of completed transcriptions are posted as JSON to this URL. Leave empty to keep them local.")} }
+            input {
+                r#type: "url",
+                placeholder: "https://example.org/feedback",
+                value: "{feedback_url}",
+                oninput: move |evt| update_profile(profiles, &profile_id, |profile| {
+                    profile.feedback_url = Some(evt.value().trim().to_string()).filter(|url| !url.is_empty());
+                })
+            }
+        }
+    }
+}
+
+/// Edits the Prometheus metrics endpoint of the current server.
+#[component]
+pub fn MetricsEndpointEditor() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let profiles = app_state.endpoint_profiles;
+    let profile_id = app_state.current_profile_id();
+    let metrics_url = app_state.current_profile().metrics_url.unwrap_or_default();
+
+    rsx! {
+        fieldset {
+            class: "profile-section",
+            legend { {i18n.t("Metrics endpoint")} }
+            p { class: "hint", {i18n.t("Prometheus metrics of a self-hosted server (queue length, job durations), shown as trends in the API status. Leave empty to disable.")} }
+            input {
+                r#type: "url",
+                placeholder: "https://example.org/metrics",
+                value: "{metrics_url}",
+                oninput: move |evt| update_profile(profiles, &profile_id, |profile| {
+                    profile.metrics_url = Some(evt.value().trim().to_string()).filter(|url| !url.is_empty());
+                })
+            }
+        }
+    }
+}
+
+/// Edits the admin token of the current endpoint, enabling the server queue panel.
+#[component]
+pub fn AdminTokenEditor() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let profiles = app_state.endpoint_profiles;
+    let profile_id = app_state.current_profile_id();
+    let token = app_state
+        .current_profile()
+        .admin_token
+        .map(|token| token.0)
+        .unwrap_or_default();
+
+    rsx! {
+        fieldset {
+            class: "profile-section",
+            legend { {i18n.t("Server administration")} }
+            p { class: "hint", {i18n.t("For operators of this server: a token with admin scope shows the server queue. Leave empty to disable.")} }
+            input {
+                r#type: "password",
+                placeholder: i18n.t("Admin token"),
+                autocomplete: "off",
+                value: "{token}",
+                oninput: move |evt| update_profile(profiles, &profile_id, |profile| {
+                    profile.admin_token = Some(evt.value().trim().to_string())
+                        .filter(|token| !token.is_empty())
+                        .map(Token);
+                })
+            }
+        }
+    }
+}
+
+/// Edits the email address or webhook notified when a job finishes.
+/// Only shown for servers advertising notification callbacks.
+#[component]
+pub fn NotificationTargetEditor(support: NotificationSupport) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let profiles = app_state.endpoint_profiles;
+    let profile_id = app_state.current_profile_id();
+    let target = app_state
+        .current_profile()
+        .notification_target
+        .unwrap_or_default();
+    let (placeholder, accepted) = match (support.email, support.webhook) {
+        (true, true) => (
+            i18n.t("name@example.org or https://example.org/hook"),
+            i18n.t("an email address or a webhook URL"),
+        ),
+        (true, false) => ("name@example.org", i18n.t("an email address")),
+        _ => ("https://example.org/hook", i18n.t("a webhook URL")),
+    };
+    let problem = (!target.trim().is_empty())
+        .then(|| match NotificationTarget::parse(&target) {
+            None => Some(i18n.format("Enter {}.", &[&accepted])),
+            Some(parsed) if !parsed.is_supported_by(&support) => {
+                Some(i18n.format("This server only accepts {}.", &[&accepted]))
+            }
+            Some(_) => None,
+        })
+        .flatten();
+
+    rsx! {
+        fieldset {
+            class: "profile-section",
+            legend { {i18n.t("Notifications")} }
+            p { class: "hint", {i18n.format("The server notifies {} when a job finishes. Registered with every submission. Leave empty to disable.", &[&accepted])} }
+            input {
+                r#type: "text",
+                placeholder,
+                value: "{target}",
+                oninput: move |evt| update_profile(profiles, &profile_id, |profile| {
+                    profile.notification_target = Some(evt.value().trim().to_string()).filter(|target| !target.is_empty());
+                })
+            }
+            if let Some(problem) = problem {
+                span { class: "status-text red", "{problem}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! Accepted file types section of the settings panel
+//! Replaces the file types accepted for transcription, for instance to add a video
+//! container the deployment does not list.
+
+use crate::i18n::use_translator;
+use crate::media::file_types;
+use crate::state::AppState;
+use dioxus::prelude::*;
+
+/// Editor of the accepted file types, applied when the field loses focus.
+/// An empty field restores those of the deployment.
+#[component]
+pub(super) fn FileTypeSettings() -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let overridden = app_state.accepted_file_types.get();
+    let deployment_types = app_state
+        .deployment_file_types()
+        .unwrap_or_else(file_types::defaults);
+    let value = overridden
+        .as_deref()
+        .map(|accepted| accepted.join(", "))
+        .unwrap_or_default();
+
+    rsx! {
+        details {
+            class: "file-type-settings",
+            summary { {i18n.t("Accepted file types")} }
+            input {
+                r#type: "text",
+                value: "{value}",
+                placeholder: deployment_types.join(", "),
+                onchange: move |evt| {
+                    let accepted = file_types::parse(&evt.value());
+                    app_state.accepted_file_types.set((!accepted.is_empty()).then_some(accepted));
+                },
+            }
+            p { class: "hint", {i18n.t("Extensions such as .m4a, or types such as audio/* or video/mp4, separated by commas. Files of other types are refused.")} }
+            if overridden.is_some() {
+                button {
+                    onclick: move |_| app_state.accepted_file_types.set(None),
+                    {i18n.t("Use the types of the deployment")}
+                }
+            }
+        }
+    }
+}
@@ -1,9 +1,33 @@
 //! Settings panel component
-//! Allows users to configure the API URL. The state is lifted to the parent component.
+//! Allows users to configure the API URL and its profiles. The state is lifted to the
+//! parent component.
 
+mod endpoint_profile;
+mod file_types;
+mod polling;
+mod postprocess;
+mod profiles;
+mod retention;
+mod stored_data;
+
+use crate::api::{discovery, ApiError};
+use crate::components::toast;
+use crate::errors::Severity;
 use crate::hooks::persistent::UsePersistent;
+use crate::i18n::{use_translator, Locale};
+use crate::notifications;
 use crate::state::{ApiConnectionStatus, AppState};
 use dioxus::prelude::*;
+use endpoint_profile::{
+    AdminTokenEditor, ApiTokenEditor, BasicAuthEditor, ChecksumToggle, FeedbackEndpointEditor,
+    KeyValueEditor, KeyValueList, MetricsEndpointEditor, NotificationTargetEditor,
+};
+use file_types::FileTypeSettings;
+use polling::PollingSettings;
+use postprocess::PostprocessSettings;
+use profiles::ProfileList;
+use retention::RetentionSettings;
+use stored_data::StoredData;
 
 /// Settings panel component for managing API configuration.
 /// It receives the shared `api_url` state as a prop from its parent.
@@ -16,6 +40,9 @@ pub fn SettingsPanel(mut api_url: UsePersistent<String>) -> Element {
     // Track if the input has been modified from the saved value.
     let mut is_modified = use_signal(|| false);
 
+    // A profile selected elsewhere replaces the URL being edited.
+    use_effect(move || input_value.set(api_url.get()));
+
     // `use_effect` to reactively check if the input value differs from the persisted one.
     // This runs whenever `input_value` or `api_url` changes.
     use_effect(move || {
@@ -23,18 +50,25 @@ pub fn SettingsPanel(mut api_url: UsePersistent<String>) -> Element {
     });
 
     let app_state = use_context::<AppState>();
+    let i18n = use_translator();
     let api_connection_status = app_state.api_connection_status.read();
+    let needs_token = app_state.current_profile().api_token.is_none()
+        && api_connection_status
+            .api_status()
+            .is_some_and(|status| status.security.authorization_enabled);
+    let notification_support = Some(app_state.capabilities.read().notifications.clone())
+        .filter(|support| support.email || support.webhook);
 
     rsx! {
         div {
             class: "settings-panel",
-            h2 { "Settings" }
+            h2 { {i18n.t("Settings")} }
 
             div {
                 class: "settings-content",
                 label {
                     r#for: "api-url",
-                    "API URL:"
+                    {i18n.t("API URL:")}
                 }
                 input {
                     id: "api-url",
@@ -57,26 +91,297 @@ pub fn SettingsPanel(mut api_url: UsePersistent<String>) -> Element {
                             api_url.set(input_value.read().clone());
                             log::info!("New API URL saved: {}", api_url.get());
                         },
-                        "Save URL"
+                        {i18n.t("Save URL")}
                     }
                 } else {
                     span {
                         class: "saved-message",
-                        "Saved"
+                        {i18n.t("Saved")}
                     }
                 }
+                ApiDetector { api_url, input_value }
                 match &*api_connection_status {
                     ApiConnectionStatus::Pending => rsx! {
-                        span { class: "status-text yellow", "Checking API status..." }
+                        span { class: "status-text yellow", {i18n.t("Checking API status...")} }
                     },
                     ApiConnectionStatus::Available(_, _) => rsx! {
-                        span { class: "status-text green", "API is online" }
+                        span { class: "status-text green", {i18n.t("API is online")} }
+                    },
+                    ApiConnectionStatus::Stale { .. } => rsx! {
+                        span { class: "status-text amber", {i18n.t("API is not responding")} }
+                    },
+                    ApiConnectionStatus::Maintenance(_, _) => rsx! {
+                        span { class: "status-text amber", {i18n.t("API is under maintenance")} }
+                    },
+                    ApiConnectionStatus::Unavailable(ApiError::HttpError(401 | 403, _), _) => rsx! {
+                        span { class: "status-text red", {i18n.t("Access denied: check the API token")} }
                     },
                     ApiConnectionStatus::Unavailable(_, _) => rsx! {
-                        span { class: "status-text red", "API is unreachable" }
+                        span { class: "status-text red", {i18n.t("API is unreachable")} }
                     },
                 }
             }
+
+            if let ApiConnectionStatus::Unavailable(err, _) = &*api_connection_status {
+                if discovery::is_wrong_url_error(err) {
+                    UrlSuggestions { api_url, input_value }
+                }
+            }
+
+            if needs_token {
+                p { class: "status-text amber", {i18n.t("This server requires authorization: set the API token in the endpoint options.")} }
+            }
+
+            ProfileList {}
+
+            LanguageSelect {}
+            LowBandwidthToggle {}
+            NotificationToggle {}
+
+            details {
+                class: "endpoint-profile",
+                summary { {i18n.t("Endpoint options")} }
+                ApiTokenEditor {}
+                BasicAuthEditor {}
+                KeyValueEditor { list: KeyValueList::CustomHeaders }
+                KeyValueEditor { list: KeyValueList::ExtraFormFields }
+                ChecksumToggle {}
+                if let Some(support) = notification_support {
+                    NotificationTargetEditor { support }
+                }
+                FeedbackEndpointEditor {}
+                MetricsEndpointEditor {}
+                AdminTokenEditor {}
+            }
+
+            FileTypeSettings {}
+            PollingSettings {}
+            PostprocessSettings {}
+            RetentionSettings {}
+            StoredData {}
+        }
+    }
+}
+
+/// Language of the interface, following the browser unless one is picked.
+#[component]
+fn LanguageSelect() -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let selected = app_state.locale.get();
+
+    rsx! {
+        label {
+            class: "language-select",
+            {i18n.t("Language")}
+            select {
+                onchange: move |evt| app_state.locale.set(Locale::from_code(&evt.value())),
+                option { value: "", selected: selected.is_none(), {i18n.t("Browser language")} }
+                for locale in Locale::ALL {
+                    option {
+                        key: "{locale.code()}",
+                        value: "{locale.code()}",
+                        selected: selected == Some(locale),
+                        "{locale.label()}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Checkbox enabling the low-bandwidth mode, which polls the server less often.
+#[component]
+fn LowBandwidthToggle() -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+
+    rsx! {
+        label {
+            class: "low-bandwidth-toggle",
+            input {
+                r#type: "checkbox",
+                checked: app_state.low_bandwidth.get(),
+                onchange: move |evt| app_state.low_bandwidth.set(evt.checked()),
+            }
+            {i18n.t(" Low-bandwidth mode")}
+            span { class: "probe-note", {i18n.t(" (checks the server less often, for slow or metered connections)")} }
+        }
+    }
+}
+
+/// Checkbox enabling desktop notifications of finished transcriptions.
+/// Enabling them asks for the browser permission, and fails if it is refused.
+#[component]
+fn NotificationToggle() -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+
+    if !notifications::is_supported() {
+        return rsx! {};
+    }
+    let enabled = app_state.desktop_notifications.get() && !notifications::is_denied();
+
+    let toggle = move |evt: Event<FormData>| {
+        if !evt.checked() {
+            app_state.desktop_notifications.set(false);
+            return;
+        }
+        spawn(async move {
+            if notifications::request_permission().await {
+                app_state.desktop_notifications.set(true);
+            } else {
+                app_state.desktop_notifications.set(false);
+                toast::show(
+                    "Notifications are blocked: allow them in the browser's site settings.",
+                    Severity::Warning,
+                );
+            }
+        });
+    };
+
+    rsx! {
+        label {
+            class: "notification-toggle",
+            input {
+                r#type: "checkbox",
+                checked: enabled,
+                onchange: toggle,
+            }
+            {i18n.t(" Desktop notifications")}
+            span { class: "probe-note", {i18n.t(" (when a transcription completes or fails)")} }
+        }
+    }
+}
+
+/// Outcome of the "Detect API" action.
+#[derive(Clone, PartialEq)]
+enum Detection {
+    Idle,
+    Detecting,
+    Found(String),
+    NotFound,
+}
+
+/// Probes conventional API paths on the origin of the URL being edited
+/// and offers the first valid endpoint found.
+#[component]
+fn ApiDetector(mut api_url: UsePersistent<String>, mut input_value: Signal<String>) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut detection = use_signal(|| Detection::Idle);
+
+    let detect = move |_| {
+        let url = input_value.read().clone();
+        let profile = app_state.current_profile();
+        detection.set(Detection::Detecting);
+        spawn(async move {
+            let result = match discovery::detect_api(&url, &profile).await {
+                Some(found) => Detection::Found(found),
+                None => Detection::NotFound,
+            };
+            detection.set(result);
+        });
+    };
+
+    rsx! {
+        button {
+            onclick: detect,
+            disabled: detection() == Detection::Detecting,
+            title: i18n.t("Probe the usual API paths on this server"),
+            if detection() == Detection::Detecting { {i18n.t("Detecting...")} } else { {i18n.t("Detect API")} }
+        }
+        match detection() {
+            Detection::Found(found) if found != api_url.get() => rsx! {
+                span {
+                    class: "detection-result",
+                    {i18n.t("API found at ")}
+                    code { "{found}" }
+                    button {
+                        onclick: move |_| {
+                            input_value.set(found.clone());
+                            api_url.set(found.clone());
+                            detection.set(Detection::Idle);
+                            log::info!("Detected API URL saved: {}", found);
+                        },
+                        {i18n.t("Use it")}
+                    }
+                }
+            },
+            Detection::NotFound => rsx! {
+                span { class: "detection-result status-text red", {i18n.t("No API found on this server.")} }
+            },
+            _ => rsx! {},
+        }
+    }
+}
+
+/// Suggests corrections when the saved URL does not look like the API base.
+/// Candidates are only probed when the user explicitly asks for it.
+#[component]
+fn UrlSuggestions(mut api_url: UsePersistent<String>, mut input_value: Signal<String>) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let current_url = api_url.get();
+    let suggestions = discovery::suggest_corrections(&current_url);
+    // Probe results are kept with the URL they were computed for.
+    let mut probe_results: Signal<Option<(String, Vec<String>)>> = use_signal(|| None);
+    let mut is_probing = use_signal(|| false);
+
+    if suggestions.is_empty() {
+        return rsx! {};
+    }
+
+    let valid_urls = probe_results
+        .read()
+        .as_ref()
+        .filter(|(url, _)| *url == current_url)
+        .map(|(_, valid)| valid.clone());
+
+    let candidates = suggestions.clone();
+    let probe = move |_| {
+        let candidates = candidates.clone();
+        let probed_url = api_url.get();
+        let profile = app_state.current_profile();
+        is_probing.set(true);
+        spawn(async move {
+            let valid = discovery::probe_candidates(&candidates, &profile).await;
+            probe_results.set(Some((probed_url, valid)));
+            is_probing.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "url-suggestions",
+            p { {i18n.t("This URL does not look like the WhisperX API. Did you mean:")} }
+            ul {
+                for suggestion in suggestions {
+                    li {
+                        key: "{suggestion}",
+                        code { "{suggestion}" }
+                        match valid_urls.as_ref().map(|valid| valid.contains(&suggestion)) {
+                            Some(true) => rsx! { span { class: "status-text green", {i18n.t(" responds as an API ")} } },
+                            Some(false) => rsx! { span { class: "status-text red", {i18n.t(" no API found ")} } },
+                            None => rsx! {},
+                        }
+                        button {
+                            onclick: move |_| {
+                                input_value.set(suggestion.clone());
+                                api_url.set(suggestion.clone());
+                                log::info!("API URL corrected to: {}", suggestion);
+                            },
+                            {i18n.t("Use this URL")}
+                        }
+                    }
+                }
+            }
+            button {
+                onclick: probe,
+                disabled: is_probing(),
+                if is_probing() { {i18n.t("Checking...")} } else { {i18n.t("Check these URLs")} }
+            }
+            span { class: "probe-note", {i18n.t(" A status request is sent to each suggested URL.")} }
         }
     }
 }
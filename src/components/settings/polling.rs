@@ -0,0 +1,71 @@
+//! Polling section of the settings panel
+//! Edits how often the server is checked for the status of the jobs and of the API.
+
+use crate::config::{
+    MAX_API_STATUS_CHECK_INTERVAL_MS, MAX_JOB_STATUS_POLL_INTERVAL_MS,
+    MIN_API_STATUS_CHECK_INTERVAL_MS, MIN_JOB_STATUS_POLL_INTERVAL_MS,
+};
+use crate::i18n::use_translator;
+use crate::state::{AppState, PollingIntervals};
+use dioxus::prelude::*;
+
+/// Editor of the polling intervals, in seconds. Values are kept within their bounds.
+#[component]
+pub(super) fn PollingSettings() -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let intervals = app_state.polling_intervals.get().clamped();
+
+    let mut update = move |change: &dyn Fn(&mut PollingIntervals)| {
+        let mut intervals = app_state.polling_intervals.get();
+        change(&mut intervals);
+        app_state.polling_intervals.set(intervals.clamped());
+    };
+
+    rsx! {
+        details {
+            class: "polling-settings",
+            summary { {i18n.t("Polling")} }
+            div {
+                class: "form-field-row",
+                label {
+                    {i18n.t("Check jobs every ")}
+                    input {
+                        r#type: "number",
+                        min: "{MIN_JOB_STATUS_POLL_INTERVAL_MS / 1000}",
+                        max: "{MAX_JOB_STATUS_POLL_INTERVAL_MS / 1000}",
+                        value: "{intervals.job_poll_ms / 1000}",
+                        onchange: move |evt| {
+                            if let Ok(secs) = evt.value().parse::<u32>() {
+                                update(&|intervals| intervals.job_poll_ms = secs.saturating_mul(1000));
+                            }
+                        }
+                    }
+                    " s"
+                }
+                label {
+                    {i18n.t("Check the API every ")}
+                    input {
+                        r#type: "number",
+                        min: "{MIN_API_STATUS_CHECK_INTERVAL_MS / 1000}",
+                        max: "{MAX_API_STATUS_CHECK_INTERVAL_MS / 1000}",
+                        value: "{intervals.status_check_ms / 1000}",
+                        onchange: move |evt| {
+                            if let Ok(secs) = evt.value().parse::<u32>() {
+                                update(&|intervals| intervals.status_check_ms = secs.saturating_mul(1000));
+                            }
+                        }
+                    }
+                    " s"
+                }
+            }
+            p { class: "hint", {i18n.t("Shorter intervals show progress sooner but load the server more. Low-bandwidth mode multiplies both.")} }
+            if intervals != PollingIntervals::default() {
+                button {
+                    onclick: move |_| app_state.polling_intervals.set(PollingIntervals::default()),
+                    {i18n.t("Restore Defaults")}
+                }
+            }
+        }
+    }
+}
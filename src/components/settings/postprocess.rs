@@ -0,0 +1,191 @@
+//! Post-processing section of the settings panel
+//! Edits the ordered pipeline of steps applied to completed transcripts.
+
+use crate::hooks::persistent::UsePersistent;
+use crate::i18n::use_translator;
+use crate::postprocess::{PipelineStep, StepConfig};
+use crate::state::AppState;
+use dioxus::prelude::*;
+
+/// Editor of the post-processing pipeline.
+#[component]
+pub(super) fn PostprocessSettings() -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut new_kind = use_signal(|| 0usize);
+    let pipeline_signal = app_state.postprocess_pipeline;
+    let pipeline = pipeline_signal.get();
+    let count = pipeline.len();
+
+    rsx! {
+        details {
+            class: "postprocess-settings",
+            summary { {i18n.t("Transcript post-processing")} }
+            p { class: "hint", {i18n.t("Steps applied in order to each completed transcript. The raw transcript is kept alongside the processed one.")} }
+            for (index, step) in pipeline.into_iter().enumerate() {
+                fieldset {
+                    key: "{index}",
+                    class: "profile-section",
+                    legend {
+                        label {
+                            input {
+                                r#type: "checkbox",
+                                checked: step.enabled,
+                                onchange: move |evt: FormEvent| update_pipeline(pipeline_signal, move |pipeline| {
+                                    pipeline[index].enabled = evt.checked();
+                                }),
+                            }
+                            " {index + 1}. {i18n.t(step.config.label())}"
+                        }
+                    }
+                    StepEditor {
+                        config: step.config.clone(),
+                        onchange: move |config| update_pipeline(pipeline_signal, move |pipeline| {
+                            pipeline[index].config = config;
+                        }),
+                    }
+                    div {
+                        class: "action-buttons",
+                        button {
+                            disabled: index == 0,
+                            onclick: move |_| update_pipeline(pipeline_signal, move |pipeline| pipeline.swap(index - 1, index)),
+                            {i18n.t("Move Up")}
+                        }
+                        button {
+                            disabled: index + 1 == count,
+                            onclick: move |_| update_pipeline(pipeline_signal, move |pipeline| pipeline.swap(index, index + 1)),
+                            {i18n.t("Move Down")}
+                        }
+                        button {
+                            onclick: move |_| update_pipeline(pipeline_signal, move |pipeline| {
+                                pipeline.remove(index);
+                            }),
+                            {i18n.t("Remove")}
+                        }
+                    }
+                }
+            }
+            div {
+                class: "form-field-row",
+                select {
+                    onchange: move |evt| new_kind.set(evt.value().parse().unwrap_or(0)),
+                    for (index, kind) in StepConfig::kinds().iter().enumerate() {
+                        option { value: "{index}", selected: index == new_kind(), {i18n.t(kind.label())} }
+                    }
+                }
+                button {
+                    onclick: move |_| {
+                        let config = StepConfig::kinds()[new_kind()].clone();
+                        update_pipeline(pipeline_signal, move |pipeline| pipeline.push(PipelineStep { enabled: true, config }));
+                    },
+                    {i18n.t("Add Step")}
+                }
+            }
+        }
+    }
+}
+
+/// Applies a change to the pipeline and persists it.
+fn update_pipeline(
+    mut pipeline: UsePersistent<Vec<PipelineStep>>,
+    change: impl FnOnce(&mut Vec<PipelineStep>),
+) {
+    let mut steps = pipeline.get();
+    change(&mut steps);
+    pipeline.set(steps);
+}
+
+/// Edits the options of a step. Text areas are applied when they lose focus,
+/// so that partially typed lines are not discarded.
+#[component]
+fn StepEditor(config: StepConfig, onchange: EventHandler<StepConfig>) -> Element {
+    let i18n = use_translator();
+    match config {
+        StepConfig::Reflow => rsx! {
+            p { class: "hint", {i18n.t("Joins the lines of each paragraph and collapses repeated spaces.")} }
+        },
+        StepConfig::SentenceCasing => rsx! {
+            p { class: "hint", {i18n.t("Capitalizes sentences and fixes the spacing around punctuation, for lowercase outputs.")} }
+        },
+        StepConfig::Redaction => rsx! {
+            p { class: "hint", {i18n.t("Replaces e-mail addresses and phone numbers with [redacted].")} }
+        },
+        StepConfig::Glossary { terms } => {
+            let text = terms
+                .iter()
+                .map(|(term, replacement)| format!("{} = {}", term, replacement))
+                .collect::<Vec<_>>()
+                .join("\n");
+            rsx! {
+                p { class: "hint", {i18n.t("One \"term = replacement\" per line. Terms match whole words, in any case.")} }
+                textarea {
+                    rows: "4",
+                    value: "{text}",
+                    onchange: move |evt| {
+                        let terms = evt
+                            .value()
+                            .lines()
+                            .filter_map(|line| line.split_once('='))
+                            .map(|(term, replacement)| (term.trim().to_string(), replacement.trim().to_string()))
+                            .filter(|(term, _)| !term.is_empty())
+                            .collect();
+                        onchange.call(StepConfig::Glossary { terms });
+                    }
+                }
+            }
+        }
+        StepConfig::Profanity { words } => rsx! {
+            p { class: "hint", {i18n.t("Words to mask, separated by commas or new lines.")} }
+            textarea {
+                rows: "3",
+                value: "{words.join(\", \")}",
+                onchange: move |evt| {
+                    let words = evt
+                        .value()
+                        .split([',', '\n'])
+                        .map(str::trim)
+                        .filter(|word| !word.is_empty())
+                        .map(str::to_string)
+                        .collect();
+                    onchange.call(StepConfig::Profanity { words });
+                }
+            }
+        },
+        StepConfig::CustomRegex {
+            pattern,
+            replacement,
+        } => {
+            let error = regex::Regex::new(&pattern).err();
+            let replacement_pattern = pattern.clone();
+            rsx! {
+                div {
+                    class: "form-field-row",
+                    input {
+                        r#type: "text",
+                        placeholder: i18n.t("Pattern"),
+                        value: "{pattern}",
+                        oninput: {
+                            let replacement = replacement.clone();
+                            move |evt: FormEvent| onchange.call(StepConfig::CustomRegex {
+                                pattern: evt.value(),
+                                replacement: replacement.clone(),
+                            })
+                        }
+                    }
+                    input {
+                        r#type: "text",
+                        placeholder: i18n.t("Replacement ($1 for groups)"),
+                        value: "{replacement}",
+                        oninput: move |evt: FormEvent| onchange.call(StepConfig::CustomRegex {
+                            pattern: replacement_pattern.clone(),
+                            replacement: evt.value(),
+                        })
+                    }
+                }
+                if let Some(error) = error {
+                    span { class: "status-text red", {i18n.format("Invalid pattern, this step will be skipped: {}", &[&error])} }
+                }
+            }
+        }
+    }
+}
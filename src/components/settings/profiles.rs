@@ -0,0 +1,172 @@
+//! Named endpoint profiles
+//! Lists the saved profiles with their connection status, switches between them,
+//! and saves new ones with their name, URL and API token. Several profiles may
+//! target the same URL, e.g. with different tokens.
+
+use crate::api;
+use crate::i18n::use_translator;
+use crate::profile::{self, remove_profile, EndpointProfile, Token};
+use crate::state::{ApiConnectionStatus, AppState};
+use chrono::Utc;
+use dioxus::prelude::*;
+
+/// The saved profiles, and a form to add one.
+#[component]
+pub fn ProfileList() -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let profiles = app_state.endpoint_profiles;
+    let current_url = app_state.api_url.get();
+    let current_id = app_state.current_profile_id();
+    let named = profile::named_profiles(&profiles.get());
+    // Incremented to check every profile again.
+    let mut refresh = use_signal(|| 0u32);
+
+    rsx! {
+        fieldset {
+            class: "profile-section named-profiles",
+            legend { {i18n.t("Profiles")} }
+            p { class: "hint", {i18n.t("Saved endpoints, to switch between a local server and a shared one.")} }
+
+            if named.is_empty() {
+                p { class: "probe-note", {i18n.t("No profile saved yet.")} }
+            } else {
+                ul {
+                    for (id, profile) in named {
+                        li {
+                            key: "{id}",
+                            if id == current_id {
+                                span {
+                                    class: "status-badge-dot {app_state.api_connection_status.read().color()}",
+                                }
+                            } else {
+                                ProfileStatus {
+                                    api_url: profile.api_url.clone(),
+                                    profile: profile.clone(),
+                                    refresh: refresh(),
+                                }
+                            }
+                            strong { " {profile.name.clone().unwrap_or_default()} " }
+                            code { "{profile.api_url}" }
+                            if id == current_id {
+                                span { class: "saved-message", {i18n.t(" In use")} }
+                            } else {
+                                button {
+                                    onclick: {
+                                        let id = id.clone();
+                                        let api_url = profile.api_url.clone();
+                                        move |_| {
+                                            log::info!("Switching to profile {} at {}", id, api_url);
+                                            app_state.select_profile(&id, &api_url);
+                                        }
+                                    },
+                                    {i18n.t("Use")}
+                                }
+                            }
+                            // Removing a profile drops its options and credentials, in use
+                            // or not. The API URL in use is kept, with its other options.
+                            button {
+                                onclick: {
+                                    let id = id.clone();
+                                    move |_| remove_profile(profiles, &id)
+                                },
+                                {i18n.t("Remove")}
+                            }
+                        }
+                    }
+                }
+                button { onclick: move |_| refresh += 1, {i18n.t("Check all")} }
+            }
+
+            NewProfileForm { current_url }
+        }
+    }
+}
+
+/// Connection status of a profile not in use, checked when shown and when it changes.
+#[component]
+fn ProfileStatus(api_url: String, profile: EndpointProfile, refresh: u32) -> Element {
+    let status = use_resource(use_reactive!(|(api_url, profile, refresh)| async move {
+        let _ = refresh;
+        ApiConnectionStatus::Pending
+            .after_check(api::get_status(&api_url, &profile).await, Utc::now())
+    }));
+    let status = status.read().clone().unwrap_or_default();
+
+    rsx! {
+        span { class: "status-badge-dot {status.color()}" }
+    }
+}
+
+/// Saves a new profile, by default for the API URL in use. A profile saved for the
+/// URL in use starts from its options, and becomes the profile in use.
+#[component]
+fn NewProfileForm(current_url: String) -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut profiles = app_state.endpoint_profiles;
+    let mut name = use_signal(String::new);
+    let mut url = use_signal(|| current_url.clone());
+    let mut token = use_signal(String::new);
+
+    let trimmed_name = name.read().trim().to_string();
+    let trimmed_url = url.read().trim().to_string();
+    let name_taken = profile::named_profiles(&profiles.get())
+        .iter()
+        .any(|(_, other)| other.name.as_deref() == Some(trimmed_name.as_str()));
+    let can_save = !trimmed_name.is_empty() && !trimmed_url.is_empty() && !name_taken;
+
+    let save = move |_| {
+        let url_value = url.read().trim().to_string();
+        let token_value = token.read().trim().to_string();
+        let in_use = url_value == app_state.api_url.get();
+        let mut profile = if in_use {
+            app_state.current_profile()
+        } else {
+            EndpointProfile::default()
+        };
+        profile.name = Some(name.read().trim().to_string());
+        profile.api_url = url_value.clone();
+        if !token_value.is_empty() {
+            profile.api_token = Some(Token(token_value));
+        }
+        let id = profile::new_profile_id(Utc::now());
+        let mut all = profiles.get();
+        all.insert(id.clone(), profile);
+        profiles.set(all);
+        if in_use {
+            app_state.select_profile(&id, &url_value);
+        }
+        name.set(String::new());
+        token.set(String::new());
+    };
+
+    rsx! {
+        div {
+            class: "form-field-row",
+            input {
+                r#type: "text",
+                placeholder: i18n.t("Name"),
+                value: "{name}",
+                oninput: move |evt| name.set(evt.value()),
+            }
+            input {
+                r#type: "text",
+                placeholder: i18n.t("API URL"),
+                value: "{url}",
+                oninput: move |evt| url.set(evt.value()),
+            }
+            input {
+                r#type: "password",
+                placeholder: i18n.t("API token (optional)"),
+                autocomplete: "off",
+                value: "{token}",
+                oninput: move |evt| token.set(evt.value()),
+            }
+            button { onclick: save, disabled: !can_save, {i18n.t("Save profile")} }
+        }
+        if name_taken {
+            p { class: "status-text red", {i18n.t("Another profile already has this name.")} }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! History retention section of the settings panel
+//! Edits how long the history is kept, and asks for confirmation before
+//! a stricter policy deletes existing entries.
+
+use crate::history::{self, RetentionPolicy};
+use crate::i18n::use_translator;
+use crate::state::AppState;
+use chrono::Utc;
+use dioxus::prelude::*;
+
+/// Editor of the history retention policy.
+#[component]
+pub(super) fn RetentionSettings() -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut draft = use_signal(|| app_state.history_retention.get());
+
+    let current = app_state.history_retention.get();
+    let history = app_state.history.get();
+    let kept = history::apply_retention(&history, &draft(), Utc::now());
+    let removed = history.len() - kept.len();
+    let max_age_days = draft()
+        .max_age_days
+        .map(|days| days.to_string())
+        .unwrap_or_default();
+
+    let apply = move |_| {
+        let policy = draft();
+        app_state.history_retention.set(policy);
+        let kept = history::apply_retention(&app_state.history.get(), &policy, Utc::now());
+        app_state.history.set(kept);
+    };
+
+    rsx! {
+        details {
+            class: "retention-settings",
+            summary { {i18n.t("History retention")} }
+            div {
+                class: "form-field-row",
+                label {
+                    {i18n.t("Keep at most ")}
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        value: "{draft().max_entries}",
+                        oninput: move |evt| {
+                            if let Ok(max_entries) = evt.value().parse::<usize>() {
+                                draft.set(RetentionPolicy { max_entries: max_entries.max(1), ..draft() });
+                            }
+                        }
+                    }
+                    {i18n.t(" entries")}
+                }
+                label {
+                    {i18n.t("Delete entries older than ")}
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        placeholder: i18n.t("never"),
+                        value: "{max_age_days}",
+                        oninput: move |evt| {
+                            let max_age_days = evt.value().parse::<u32>().ok().filter(|days| *days > 0);
+                            draft.set(RetentionPolicy { max_age_days, ..draft() });
+                        }
+                    }
+                    {i18n.t(" days")}
+                }
+            }
+            if draft() != current {
+                if removed > 0 {
+                    p { class: "hint severity-warning", {i18n.format("Applying this policy deletes {} history entries now.", &[&removed])} }
+                }
+                button {
+                    onclick: apply,
+                    if removed > 0 { {i18n.format("Delete {} Entries and Apply", &[&removed])} } else { {i18n.t("Apply")} }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Stored data section of the settings panel
+//! Lists what the application keeps in the browser, with sizes, exports it all
+//! as JSON, and deletes it all at once. Orphaned entries can be removed on their own.
+
+use crate::i18n::use_translator;
+use crate::media::{self, format_size, handles};
+use crate::storage::{self, StoredEntry};
+use chrono::Local;
+use dioxus::prelude::*;
+
+/// Lists the persisted entries, with export and deletion of the whole data.
+#[component]
+pub(super) fn StoredData() -> Element {
+    let i18n = use_translator();
+    let mut entries: Signal<Vec<StoredEntry>> = use_signal(Vec::new);
+    let mut confirming = use_signal(|| false);
+    let has_orphans = entries.read().iter().any(StoredEntry::is_orphaned);
+    let total_size: usize = entries.read().iter().map(|entry| entry.size).sum();
+
+    let export = move |_| {
+        let file_name = format!("leontine-data-{}.json", Local::now().format("%Y%m%d"));
+        media::download(&file_name, "application/json", &storage::export_all());
+    };
+
+    // Persisted values are also held in memory: reloading starts over from a clean state.
+    let delete_everything = move |_| {
+        let removed = storage::clear_all();
+        handles::delete_all();
+        log::info!("Deleted all local data: {:?}", removed);
+        if let Some(window) = web_sys::window() {
+            let _ = window.location().reload();
+        }
+    };
+
+    rsx! {
+        details {
+            class: "stored-data",
+            // Read the storage each time the section is opened, so the listing is current.
+            ontoggle: move |_| entries.set(storage::list_entries()),
+            summary { {i18n.t("Your data")} }
+            p { class: "hint", {i18n.t("Everything below is stored in this browser only. Recordings are never stored, only references to them.")} }
+            table {
+                tbody {
+                    for entry in entries() {
+                        tr {
+                            key: "{entry.key}",
+                            class: if entry.is_orphaned() { "orphaned" },
+                            td {
+                                {i18n.t(match entry.known {
+                                    Some(known) => known.description(),
+                                    None => "No longer used",
+                                })}
+                            }
+                            td {
+                                code {
+                                    title: entry.known.map_or("", |known| known.value_type()),
+                                    "{entry.key}"
+                                }
+                            }
+                            td { "{format_size(entry.size as u64)}" }
+                        }
+                    }
+                }
+            }
+            p { class: "hint", {i18n.format("{} entries, {} in total.", &[&entries.read().len(), &format_size(total_size as u64)])} }
+            div {
+                class: "action-buttons",
+                button { onclick: export, {i18n.t("Export as JSON")} }
+                if has_orphans {
+                    button {
+                        onclick: move |_| {
+                            let removed = storage::clean_orphans();
+                            log::info!("Removed orphaned storage keys: {:?}", removed);
+                            entries.set(storage::list_entries());
+                        },
+                        {i18n.t("Remove orphaned entries")}
+                    }
+                }
+                button { class: "button-clear", onclick: move |_| confirming.set(true), {i18n.t("Delete Everything")} }
+            }
+            if confirming() {
+                div {
+                    class: "bulk-confirmation",
+                    p { {i18n.t("Settings, credentials, history, results and consents will be deleted from this browser, and the page reloaded. Jobs still on the server are not cancelled.")} }
+                    div {
+                        class: "action-buttons",
+                        button { onclick: move |_| confirming.set(false), {i18n.t("Cancel")} }
+                        button { class: "button-clear", onclick: delete_everything, {i18n.t("Delete Everything")} }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Status badge component
+//! A standalone view of the API availability and queue depth, with minimal chrome,
+//! for embedding in an iframe. It has its own status monitor, independent of the
+//! application state.
+
+use crate::config::{API_STATUS_CHECK_INTERVAL_MS, DEFAULT_API_URL};
+use crate::embed;
+use crate::hooks::persistent::use_persistent;
+use crate::i18n::use_translator_provider;
+use crate::profile::{self, EndpointProfiles};
+use crate::services::status_monitor::use_status_monitor;
+use crate::state::ApiConnectionStatus;
+use crate::storage::StorageKey;
+use dioxus::prelude::*;
+
+/// The badge, for the API URL of the address or else the saved one.
+/// Credentials come from the endpoint profile saved in this browser.
+#[component]
+pub fn StatusBadge() -> Element {
+    let stored_url = use_persistent(StorageKey::ApiUrl, || DEFAULT_API_URL.to_string());
+    let profiles = use_persistent(StorageKey::EndpointProfiles, EndpointProfiles::default);
+    let profile_id = use_persistent(StorageKey::ProfileId, || None::<String>);
+    let i18n = use_translator_provider(use_persistent(StorageKey::Locale, || None));
+    let api_url = use_hook(|| embed::api_url_param().unwrap_or_else(|| stored_url.get()));
+    let status = use_signal(|| ApiConnectionStatus::Pending);
+
+    let checked_url = api_url.clone();
+    use_status_monitor(
+        status,
+        move || {
+            let profile =
+                profile::profile_for(&profiles.get(), profile_id.get().as_deref(), &checked_url);
+            (checked_url.clone(), profile)
+        },
+        || API_STATUS_CHECK_INTERVAL_MS as u32,
+    )
+    .use_subscription();
+
+    let color = status.read().color();
+    let label = match &*status.read() {
+        ApiConnectionStatus::Available(_, _) => "Transcription Online",
+        ApiConnectionStatus::Stale { .. } => "Transcription Not responding",
+        ApiConnectionStatus::Maintenance(_, _) => "Transcription Maintenance",
+        ApiConnectionStatus::Unavailable(_, _) => "Transcription Unavailable",
+        ApiConnectionStatus::Pending => "Transcription Checking...",
+    };
+    let queue = status.read().api_status().map(|status| {
+        i18n.format(
+            "{} queued, {} processing",
+            &[
+                &status.queue_state.queued_jobs,
+                &status.queue_state.processing_jobs,
+            ],
+        )
+    });
+
+    rsx! {
+        div {
+            class: "status-badge",
+            title: "{api_url}",
+            span { class: "status-badge-dot {color}" }
+            span { class: "status-badge-label", {i18n.t(label)} }
+            if let Some(queue) = queue {
+                span { class: "status-badge-queue", "{queue}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Storage banner component
+//! Informs the user when settings cannot be saved in the browser storage.
+
+use crate::history;
+use crate::i18n::use_translator;
+use crate::state::AppState;
+use crate::storage::{self, QUOTA_EXCEEDED};
+use dioxus::prelude::*;
+
+/// Banner warning that settings are kept in memory only when localStorage is unavailable,
+/// or prompting the user to free browser storage once the quota is exhausted.
+#[component]
+pub fn StorageBanner() -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut dismissed = use_signal(|| false);
+
+    if !storage::is_available() {
+        if dismissed() {
+            return rsx! {};
+        }
+        return rsx! {
+            div {
+                class: "storage-banner severity-info",
+                p {
+                    {i18n.t("Browser storage is unavailable (private browsing or embedded view): ")}
+                    {i18n.t("settings and the active job will not be kept after this page is closed.")}
+                }
+                button { onclick: move |_| dismissed.set(true), {i18n.t("Dismiss")} }
+            }
+        };
+    }
+
+    if !QUOTA_EXCEEDED() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "storage-banner severity-warning",
+            p { {i18n.t("The browser storage is full: your latest changes could not be saved.")} }
+            div {
+                class: "action-buttons",
+                if !app_state.trash.get().is_empty() {
+                    button {
+                        onclick: move |_| {
+                            app_state.trash.set(Vec::new());
+                            storage::clean_orphans();
+                            *QUOTA_EXCEEDED.write() = false;
+                        },
+                        {i18n.t("Empty the history trash")}
+                    }
+                }
+                if app_state.history.get().iter().filter(|entry| !entry.starred).count() > 1 {
+                    button {
+                        onclick: move |_| {
+                            let trimmed = history::trim_oldest(&app_state.history.get());
+                            app_state.history.set(trimmed);
+                            storage::clean_orphans();
+                            *QUOTA_EXCEEDED.write() = false;
+                        },
+                        {i18n.t("Remove oldest history entries")}
+                    }
+                }
+                button {
+                    onclick: move |_| {
+                        let starred = history::clear_unstarred(&app_state.history.get());
+                        app_state.history.set(starred);
+                        app_state.submission_log.remove();
+                        storage::clean_orphans();
+                        *QUOTA_EXCEEDED.write() = false;
+                    },
+                    {i18n.t("Clear submission history")}
+                }
+                button {
+                    onclick: move |_| *QUOTA_EXCEEDED.write() = false,
+                    {i18n.t("Dismiss")}
+                }
+            }
+        }
+    }
+}
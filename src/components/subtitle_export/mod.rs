@@ -0,0 +1,164 @@
+//! Subtitle export component
+//! Exports a segmented transcript as SRT or WebVTT, fitted to the configured
+//! line-length and cue-duration constraints, with a report of the remaining violations.
+//! For video inputs, the cues can be previewed burnt in before export; for audio
+//! inputs, they can be followed while the recording plays.
+
+mod player;
+mod preview;
+
+use crate::i18n::{use_translator, Translator};
+use crate::media::text_data_url;
+use crate::state::AppState;
+use crate::transcript::subtitles::{self, SubtitleConstraints, Violation};
+use crate::transcript::TranscriptSegment;
+use dioxus::prelude::*;
+use player::SubtitlePlayer;
+use preview::BurnInPreview;
+
+/// Maximum number of violations listed in the report.
+const MAX_REPORTED_VIOLATIONS: usize = 10;
+
+/// Subtitle download links for the segments of a job, with the burn-in preview
+/// when the submitted file is a video available at `video_url`, or the subtitle
+/// player when it is a recording available at `audio_url`.
+/// `corrected` tells whether the transcript text was corrected, which the timed
+/// segments cannot reflect.
+#[component]
+pub fn SubtitleExport(
+    job_id: String,
+    segments: Vec<TranscriptSegment>,
+    corrected: bool,
+    video_url: Option<String>,
+    audio_url: Option<String>,
+) -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut previewing = use_signal(|| false);
+    let constraints = app_state.subtitle_constraints.get();
+
+    let cues = subtitles::fit(&segments, &constraints);
+    let violations = subtitles::validate(&cues, &constraints);
+    let srt_href = text_data_url(&subtitles::to_srt(&cues));
+    let vtt_href = text_data_url(&subtitles::to_vtt(&cues));
+
+    let mut update = move |change: fn(&mut SubtitleConstraints, f64), value: String| {
+        if let Ok(value) = value.parse::<f64>() {
+            let mut constraints = app_state.subtitle_constraints.get();
+            change(&mut constraints, value);
+            app_state.subtitle_constraints.set(constraints);
+        }
+    };
+
+    rsx! {
+        details {
+            class: "subtitle-export",
+            summary { {i18n.t("Export Subtitles")} }
+            if corrected {
+                p { class: "hint", {i18n.t("The subtitles follow the timed segments, with the post-processing steps applied: corrections made to the transcript text are not included.")} }
+            }
+            div {
+                class: "form-field-row",
+                label {
+                    {i18n.t("Characters per line ")}
+                    input {
+                        r#type: "number",
+                        min: "10",
+                        value: "{constraints.max_line_chars}",
+                        onchange: move |evt| update(|c, v| c.max_line_chars = (v as usize).max(10), evt.value()),
+                    }
+                }
+                label {
+                    {i18n.t("Lines per cue ")}
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        value: "{constraints.max_lines}",
+                        onchange: move |evt| update(|c, v| c.max_lines = (v as usize).max(1), evt.value()),
+                    }
+                }
+            }
+            div {
+                class: "form-field-row",
+                label {
+                    {i18n.t("Cue duration from ")}
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        step: "0.1",
+                        value: "{constraints.min_cue_secs}",
+                        onchange: move |evt| update(|c, v| c.min_cue_secs = v.max(0.0), evt.value()),
+                    }
+                }
+                label {
+                    {i18n.t(" to ")}
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        step: "0.1",
+                        value: "{constraints.max_cue_secs}",
+                        onchange: move |evt| update(|c, v| c.max_cue_secs = v.max(1.0), evt.value()),
+                    }
+                    " s"
+                }
+            }
+            if violations.is_empty() {
+                p { class: "hint", {i18n.format("{} cues, all within the constraints.", &[&cues.len()])} }
+            } else {
+                div {
+                    class: "subtitle-violations severity-warning",
+                    p { {i18n.format("{} violations remain in {} cues:", &[&violations.len(), &cues.len()])} }
+                    ul {
+                        for violation in violations.iter().take(MAX_REPORTED_VIOLATIONS) {
+                            li { {describe(violation, i18n)} }
+                        }
+                    }
+                    if violations.len() > MAX_REPORTED_VIOLATIONS {
+                        p { {i18n.format("and {} more.", &[&(violations.len() - MAX_REPORTED_VIOLATIONS)])} }
+                    }
+                }
+            }
+            if let Some(video_url) = video_url.clone().filter(|_| previewing()) {
+                BurnInPreview { video_url, cues: cues.clone() }
+            }
+            if let Some(audio_url) = audio_url.clone().filter(|_| previewing()) {
+                SubtitlePlayer { audio_url, cues: cues.clone() }
+            }
+            div {
+                class: "action-buttons",
+                if video_url.is_some() {
+                    button {
+                        onclick: move |_| previewing.toggle(),
+                        if previewing() { {i18n.t("Hide Preview")} } else { {i18n.t("Preview on Video")} }
+                    }
+                } else if audio_url.is_some() {
+                    button {
+                        onclick: move |_| previewing.toggle(),
+                        if previewing() { {i18n.t("Hide Player")} } else { {i18n.t("Play with Subtitles")} }
+                    }
+                }
+                a { class: "download-button", href: "{srt_href}", download: "{job_id}.srt", {i18n.t("Download SRT")} }
+                a { class: "download-button", href: "{vtt_href}", download: "{job_id}.vtt", {i18n.t("Download VTT")} }
+            }
+        }
+    }
+}
+
+/// The violation, as listed in the report.
+fn describe(violation: &Violation, i18n: Translator) -> String {
+    match violation {
+        Violation::LineTooLong { cue, chars } => {
+            i18n.format("Cue {}: a line has {} characters", &[&(cue + 1), chars])
+        }
+        Violation::TooManyLines { cue, lines } => {
+            i18n.format("Cue {}: {} lines", &[&(cue + 1), lines])
+        }
+        Violation::TooLong { cue, secs } => {
+            i18n.format("Cue {}: lasts {} s", &[&(cue + 1), &format!("{:.1}", secs)])
+        }
+        Violation::TooShort { cue, secs } => i18n.format(
+            "Cue {}: lasts only {} s",
+            &[&(cue + 1), &format!("{:.1}", secs)],
+        ),
+    }
+}
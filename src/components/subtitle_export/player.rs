@@ -0,0 +1,82 @@
+//! Subtitle player
+//! Plays the recording with the list of cues, highlighting the cue being heard and
+//! keeping it in view. Clicking a cue plays the recording from its start.
+
+use crate::components::output_device::OutputDeviceSelect;
+use crate::document::eval;
+use crate::transcript::format_timestamp;
+use crate::transcript::subtitles::Cue;
+use dioxus::prelude::*;
+
+/// Identifier of the audio element, driven through JS for seeking.
+const AUDIO_ID: &str = "subtitle-player-audio";
+
+/// The recording at `audio_url`, with the cues synchronized to its playback.
+#[component]
+pub fn SubtitlePlayer(audio_url: String, cues: Vec<Cue>) -> Element {
+    let mut current: Signal<Option<usize>> = use_signal(|| None);
+    let cue_times: Vec<(f64, f64)> = cues.iter().map(|cue| (cue.start, cue.end)).collect();
+
+    let track_playback = move |_| {
+        let cue_times = cue_times.clone();
+        spawn(async move {
+            let time = eval(&format!(
+                "return document.getElementById('{}').currentTime;",
+                AUDIO_ID
+            ))
+            .join::<f64>()
+            .await;
+            let Ok(time) = time else {
+                return;
+            };
+            let playing = cue_times
+                .iter()
+                .position(|(start, end)| *start <= time && time < *end);
+            if playing.is_some() && playing != *current.peek() {
+                let _ = eval(&format!(
+                    "document.getElementById('subtitle-cue-{}')?.scrollIntoView({{ block: 'nearest' }});",
+                    playing.unwrap_or_default()
+                ));
+            }
+            current.set(playing);
+        });
+    };
+
+    let play_from = move |start: f64| {
+        let _ = eval(&format!(
+            "const audio = document.getElementById('{}'); audio.currentTime = {}; audio.play();",
+            AUDIO_ID, start
+        ));
+    };
+
+    rsx! {
+        div {
+            class: "subtitle-player",
+            audio {
+                id: AUDIO_ID,
+                controls: true,
+                src: "{audio_url}",
+                ontimeupdate: track_playback,
+            }
+            OutputDeviceSelect { audio_id: AUDIO_ID }
+            div {
+                class: "subtitle-cues",
+                for (index, cue) in cues.into_iter().enumerate() {
+                    div {
+                        key: "{index}",
+                        id: "subtitle-cue-{index}",
+                        class: if current() == Some(index) { "subtitle-cue current" } else { "subtitle-cue" },
+                        onclick: move |_| play_from(cue.start),
+                        span { class: "segment-time", "{format_timestamp(cue.start)}" }
+                        span {
+                            class: "subtitle-cue-text",
+                            for line in cue.lines.iter() {
+                                span { "{line}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
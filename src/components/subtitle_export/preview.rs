@@ -0,0 +1,67 @@
+//! Burn-in preview
+//! Draws the cues over the playing video on a canvas, with the line breaks and
+//! styling they would have once burnt in, for spot-checking before export.
+
+use crate::document::eval;
+use crate::transcript::subtitles::Cue;
+use dioxus::prelude::*;
+
+/// Draws the active cue on the overlay canvas at every animation frame.
+/// Each run takes over from the previous one through a generation counter,
+/// so that a single drawing loop stays active.
+const PREVIEW_SCRIPT: &str = r#"
+    const cues = await dioxus.recv();
+    const generation = (window.leontineSubtitlePreview || 0) + 1;
+    window.leontineSubtitlePreview = generation;
+    const draw = () => {
+        const video = document.getElementById('subtitle-preview-video');
+        const canvas = document.getElementById('subtitle-preview-canvas');
+        if (!video || !canvas || window.leontineSubtitlePreview !== generation) return;
+        if (canvas.width !== video.clientWidth || canvas.height !== video.clientHeight) {
+            canvas.width = video.clientWidth;
+            canvas.height = video.clientHeight;
+        }
+        const context = canvas.getContext('2d');
+        context.clearRect(0, 0, canvas.width, canvas.height);
+        const time = video.currentTime;
+        const cue = cues.find((cue) => cue.start <= time && time < cue.end);
+        if (cue) {
+            const size = Math.max(12, Math.round(canvas.height / 18));
+            context.font = `${size}px sans-serif`;
+            context.textAlign = 'center';
+            context.textBaseline = 'bottom';
+            context.lineJoin = 'round';
+            context.lineWidth = size / 6;
+            context.strokeStyle = 'black';
+            context.fillStyle = 'white';
+            // Lines are stacked upwards from the bottom margin, above the video controls.
+            cue.lines.slice().reverse().forEach((line, index) => {
+                const y = canvas.height - size * 2 - index * size * 1.2;
+                context.strokeText(line, canvas.width / 2, y);
+                context.fillText(line, canvas.width / 2, y);
+            });
+        }
+        requestAnimationFrame(draw);
+    };
+    requestAnimationFrame(draw);
+"#;
+
+/// The video with the cues drawn over it.
+#[component]
+pub fn BurnInPreview(video_url: String, cues: Vec<Cue>) -> Element {
+    // Restart the drawing loop with the new cues whenever the constraints change them.
+    use_effect(use_reactive!(|cues| {
+        let preview = eval(PREVIEW_SCRIPT);
+        if let Err(err) = preview.send(cues) {
+            log::warn!("Failed to start the subtitle preview: {:?}", err);
+        }
+    }));
+
+    rsx! {
+        div {
+            class: "burn-in-preview",
+            video { id: "subtitle-preview-video", controls: true, src: "{video_url}" }
+            canvas { id: "subtitle-preview-canvas" }
+        }
+    }
+}
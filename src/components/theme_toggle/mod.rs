@@ -0,0 +1,24 @@
+//! Theme toggle component
+//! A header button cycling through the system, light and dark themes.
+
+use crate::i18n::use_translator;
+use crate::state::AppState;
+use dioxus::prelude::*;
+
+/// The toggle, labelled with the current theme.
+#[component]
+pub fn ThemeToggle() -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let theme = app_state.theme.get();
+    let label = i18n.format("Theme: {}", &[&i18n.t(theme.label())]);
+
+    rsx! {
+        button {
+            class: "theme-toggle",
+            title: i18n.t("Switch to the next theme"),
+            onclick: move |_| app_state.theme.set(theme.next()),
+            "{label}"
+        }
+    }
+}
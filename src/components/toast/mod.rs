@@ -0,0 +1,75 @@
+//! Toast notifications
+//! Short-lived messages shown on top of the page, raised from anywhere with [`show`].
+//! Messages are kept in English and translated when displayed.
+
+use crate::config::TOAST_DURATION_MS;
+use crate::errors::Severity;
+use crate::i18n::use_translator;
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+
+/// A notification currently displayed.
+#[derive(Debug, Clone, PartialEq)]
+struct Toast {
+    id: u32,
+    /// English message, with `{}` placeholders for `args`.
+    message: &'static str,
+    args: Vec<String>,
+    severity: Severity,
+}
+
+/// The displayed notifications, oldest first.
+static TOASTS: GlobalSignal<Vec<Toast>> = Signal::global(Vec::new);
+
+/// Identifier of the next notification.
+static NEXT_ID: GlobalSignal<u32> = Signal::global(|| 0);
+
+/// Displays a notification, dismissed automatically after `TOAST_DURATION_MS`.
+pub fn show(message: &'static str, severity: Severity) {
+    show_with(message, &[], severity);
+}
+
+/// Displays a notification whose `{}` placeholders are filled with `args`, in order.
+pub fn show_with(message: &'static str, args: &[&dyn std::fmt::Display], severity: Severity) {
+    let id = *NEXT_ID.peek();
+    *NEXT_ID.write() = id.wrapping_add(1);
+    TOASTS.write().push(Toast {
+        id,
+        message,
+        args: args.iter().map(|arg| arg.to_string()).collect(),
+        severity,
+    });
+    spawn_forever(async move {
+        TimeoutFuture::new(TOAST_DURATION_MS).await;
+        dismiss(id);
+    });
+}
+
+/// Removes a notification.
+fn dismiss(id: u32) {
+    TOASTS.write().retain(|toast| toast.id != id);
+}
+
+/// Renders the displayed notifications. Must be mounted once, in the `App` component.
+#[component]
+pub fn ToastHost() -> Element {
+    let i18n = use_translator();
+
+    rsx! {
+        div {
+            class: "toast-host",
+            for toast in TOASTS() {
+                div {
+                    key: "{toast.id}",
+                    class: "toast {toast.severity.css_class()}",
+                    onclick: move |_| dismiss(toast.id),
+                    {
+                        let args: Vec<&dyn std::fmt::Display> =
+                            toast.args.iter().map(|arg| arg as &dyn std::fmt::Display).collect();
+                        i18n.format(toast.message, &args)
+                    }
+                }
+            }
+        }
+    }
+}
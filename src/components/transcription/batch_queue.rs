@@ -0,0 +1,187 @@
+//! Batch queue
+//! Lists the files selected together with the status of each, submits them, and
+//! downloads their transcripts as a single document once done.
+
+use crate::document::eval;
+use crate::i18n::use_translator;
+use crate::media;
+use crate::services::batch::{self, BatchItemStatus};
+use crate::state::AppState;
+use crate::transcript::export;
+use dioxus::prelude::*;
+
+/// The files of the batch queue. `onstart` starts submitting them, with whether up to
+/// the server's number of concurrent jobs may run at once.
+#[component]
+pub fn BatchQueue(awaiting_consent: bool, onstart: EventHandler<bool>) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut parallel = use_signal(|| false);
+
+    let items: Vec<_> = app_state
+        .batch
+        .read()
+        .iter()
+        .map(|item| (item.clone(), batch::status(app_state, item)))
+        .collect();
+    let is_running = batch::is_running();
+    let is_paused = batch::is_paused();
+    let has_waiting = items
+        .iter()
+        .any(|(_, status)| *status == BatchItemStatus::Waiting);
+    let finished = items
+        .iter()
+        .filter(|(_, status)| status.is_finished())
+        .count();
+    let done = items
+        .iter()
+        .filter(|(_, status)| *status == BatchItemStatus::Done)
+        .count();
+    let max_concurrent_jobs = app_state
+        .api_connection_status
+        .read()
+        .api_status()
+        .map(|status| status.processing.max_concurrent_jobs)
+        .filter(|max| *max > 1);
+    let accepts_submissions = app_state.api_connection_status.read().accepts_submissions();
+
+    let combined = move || {
+        batch::completed_entries(app_state)
+            .iter()
+            .map(|entry| {
+                export::markdown(
+                    &entry.title(),
+                    entry.result.as_deref().unwrap_or_default(),
+                    entry.processed_result.as_deref(),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n---\n\n")
+    };
+
+    rsx! {
+        div {
+            class: "batch-queue",
+            p { {i18n.format("{} files in the queue, {} finished.", &[&items.len(), &finished])} }
+            table {
+                for (item, status) in items {
+                    tr {
+                        key: "{item.id}",
+                        td { class: "batch-file-name", "{item.file_name}" }
+                        td {
+                            if let Some(size) = item.size {
+                                "{media::format_size(size)}"
+                            }
+                        }
+                        td { BatchStatus { status: status.clone() } }
+                        td {
+                            if status == BatchItemStatus::Waiting && !is_running {
+                                button {
+                                    onclick: move |_| batch::remove(app_state, item.id),
+                                    {i18n.t("Remove")}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if is_running {
+                div {
+                    class: "action-buttons",
+                    button { onclick: move |_| batch::pause(app_state), {i18n.t("Pause")} }
+                    button { class: "button-cancel", onclick: move |_| batch::stop(app_state), {i18n.t("Stop Queue")} }
+                }
+                p { class: "hint", {i18n.t("Pausing frees the bandwidth: no further file is sent until the queue resumes.")} }
+                p { class: "hint", {i18n.t("The jobs already submitted keep being monitored when the queue stops.")} }
+            } else {
+                if is_paused && has_waiting {
+                    p { class: "hint", {i18n.t("The queue is paused. The file being uploaded is sent again on resume; the others are kept as they stand.")} }
+                }
+                if has_waiting && !is_paused {
+                    if let Some(max) = max_concurrent_jobs {
+                        label {
+                            input {
+                                r#type: "checkbox",
+                                checked: parallel(),
+                                onchange: move |evt| parallel.set(evt.checked()),
+                            }
+                            {i18n.format(" Run up to {} jobs at once, as many as the server processes concurrently", &[&max])}
+                        }
+                    }
+                }
+                div {
+                    class: "action-buttons",
+                    button { class: "button-clear", onclick: move |_| batch::clear(app_state), {i18n.t("Clear Queue")} }
+                    button {
+                        onclick: move |_| { let _ = eval(r#"document.getElementById('file-upload-input').click();"#); },
+                        disabled: !(app_state.can_select_file)(),
+                        {i18n.t("Add Files")}
+                    }
+                    if has_waiting && is_paused {
+                        button {
+                            class: "button-transcribe",
+                            onclick: move |_| {
+                                batch::resume(app_state);
+                            },
+                            disabled: !accepts_submissions,
+                            {i18n.t("Resume")}
+                        }
+                    } else if has_waiting {
+                        button {
+                            class: "button-transcribe",
+                            onclick: move |_| onstart.call(parallel()),
+                            disabled: !accepts_submissions || awaiting_consent,
+                            {i18n.t("Transcribe All")}
+                        }
+                    }
+                }
+            }
+            if done > 0 {
+                div {
+                    class: "action-buttons",
+                    button {
+                        onclick: move |_| media::download("transcripts.md", "text/markdown", &combined()),
+                        {i18n.format("Download the {} Transcripts", &[&done])}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Status of a file of the queue.
+#[component]
+fn BatchStatus(status: BatchItemStatus) -> Element {
+    let i18n = use_translator();
+    let (class, label) = match &status {
+        BatchItemStatus::Waiting => ("waiting", i18n.t("Waiting").to_string()),
+        BatchItemStatus::Uploading(progress) => (
+            "uploading",
+            i18n.format(
+                "Uploading ({}%)",
+                &[&progress.map_or(0, |progress| progress.percent())],
+            ),
+        ),
+        BatchItemStatus::Queued(Some(position)) => {
+            ("queued", i18n.format("Queued at position {}", &[position]))
+        }
+        BatchItemStatus::Queued(None) => ("queued", i18n.t("Queued").to_string()),
+        BatchItemStatus::Processing => ("processing", i18n.t("Processing").to_string()),
+        BatchItemStatus::Done => ("done", i18n.t("Done").to_string()),
+        BatchItemStatus::Failed(_) => ("failed", i18n.t("Failed").to_string()),
+    };
+    let reason = match status {
+        BatchItemStatus::Failed(Some(error)) => {
+            let info = error.info();
+            Some(format!("{} ({})", i18n.t(info.message), info.code))
+        }
+        _ => None,
+    };
+
+    rsx! {
+        span { class: "batch-status {class}", "{label}" }
+        if let Some(reason) = reason {
+            span { class: "hint", " {reason}" }
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! Upload consent checkbox
+//! Shows the consent statement of the deployment, which must be accepted
+//! before the selected file can be submitted.
+
+use crate::runtime_config::ConsentConfig;
+use dioxus::prelude::*;
+
+/// The statement with its checkbox. `accepted` is reset once the consent is recorded.
+#[component]
+pub fn ConsentCheckbox(consent: ConsentConfig, accepted: Signal<bool>) -> Element {
+    rsx! {
+        label {
+            class: "upload-consent",
+            input {
+                r#type: "checkbox",
+                checked: accepted(),
+                onchange: move |evt| accepted.set(evt.checked()),
+            }
+            " {consent.text}"
+        }
+    }
+}
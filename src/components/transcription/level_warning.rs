@@ -0,0 +1,72 @@
+//! Level warning
+//! Warns before submission about levels that make transcriptions less accurate,
+//! and suggests transcribing the only audible channel of a stereo recording.
+
+use crate::errors::{AppError, ValidationError};
+use crate::i18n::use_translator;
+use crate::media::channels::ChannelSelection;
+use crate::media::levels::{AudioLevels, LevelIssue};
+use dioxus::prelude::*;
+
+/// The issues of the levels of the selected recording, if any.
+/// `selection` is the channel selection of the submission, which the suggestion sets.
+#[component]
+pub fn LevelWarning(levels: AudioLevels, selection: Signal<ChannelSelection>) -> Element {
+    let i18n = use_translator();
+    let issues = levels.issues();
+    if issues.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        for issue in issues {
+            match issue {
+                LevelIssue::Clipping(ratio) => rsx! {
+                    LevelIssueMessage { error: ValidationError::AudioClipped(ratio) }
+                },
+                LevelIssue::TooQuiet(level) => rsx! {
+                    LevelIssueMessage { error: ValidationError::AudioTooQuiet(level) }
+                },
+                LevelIssue::SilentChannel(silent) => {
+                    let audible = ChannelSelection::Only(silent.other());
+                    let silent_label = i18n.t(silent.label()).to_lowercase();
+                    let audible_label = i18n.t(silent.other().label()).to_lowercase();
+                    rsx! {
+                        p {
+                            class: "hint",
+                            {i18n.format("The {} channel of this recording is nearly silent. ", &[&silent_label])}
+                            if selection() == audible {
+                                {i18n.format("Only the {} channel will be transcribed.", &[&audible_label])}
+                            } else {
+                                button {
+                                    onclick: move |_| selection.set(audible),
+                                    {i18n.format("Transcribe the {} channel only", &[&audible_label])}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A warning of the catalogue, with its code, details and remediation.
+#[component]
+fn LevelIssueMessage(error: ValidationError) -> Element {
+    let i18n = use_translator();
+    let info = AppError::from(error).info();
+    rsx! {
+        div {
+            class: "duration-warning {info.severity.css_class()}",
+            p {
+                strong { {i18n.t(info.message)} }
+                span { class: "error-code", " ({info.code})" }
+            }
+            if let Some(detail) = info.detail {
+                p { "{detail}" }
+            }
+            p { {i18n.t(info.remediation)} }
+        }
+    }
+}
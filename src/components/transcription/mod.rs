@@ -1,28 +1,52 @@
 //! Transcription panel component
 //! Manages file selection, state, and actions for transcription.
 
-use crate::api::{self, ApiError, JobState, JobStatus, TranscriptionJob};
+mod batch_queue;
+mod consent_checkbox;
+mod level_warning;
+mod processing_estimate;
+mod result;
+mod upload_limit;
+mod upload_progress;
+
+use crate::api::{ApiError, DiarizationOptions, JobStatus, NotificationTarget, OutputFormat};
+use crate::components::error_message::ErrorMessage;
+use crate::components::feedback::FeedbackWidget;
+use crate::components::receipt::{JobLink, SubmissionReceipt};
+use crate::components::recorder::Recorder;
+use crate::components::toast;
+use crate::config::{LOW_BANDWIDTH_UPLOAD_SPEED, MAX_SELECTION_CHECKSUM_SIZE};
+use crate::consent;
 use crate::document::eval;
-use crate::hooks::persistent::use_persistent;
+use crate::embed::FixedOptions;
+use crate::errors::{AppError, Severity, ValidationError};
+use crate::history::{self, HistoryEntry};
 use crate::hooks::persistent::UsePersistent;
-use crate::state::{ApiConnectionStatus, AppState};
+use crate::i18n::use_translator;
+use crate::media;
+use crate::media::channels::ChannelSelection;
+use crate::media::file_types;
+use crate::media::levels::AudioLevels;
+use crate::media::native_file::NativeFile;
+use crate::services::submission::{self, SubmissionRequest, SubmissionState};
+use crate::services::{batch, job_manager};
+use crate::state::{ApiConnectionStatus, AppState, TranscriptionUiStatus};
+use crate::throttle;
+use crate::{checksum, clipboard, diagnostics};
+use batch_queue::BatchQueue;
+use chrono::{DateTime, Duration, Utc};
+use consent_checkbox::ConsentCheckbox;
 use dioxus::html::HasFileData;
 use dioxus::prelude::*;
 use dioxus_elements::FileEngine;
 use gloo_timers::callback::Interval;
-use log::{error, info};
+use level_warning::LevelWarning;
+use log::{error, info, warn};
+use processing_estimate::ProcessingEstimate;
+use result::TranscriptResult;
 use std::sync::Arc;
-
-/// Represents the current state of the transcription UI, acting as a simple state machine.
-#[derive(Clone, PartialEq, Debug)]
-enum TranscriptionUiStatus {
-    Idle,
-    FileSelected,
-    Submitting,
-    Monitoring,
-    Completed(String),
-    Error(String),
-}
+use upload_limit::UploadLimitCheck;
+use upload_progress::UploadProgressBar;
 
 // --- Component Props ---
 
@@ -36,228 +60,447 @@ pub struct TranscriptionPanelProps {
 pub fn TranscriptionPanel(props: TranscriptionPanelProps) -> Element {
     // --- State Signals ---
     let app_state = use_context::<AppState>();
-    let mut ui_status = use_signal(|| TranscriptionUiStatus::Idle);
+    let mut ui_status = app_state.transcription_ui_status;
     let mut selected_file: Signal<Option<Arc<dyn FileEngine>>> = use_signal(|| None);
     let mut is_dragging = use_signal(|| false);
+    let mut selected_duration: Signal<Option<f64>> = use_signal(|| None);
+    let mut selected_size: Signal<Option<u64>> = use_signal(|| None);
+    let mut selected_sha256: Signal<Option<String>> = use_signal(|| None);
+    // Upload speed of the last submission, in bytes per second.
+    let mut upload_speed: Signal<Option<f64>> = use_signal(|| None);
+    // Object URL of the selected file, to listen to it while proofreading.
+    let audio_url: Signal<Option<String>> = use_signal(|| None);
+    let mut selected_is_video = use_signal(|| false);
+    // Error of the last failed submission, shown as a banner while the file stays selected.
+    let mut submission_error: Signal<Option<AppError>> = use_signal(|| None);
+    // Levels of the selected recording, when analyzed.
+    let mut selected_levels: Signal<Option<AudioLevels>> = use_signal(|| None);
+    // Channels of the selected recording to transcribe separately.
+    let mut channel_selection = use_signal(ChannelSelection::default);
+    // Inspection of the selected file, cancelled when another file is selected or the
+    // selection is cleared, so that it never writes over a newer selection.
+    let mut selection_task: Signal<Option<Task>> = use_signal(|| None);
+    let mut cancel_inspection = move || {
+        if let Some(task) = selection_task.take() {
+            task.cancel();
+        }
+    };
 
     // --- Persistent and Polled State ---
-    let mut active_job: UsePersistent<Option<TranscriptionJob>> =
-        use_persistent("leontine-active-job", || None);
-    let mut job_state: Signal<Option<Result<JobState, ApiError>>> = use_signal(|| None);
-    let refresh_trigger = use_signal(|| 0);
-    let mut interval_timer: Signal<Option<Interval>> = use_signal(|| None);
+    // The active jobs and their states are updated by the app-level job manager.
+    let active_jobs = app_state.active_jobs;
+
+    // --- Submission Throttle ---
+    let submission_log = app_state.submission_log;
+    let history = app_state.history;
+    let throttle_clock = use_signal(Utc::now);
+    let submission_limit = app_state.submission_limit();
+    let retry_after =
+        throttle::retry_after(&submission_log.get(), submission_limit, throttle_clock());
 
     let api_url_prop = props.api_url;
+    // Options fixed by an embedding page replace the user's choices.
+    let fixed_options = try_use_context::<FixedOptions>().unwrap_or_default();
+    let i18n = use_translator();
 
-    // Derived signal to check if API is available
-    let is_api_available = use_memo(move || {
-        matches!(
-            *app_state.api_connection_status.read(),
-            ApiConnectionStatus::Available(_, _)
-        )
-    });
+    let is_api_available = app_state.is_api_available;
+    // Submissions wait for a successful status check, unlike the monitoring of the jobs.
+    let accepts_submissions = app_state.api_connection_status.read().accepts_submissions();
 
-    // --- Effect to check for an existing job on load ---
+    // Let the user know when the API comes back after being unreachable,
+    // as the panel unlocks by itself.
+    let mut was_unavailable = use_signal(|| false);
     use_effect(move || {
-        if *is_api_available.read()
-            && active_job.get().is_some()
-            && *ui_status.peek() == TranscriptionUiStatus::Idle
-        {
-            info!("Found active job on load, starting monitoring.");
-            ui_status.set(TranscriptionUiStatus::Monitoring);
+        let unavailable = matches!(
+            *app_state.api_connection_status.read(),
+            ApiConnectionStatus::Unavailable(_, _)
+        );
+        if unavailable {
+            was_unavailable.set(true);
+        } else if is_api_available() && *was_unavailable.peek() {
+            was_unavailable.set(false);
+            toast::show(
+                "The API is back online: transcription is available again.",
+                Severity::Info,
+            );
         }
     });
 
-    // --- Resource for the initial transcription submission ---
-    use_resource(move || async move {
-        if *ui_status.read() != TranscriptionUiStatus::Submitting {
-            return;
-        }
-
-        info!("Transcription submission process triggered.");
-        let file_to_upload = selected_file.read().clone();
-        let api_url = api_url_prop.get();
-
-        if let Some(file) = file_to_upload {
-            let result = api::submit_transcription(&api_url, &file).await;
-            match result {
-                Ok(job) => {
-                    info!("Job submitted successfully: {}", job.job_id);
-                    active_job.set(Some(job));
-                    ui_status.set(TranscriptionUiStatus::Monitoring);
-                }
-                Err(e) => {
-                    error!("Job submission failed: {:?}", e);
-                    ui_status.set(TranscriptionUiStatus::Error(e.to_string()));
-                }
+    // --- Transcription submission ---
+    // A submission runs as a task of the submission service, which is cancelled if the
+    // panel goes away before it ends; the batch queue is paused, to be resumed when the
+    // panel comes back. The panel only reacts to its outcome.
+    use_drop(move || {
+        submission::cancel(app_state);
+        batch::pause(app_state);
+    });
+    use_effect(move || {
+        let outcome = app_state.submission.read().clone();
+        match outcome {
+            SubmissionState::Submitted {
+                upload_speed: speed,
+            } => upload_speed.set(speed),
+            SubmissionState::Failed(error) if error.is_recoverable() => {
+                // Keep the file so the user can submit it again right away.
+                submission_error.set(Some(error));
             }
-        } else {
-            ui_status.set(TranscriptionUiStatus::Error(
-                "File not available for submission.".to_string(),
-            ));
+            SubmissionState::Failed(_) => {
+                cancel_inspection();
+                selected_file.set(None);
+            }
+            SubmissionState::Idle | SubmissionState::Uploading(_) => return,
         }
+        let mut submission = app_state.submission;
+        submission.set(SubmissionState::Idle);
     });
+    let submission_in_flight = app_state.submission.read().is_in_flight();
 
-    // --- Resource for polling the job status ---
-    let job_status_resource = use_resource(move || async move {
-        refresh_trigger.into_value(); // Trigger resource re-run on refresh_trigger change
-
-        // Only poll if API is available and there's an active job
-        if *is_api_available.read() {
-            if let Some(job) = active_job.get() {
-                let api_url = api_url_prop.get();
-                let result = api::get_job_status(&api_url, &job.job_id).await;
-                let mut should_clear_job = false;
-
-                match &result {
-                    Ok(state) => match state.status {
-                        JobStatus::Completed => {
-                            let result_data = state.data.clone().unwrap_or_else(|| {
-                                "Transcription completed, but no data was returned.".to_string()
-                            });
-                            ui_status.set(TranscriptionUiStatus::Completed(result_data));
-                            should_clear_job = true;
-                        }
-                        JobStatus::Failed => {
-                            let error_message =
-                                state.data.as_deref().unwrap_or("No details provided.");
-                            error!("Job {} failed on server: {}", job.job_id, error_message);
-                            ui_status.set(TranscriptionUiStatus::Error(format!(
-                                "Job failed: {}",
-                                error_message
-                            )));
-                            should_clear_job = true;
-                        }
-                        _ => { /* Queued or Processing, just update state and continue */ }
-                    },
-                    Err(ApiError::HttpError(404, _)) => {
-                        error!(
-                            "Job {} not found on server. Clearing local job.",
-                            job.job_id
-                        );
-                        ui_status.set(TranscriptionUiStatus::Error(
-                            "The previous job was not found on the server. It may have expired."
-                                .to_string(),
-                        ));
-                        should_clear_job = true;
-                    }
-                    Err(e) => {
-                        // Other errors, just display and polling will continue if API is still available
-                        error!("Error polling job status: {:?}", e);
-                        job_state.set(Some(Err(e.clone()))); // Set job_state to error
-                    }
-                }
+    // --- Event Handlers and Helpers ---
+    // File selection is locked while a job is in progress or the API is unavailable.
+    let is_disabled = move || !(app_state.can_select_file)();
 
-                if let Ok(state) = result {
-                    job_state.set(Some(Ok(state)));
-                }
+    // The maximum upload size advertised by the server, zero meaning no limit.
+    let max_file_size = move || {
+        app_state
+            .api_connection_status
+            .read()
+            .api_status()
+            .map(|status| status.resources.max_file_size)
+            .filter(|limit| *limit > 0)
+    };
+    // Why the last file was refused, shown until another one is selected.
+    let mut selection_error: Signal<Option<AppError>> = use_signal(|| None);
 
-                return should_clear_job;
-            }
+    let mut handle_file_selection = move |file_engine: Arc<dyn FileEngine>| {
+        if is_disabled() {
+            return;
+        }
+        cancel_inspection();
+        // Several files selected together are queued, to be submitted one by one,
+        // as are the files added to a queue.
+        if file_engine.files().len() > 1 || !app_state.batch.peek().is_empty() {
+            selection_error.set(None);
+            spawn(async move {
+                for file in NativeFile::from_selection(&file_engine).await {
+                    #[allow(clippy::arc_with_non_send_sync)]
+                    let file: Arc<dyn FileEngine> = Arc::new(file);
+                    let Some(file_name) = file.files().first().cloned() else {
+                        continue;
+                    };
+                    let size = file.file_size(&file_name).await;
+                    let mime_type = media::mime_type(&file).await.unwrap_or_default();
+                    let refusal = media::validate(
+                        &file_name,
+                        size,
+                        &mime_type,
+                        &app_state.accepted_types(),
+                        max_file_size(),
+                    )
+                    .err();
+                    if let Some(e) = &refusal {
+                        warn!("Rejected {} from the batch queue: {:?}", file_name, e);
+                    }
+                    batch::add(app_state, file, size, refusal.map(AppError::from));
+                }
+                let _ = eval(r#"document.getElementById('file-upload-input').value = '';"#);
+            });
+            return;
         }
+        let Some(file_name) = file_engine.files().first().cloned() else {
+            return;
+        };
+        selection_error.set(None);
+        let task = spawn(async move {
+            // Files the server would refuse are rejected before they are selected.
+            let size = file_engine.file_size(&file_name).await;
+            let mime_type = media::mime_type(&file_engine).await.unwrap_or_default();
+            if let Err(e) = media::validate(
+                &file_name,
+                size,
+                &mime_type,
+                &app_state.accepted_types(),
+                max_file_size(),
+            ) {
+                warn!("Rejected the selection of {}: {:?}", file_name, e);
+                selection_error.set(Some(e.into()));
+                let _ = eval(r#"document.getElementById('file-upload-input').value = '';"#);
+                return;
+            }
 
-        false
-    });
+            selected_file.set(Some(file_engine.clone()));
+            selected_duration.set(None);
+            selected_size.set(size);
+            selected_sha256.set(None);
+            submission_error.set(None);
+            selected_levels.set(None);
+            channel_selection.set(ChannelSelection::default());
+            ui_status.set(TranscriptionUiStatus::FileSelected);
 
-    // --- Effect to clear the job based on the resource result ---
-    use_effect(move || {
-        if let Some(should_clear) = job_status_resource.value().read().as_ref() {
-            if *should_clear {
-                info!("Clearing active job from persistent storage.");
-                active_job.set(None);
+            // Inspect the file to warn about very long recordings, about files
+            // already being transcribed, and about poor levels.
+            // Large files are only hashed once, on submission.
+            if size.is_some_and(|size| size <= MAX_SELECTION_CHECKSUM_SIZE) {
+                selected_sha256.set(checksum::file_sha256_hex(&file_engine).await);
             }
-        }
-    });
+            let duration = media::probe_duration(&file_engine).await;
+            selected_duration.set(duration);
+            selected_is_video.set(media::is_video(&file_engine).await);
+            replace_audio_url(audio_url, media::object_url(&file_engine).await);
+            selected_levels.set(media::levels::analyze(&file_engine, duration).await);
+        });
+        selection_task.set(Some(task));
+    };
 
-    // --- Store the previous UI status to detect changes ---
-    let mut previous_status = use_signal(|| TranscriptionUiStatus::Idle);
+    let mut report_copied = use_signal(|| None::<bool>);
 
-    // Create a dedicated polling mechanism that doesn't trigger reactivity loops
-    use_effect(move || {
-        let current_status = ui_status.read().clone();
-
-        // Only act when the status changes, and only if API is available
-        if *is_api_available.read() && current_status != *previous_status.read() {
-            let is_monitoring = current_status == TranscriptionUiStatus::Monitoring;
+    // --- Upload Consent ---
+    // The deployment may require a statement to be accepted before uploads.
+    // Submissions wait for the runtime configuration, which tells whether it does.
+    let mut consent_log = app_state.consent_log;
+    let mut consent_accepted = use_signal(|| false);
+    let required_consent = move || {
+        app_state
+            .runtime_config
+            .read()
+            .as_ref()
+            .and_then(|config| config.consent.clone())
+            .filter(|consent| consent::is_required(consent, &consent_log.get()))
+    };
+    let awaiting_consent = app_state.runtime_config.read().is_none()
+        || (required_consent().is_some() && !consent_accepted());
 
-            // Cancel existing timer if there is one
-            if interval_timer.with(|timer| timer.is_some()) {
-                if let Some(timer) = interval_timer.write().take() {
-                    info!("Cancelling existing timer due to status change");
-                    timer.cancel();
-                }
+    // Records the acceptance of the consent statement, when one is required.
+    // Returns whether the upload may go ahead.
+    let mut record_consent = move || {
+        if let Some(consent) = required_consent() {
+            if !consent_accepted() {
+                return false;
             }
+            consent_log.set(consent::record(&consent_log.get(), &consent, Utc::now()));
+            consent_accepted.set(false);
+        }
+        true
+    };
 
-            // Create new timer only if we're in monitoring state AND API is available
-            if is_monitoring {
-                info!("Starting polling timer for monitoring");
-
-                // Create the timer outside of any reactive context
-                let timer_fn = {
-                    let mut refresh_clone = refresh_trigger.clone();
-                    move || {
-                        // Increment the trigger counter to initiate a new poll
-                        refresh_clone += 1;
-                    }
-                };
+    // The submission of a file, with the options chosen in the panel.
+    let request_for = move |file: Arc<dyn FileEngine>| {
+        // The target is only registered with servers able to notify it.
+        let notification = app_state
+            .current_profile()
+            .notification_target
+            .as_deref()
+            .and_then(NotificationTarget::parse)
+            .filter(|target| target.is_supported_by(&app_state.capabilities.peek().notifications));
+        // Servers without diarization get the default options.
+        let diarization = if app_state.capabilities.peek().diarization {
+            fixed_options
+                .diarization
+                .unwrap_or_else(|| app_state.diarization.get())
+        } else {
+            DiarizationOptions::default()
+        };
+        SubmissionRequest {
+            api_url: api_url_prop.get(),
+            profile_id: app_state.current_profile_id(),
+            file,
+            size: selected_size(),
+            duration: selected_duration(),
+            output_format: fixed_options
+                .output_format
+                .unwrap_or_else(|| app_state.output_format.get()),
+            diarization,
+            notification,
+            channels: channel_selection(),
+            sha256: selected_sha256(),
+        }
+    };
 
-                let new_timer = Interval::new(5000, timer_fn);
-                *interval_timer.write() = Some(new_timer);
+    let mut submit = move || {
+        report_copied.set(None);
+        if throttle::retry_after(
+            &submission_log.get(),
+            app_state.submission_limit(),
+            Utc::now(),
+        )
+        .is_none()
+        {
+            if !record_consent() {
+                return;
             }
+            let Some(file) = selected_file.read().clone() else {
+                ui_status.set(TranscriptionUiStatus::Error(
+                    ApiError::FileNotAvailable.into(),
+                ));
+                return;
+            };
+            submission_error.set(None);
+            submission::start(app_state, request_for(file));
+        }
+    };
 
-            // Update previous status
-            previous_status.set(current_status);
-        } else if !*is_api_available.read() {
-            // If API becomes unavailable, stop any monitoring and reset UI to idle or error.
-            if let Some(timer) = interval_timer.write().take() {
-                info!("API unavailable, cancelling monitoring timer.");
-                timer.cancel();
-            }
-            if *ui_status.peek() == TranscriptionUiStatus::Monitoring
-                || *ui_status.peek() == TranscriptionUiStatus::Submitting
-            {
-                ui_status.set(TranscriptionUiStatus::Idle); // Or perhaps a more specific error state
-                job_state.set(None); // Clear job state display
+    // The files of the batch queue are submitted with the options of the panel.
+    let start_batch = move |parallel: bool| {
+        let first_waiting = app_state
+            .batch
+            .peek()
+            .iter()
+            .find(|item| item.state == batch::BatchItemState::Waiting)
+            .map(|item| item.file.clone());
+        if let Some(file) = first_waiting {
+            if record_consent() {
+                batch::start(
+                    app_state,
+                    SubmissionRequest {
+                        sha256: None,
+                        ..request_for(file)
+                    },
+                    parallel,
+                );
             }
         }
-    });
+    };
 
-    // --- Final cleanup on unmount ---
-    use_drop(move || {
-        if let Some(timer) = interval_timer.write().take() {
-            info!("Clearing timer on component drop.");
-            timer.cancel();
+    let resume_monitoring = move |_| {
+        report_copied.set(None);
+        if let Some(job) = app_state.focused_job() {
+            job_manager::resume(app_state, &job.job_id);
         }
-    });
-
-    // --- Event Handlers and Helpers ---
-    let is_locked_ui = move || {
-        !matches!(
-            ui_status(),
-            TranscriptionUiStatus::Idle | TranscriptionUiStatus::FileSelected
-        )
+        ui_status.set(TranscriptionUiStatus::Monitoring);
     };
 
-    // Combine UI locked status with API availability
-    let is_disabled = move || is_locked_ui() || !*is_api_available.read();
-
-    let mut handle_file_selection = move |file_engine: Arc<dyn FileEngine>| {
-        if is_disabled() {
+    // Cancels the monitored job on the server, then forgets it. On failure, monitoring
+    // stops with the error, from which it can be resumed.
+    let mut cancelling = use_signal(|| false);
+    let cancel_job = move |_| {
+        let Some(job) = app_state.focused_job() else {
             return;
+        };
+        cancelling.set(true);
+        spawn(async move {
+            match job_manager::cancel(app_state, &job.job_id).await {
+                Ok(()) => {
+                    // The next job, if any, stays monitored.
+                    if active_jobs.get().is_empty() {
+                        ui_status.set(if selected_file.read().is_some() {
+                            TranscriptionUiStatus::FileSelected
+                        } else {
+                            TranscriptionUiStatus::Idle
+                        });
+                    }
+                    toast::show("The transcription job was cancelled.", Severity::Info);
+                }
+                Err(e) => {
+                    error!("Cancelling job {} failed: {:?}", job.job_id, e);
+                    ui_status.set(TranscriptionUiStatus::Error(e.into()));
+                }
+            }
+            cancelling.set(false);
+        });
+    };
+
+    let report_issue = move |error: AppError| {
+        let mut context = vec![("API URL", api_url_prop.get())];
+        if let Some(job) = app_state.focused_job() {
+            context.push(("Job ID", job.job_id));
         }
-        if !file_engine.files().is_empty() {
-            selected_file.set(Some(file_engine));
-            ui_status.set(TranscriptionUiStatus::FileSelected);
+        if let Some(file_name) = selected_file
+            .read()
+            .as_ref()
+            .and_then(|engine| engine.files().first().cloned())
+        {
+            context.push(("File", file_name));
         }
+        let report = diagnostics::report(&error, &context);
+        spawn(async move {
+            report_copied.set(Some(clipboard::copy_text(&report).await));
+        });
     };
 
-    let reset_state = move |_| {
+    let mut clear_selection = move || {
+        report_copied.set(None);
+        cancel_inspection();
         selected_file.set(None);
+        selected_duration.set(None);
+        selected_size.set(None);
+        selected_sha256.set(None);
+        replace_audio_url(audio_url, None);
+        selected_is_video.set(false);
+        upload_speed.set(None);
+        submission_error.set(None);
+        selected_levels.set(None);
+        channel_selection.set(ChannelSelection::default());
         ui_status.set(TranscriptionUiStatus::Idle);
-        active_job.set(None);
-        job_state.set(None);
         let _ = eval(r#"document.getElementById('file-upload-input').value = '';"#);
     };
+    let reset_state = move |_| clear_selection();
+
+    // A recording too long for the server is split into parts, which are queued and
+    // submitted one after the other with the options of the panel.
+    let mut splitting = use_signal(|| false);
+    let split_and_submit = move |_| {
+        let (Some(file), Some(duration)) = (selected_file.read().clone(), selected_duration())
+        else {
+            return;
+        };
+        if !record_consent() {
+            return;
+        }
+        splitting.set(true);
+        submission_error.set(None);
+        spawn(async move {
+            let max_duration = app_state.max_recommended_duration();
+            match media::split_in_parts(&file, duration, max_duration).await {
+                Ok(parts) => {
+                    info!("Split the recording into {} parts.", parts.len());
+                    for part in &parts {
+                        let Some(file_name) = part.files().first().cloned() else {
+                            continue;
+                        };
+                        let size = part.file_size(&file_name).await;
+                        let refusal = media::validate(
+                            &file_name,
+                            size,
+                            "audio/wav",
+                            &app_state.accepted_types(),
+                            max_file_size(),
+                        )
+                        .err();
+                        batch::add(app_state, part.clone(), size, refusal.map(AppError::from));
+                    }
+                    if let Some(first) = parts.first().cloned() {
+                        // The parts are mono: the channel selection no longer applies.
+                        let request = SubmissionRequest {
+                            sha256: None,
+                            channels: ChannelSelection::Mixed,
+                            ..request_for(first)
+                        };
+                        clear_selection();
+                        batch::start(app_state, request, false);
+                    }
+                }
+                Err(e) => {
+                    error!("Splitting the recording failed: {:?}", e);
+                    submission_error.set(Some(e.into()));
+                }
+            }
+            splitting.set(false);
+        });
+    };
+
+    // A recent unfinished job of the same file, likely still transcribing.
+    let duplicate_of = selected_sha256().and_then(|sha256| {
+        history::find_unfinished_duplicate(&history.get(), &sha256, Utc::now()).cloned()
+    });
+    // The latest completed transcription of the same file.
+    let transcribed_before = selected_sha256()
+        .and_then(|sha256| history::find_transcribed(&history.get(), &sha256).cloned());
+
+    // A result opened from the history may belong to another file than the selected one.
+    let is_selected_recording = move |job_id: &str| {
+        let job_sha256 =
+            history::find(&history.get(), job_id).and_then(|entry| entry.job.sha256.clone());
+        job_sha256.is_some() && job_sha256 == selected_sha256()
+    };
 
     // --- Dynamic CSS classes ---
     let mut upload_area_class = String::from("upload-area");
@@ -271,7 +514,7 @@ pub fn TranscriptionPanel(props: TranscriptionPanelProps) -> Element {
     rsx! {
         div {
             class: "transcription-panel",
-            h2 { "Transcription" }
+            h2 { {i18n.t("Transcription")} }
             div {
                 class: "{upload_area_class}",
                 // Disable drag/drop events if API is unavailable or UI is locked
@@ -287,70 +530,234 @@ pub fn TranscriptionPanel(props: TranscriptionPanelProps) -> Element {
                 input {
                     r#type: "file",
                     id: "file-upload-input",
-                    accept: "audio/*",
-                    multiple: false,
+                    accept: file_types::accept_attribute(&app_state.accepted_types()),
+                    multiple: true,
                     disabled: is_disabled(), // Input disabled if API unavailable
                     style: "display: none;",
                     onchange: move |evt| { if let Some(file_engine) = evt.files() { handle_file_selection(file_engine); } },
                 },
                 div {
                     class: "upload-content",
-                    if let Some(file_engine) = selected_file() {
-                        if let Some(file_name) = file_engine.files().first() {
-                            p { "Selected file: ", strong { "{file_name}" } }
+                    // Files selected together are shown as a queue instead of a single file.
+                    if !app_state.batch.read().is_empty() {
+                        if let Some(consent) = required_consent().filter(|_| !batch::is_running() && !batch::is_paused()) {
+                            ConsentCheckbox { consent, accepted: consent_accepted }
                         }
-                    }
-
-                    // Render different UI states
-                    match ui_status() {
-                        TranscriptionUiStatus::Idle => rsx! {
-                            p { "Drag and drop an audio file here, or click the button below." }
-                            button {
-                                onclick: move |_| { let _ = eval(r#"document.getElementById('file-upload-input').click();"#); },
-                                disabled: !*is_api_available.read(), // Button disabled if API unavailable
-                                "Select Audio File"
-                            }
-                            if !*is_api_available.read() {
-                                p { class: "error-message", "API is unreachable. Please check settings." }
+                        BatchQueue { awaiting_consent, onstart: start_batch }
+                    } else {
+                        if let Some(file_engine) = selected_file() {
+                            if let Some(file_name) = file_engine.files().first() {
+                                p { {i18n.t("Selected file: ")}, strong { "{file_name}" } }
                             }
-                        },
-                        TranscriptionUiStatus::FileSelected => rsx! {
-                            div {
-                                class: "action-buttons",
-                                button { class: "button-clear", onclick: reset_state, "Clear Selection" }
+                        }
+
+                        // Render different UI states
+                        match ui_status() {
+                            TranscriptionUiStatus::Idle => rsx! {
+                                p { {i18n.t("Drag and drop audio files here, select them below, or record from the microphone.")} }
+                                if let Some(limit) = max_file_size() {
+                                    p { class: "hint", {i18n.format("The server accepts files up to {}.", &[&media::format_size(limit)])} }
+                                }
+                                if let Some(error) = selection_error() {
+                                    ErrorMessage { error }
+                                }
                                 button {
-                                    class: "button-transcribe",
-                                    onclick: move |_| ui_status.set(TranscriptionUiStatus::Submitting),
-                                    disabled: !*is_api_available.read(), // Button disabled if API unavailable
-                                    "Transcribe Audio"
+                                    onclick: move |_| { let _ = eval(r#"document.getElementById('file-upload-input').click();"#); },
+                                    disabled: is_disabled(), // Button disabled if API unavailable or under maintenance
+                                    {i18n.t("Select Audio Files")}
                                 }
-                            }
-                        },
-                        TranscriptionUiStatus::Submitting => rsx! { p { class: "transcribing-message", "Submitting job... Please wait." } },
-                        TranscriptionUiStatus::Monitoring => {
-                            let status_message = if let Some(Ok(state)) = job_state() {
-                                match state.status {
-                                    JobStatus::Queued => format!("Job is queued at position {}.", state.queue_position.unwrap_or(0)),
-                                    JobStatus::Processing => "Job is being processed...".to_string(),
-                                    _ => "Waiting for status update...".to_string(),
+                                Recorder {
+                                    disabled: is_disabled(),
+                                    onrecorded: handle_file_selection,
+                                }
+                                if !accepts_submissions && !(app_state.is_maintenance)() {
+                                    ApiUnavailableNote {}
+                                }
+                                if (app_state.is_maintenance)() {
+                                    MaintenanceNote {}
+                                }
+                            },
+                            TranscriptionUiStatus::FileSelected => rsx! {
+                                if let Some(error) = submission_error() {
+                                    ErrorMessage { error }
+                                }
+                                if let Some(duplicate) = duplicate_of.clone() {
+                                    DuplicateWarning { duplicate }
+                                } else if let Some(previous) = transcribed_before.clone() {
+                                    PreviousTranscription { previous }
+                                }
+                                if fixed_options.output_format.is_none() {
+                                    OutputFormatSelect {}
+                                }
+                                if fixed_options.diarization.is_none() && app_state.capabilities.read().diarization {
+                                    DiarizationFields {}
+                                }
+                                ChannelSelect { selection: channel_selection }
+                                if let Some(consent) = required_consent() {
+                                    ConsentCheckbox { consent, accepted: consent_accepted }
+                                }
+                                div {
+                                    class: "action-buttons",
+                                    button { class: "button-clear", onclick: reset_state, {i18n.t("Clear Selection")} }
+                                    button {
+                                        class: "button-transcribe",
+                                        onclick: move |_| submit(),
+                                        // Button disabled if API unavailable, the submission limit is reached,
+                                        // a submission is already in flight, or consent is missing
+                                        disabled: !(app_state.can_submit)() || retry_after.is_some() || submission_in_flight || awaiting_consent,
+                                        if submission_error.read().is_some() {
+                                            {i18n.t("Retry Submission")}
+                                        } else if duplicate_of.is_some() {
+                                            {i18n.t("Submit Anyway")}
+                                        } else if transcribed_before.is_some() {
+                                            {i18n.t("Transcribe Again")}
+                                        } else {
+                                            {i18n.t("Transcribe Audio")}
+                                        }
+                                    }
+                                }
+                                if !accepts_submissions && !(app_state.is_maintenance)() {
+                                    ApiUnavailableNote {}
+                                }
+                                if (app_state.is_maintenance)() {
+                                    MaintenanceNote {}
+                                }
+                                if let Some(duration) = selected_duration().filter(|d| *d > app_state.max_recommended_duration()) {
+                                    DurationWarning {
+                                        duration,
+                                        max_duration: app_state.max_recommended_duration(),
+                                        splitting: splitting(),
+                                        disabled: !(app_state.can_submit)() || submission_in_flight || awaiting_consent,
+                                        onsplit: split_and_submit,
+                                    }
+                                }
+                                if let Some(levels) = selected_levels() {
+                                    LevelWarning { levels, selection: channel_selection }
+                                }
+                                if let Some(file_size) = selected_size() {
+                                    UploadLimitCheck { file_size }
+                                }
+                                if let Some(wait) = retry_after {
+                                    SubmissionCountdown { clock: throttle_clock, wait, limit: submission_limit }
+                                }
+                            },
+                            TranscriptionUiStatus::Submitting => rsx! {
+                                p { class: "transcribing-message", {i18n.t("Submitting job... Please wait.")} }
+                                UploadProgressBar {
+                                    progress: match *app_state.submission.read() {
+                                        SubmissionState::Uploading(progress) => progress,
+                                        _ => None,
+                                    }
+                                }
+                            },
+                            TranscriptionUiStatus::Monitoring => {
+                                let focused_job = app_state.focused_job();
+                                let job_state = focused_job
+                                    .as_ref()
+                                    .and_then(|job| app_state.job_states.read().get(&job.job_id).cloned());
+                                let is_processing = matches!(&job_state, Some(Ok(state)) if state.status == JobStatus::Processing);
+                                let status_message = if let Some(Ok(state)) = job_state.clone() {
+                                    match state.status {
+                                        JobStatus::Queued => i18n.format("Job is queued at position {}.", &[&state.queue_position.unwrap_or(0)]),
+                                        JobStatus::Processing => i18n.t("Job is being processed...").to_string(),
+                                        JobStatus::Unknown(status) => i18n.format("The server reports the job as \"{}\", still checking...", &[&status]),
+                                        _ => i18n.t("Waiting for status update...").to_string(),
+                                    }
+                                } else if let Some(Err(e)) = job_state {
+                                    i18n.format("Error polling job status: {}. Retrying...", &[&e])
+                                } else {
+                                    i18n.t("Checking job status...").to_string()
+                                };
+                                rsx! {
+                                    p { class: "transcribing-message", "{status_message}" }
+                                    if !*is_api_available.read() {
+                                        OfflineMonitoringNote {}
+                                    }
+                                    if let Some(job) = focused_job.clone().filter(|_| is_processing) {
+                                        ProcessingEstimate { job }
+                                    }
+                                    if let Some(speed) = upload_speed().filter(|s| *s < LOW_BANDWIDTH_UPLOAD_SPEED) {
+                                        LowBandwidthSuggestion { speed }
+                                    }
+                                    if let Some(job) = focused_job {
+                                        JobLink { api_url: job.api_url.clone().unwrap_or_else(|| api_url_prop.get()), job_id: job.job_id.clone() }
+                                        SubmissionReceipt { job }
+                                    }
+                                    div {
+                                        class: "action-buttons",
+                                        if app_state.capabilities.read().can_cancel() {
+                                            button {
+                                                class: "button-cancel",
+                                                onclick: cancel_job,
+                                                disabled: cancelling() || !*is_api_available.read(),
+                                                if cancelling() { {i18n.t("Cancelling...")} } else { {i18n.t("Cancel Job")} }
+                                            }
+                                        }
+                                        button {
+                                            onclick: move |_| { let _ = eval(r#"document.getElementById('file-upload-input').click();"#); },
+                                            disabled: is_disabled(),
+                                            {i18n.t("Transcribe Another File")}
+                                        }
+                                    }
+                                    p { class: "hint", {i18n.t("This job keeps being monitored while you transcribe other files.")} }
+                                    if let Some(error) = selection_error() {
+                                        ErrorMessage { error }
+                                    }
+                                }
+                            },
+                            TranscriptionUiStatus::Completed { job_id, result, processed } => rsx! {
+                                div { class: "success-message",
+                                    p { {i18n.t("Transcription successful!")} }
+                                    TranscriptResult {
+                                        job_id: job_id.clone(),
+                                        result,
+                                        processed,
+                                        audio_url: audio_url().filter(|_| is_selected_recording(&job_id)),
+                                        is_video: selected_is_video(),
+                                    }
+                                }
+                                FeedbackWidget { job_id }
+                                button { class: "button-new", onclick: reset_state, {i18n.t("Start New Transcription")} }
+                            },
+                            TranscriptionUiStatus::Error(error) => {
+                                // Offer the actions that make sense for where the failure happened.
+                                let can_resume_monitoring = app_state.focused_job().is_some();
+                                let can_retry_submission = !can_resume_monitoring && selected_file.read().is_some();
+                                let reported_error = error.clone();
+                                rsx! {
+                                    ErrorMessage { error }
+                                    div {
+                                        class: "action-buttons",
+                                        if can_retry_submission {
+                                            button {
+                                                class: "button-retry",
+                                                onclick: move |_| submit(),
+                                                disabled: !accepts_submissions || retry_after.is_some() || submission_in_flight,
+                                                {i18n.t("Resubmit File")}
+                                            }
+                                        }
+                                        if can_resume_monitoring {
+                                            button {
+                                                class: "button-resume",
+                                                onclick: resume_monitoring,
+                                                disabled: !*is_api_available.read(),
+                                                {i18n.t("Resume Monitoring")}
+                                            }
+                                        }
+                                        button {
+                                            class: "button-report",
+                                            onclick: move |_| report_issue(reported_error.clone()),
+                                            {i18n.t("Report Issue")}
+                                        }
+                                        button { class: "button-new", onclick: reset_state, {i18n.t("Start New Transcription")} }
+                                    }
+                                    match report_copied() {
+                                        Some(true) => rsx! { p { class: "report-feedback", {i18n.t("Issue report copied to the clipboard.")} } },
+                                        Some(false) => rsx! { p { class: "report-feedback", {i18n.t("Could not access the clipboard.")} } },
+                                        None => rsx! {},
+                                    }
                                 }
-                            } else if let Some(Err(e)) = job_state() {
-                                format!("Error polling job status: {}. Retrying...", e)
-                            } else {
-                                "Checking job status...".to_string()
-                            };
-                            rsx! { p { class: "transcribing-message", "{status_message}" } }
-                        },
-                        TranscriptionUiStatus::Completed(result) => rsx! {
-                            div { class: "success-message",
-                                p { "Transcription successful!" }
-                                p { code { "{result}" } }
                             }
-                            button { class: "button-new", onclick: reset_state, "Start New Transcription" }
-                        },
-                        TranscriptionUiStatus::Error(error_message) => rsx! {
-                            div { class: "error-message", p { "{error_message}" } }
-                            button { class: "button-new", onclick: reset_state, "Start New Transcription" }
                         }
                     }
                 }
@@ -358,3 +765,358 @@ pub fn TranscriptionPanel(props: TranscriptionPanelProps) -> Element {
         }
     }
 }
+
+/// Selects the format of the result requested at submission.
+#[component]
+fn OutputFormatSelect() -> Element {
+    let i18n = use_translator();
+    let mut output_format = use_context::<AppState>().output_format;
+    rsx! {
+        label {
+            class: "output-format",
+            {i18n.t("Result format ")}
+            select {
+                onchange: move |evt| {
+                    if let Some(format) = OutputFormat::from_extension(&evt.value()) {
+                        output_format.set(format);
+                    }
+                },
+                for format in OutputFormat::ALL {
+                    option {
+                        value: "{format.extension()}",
+                        selected: format == output_format.get(),
+                        "{format.label()}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// What to transcribe of the channels of a stereo recording, such as a call with
+/// a speaker on each channel.
+#[component]
+fn ChannelSelect(selection: Signal<ChannelSelection>) -> Element {
+    let i18n = use_translator();
+    rsx! {
+        label {
+            class: "channel-select",
+            {i18n.t("Stereo channels ")}
+            select {
+                onchange: move |evt| {
+                    if let Some(value) = ChannelSelection::from_value(&evt.value()) {
+                        selection.set(value);
+                    }
+                },
+                for value in ChannelSelection::ALL {
+                    option {
+                        value: "{value.value()}",
+                        selected: value == selection(),
+                        {i18n.t(value.label())}
+                    }
+                }
+            }
+        }
+        if selection() == ChannelSelection::Split {
+            p { class: "hint", {i18n.t("Each channel is transcribed as a job of its own; the result then shows both channels interleaved, one speaker per channel.")} }
+        }
+    }
+}
+
+/// Speaker diarization toggle, with the expected number of speakers.
+#[component]
+fn DiarizationFields() -> Element {
+    let i18n = use_translator();
+    let mut diarization = use_context::<AppState>().diarization;
+    let options = diarization.get();
+    let mut update = move |change: fn(&mut DiarizationOptions, Option<u32>), value: String| {
+        let mut options = diarization.get();
+        change(
+            &mut options,
+            value.trim().parse().ok().filter(|count| *count > 0),
+        );
+        diarization.set(options);
+    };
+    let min_value = options
+        .min_speakers
+        .map(|count| count.to_string())
+        .unwrap_or_default();
+    let max_value = options
+        .max_speakers
+        .map(|count| count.to_string())
+        .unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "diarization-fields",
+            label {
+                input {
+                    r#type: "checkbox",
+                    checked: options.enabled,
+                    onchange: move |evt| {
+                        let mut options = diarization.get();
+                        options.enabled = evt.checked();
+                        diarization.set(options);
+                    },
+                }
+                {i18n.t(" Identify speakers")}
+            }
+            if options.enabled {
+                label {
+                    {i18n.t(" from ")}
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        placeholder: i18n.t("any"),
+                        value: "{min_value}",
+                        onchange: move |evt| update(|o, count| o.min_speakers = count, evt.value()),
+                    }
+                }
+                label {
+                    {i18n.t(" to ")}
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        placeholder: i18n.t("any"),
+                        value: "{max_value}",
+                        onchange: move |evt| update(|o, count| o.max_speakers = count, evt.value()),
+                    }
+                    {i18n.t(" speakers")}
+                }
+            }
+        }
+    }
+}
+
+/// Warns that the selected file appears to be already transcribing.
+#[component]
+fn DuplicateWarning(duplicate: HistoryEntry) -> Element {
+    let i18n = use_translator();
+    let submitted_at = duplicate
+        .job
+        .submitted_at
+        .map(|at| at.with_timezone(&chrono::Local).format("%H:%M").to_string())
+        .unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "duplicate-warning severity-warning",
+            p {
+                {i18n.t("This file appears to already be transcribing (job ")}
+                code { "{duplicate.job.job_id}" }
+                {i18n.format(", submitted at {}). Submit it anyway?", &[&submitted_at])}
+            }
+        }
+    }
+}
+
+/// Tells that the selected file was already transcribed, and offers to open that result
+/// instead of submitting it again.
+#[component]
+fn PreviousTranscription(previous: HistoryEntry) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let submitted_on = previous
+        .job
+        .submitted_at
+        .map(|at| {
+            let at = at.with_timezone(&chrono::Local);
+            i18n.format(
+                " on {} at {}",
+                &[&at.format("%Y-%m-%d"), &at.format("%H:%M")],
+            )
+        })
+        .unwrap_or_default();
+    let options = if previous.job.params.is_empty() {
+        i18n.t("the default options").to_string()
+    } else {
+        previous
+            .job
+            .params
+            .iter()
+            .map(|(key, value)| format!("{} = {}", key, value))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let open = move |_| {
+        if let Some(result) = previous.result.clone() {
+            let mut ui_status = app_state.transcription_ui_status;
+            ui_status.set(TranscriptionUiStatus::Completed {
+                job_id: previous.job.job_id.clone(),
+                result,
+                processed: previous.processed_result.clone(),
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "duplicate-warning severity-info",
+            p { {i18n.format("You transcribed this file{} with {}.", &[&submitted_on, &options])} }
+            button { onclick: open, {i18n.t("Open Previous Result")} }
+        }
+    }
+}
+
+/// Suggests the low-bandwidth mode after a slow upload.
+#[component]
+fn LowBandwidthSuggestion(speed: f64) -> Element {
+    let mut app_state = use_context::<AppState>();
+    let i18n = use_translator();
+
+    if app_state.low_bandwidth.get() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "low-bandwidth-suggestion severity-info",
+            p {
+                {i18n.format("The upload ran at about {}/s. ", &[&media::format_size(speed as u64)])}
+                {i18n.t("On a slow or metered connection, the low-bandwidth mode checks the server less often.")}
+            }
+            button {
+                onclick: move |_| app_state.low_bandwidth.set(true),
+                {i18n.t("Enable Low-Bandwidth Mode")}
+            }
+        }
+    }
+}
+
+/// Replaces the object URL of the selected file, releasing the previous one.
+fn replace_audio_url(mut audio_url: Signal<Option<String>>, url: Option<String>) {
+    if let Some(previous) = std::mem::replace(&mut *audio_url.write(), url) {
+        media::revoke_object_url(&previous);
+    }
+}
+
+/// Explains why transcription is disabled while the API is unreachable.
+#[component]
+fn ApiUnavailableNote() -> Element {
+    let i18n = use_translator();
+    rsx! {
+        p {
+            class: "error-message",
+            {i18n.t("The API is unreachable, so transcription is disabled. ")}
+            {i18n.t("It will be enabled again as soon as a status check succeeds; check the settings if it persists.")}
+        }
+    }
+}
+
+/// Explains why the job statuses no longer change while the API is unreachable.
+#[component]
+fn OfflineMonitoringNote() -> Element {
+    let i18n = use_translator();
+    rsx! {
+        p {
+            class: "hint",
+            {i18n.t("The API is unreachable: the jobs will be checked again as soon as it is back.")}
+        }
+    }
+}
+
+/// Explains why submissions are disabled while the server is under maintenance.
+#[component]
+fn MaintenanceNote() -> Element {
+    let i18n = use_translator();
+    rsx! {
+        p {
+            class: "hint",
+            {i18n.t("Submissions are paused during the server maintenance. ")}
+            {i18n.t("They will be enabled again as soon as the server reports the maintenance is over.")}
+        }
+    }
+}
+
+/// Warns that a long recording may exceed the server timeouts, suggests how to split
+/// it into shorter parts, and offers to split it and submit the parts as a batch.
+#[component]
+fn DurationWarning(
+    duration: f64,
+    max_duration: f64,
+    splitting: bool,
+    disabled: bool,
+    onsplit: EventHandler<()>,
+) -> Element {
+    let i18n = use_translator();
+    let info = AppError::from(ValidationError::RecordingTooLong).info();
+    let (parts, part_duration) = media::suggest_chunks(duration, max_duration);
+    let duration = media::format_duration(duration);
+    let max_duration = media::format_duration(max_duration);
+    let part_duration = media::format_duration(part_duration);
+
+    rsx! {
+        div {
+            class: "duration-warning {info.severity.css_class()}",
+            p {
+                strong { {i18n.t(info.message)} }
+                span { class: "error-code", " ({info.code})" }
+            }
+            p {
+                {i18n.t("This recording lasts ")}
+                strong { "{duration}" }
+                {i18n.format(", longer than the recommended maximum of {}. ", &[&max_duration])}
+                {i18n.t("The job may exceed the server timeouts.")}
+            }
+            p {
+                {i18n.t(info.remediation)}
+                {
+                    i18n.format(
+                        " Splitting it into {} parts of about {} each is suggested.",
+                        &[&parts, &part_duration],
+                    )
+                }
+            }
+            button {
+                class: "button-split",
+                onclick: move |_| onsplit.call(()),
+                disabled: disabled || splitting,
+                if splitting {
+                    {i18n.t("Splitting the recording...")}
+                } else {
+                    {i18n.format("Split into {} parts and submit", &[&parts])}
+                }
+            }
+        }
+    }
+}
+
+/// Tells the user the submission limit is reached and counts down until the next slot.
+/// While mounted, it refreshes the shared throttle clock every second so the parent
+/// re-enables the "Transcribe Audio" button as soon as the limit lifts.
+#[component]
+fn SubmissionCountdown(
+    mut clock: Signal<DateTime<Utc>>,
+    wait: Duration,
+    limit: throttle::Limit,
+) -> Element {
+    let i18n = use_translator();
+    let mut timer = use_signal(|| Some(Interval::new(1000, move || clock.set(Utc::now()))));
+
+    use_drop(move || {
+        if let Some(timer) = timer.write().take() {
+            timer.cancel();
+        }
+    });
+
+    let info = AppError::from(ValidationError::SubmissionThrottled).info();
+    let window_minutes = limit.window_secs / 60;
+    let countdown = throttle::format_countdown(wait);
+
+    rsx! {
+        p {
+            class: "throttle-message {info.severity.css_class()}",
+            {i18n.t(info.message)}
+            {
+                i18n.format(
+                    " ({} per {} minutes, {}). ",
+                    &[&limit.max, &window_minutes, &info.code],
+                )
+            }
+            {i18n.t("You can submit again in ")}
+            strong { "{countdown}" }
+            "."
+        }
+    }
+}
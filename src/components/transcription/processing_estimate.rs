@@ -0,0 +1,64 @@
+//! Processing estimate
+//! Displays an estimate of the progress of a job being processed, from the duration of
+//! its media and the throughput observed on the past jobs of the endpoint.
+
+use crate::api::TranscriptionJob;
+use crate::config::PROGRESS_ESTIMATE_REFRESH_MS;
+use crate::i18n::use_translator;
+use crate::media::format_duration;
+use crate::state::AppState;
+use crate::throughput;
+use chrono::Utc;
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+
+/// A progress bar estimating the processing of a job, refreshed every second.
+/// Nothing is shown until a job of the endpoint was timed, or when the duration of
+/// the media is unknown.
+#[component]
+pub fn ProcessingEstimate(job: TranscriptionJob) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut now = use_signal(Utc::now);
+    use_future(move || async move {
+        loop {
+            TimeoutFuture::new(PROGRESS_ESTIMATE_REFRESH_MS).await;
+            now.set(Utc::now());
+        }
+    });
+
+    let rate = app_state
+        .processing_rates
+        .get()
+        .get(&app_state.api_url.get())
+        .copied();
+    let (Some(rate), Some(duration), Some(since)) =
+        (rate, job.media_duration, job.processing_since)
+    else {
+        return rsx! {};
+    };
+    let elapsed = (now() - since).num_milliseconds().max(0) as f64 / 1000.0;
+    let share = throughput::estimate(rate, duration, elapsed);
+    let percent = (share * 100.0).round();
+    let details = match throughput::remaining_secs(rate, duration, elapsed) {
+        Some(remaining) => i18n.format("about {} left", &[&format_duration(remaining)]),
+        None => i18n.t("taking longer than usual").to_string(),
+    };
+
+    rsx! {
+        div {
+            class: "processing-estimate",
+            div {
+                class: "progress-bar",
+                div {
+                    class: "progress-fill",
+                    style: "width: {percent}%",
+                }
+            }
+            p {
+                class: "probe-note",
+                {i18n.format("Estimate from the past jobs of this server: {}%, {}", &[&percent, &details])}
+            }
+        }
+    }
+}
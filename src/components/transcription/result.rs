@@ -0,0 +1,344 @@
+//! Transcription result
+//! Displays a completed transcript and the tools to correct it, in the proofreading
+//! view or the editor.
+//! Corrections apply to the processed version; the raw transcript is always kept.
+
+use crate::api::OutputFormat;
+use crate::clipboard;
+use crate::components::bilingual::BilingualView;
+use crate::components::channel_view::ChannelView;
+use crate::components::context_menu::{Action, ContextMenuArea};
+use crate::components::copy_button::CopyButton;
+use crate::components::editor::TranscriptEditor;
+use crate::components::find_replace::FindReplace;
+use crate::components::proofreading::ProofreadingView;
+use crate::components::subtitle_export::SubtitleExport;
+use crate::components::toast;
+use crate::errors::Severity;
+use crate::history;
+use crate::i18n::use_translator;
+use crate::media;
+use crate::postprocess;
+use crate::state::{AppState, TranscriptionUiStatus};
+use crate::transcript::{self, export, TranscriptSegment};
+use dioxus::prelude::*;
+
+/// Number of distinct speaker colors (`speaker-N` CSS classes), cycled through beyond.
+const SPEAKER_COLORS: usize = 6;
+
+/// The completed transcript, with find and replace, proofreading and the undo of corrections.
+/// `audio_url` is the recording, while the submitted file is still selected;
+/// `is_video` tells whether it is a video, on which subtitles can be previewed.
+#[component]
+pub fn TranscriptResult(
+    job_id: String,
+    result: String,
+    processed: Option<String>,
+    audio_url: Option<String>,
+    is_video: bool,
+) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut proofreading = use_signal(|| false);
+    let mut editing = use_signal(|| false);
+    // Display-only cleanup of casing and punctuation, for lowercase outputs.
+    let mut tidy = use_signal(|| false);
+    // Versions of the processed transcript before each correction, latest last.
+    let mut undo_stack: Signal<Vec<Option<String>>> = use_signal(Vec::new);
+
+    let displayed = processed.clone().unwrap_or_else(|| result.clone());
+
+    // Publishes a version of the processed transcript to the panel and the history.
+    let publish = {
+        let job_id = job_id.clone();
+        let result = result.clone();
+        move |processed: Option<String>| {
+            let mut history = app_state.history;
+            history.set(history::update(&history.get(), &job_id, |entry| {
+                entry.processed_result = processed.clone();
+            }));
+            let mut ui_status = app_state.transcription_ui_status;
+            ui_status.set(TranscriptionUiStatus::Completed {
+                job_id: job_id.clone(),
+                result: result.clone(),
+                processed,
+            });
+        }
+    };
+
+    let mut apply = {
+        let publish = publish.clone();
+        let processed = processed.clone();
+        move |corrected: String| {
+            undo_stack.write().push(processed.clone());
+            publish(Some(corrected));
+        }
+    };
+
+    let undo = move |_| {
+        if let Some(previous) = undo_stack.write().pop() {
+            publish(previous);
+        }
+    };
+
+    if proofreading() {
+        return rsx! {
+            ProofreadingView {
+                job_id,
+                audio_url,
+                result,
+                text: displayed,
+                onsave: move |corrected| {
+                    apply(corrected);
+                    proofreading.set(false);
+                },
+                onclose: move |_| proofreading.set(false),
+            }
+        };
+    }
+
+    if editing() {
+        return rsx! {
+            TranscriptEditor {
+                job_id,
+                text: displayed,
+                original: result,
+                onsave: move |corrected| {
+                    apply(corrected);
+                    editing.set(false);
+                },
+                onclose: move |_| editing.set(false),
+            }
+        };
+    }
+    let has_draft = app_state.transcript_drafts.get().contains_key(&job_id);
+
+    let shown = if tidy() {
+        postprocess::tidy_for_display(&displayed)
+    } else {
+        displayed.clone()
+    };
+
+    // Segments come from the raw result, so they are shown until the transcript is corrected.
+    let segments = transcript::parse_segments(&result)
+        .filter(|segments| processed.is_none() && !tidy() && !segments.is_empty());
+    let entry = history::find(&app_state.history.get(), &job_id).cloned();
+    let output_format = entry.as_ref().and_then(|entry| entry.job.output_format);
+    let title = entry
+        .and_then(|entry| entry.job.file_name)
+        .unwrap_or_else(|| job_id.clone());
+    let markdown = export::markdown(&title, &result, processed.as_deref());
+    // Only plain text results are downloaded corrected: structured formats keep their syntax.
+    let download_text = match output_format {
+        Some(OutputFormat::Txt) => &displayed,
+        _ => &result,
+    };
+
+    let mut result_actions = vec![Action::CopyText, Action::CopyJobId];
+    result_actions.extend(
+        export::available_formats(&result, output_format)
+            .into_iter()
+            .map(Action::Download),
+    );
+    let run_action = {
+        let job_id = job_id.clone();
+        let result = result.clone();
+        let processed = processed.clone();
+        let displayed = displayed.clone();
+        move |action: Action| match action {
+            Action::CopyText | Action::CopyJobId => {
+                let text = if action == Action::CopyText {
+                    displayed.clone()
+                } else {
+                    job_id.clone()
+                };
+                spawn(async move {
+                    if !clipboard::copy_text(&text).await {
+                        toast::show("Could not access the clipboard.", Severity::Warning);
+                    }
+                });
+            }
+            Action::Download(format) => export::download(
+                &job_id,
+                &result,
+                processed.as_deref(),
+                output_format,
+                format,
+                &app_state.subtitle_constraints.get(),
+                &app_state.postprocess_pipeline.get(),
+            ),
+            Action::Open | Action::Rename | Action::Rerun | Action::Delete => {}
+        }
+    };
+
+    rsx! {
+        ContextMenuArea {
+            actions: result_actions,
+            onaction: run_action,
+            if let Some(segments) = segments {
+                SegmentTable { segments }
+            } else {
+                ResultText { text: shown, output_format }
+            }
+        }
+        label {
+            class: "tidy-toggle",
+            input {
+                r#type: "checkbox",
+                checked: tidy(),
+                onchange: move |evt| tidy.set(evt.checked()),
+            }
+            " "
+            {i18n.t("Fix casing and punctuation (display only)")}
+        }
+        if processed.is_some() {
+            details {
+                summary { {i18n.t("Raw transcript")} }
+                p { code { "{result}" } }
+            }
+        }
+        FindReplace { text: displayed.clone(), onapply: apply.clone() }
+        if let Some(segments) = transcript::parse_segments(&result) {
+            SubtitleExport {
+                job_id: job_id.clone(),
+                segments: postprocess::run_on_segments(&app_state.postprocess_pipeline.get(), &segments),
+                corrected: processed.is_some(),
+                video_url: audio_url.clone().filter(|_| is_video),
+                audio_url: audio_url.clone().filter(|_| !is_video),
+            }
+            ChannelView { job_id: job_id.clone(), segments: segments.clone() }
+            BilingualView { job_id: job_id.clone(), segments }
+        }
+        div {
+            class: "action-buttons",
+            CopyButton { text: displayed.clone(), label: "Copy Transcript" }
+            CopyButton { text: markdown, label: "Copy as Markdown" }
+            button { onclick: move |_| proofreading.set(true), {i18n.t("Proofread")} }
+            button {
+                onclick: move |_| editing.set(true),
+                if has_draft { {i18n.t("Resume Editing")} } else { {i18n.t("Edit Transcript")} }
+            }
+            if let Some(output_format) = output_format {
+                a {
+                    class: "download-button",
+                    href: "{media::data_url(output_format.mime_type(), download_text)}",
+                    download: "{job_id}.{output_format.extension()}",
+                    {i18n.t("Download Result")}
+                }
+            }
+            if !undo_stack.read().is_empty() {
+                button { onclick: undo, {i18n.t("Undo Correction")} }
+            }
+        }
+    }
+}
+
+/// The transcript, laid out for its format: JSON is pretty-printed, subtitles and
+/// tables keep their lines in a monospace block, and plain text flows.
+#[component]
+fn ResultText(text: String, output_format: Option<OutputFormat>) -> Element {
+    match output_format {
+        Some(OutputFormat::Json) => {
+            let pretty = serde_json::from_str::<serde_json::Value>(&text)
+                .and_then(|value| serde_json::to_string_pretty(&value))
+                .unwrap_or(text);
+            rsx! { pre { class: "result-text result-json", "{pretty}" } }
+        }
+        Some(OutputFormat::Srt | OutputFormat::Vtt | OutputFormat::Tsv) => {
+            rsx! { pre { class: "result-text", "{text}" } }
+        }
+        Some(OutputFormat::Txt) | None => rsx! { p { code { "{text}" } } },
+    }
+}
+
+/// The segments as a table, with columns of timestamps and speakers that can be hidden.
+/// Each speaker has its own color.
+#[component]
+fn SegmentTable(segments: Vec<TranscriptSegment>) -> Element {
+    let i18n = use_translator();
+    let mut show_times = use_signal(|| true);
+    let mut show_speakers = use_signal(|| true);
+
+    let mut speakers: Vec<&str> = Vec::new();
+    for speaker in segments
+        .iter()
+        .filter_map(|segment| segment.speaker.as_deref())
+    {
+        if !speakers.contains(&speaker) {
+            speakers.push(speaker);
+        }
+    }
+    let color = |speaker: &str| {
+        speakers
+            .iter()
+            .position(|known| *known == speaker)
+            .unwrap_or(0)
+            % SPEAKER_COLORS
+    };
+    let has_speakers = !speakers.is_empty();
+    let speakers_shown = has_speakers && show_speakers();
+
+    rsx! {
+        div {
+            class: "segment-columns",
+            label {
+                input {
+                    r#type: "checkbox",
+                    checked: show_times(),
+                    onchange: move |evt| show_times.set(evt.checked()),
+                }
+                " "
+                {i18n.t("Timestamps")}
+            }
+            if has_speakers {
+                label {
+                    input {
+                        r#type: "checkbox",
+                        checked: show_speakers(),
+                        onchange: move |evt| show_speakers.set(evt.checked()),
+                    }
+                    " "
+                    {i18n.t("Speakers")}
+                }
+            }
+        }
+        div {
+            class: "segment-table-wrapper",
+            table {
+                class: "segment-table",
+                thead {
+                    tr {
+                        if show_times() {
+                            th { {i18n.t("Time")} }
+                        }
+                        if speakers_shown {
+                            th { {i18n.t("Speaker")} }
+                        }
+                        th { {i18n.t("Text")} }
+                    }
+                }
+                tbody {
+                    for (index, segment) in segments.iter().enumerate() {
+                        tr {
+                            key: "{index}",
+                            if show_times() {
+                                td {
+                                    class: "segment-time",
+                                    "{transcript::format_timestamp(segment.start)} – {transcript::format_timestamp(segment.end)}"
+                                }
+                            }
+                            if speakers_shown {
+                                td {
+                                    if let Some(speaker) = segment.speaker.as_deref() {
+                                        strong { class: "speaker speaker-{color(speaker)}", "{speaker}" }
+                                    }
+                                }
+                            }
+                            td { "{segment.text.trim()}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Upload limit check
+//! Warns when the selected file exceeds an upload limit detected in front of the API,
+//! and offers an optional probe to detect such limits.
+
+use crate::api;
+use crate::components::error_message::ErrorMessage;
+use crate::config::UPLOAD_PROBE_SIZES;
+use crate::errors::AppError;
+use crate::i18n::use_translator;
+use crate::media::format_size;
+use crate::profile::{update_profile, UploadLimitProbe};
+use crate::state::AppState;
+use dioxus::prelude::*;
+
+/// Checks the selected file size against the detected and advertised upload limits.
+#[component]
+pub fn UploadLimitCheck(file_size: u64) -> Element {
+    let app_state = use_context::<AppState>();
+    let i18n = use_translator();
+    let mut is_probing = use_signal(|| false);
+    let mut probe_error: Signal<Option<AppError>> = use_signal(|| None);
+
+    let probe = app_state.current_profile().upload_limit_probe;
+    let advertised_limit = app_state
+        .api_connection_status
+        .read()
+        .api_status()
+        .map(|status| status.resources.max_file_size);
+
+    // A detected limit the file exceeds: the upload will most likely fail.
+    if let Some(rejected_at) = probe
+        .as_ref()
+        .and_then(|probe| probe.rejected_at)
+        .filter(|rejected_at| file_size >= *rejected_at)
+    {
+        let advertised = advertised_limit
+            .map(|limit| {
+                i18n.format(
+                    " even though the API accepts up to {}",
+                    &[&format_size(limit)],
+                )
+            })
+            .unwrap_or_default();
+        return rsx! {
+            p {
+                class: "upload-limit-warning severity-warning",
+                {i18n.format("A proxy in front of the API rejected uploads of {}{}. ", &[&format_size(rejected_at), &advertised])}
+                {i18n.format("This file ({}) will likely be rejected.", &[&format_size(file_size)])}
+            }
+        };
+    }
+    if probe
+        .as_ref()
+        .is_some_and(|probe| probe.rejected_at.is_some())
+    {
+        return rsx! {};
+    }
+
+    // Only sizes the file reaches, below the advertised limit, and not already tested are worth probing.
+    let accepted_up_to = probe.as_ref().and_then(|probe| probe.accepted_up_to);
+    let untested_sizes: Vec<u64> = UPLOAD_PROBE_SIZES
+        .iter()
+        .copied()
+        .filter(|size| *size < file_size)
+        .filter(|size| advertised_limit.is_none_or(|limit| *size < limit))
+        .filter(|size| accepted_up_to.is_none_or(|accepted| *size > accepted))
+        .collect();
+    if untested_sizes.is_empty() {
+        return rsx! {};
+    }
+
+    let probe_volume = format_size(untested_sizes.iter().sum());
+    let run_probe = move |_| {
+        let sizes = untested_sizes.clone();
+        let api_url = app_state.api_url.get();
+        let profile_id = app_state.current_profile_id();
+        let profile = app_state.current_profile();
+        is_probing.set(true);
+        probe_error.set(None);
+        spawn(async move {
+            match api::probe_upload_limit(&api_url, &profile, &sizes).await {
+                Ok(result) => update_profile(app_state.endpoint_profiles, &profile_id, |profile| {
+                    let previous = profile.upload_limit_probe.take();
+                    profile.upload_limit_probe = Some(UploadLimitProbe {
+                        accepted_up_to: result
+                            .accepted_up_to
+                            .max(previous.and_then(|previous| previous.accepted_up_to)),
+                        ..result
+                    });
+                }),
+                Err(e) => probe_error.set(Some(e.into())),
+            }
+            is_probing.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "upload-limit-check",
+            button {
+                onclick: run_probe,
+                disabled: is_probing(),
+                if is_probing() { {i18n.t("Checking upload limit...")} } else { {i18n.t("Check proxy upload limit")} }
+            }
+            span {
+                class: "probe-note",
+                {i18n.format(" Optional: sends up to {} of test data to detect a reverse-proxy limit.", &[&probe_volume])}
+            }
+            if let Some(error) = probe_error() {
+                ErrorMessage { error }
+            }
+        }
+    }
+}
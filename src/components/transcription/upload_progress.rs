@@ -0,0 +1,51 @@
+//! Upload progress
+//! Displays the progress, throughput and remaining time of the file upload.
+
+use crate::i18n::use_translator;
+use crate::media::{format_duration, format_size};
+use crate::services::submission::UploadProgress;
+use dioxus::prelude::*;
+
+/// A progress bar with the throughput and the estimated time left.
+/// Until the upload starts, while the file is read and hashed, the bar is indeterminate.
+#[component]
+pub fn UploadProgressBar(progress: Option<UploadProgress>) -> Element {
+    let i18n = use_translator();
+    let Some(progress) = progress else {
+        return rsx! {
+            div {
+                class: "upload-progress",
+                progress {}
+                p { class: "probe-note", {i18n.t("Preparing the file for upload...")} }
+            }
+        };
+    };
+    let mut details = i18n.format(
+        "{} of {} ({}%)",
+        &[
+            &format_size(progress.sent),
+            &format_size(progress.total),
+            &progress.percent(),
+        ],
+    );
+    if let Some(speed) = progress.speed() {
+        details.push_str(&format!(" — {}/s", format_size(speed as u64)));
+    }
+    match progress.remaining_secs() {
+        Some(_) if progress.sent >= progress.total => {
+            details.push_str(i18n.t(" — waiting for the server"));
+        }
+        Some(remaining) => {
+            details.push_str(&i18n.format(" — about {} left", &[&format_duration(remaining)]));
+        }
+        None => {}
+    }
+
+    rsx! {
+        div {
+            class: "upload-progress",
+            progress { max: "{progress.total}", value: "{progress.sent}" }
+            p { class: "probe-note", "{details}" }
+        }
+    }
+}
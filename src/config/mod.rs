@@ -6,11 +6,237 @@ pub const DEFAULT_API_URL: &str = "https://llm.iut-rodez.fr/leontine/api";
 /// Path to the API status endpoint
 pub const API_STATUS_ENDPOINT: &str = "/status";
 
+/// Conventional API base paths probed by the "Detect API" action, in order of preference
+pub const API_DISCOVERY_PATHS: &[&str] = &["/api", "", "/whisper"];
+
 /// Path to the API transcription endpoint
 pub const API_TRANSCRIPTION_ENDPOINT: &str = "/transcription";
 
-/// Initial API status check delay in milliseconds (check once when the component mounts)
-pub const API_STATUS_INITIAL_CHECK_MS: u64 = 100;
+/// Path to the admin queue listing endpoint, exposed by servers run by the user
+pub const API_ADMIN_JOBS_ENDPOINT: &str = "/admin/jobs";
+
+/// Suffixes of the Prometheus metrics counting the queued jobs
+pub const METRICS_QUEUE_LENGTH_SUFFIXES: &[&str] = &["_queue_length", "_queued_jobs"];
+
+/// Suffix of the Prometheus histogram or summary of job durations (in seconds)
+pub const METRICS_JOB_DURATION_SUFFIX: &str = "_job_duration_seconds";
+
+/// Number of metrics scrapes kept to draw trends
+pub const METRICS_HISTORY_SAMPLES: usize = 30;
+
+/// Header carrying the SHA-256 checksum of the uploaded file, when enabled in the endpoint profile
+pub const CHECKSUM_HEADER: &str = "X-Content-SHA256";
+
+/// Suffix of the status URL of a job giving its Server-Sent Events stream
+pub const JOB_EVENTS_SUFFIX: &str = "/events";
+
+/// Path of the endpoint giving the status of several jobs at once, relative to the API URL
+pub const BATCH_JOB_STATUS_PATH: &str = "/jobs/status";
 
 /// Interval between API status checks in milliseconds (check every 30 seconds)
 pub const API_STATUS_CHECK_INTERVAL_MS: u64 = 30000;
+
+/// Interval between job status checks in milliseconds while a job is monitored
+pub const JOB_STATUS_POLL_INTERVAL_MS: u32 = 5000;
+
+/// Number of queue positions ahead of a job for each step lengthening its polling interval
+pub const ADAPTIVE_POLL_QUEUE_STEP: u32 = 2;
+
+/// Maximum factor applied to the job polling interval of a job deep in the queue
+pub const ADAPTIVE_POLL_MAX_FACTOR: u32 = 6;
+
+/// Bounds of the job polling interval which can be set in the settings, in milliseconds
+pub const MIN_JOB_STATUS_POLL_INTERVAL_MS: u32 = 1000;
+pub const MAX_JOB_STATUS_POLL_INTERVAL_MS: u32 = 300_000;
+
+/// Bounds of the API status check interval which can be set in the settings, in milliseconds
+pub const MIN_API_STATUS_CHECK_INTERVAL_MS: u32 = 5000;
+pub const MAX_API_STATUS_CHECK_INTERVAL_MS: u32 = 600_000;
+
+/// Factor applied to the polling intervals in low-bandwidth mode
+pub const LOW_BANDWIDTH_INTERVAL_FACTOR: u32 = 4;
+
+/// Factor applied to the job polling interval while the server is under maintenance
+pub const MAINTENANCE_POLL_INTERVAL_FACTOR: u32 = 3;
+
+/// Upload speed (in bytes per second) below which low-bandwidth mode is suggested
+pub const LOW_BANDWIDTH_UPLOAD_SPEED: f64 = 100.0 * 1024.0;
+
+/// How long a toast notification stays on screen, in milliseconds
+pub const TOAST_DURATION_MS: u32 = 5000;
+
+/// Body sizes (in bytes) used to probe reverse-proxy upload limits,
+/// just above common proxy defaults (1, 10 and 50 MiB)
+pub const UPLOAD_PROBE_SIZES: &[u64] = &[
+    1024 * 1024 + 1024,
+    10 * 1024 * 1024 + 1024,
+    50 * 1024 * 1024 + 1024,
+];
+
+/// Default maximum number of past submissions kept in the history
+pub const DEFAULT_HISTORY_MAX_ENTRIES: usize = 50;
+
+/// Default age, in days, after which history entries expire
+pub const DEFAULT_HISTORY_MAX_AGE_DAYS: u32 = 30;
+
+/// Number of days deleted history entries stay in the trash before they are deleted permanently
+pub const TRASH_RETENTION_DAYS: i64 = 7;
+
+/// Interval between two runs of the cleanup service, in milliseconds (every hour)
+pub const CLEANUP_INTERVAL_MS: u32 = 3_600_000;
+
+/// Period, in hours, during which an unfinished job of the history counts as a possible
+/// duplicate when the same file is submitted again
+pub const DUPLICATE_GUARD_WINDOW_HOURS: i64 = 24;
+
+/// Maximum number of attempts of an idempotent API request failing with a transient error
+pub const RETRY_MAX_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry of an API request, in milliseconds, doubled at each attempt
+pub const RETRY_BASE_DELAY_MS: u32 = 500;
+
+/// Upper bound of the delay between two attempts of an API request, in milliseconds
+pub const RETRY_MAX_DELAY_MS: u32 = 8000;
+
+/// Number of consecutive job status polling failures before monitoring stops
+pub const MAX_CONSECUTIVE_POLL_FAILURES: u32 = 3;
+
+/// Number of consecutive failed API status checks, after a successful one,
+/// before the API is reported unavailable. Until then, the last known status is shown as stale.
+pub const MAX_STALE_STATUS_CHECKS: u32 = 3;
+
+/// Maximum number of submissions allowed per throttle window (0 disables the throttle)
+pub const SUBMISSION_RATE_LIMIT_MAX: usize = 10;
+
+/// Length of the submission throttle window in seconds (one hour)
+pub const SUBMISSION_RATE_LIMIT_WINDOW_SECS: i64 = 3600;
+
+/// Recordings longer than this duration (in seconds) trigger a server timeout warning (one hour)
+pub const MAX_RECOMMENDED_DURATION_SECS: f64 = 3600.0;
+
+/// Target duration (in seconds) of each part when suggesting to split a long recording
+pub const SUGGESTED_CHUNK_DURATION_SECS: f64 = 1800.0;
+
+/// Segment confidence below which the heatmap flags a segment as unreliable
+pub const LOW_CONFIDENCE_THRESHOLD: f64 = 0.5;
+
+/// Segment confidence from which the heatmap considers a segment reliable
+pub const HIGH_CONFIDENCE_THRESHOLD: f64 = 0.8;
+
+/// Number of least confident segments offered for review by the proofreading navigation
+pub const LOWEST_CONFIDENCE_SEGMENTS: usize = 10;
+
+/// Default maximum number of characters per subtitle line
+pub const SUBTITLE_MAX_LINE_CHARS: usize = 42;
+
+/// Default maximum number of lines per subtitle cue
+pub const SUBTITLE_MAX_LINES: usize = 2;
+
+/// Default maximum duration of a subtitle cue, in seconds
+pub const SUBTITLE_MAX_CUE_SECS: f64 = 7.0;
+
+/// Default minimum duration of a subtitle cue, in seconds
+pub const SUBTITLE_MIN_CUE_SECS: f64 = 1.0;
+
+/// Minimum pause between two segments, in seconds, for a chapter to start after it
+pub const CHAPTER_MIN_PAUSE_SECS: f64 = 2.0;
+
+/// Minimum duration of a chapter, in seconds
+pub const CHAPTER_MIN_DURATION_SECS: f64 = 120.0;
+
+/// Number of segments on each side of a pause whose keywords are compared to detect a topic change
+pub const CHAPTER_KEYWORD_WINDOW: usize = 8;
+
+/// Maximum share of common keywords on both sides of a pause for a topic change
+pub const CHAPTER_MAX_KEYWORD_OVERLAP: f64 = 0.1;
+
+/// Minimum number of characters of the words counted as keywords
+pub const CHAPTER_KEYWORD_MIN_CHARS: usize = 5;
+
+/// Number of keywords in generated chapter titles
+pub const CHAPTER_TITLE_KEYWORDS: usize = 3;
+
+/// Default width of the left sidebar (history and jobs), in CSS pixels
+pub const DEFAULT_LEFT_PANEL_WIDTH_PX: u32 = 320;
+
+/// Default width of the right panel (settings), in CSS pixels
+pub const DEFAULT_RIGHT_PANEL_WIDTH_PX: u32 = 360;
+
+/// Minimum width of a sidebar when resized, in CSS pixels
+pub const MIN_PANEL_WIDTH_PX: u32 = 200;
+
+/// Maximum width of a sidebar when resized, in CSS pixels
+pub const MAX_PANEL_WIDTH_PX: u32 = 640;
+
+/// Duration of the touch after which a long press opens the context menu, in milliseconds
+pub const LONG_PRESS_DURATION_MS: u32 = 500;
+
+/// Path of the runtime configuration of the deployment, relative to the application
+pub const RUNTIME_CONFIG_PATH: &str = "leontine.config.json";
+
+/// Maximum number of consent acceptances kept in local storage
+pub const MAX_CONSENT_RECORDS: usize = 100;
+
+/// Offset between the server and local clocks below which both are considered in agreement,
+/// in milliseconds
+pub const CLOCK_SKEW_TOLERANCE_MS: i64 = 2000;
+
+/// Delay without typing after which the transcript editor saves its draft, in milliseconds
+pub const EDITOR_AUTOSAVE_DELAY_MS: u32 = 1500;
+
+/// Maximum number of transcript drafts kept in local storage, the oldest being dropped
+pub const MAX_TRANSCRIPT_DRAFTS: usize = 20;
+
+/// Weight of the latest job in the observed processing throughput of an endpoint, between 0 and 1
+pub const THROUGHPUT_SMOOTHING: f64 = 0.3;
+
+/// Share of the work the processing estimate never goes beyond, the end being known only from the server
+pub const MAX_ESTIMATED_PROGRESS: f64 = 0.95;
+
+/// Interval between refreshes of the processing estimate, in milliseconds
+pub const PROGRESS_ESTIMATE_REFRESH_MS: u32 = 1000;
+
+/// Path of the service worker caching the application shell, relative to the application
+pub const SERVICE_WORKER_PATH: &str = "sw.js";
+
+/// Peak level (between 0 and 1) quiet recordings are brought to when normalized for playback
+pub const NORMALIZATION_TARGET_PEAK: f64 = 0.9;
+
+/// Highest gain applied to a recording when normalized for playback
+pub const MAX_NORMALIZATION_GAIN: f64 = 10.0;
+
+/// Interval between the heartbeats of the tab monitoring the jobs for the others, in milliseconds
+pub const LEADER_HEARTBEAT_MS: u32 = 2000;
+
+/// Delay without heartbeat after which another tab takes over the monitoring of the jobs, in milliseconds
+pub const LEADER_TIMEOUT_MS: u32 = 5000;
+
+/// Sample rate of the channels split from a stereo recording, in Hz (what the transcription model works at)
+pub const SPLIT_CHANNEL_SAMPLE_RATE: u32 = 16000;
+
+/// Longest recording whose levels are analyzed on selection, in seconds (the whole recording is decoded)
+pub const MAX_LEVEL_ANALYSIS_DURATION_SECS: f64 = 1800.0;
+
+/// Sample rate at which the levels of a recording are analyzed, in Hz
+pub const LEVEL_ANALYSIS_SAMPLE_RATE: u32 = 16000;
+
+/// Sample level (between 0 and 1) from which a sample is considered clipped
+pub const CLIPPING_SAMPLE_LEVEL: f64 = 0.99;
+
+/// Share of clipped samples from which a recording is considered heavily clipped
+pub const MAX_CLIPPED_RATIO: f64 = 0.001;
+
+/// RMS level below which a recording is considered too quiet to transcribe well, in dBFS
+pub const MIN_RMS_DBFS: f64 = -40.0;
+
+/// Difference of level between the channels from which one is considered nearly silent, in dB
+pub const CHANNEL_IMBALANCE_DB: f64 = 20.0;
+
+/// Interval at which the batch queue checks whether its next file can be submitted, in milliseconds
+pub const BATCH_CHECK_INTERVAL_MS: u32 = 1000;
+
+/// File types accepted for transcription when the deployment does not set its own, as in the HTML `accept` attribute
+pub const DEFAULT_ACCEPTED_FILE_TYPES: &[&str] = &["audio/*", "video/*", "application/ogg"];
+
+/// Largest file hashed on selection to recognize files already transcribed, in bytes (larger files are only hashed on submission)
+pub const MAX_SELECTION_CHECKSUM_SIZE: u64 = 200 * 1024 * 1024;
@@ -0,0 +1,40 @@
+//! Upload consent
+//! Keeps track of the consent statements of the runtime configuration accepted
+//! by the user, with the time of acceptance. The records never leave the browser.
+
+use crate::config::MAX_CONSENT_RECORDS;
+use crate::runtime_config::ConsentConfig;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// An acceptance of a consent statement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConsentRecord {
+    /// Revision of the accepted statement.
+    pub revision: String,
+    pub accepted_at: DateTime<Utc>,
+}
+
+/// Whether the statement must be accepted before the next submission.
+pub fn is_required(consent: &ConsentConfig, log: &[ConsentRecord]) -> bool {
+    consent.every_submission
+        || !log
+            .iter()
+            .any(|record| record.revision == consent.revision())
+}
+
+/// Returns the log with a new acceptance of the statement, keeping the latest records.
+pub fn record(
+    log: &[ConsentRecord],
+    consent: &ConsentConfig,
+    now: DateTime<Utc>,
+) -> Vec<ConsentRecord> {
+    let mut log = log.to_vec();
+    log.push(ConsentRecord {
+        revision: consent.revision().to_string(),
+        accepted_at: now,
+    });
+    let excess = log.len().saturating_sub(MAX_CONSENT_RECORDS);
+    log.drain(..excess);
+    log
+}
@@ -0,0 +1,57 @@
+//! Job deep links
+//! A deep link is the address of the application with the API URL and a job id
+//! in its query string. Opening it, e.g. on a phone after scanning its QR code,
+//! resumes monitoring of that job.
+
+use web_sys::{Url, UrlSearchParams};
+
+/// Query parameter holding the API URL.
+const API_PARAM: &str = "api";
+/// Query parameter holding the job id.
+const JOB_PARAM: &str = "job";
+
+/// A job referenced by a deep link.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobLink {
+    pub api_url: String,
+    pub job_id: String,
+}
+
+/// Builds the deep link of a job, based on the current address of the application.
+pub fn job_link(api_url: &str, job_id: &str) -> Option<String> {
+    let location = web_sys::window()?.location().href().ok()?;
+    let url = Url::new(&location).ok()?;
+    url.set_hash("");
+    let params = UrlSearchParams::new().ok()?;
+    params.append(API_PARAM, api_url);
+    params.append(JOB_PARAM, job_id);
+    url.set_search(&String::from(params.to_string()));
+    Some(url.href())
+}
+
+/// Reads the job referenced by the current address, if any, and removes it from the
+/// address bar so that reloading the page does not open the job again.
+pub fn take_from_location() -> Option<JobLink> {
+    let window = web_sys::window()?;
+    let url = Url::new(&window.location().href().ok()?).ok()?;
+    let params = url.search_params();
+    let link = JobLink {
+        api_url: params.get(API_PARAM)?,
+        job_id: params.get(JOB_PARAM)?,
+    };
+    if link.api_url.is_empty() || link.job_id.is_empty() {
+        return None;
+    }
+
+    params.delete(API_PARAM);
+    params.delete(JOB_PARAM);
+    url.set_search(&String::from(params.to_string()));
+    if let Ok(history) = window.history() {
+        let _ = history.replace_state_with_url(
+            &web_sys::wasm_bindgen::JsValue::NULL,
+            "",
+            Some(&url.href()),
+        );
+    }
+    Some(link)
+}
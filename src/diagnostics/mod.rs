@@ -0,0 +1,31 @@
+//! Diagnostics reports for the Leontine application
+//! Builds a plain-text summary of an error and its context that users can
+//! paste into an issue or an email to the server administrator.
+
+use crate::errors::AppError;
+use chrono::Utc;
+
+/// Builds a diagnostics report for an error.
+/// `context` holds additional labelled values (API URL, job id, file name...).
+pub fn report(error: &AppError, context: &[(&str, String)]) -> String {
+    let info = error.info();
+    let mut lines = vec![
+        "Leontine issue report".to_string(),
+        format!("Time: {}", Utc::now().to_rfc3339()),
+        format!("Version: {}", env!("CARGO_PKG_VERSION")),
+        format!("Error code: {}", info.code),
+        format!("Message: {}", info.message),
+    ];
+
+    if let Some(detail) = &info.detail {
+        lines.push(format!("Details: {}", detail));
+    }
+    for (label, value) in context {
+        lines.push(format!("{}: {}", label, value));
+    }
+    if let Some(user_agent) = web_sys::window().and_then(|w| w.navigator().user_agent().ok()) {
+        lines.push(format!("User agent: {}", user_agent));
+    }
+
+    lines.join("\n")
+}
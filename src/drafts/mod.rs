@@ -0,0 +1,62 @@
+//! Transcript drafts
+//! Corrections being typed in the transcript editor, kept by job id until they are
+//! applied or reverted, so that closing the page never loses them.
+
+use crate::config::MAX_TRANSCRIPT_DRAFTS;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// The drafts, by job id.
+pub type TranscriptDrafts = BTreeMap<String, TranscriptDraft>;
+
+/// A transcript being corrected.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptDraft {
+    pub text: String,
+    pub saved_at: DateTime<Utc>,
+}
+
+/// Returns the drafts with the draft of a job saved, keeping the latest ones.
+pub fn save(
+    drafts: &TranscriptDrafts,
+    job_id: &str,
+    text: String,
+    now: DateTime<Utc>,
+) -> TranscriptDrafts {
+    let mut drafts = drafts.clone();
+    drafts.insert(
+        job_id.to_string(),
+        TranscriptDraft {
+            text,
+            saved_at: now,
+        },
+    );
+    while drafts.len() > MAX_TRANSCRIPT_DRAFTS {
+        let Some(oldest) = drafts
+            .iter()
+            .min_by_key(|(_, draft)| draft.saved_at)
+            .map(|(job_id, _)| job_id.clone())
+        else {
+            break;
+        };
+        drafts.remove(&oldest);
+    }
+    drafts
+}
+
+/// Returns the drafts without the draft of a job.
+pub fn discard(drafts: &TranscriptDrafts, job_id: &str) -> TranscriptDrafts {
+    let mut drafts = drafts.clone();
+    drafts.remove(job_id);
+    drafts
+}
+
+/// Returns the drafts of the kept jobs only.
+pub fn retain(drafts: &TranscriptDrafts, kept_job_ids: &HashSet<String>) -> TranscriptDrafts {
+    drafts
+        .iter()
+        .filter(|(job_id, _)| kept_job_ids.contains(*job_id))
+        .map(|(job_id, draft)| (job_id.clone(), draft.clone()))
+        .collect()
+}
@@ -0,0 +1,98 @@
+//! Embedded views
+//! Standalone views of the application with minimal chrome, selected by the `view`
+//! query parameter, so that they can be embedded in an iframe (e.g. on an intranet
+//! dashboard). The API URL can be given by the `api` parameter, as in deep links.
+
+use crate::api::{DiarizationOptions, OutputFormat};
+use web_sys::{Url, UrlSearchParams};
+
+/// Query parameter selecting the view.
+const VIEW_PARAM: &str = "view";
+/// Query parameter holding the API URL.
+const API_PARAM: &str = "api";
+/// Query parameter fixing the output format of the upload view.
+const FORMAT_PARAM: &str = "format";
+/// Query parameter fixing whether the upload view requests diarization.
+const DIARIZE_PARAM: &str = "diarize";
+/// Query parameters fixing the speaker counts of the upload view.
+const MIN_SPEAKERS_PARAM: &str = "min_speakers";
+const MAX_SPEAKERS_PARAM: &str = "max_speakers";
+
+/// A view meant to be embedded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbedView {
+    /// API availability and queue depth.
+    StatusBadge,
+    /// File selection and job monitoring, without the settings.
+    Upload,
+}
+
+/// Transcription options fixed by the address of the upload view,
+/// which the user cannot change there.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FixedOptions {
+    pub output_format: Option<OutputFormat>,
+    pub diarization: Option<DiarizationOptions>,
+}
+
+impl EmbedView {
+    fn name(self) -> &'static str {
+        match self {
+            EmbedView::StatusBadge => "status-badge",
+            EmbedView::Upload => "upload",
+        }
+    }
+}
+
+/// The view requested by the current address, if it is an embedded one.
+pub fn current_view() -> Option<EmbedView> {
+    match search_params()?.get(VIEW_PARAM)?.as_str() {
+        "status-badge" => Some(EmbedView::StatusBadge),
+        "upload" => Some(EmbedView::Upload),
+        _ => None,
+    }
+}
+
+/// The API URL given by the current address, if any.
+pub fn api_url_param() -> Option<String> {
+    search_params()?
+        .get(API_PARAM)
+        .filter(|api_url| !api_url.is_empty())
+}
+
+/// The transcription options fixed by the current address.
+/// Diarization is fixed by `diarize`, the speaker counts only apply along with it.
+pub fn fixed_options() -> FixedOptions {
+    let Some(params) = search_params() else {
+        return FixedOptions::default();
+    };
+    let speakers = |name: &str| params.get(name).and_then(|count| count.parse().ok());
+    FixedOptions {
+        output_format: params
+            .get(FORMAT_PARAM)
+            .and_then(|format| OutputFormat::from_extension(&format)),
+        diarization: params.get(DIARIZE_PARAM).map(|diarize| DiarizationOptions {
+            enabled: matches!(diarize.as_str(), "true" | "1"),
+            min_speakers: speakers(MIN_SPEAKERS_PARAM),
+            max_speakers: speakers(MAX_SPEAKERS_PARAM),
+        }),
+    }
+}
+
+/// Builds the address of an embedded view for an API URL, based on the current
+/// address of the application.
+pub fn view_link(view: EmbedView, api_url: &str) -> Option<String> {
+    let location = web_sys::window()?.location().href().ok()?;
+    let url = Url::new(&location).ok()?;
+    url.set_hash("");
+    let params = UrlSearchParams::new().ok()?;
+    params.append(VIEW_PARAM, view.name());
+    params.append(API_PARAM, api_url);
+    url.set_search(&String::from(params.to_string()));
+    Some(url.href())
+}
+
+fn search_params() -> Option<UrlSearchParams> {
+    let location = web_sys::window()?.location().href().ok()?;
+    Some(Url::new(&location).ok()?.search_params())
+}
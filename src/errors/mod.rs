@@ -0,0 +1,430 @@
+//! Error catalog for the Leontine application
+//! Maps every API error, validation failure and job failure to a stable error code,
+//! a user-facing message (with its i18n key), a severity and a suggested remediation,
+//! so that every part of the UI presents errors consistently.
+
+use crate::api::ApiError;
+use crate::media::format_size;
+use std::fmt;
+
+/// How serious an error is, from the user's point of view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// Informational, nothing went wrong.
+    Info,
+    /// Something needs attention, but the user can carry on.
+    Warning,
+    /// The requested action failed.
+    Error,
+}
+
+impl Severity {
+    /// CSS class used to style messages of this severity.
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            Severity::Info => "severity-info",
+            Severity::Warning => "severity-warning",
+            Severity::Error => "severity-error",
+        }
+    }
+}
+
+/// Stable error codes, shown to users so they can be quoted in support requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    ApiNotConfigured,
+    NetworkFailure,
+    EndpointNotFound,
+    AccessDenied,
+    FileTooLarge,
+    ServerRateLimited,
+    RequestRejected,
+    ServerFailure,
+    UnexpectedResponse,
+    NotAnApi,
+    ServerMaintenance,
+    FileUnavailable,
+    SubmissionThrottled,
+    RecordingTooLong,
+    MicrophoneUnavailable,
+    FileExceedsLimit,
+    UnsupportedFileType,
+    ChannelSplitFailed,
+    AudioClipped,
+    AudioTooQuiet,
+    RecordingSplitFailed,
+    JobFailed,
+    JobNotFound,
+}
+
+impl ErrorCode {
+    /// The code as displayed to users.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::ApiNotConfigured => "E100",
+            ErrorCode::NetworkFailure => "E101",
+            ErrorCode::EndpointNotFound => "E102",
+            ErrorCode::AccessDenied => "E103",
+            ErrorCode::FileTooLarge => "E104",
+            ErrorCode::ServerRateLimited => "E105",
+            ErrorCode::RequestRejected => "E106",
+            ErrorCode::ServerFailure => "E107",
+            ErrorCode::UnexpectedResponse => "E108",
+            ErrorCode::NotAnApi => "E109",
+            ErrorCode::ServerMaintenance => "E110",
+            ErrorCode::FileUnavailable => "E200",
+            ErrorCode::SubmissionThrottled => "E201",
+            ErrorCode::RecordingTooLong => "E202",
+            ErrorCode::MicrophoneUnavailable => "E203",
+            ErrorCode::FileExceedsLimit => "E204",
+            ErrorCode::UnsupportedFileType => "E205",
+            ErrorCode::ChannelSplitFailed => "E206",
+            ErrorCode::AudioClipped => "E207",
+            ErrorCode::AudioTooQuiet => "E208",
+            ErrorCode::RecordingSplitFailed => "E209",
+            ErrorCode::JobFailed => "E300",
+            ErrorCode::JobNotFound => "E301",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Client-side validation failures detected before anything is sent to the API.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    /// The client-side submission limit is reached.
+    SubmissionThrottled,
+    /// The recording is longer than the recommended maximum duration.
+    RecordingTooLong,
+    /// The file is larger than the maximum size advertised by the server, in bytes.
+    FileTooLarge { size: u64, limit: u64 },
+    /// The file is not of an accepted type, with its MIME type (or its name when
+    /// unknown) and the accepted types.
+    UnsupportedFileType {
+        file_type: String,
+        accepted: Vec<String>,
+    },
+    /// The channels of the recording could not be separated, with the reason.
+    ChannelSplit(String),
+    /// Too many samples of the recording are clipped, with their share.
+    AudioClipped(f64),
+    /// The recording is very quiet, with its RMS level in dBFS.
+    AudioTooQuiet(f64),
+    /// The recording could not be split into shorter parts, with the reason.
+    RecordingSplit(String),
+}
+
+/// Any error the application may present to the user.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppError {
+    /// A request to the API failed.
+    Api(ApiError),
+    /// The user input was rejected before submission.
+    Validation(ValidationError),
+    /// The microphone could not be recorded, with the browser's explanation.
+    Recording(String),
+    /// The server reported the job as failed, with optional details.
+    JobFailed(Option<String>),
+    /// The job is unknown to the server (expired or purged).
+    JobNotFound,
+}
+
+impl AppError {
+    /// Whether the user can simply try the same action again with the same input.
+    /// Unrecoverable errors require selecting a new file or starting over.
+    pub fn is_recoverable(&self) -> bool {
+        !matches!(
+            self,
+            AppError::Api(ApiError::FileNotAvailable)
+                | AppError::JobFailed(_)
+                | AppError::JobNotFound
+        )
+    }
+}
+
+impl From<ApiError> for AppError {
+    fn from(err: ApiError) -> Self {
+        AppError::Api(err)
+    }
+}
+
+impl From<ValidationError> for AppError {
+    fn from(err: ValidationError) -> Self {
+        AppError::Validation(err)
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let info = self.info();
+        write!(f, "[{}] {}", info.code, info.message)
+    }
+}
+
+/// The catalog entry describing an error, ready to be rendered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorInfo {
+    pub code: ErrorCode,
+    /// Translation key of the user-facing message.
+    pub message_key: &'static str,
+    /// Default (English) user-facing message.
+    pub message: &'static str,
+    pub severity: Severity,
+    /// What the user can do about it.
+    pub remediation: &'static str,
+    /// Technical details, useful for diagnostics.
+    pub detail: Option<String>,
+    /// The offending part of a server response, if any.
+    pub excerpt: Option<String>,
+}
+
+impl AppError {
+    /// Looks the error up in the catalog.
+    pub fn info(&self) -> ErrorInfo {
+        match self {
+            AppError::Api(err) => api_error_info(err),
+            AppError::Validation(err) => validation_error_info(err),
+            AppError::Recording(detail) => entry(
+                ErrorCode::MicrophoneUnavailable,
+                "error.microphone_unavailable",
+                "The microphone could not be recorded.",
+                Severity::Error,
+                "Allow this site to use the microphone in the browser settings, then try again.",
+                Some(detail.clone()),
+            ),
+            AppError::JobFailed(detail) => entry(
+                ErrorCode::JobFailed,
+                "error.job_failed",
+                "The server could not transcribe this file.",
+                Severity::Error,
+                "Check that the file is a valid audio recording, then submit it again.",
+                detail.clone(),
+            ),
+            AppError::JobNotFound => entry(
+                ErrorCode::JobNotFound,
+                "error.job_not_found",
+                "The job was not found on the server. It may have expired.",
+                Severity::Warning,
+                "Submit the file again to start a new transcription.",
+                None,
+            ),
+        }
+    }
+}
+
+fn entry(
+    code: ErrorCode,
+    message_key: &'static str,
+    message: &'static str,
+    severity: Severity,
+    remediation: &'static str,
+    detail: Option<String>,
+) -> ErrorInfo {
+    ErrorInfo {
+        code,
+        message_key,
+        message,
+        severity,
+        remediation,
+        detail,
+        excerpt: None,
+    }
+}
+
+fn api_error_info(err: &ApiError) -> ErrorInfo {
+    let detail = Some(err.to_string());
+    match err {
+        ApiError::NotConfigured => entry(
+            ErrorCode::ApiNotConfigured,
+            "error.api_not_configured",
+            "The API URL is not configured.",
+            Severity::Warning,
+            "Enter the WhisperX API URL in the settings and save it.",
+            None,
+        ),
+        ApiError::RequestFailed(_) => entry(
+            ErrorCode::NetworkFailure,
+            "error.network_failure",
+            "The API could not be reached.",
+            Severity::Error,
+            "Check your network connection and the API URL, then try again.",
+            detail,
+        ),
+        ApiError::HttpError(404, _) => entry(
+            ErrorCode::EndpointNotFound,
+            "error.endpoint_not_found",
+            "The API endpoint was not found.",
+            Severity::Error,
+            "Check that the API URL points to the WhisperX API base path.",
+            detail,
+        ),
+        ApiError::HttpError(401 | 403, _) => entry(
+            ErrorCode::AccessDenied,
+            "error.access_denied",
+            "The server refused access to the API: the API token is missing or was rejected.",
+            Severity::Error,
+            "Set the API token in the endpoint options, or ask the server administrator for one.",
+            detail,
+        ),
+        ApiError::HttpError(413, _) => entry(
+            ErrorCode::FileTooLarge,
+            "error.file_too_large",
+            "The file is too large for the server.",
+            Severity::Error,
+            "Compress the recording or split it into shorter parts.",
+            detail,
+        ),
+        ApiError::HttpError(429, _) => entry(
+            ErrorCode::ServerRateLimited,
+            "error.server_rate_limited",
+            "The server received too many requests.",
+            Severity::Warning,
+            "Wait a few minutes before trying again.",
+            detail,
+        ),
+        ApiError::HttpError(status, _) if *status >= 500 => entry(
+            ErrorCode::ServerFailure,
+            "error.server_failure",
+            "The server encountered an internal error.",
+            Severity::Error,
+            "Try again later. If the problem persists, contact the server administrator.",
+            detail,
+        ),
+        ApiError::HttpError(_, _) => entry(
+            ErrorCode::RequestRejected,
+            "error.request_rejected",
+            "The server rejected the request.",
+            Severity::Error,
+            "Check the selected file and settings, then try again.",
+            detail,
+        ),
+        ApiError::UnexpectedResponse(mismatch) => ErrorInfo {
+            excerpt: Some(mismatch.excerpt.clone()),
+            ..entry(
+                ErrorCode::UnexpectedResponse,
+                "error.unexpected_response",
+                "The server returned an unexpected response — are you pointing at the right URL?",
+                Severity::Error,
+                "Check that the API URL points to the WhisperX API base path, not to a web page.",
+                Some(format!("Field `{}`: {}", mismatch.path, mismatch.reason)),
+            )
+        },
+        ApiError::NotAnApi(_) => entry(
+            ErrorCode::NotAnApi,
+            "error.not_an_api",
+            "This URL serves a web page, not the WhisperX API.",
+            Severity::Error,
+            "Use the API base URL (it often ends with /api). The settings panel suggests corrections.",
+            detail,
+        ),
+        ApiError::ParseError(_) => entry(
+            ErrorCode::UnexpectedResponse,
+            "error.unexpected_response",
+            "The server returned an unexpected response.",
+            Severity::Error,
+            "Check that the API URL points to a WhisperX API server.",
+            detail,
+        ),
+        ApiError::Maintenance(_) => entry(
+            ErrorCode::ServerMaintenance,
+            "error.server_maintenance",
+            "The server is under maintenance.",
+            Severity::Warning,
+            "Submissions are paused until the maintenance ends. Jobs already submitted are still monitored.",
+            None,
+        ),
+        ApiError::FileNotAvailable => entry(
+            ErrorCode::FileUnavailable,
+            "error.file_unavailable",
+            "The selected file could not be read.",
+            Severity::Error,
+            "Select the file again. It may have been moved or deleted.",
+            None,
+        ),
+    }
+}
+
+fn validation_error_info(err: &ValidationError) -> ErrorInfo {
+    match err {
+        ValidationError::SubmissionThrottled => entry(
+            ErrorCode::SubmissionThrottled,
+            "error.submission_throttled",
+            "You have reached the submission limit.",
+            Severity::Warning,
+            "Wait until the countdown ends before submitting again.",
+            None,
+        ),
+        ValidationError::RecordingTooLong => entry(
+            ErrorCode::RecordingTooLong,
+            "error.recording_too_long",
+            "This recording is longer than the recommended maximum.",
+            Severity::Warning,
+            "Split the recording into shorter parts before submitting.",
+            None,
+        ),
+        ValidationError::FileTooLarge { size, limit } => entry(
+            ErrorCode::FileExceedsLimit,
+            "error.file_exceeds_limit",
+            "This file is larger than the server accepts.",
+            Severity::Error,
+            "Compress the recording or split it into shorter parts.",
+            Some(format!(
+                "The file is {}, the server accepts up to {}.",
+                format_size(*size),
+                format_size(*limit)
+            )),
+        ),
+        ValidationError::UnsupportedFileType {
+            file_type,
+            accepted,
+        } => entry(
+            ErrorCode::UnsupportedFileType,
+            "error.unsupported_file_type",
+            "This type of file is not accepted.",
+            Severity::Error,
+            "Select an audio file, such as MP3, WAV, M4A, OGG or FLAC, or a video if accepted.",
+            Some(format!(
+                "File type: {} (accepted: {})",
+                file_type,
+                accepted.join(", ")
+            )),
+        ),
+        ValidationError::ChannelSplit(reason) => entry(
+            ErrorCode::ChannelSplitFailed,
+            "error.channel_split_failed",
+            "The channels of this recording could not be separated.",
+            Severity::Warning,
+            "Submit both channels mixed, or convert the recording to WAV first.",
+            Some(reason.clone()),
+        ),
+        ValidationError::AudioClipped(ratio) => entry(
+            ErrorCode::AudioClipped,
+            "error.audio_clipped",
+            "This recording is heavily clipped.",
+            Severity::Warning,
+            "Distorted speech is transcribed less accurately. Record again with a lower input gain if possible.",
+            Some(format!("{:.2}% of the samples are clipped.", ratio * 100.0)),
+        ),
+        ValidationError::AudioTooQuiet(level) => entry(
+            ErrorCode::AudioTooQuiet,
+            "error.audio_too_quiet",
+            "This recording is very quiet.",
+            Severity::Warning,
+            "Quiet speech is transcribed less accurately. Amplify or normalize the recording with an audio editor before submitting it.",
+            Some(format!("Average level: {:.0} dBFS.", level)),
+        ),
+        ValidationError::RecordingSplit(reason) => entry(
+            ErrorCode::RecordingSplitFailed,
+            "error.recording_split_failed",
+            "This recording could not be split into parts.",
+            Severity::Error,
+            "Split the recording with an audio editor, or submit it as is.",
+            Some(reason.clone()),
+        ),
+    }
+}
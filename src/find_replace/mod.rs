@@ -0,0 +1,186 @@
+//! Find and replace over transcripts
+//! Matches a plain or regular expression query and previews or applies its replacements,
+//! for systematic corrections such as misheard names.
+
+use regex::{NoExpand, Regex, RegexBuilder};
+
+/// Number of characters of context shown around each match in previews.
+const CONTEXT_CHARS: usize = 30;
+
+/// How the search text is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Mode {
+    /// The text is searched literally.
+    #[default]
+    Plain,
+    /// The text is a regular expression; the replacement may refer to groups (`$1`).
+    Regex,
+}
+
+/// A find and replace request.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Query {
+    pub find: String,
+    pub replace: String,
+    pub mode: Mode,
+    pub case_sensitive: bool,
+}
+
+/// A match, with its surroundings, before and after replacement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Preview {
+    pub before: String,
+    pub matched: String,
+    pub replacement: String,
+    pub after: String,
+}
+
+impl Query {
+    /// Compiles the query. An empty search matches nothing.
+    fn compile(&self) -> Result<Option<Regex>, regex::Error> {
+        if self.find.is_empty() {
+            return Ok(None);
+        }
+        let pattern = match self.mode {
+            Mode::Plain => regex::escape(&self.find),
+            Mode::Regex => self.find.clone(),
+        };
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+            .map(Some)
+    }
+
+    /// Replacement of one match, expanding groups in regex mode.
+    fn replacement_for(&self, captures: &regex::Captures) -> String {
+        match self.mode {
+            Mode::Plain => self.replace.clone(),
+            Mode::Regex => {
+                let mut expanded = String::new();
+                captures.expand(&self.replace, &mut expanded);
+                expanded
+            }
+        }
+    }
+
+    /// Lists the matches of the query in `text`, with their replacement.
+    pub fn preview(&self, text: &str) -> Result<Vec<Preview>, regex::Error> {
+        let Some(pattern) = self.compile()? else {
+            return Ok(Vec::new());
+        };
+        Ok(pattern
+            .captures_iter(text)
+            .filter_map(|captures| {
+                let found = captures.get(0)?;
+                let before = &text[..found.start()];
+                let after = &text[found.end()..];
+                // Skip empty matches, which a regex may produce between characters.
+                (!found.is_empty()).then(|| Preview {
+                    before: tail(before, CONTEXT_CHARS),
+                    matched: found.as_str().to_string(),
+                    replacement: self.replacement_for(&captures),
+                    after: after.chars().take(CONTEXT_CHARS).collect(),
+                })
+            })
+            .collect())
+    }
+
+    /// Replaces every match of the query in `text`.
+    pub fn replace_all(&self, text: &str) -> Result<String, regex::Error> {
+        let Some(pattern) = self.compile()? else {
+            return Ok(text.to_string());
+        };
+        Ok(match self.mode {
+            Mode::Plain => pattern.replace_all(text, NoExpand(&self.replace)),
+            Mode::Regex => pattern.replace_all(text, self.replace.as_str()),
+        }
+        .into_owned())
+    }
+}
+
+/// The last `count` characters of `text`.
+fn tail(text: &str, count: usize) -> String {
+    let skip = text.chars().count().saturating_sub(count);
+    text.chars().skip(skip).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(find: &str, replace: &str, mode: Mode, case_sensitive: bool) -> Query {
+        Query {
+            find: find.to_string(),
+            replace: replace.to_string(),
+            mode,
+            case_sensitive,
+        }
+    }
+
+    #[test]
+    fn plain_queries_are_literal_and_case_insensitive_by_default() {
+        let query = query("Dr. Smith", "Dr Smyth", Mode::Plain, false);
+        assert_eq!(
+            query.replace_all("dr. smith met DrX Smith").unwrap(),
+            "Dr Smyth met DrX Smith"
+        );
+    }
+
+    #[test]
+    fn plain_replacements_do_not_expand_groups() {
+        let query = query("price", "$1", Mode::Plain, true);
+        assert_eq!(query.replace_all("the price").unwrap(), "the $1");
+    }
+
+    #[test]
+    fn case_sensitive_queries() {
+        let query = query("Paris", "Lyon", Mode::Plain, true);
+        assert_eq!(query.replace_all("paris, Paris").unwrap(), "paris, Lyon");
+    }
+
+    #[test]
+    fn regex_replacements_expand_groups() {
+        let query = query(r"(\d+) h (\d+)", "$1:$2", Mode::Regex, false);
+        assert_eq!(query.replace_all("at 10 h 30").unwrap(), "at 10:30");
+        let preview = query.preview("at 10 h 30").unwrap();
+        assert_eq!(preview.len(), 1);
+        assert_eq!(preview[0].replacement, "10:30");
+    }
+
+    #[test]
+    fn invalid_regex_is_an_error() {
+        let query = query("(unclosed", "", Mode::Regex, false);
+        assert!(query.preview("text").is_err());
+        assert!(query.replace_all("text").is_err());
+    }
+
+    #[test]
+    fn empty_search_matches_nothing() {
+        let query = query("", "x", Mode::Plain, false);
+        assert!(query.preview("text").unwrap().is_empty());
+        assert_eq!(query.replace_all("text").unwrap(), "text");
+    }
+
+    #[test]
+    fn previews_show_the_context_of_each_match() {
+        let text = format!("{}cat{}", "a".repeat(40), "b".repeat(40));
+        let preview = query("cat", "dog", Mode::Plain, false)
+            .preview(&text)
+            .unwrap();
+        assert_eq!(
+            preview,
+            vec![Preview {
+                before: "a".repeat(CONTEXT_CHARS),
+                matched: "cat".to_string(),
+                replacement: "dog".to_string(),
+                after: "b".repeat(CONTEXT_CHARS),
+            }]
+        );
+    }
+
+    #[test]
+    fn empty_regex_matches_are_skipped() {
+        let query = query("x*", "y", Mode::Regex, false);
+        assert_eq!(query.preview("axb").unwrap().len(), 1);
+    }
+}
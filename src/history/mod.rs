@@ -0,0 +1,402 @@
+//! Submission history
+//! Keeps the receipts of past submissions, newest first, so they can be looked up
+//! after the active job has been cleared.
+//! Deleted entries go to a trash first, from which they can be restored until
+//! the cleanup service deletes them permanently.
+
+use crate::api::TranscriptionJob;
+use crate::config::{
+    DEFAULT_HISTORY_MAX_AGE_DAYS, DEFAULT_HISTORY_MAX_ENTRIES, DUPLICATE_GUARD_WINDOW_HOURS,
+    TRASH_RETENTION_DAYS,
+};
+use crate::transcript::chapters::Chapter;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A past submission.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// The API URL the job was submitted to.
+    pub api_url: String,
+    /// The submission receipt.
+    pub job: TranscriptionJob,
+    /// When the job finished, successfully or not, as seen by the client.
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// The transcription result, once completed.
+    #[serde(default)]
+    pub result: Option<String>,
+    /// The result after post-processing, when the pipeline had enabled steps.
+    #[serde(default)]
+    pub processed_result: Option<String>,
+    /// Name given by the user, shown instead of the file name.
+    #[serde(default)]
+    pub label: Option<String>,
+    /// The chapters of the transcript, once their titles have been edited.
+    #[serde(default)]
+    pub chapters: Option<Vec<Chapter>>,
+    /// The user's feedback on the transcription quality.
+    #[serde(default)]
+    pub feedback: Option<Feedback>,
+    /// Starred entries are never removed by cleanups.
+    #[serde(default)]
+    pub starred: bool,
+    /// Whether the transcript was imported from a file rather than transcribed by the API.
+    #[serde(default)]
+    pub imported: bool,
+    /// The recording the transcript was made from, to find it again for proofreading.
+    #[serde(default)]
+    pub audio: Option<AudioReference>,
+    /// Whether the result was never opened since the job completed.
+    #[serde(default)]
+    pub unviewed: bool,
+}
+
+impl HistoryEntry {
+    /// Creates the entry of a new submission.
+    pub fn new(api_url: String, job: TranscriptionJob) -> Self {
+        HistoryEntry {
+            api_url,
+            job,
+            completed_at: None,
+            result: None,
+            processed_result: None,
+            label: None,
+            chapters: None,
+            feedback: None,
+            starred: false,
+            imported: false,
+            audio: None,
+            unviewed: false,
+        }
+    }
+
+    /// Title of the entry: its name, the file name, or the job id for older entries.
+    pub fn title(&self) -> String {
+        self.label
+            .clone()
+            .or_else(|| self.job.file_name.clone())
+            .unwrap_or_else(|| self.job.job_id.clone())
+    }
+}
+
+/// What is known of the recording of an entry, to re-open it or check a file selected again.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioReference {
+    pub file_name: String,
+    /// Size of the file, in bytes.
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// Whether a handle of the file is kept in the browser, to re-open it without selecting it.
+    #[serde(default)]
+    pub handle_stored: bool,
+}
+
+/// A deleted history entry, kept in the trash until it is deleted permanently.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrashedEntry {
+    pub entry: HistoryEntry,
+    pub deleted_at: DateTime<Utc>,
+}
+
+impl TrashedEntry {
+    /// When the entry is deleted permanently by the cleanup service.
+    pub fn purge_at(&self) -> DateTime<Utc> {
+        self.deleted_at + Duration::days(TRASH_RETENTION_DAYS)
+    }
+}
+
+/// Feedback on the quality of a transcription.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Feedback {
+    /// Rating, from 1 to 5.
+    pub rating: u8,
+    /// Free-text description of the issues noticed.
+    #[serde(default)]
+    pub comment: String,
+    pub given_at: DateTime<Utc>,
+    /// Whether the feedback was accepted by the feedback endpoint.
+    #[serde(default)]
+    pub sent: bool,
+}
+
+/// How long history entries are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    /// Maximum number of entries; the oldest ones are dropped beyond it.
+    pub max_entries: usize,
+    /// Age after which entries expire, `None` to keep them regardless of age.
+    pub max_age_days: Option<u32>,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        RetentionPolicy {
+            max_entries: DEFAULT_HISTORY_MAX_ENTRIES,
+            max_age_days: Some(DEFAULT_HISTORY_MAX_AGE_DAYS),
+        }
+    }
+}
+
+impl RetentionPolicy {
+    /// Whether an entry has expired at `now`.
+    /// Entries without a submission date never expire.
+    fn is_expired(&self, entry: &HistoryEntry, now: DateTime<Utc>) -> bool {
+        match (self.max_age_days, entry.job.submitted_at) {
+            (Some(days), Some(submitted_at)) => now - submitted_at > Duration::days(days.into()),
+            _ => false,
+        }
+    }
+}
+
+/// Returns the history without the entries the policy no longer keeps.
+/// Starred entries are always kept, and do not count towards the maximum.
+pub fn apply_retention(
+    history: &[HistoryEntry],
+    policy: &RetentionPolicy,
+    now: DateTime<Utc>,
+) -> Vec<HistoryEntry> {
+    let mut unstarred_kept = 0;
+    history
+        .iter()
+        .filter(|entry| {
+            if entry.starred {
+                return true;
+            }
+            let keep = !policy.is_expired(entry, now) && unstarred_kept < policy.max_entries;
+            if keep {
+                unstarred_kept += 1;
+            }
+            keep
+        })
+        .cloned()
+        .collect()
+}
+
+/// Returns the history without the oldest half of its unstarred entries, to free storage space.
+pub fn trim_oldest(history: &[HistoryEntry]) -> Vec<HistoryEntry> {
+    let unstarred = history.iter().filter(|entry| !entry.starred).count();
+    let mut unstarred_kept = 0;
+    history
+        .iter()
+        .filter(|entry| {
+            if entry.starred {
+                return true;
+            }
+            unstarred_kept += 1;
+            unstarred_kept <= unstarred / 2
+        })
+        .cloned()
+        .collect()
+}
+
+/// Returns the starred entries only, the others being deleted.
+pub fn clear_unstarred(history: &[HistoryEntry]) -> Vec<HistoryEntry> {
+    history
+        .iter()
+        .filter(|entry| entry.starred)
+        .cloned()
+        .collect()
+}
+
+/// Returns the history with `entry` added on top.
+/// An entry for the same job replaces the previous one, and the retention policy
+/// is applied to the result.
+pub fn record(
+    history: &[HistoryEntry],
+    entry: HistoryEntry,
+    policy: &RetentionPolicy,
+) -> Vec<HistoryEntry> {
+    let mut updated = Vec::with_capacity(history.len() + 1);
+    updated.extend(
+        history
+            .iter()
+            .filter(|past| past.job.job_id != entry.job.job_id)
+            .cloned(),
+    );
+    updated.insert(0, entry);
+    apply_retention(&updated, policy, Utc::now())
+}
+
+/// Returns the history with the result of a completed job recorded,
+/// raw and post-processed.
+pub fn complete(
+    history: &[HistoryEntry],
+    job_id: &str,
+    result: &str,
+    processed: Option<&str>,
+    completed_at: DateTime<Utc>,
+) -> Vec<HistoryEntry> {
+    update(history, job_id, |entry| {
+        entry.completed_at = Some(completed_at);
+        entry.result = Some(result.to_string());
+        entry.processed_result = processed.map(str::to_string);
+        entry.unviewed = true;
+    })
+}
+
+/// Returns the history with the result of a job marked as viewed.
+pub fn mark_viewed(history: &[HistoryEntry], job_id: &str) -> Vec<HistoryEntry> {
+    update(history, job_id, |entry| entry.unviewed = false)
+}
+
+/// Returns the history with a job marked as finished without result (failed or lost).
+pub fn finish(
+    history: &[HistoryEntry],
+    job_id: &str,
+    finished_at: DateTime<Utc>,
+) -> Vec<HistoryEntry> {
+    update(history, job_id, |entry| {
+        entry.completed_at = Some(finished_at)
+    })
+}
+
+/// Returns the history with a change applied to the entry of a job, if present.
+pub fn update(
+    history: &[HistoryEntry],
+    job_id: &str,
+    change: impl FnOnce(&mut HistoryEntry),
+) -> Vec<HistoryEntry> {
+    let mut updated = history.to_vec();
+    if let Some(entry) = updated.iter_mut().find(|entry| entry.job.job_id == job_id) {
+        change(entry);
+    }
+    updated
+}
+
+/// Moves the entries matching `is_deleted` from the history to the top of the trash.
+/// Returns the updated history and trash.
+pub fn move_to_trash(
+    history: &[HistoryEntry],
+    trash: &[TrashedEntry],
+    is_deleted: impl Fn(&HistoryEntry) -> bool,
+    now: DateTime<Utc>,
+) -> (Vec<HistoryEntry>, Vec<TrashedEntry>) {
+    let (deleted, kept): (Vec<HistoryEntry>, Vec<HistoryEntry>) =
+        history.iter().cloned().partition(|entry| is_deleted(entry));
+    let mut updated_trash: Vec<TrashedEntry> = deleted
+        .into_iter()
+        .map(|entry| TrashedEntry {
+            entry,
+            deleted_at: now,
+        })
+        .collect();
+    updated_trash.extend(trash.iter().cloned());
+    (kept, updated_trash)
+}
+
+/// Moves the entry of a job back from the trash to the history, at the place of its
+/// submission date. Returns the updated history and trash.
+pub fn restore(
+    history: &[HistoryEntry],
+    trash: &[TrashedEntry],
+    job_id: &str,
+) -> (Vec<HistoryEntry>, Vec<TrashedEntry>) {
+    let mut updated_history = history.to_vec();
+    let mut updated_trash = trash.to_vec();
+    if let Some(index) = updated_trash
+        .iter()
+        .position(|trashed| trashed.entry.job.job_id == job_id)
+    {
+        let entry = updated_trash.remove(index).entry;
+        insert_by_date(&mut updated_history, entry);
+    }
+    (updated_history, updated_trash)
+}
+
+/// Returns the history with the entries of another history, such as an export, added
+/// at the place of their submission dates. Entries of jobs already present are skipped.
+pub fn merge(history: &[HistoryEntry], entries: Vec<HistoryEntry>) -> Vec<HistoryEntry> {
+    let mut merged = history.to_vec();
+    for entry in entries {
+        if find(&merged, &entry.job.job_id).is_none() {
+            insert_by_date(&mut merged, entry);
+        }
+    }
+    merged
+}
+
+/// Inserts an entry before the first one submitted earlier, keeping the history newest first.
+fn insert_by_date(history: &mut Vec<HistoryEntry>, entry: HistoryEntry) {
+    let position = history
+        .iter()
+        .position(|other| other.job.submitted_at < entry.job.submitted_at)
+        .unwrap_or(history.len());
+    history.insert(position, entry);
+}
+
+/// Returns the trash without the entries due for permanent deletion at `now`.
+pub fn purge_trash(trash: &[TrashedEntry], now: DateTime<Utc>) -> Vec<TrashedEntry> {
+    trash
+        .iter()
+        .filter(|trashed| trashed.purge_at() > now)
+        .cloned()
+        .collect()
+}
+
+/// Finds the entry of a job.
+pub fn find<'a>(history: &'a [HistoryEntry], job_id: &str) -> Option<&'a HistoryEntry> {
+    history.iter().find(|entry| entry.job.job_id == job_id)
+}
+
+/// Finds the other completed jobs of the same file as a job, such as its translation.
+pub fn find_same_file<'a>(history: &'a [HistoryEntry], job_id: &str) -> Vec<&'a HistoryEntry> {
+    let Some(sha256) = find(history, job_id).and_then(|entry| entry.job.sha256.as_deref()) else {
+        return Vec::new();
+    };
+    history
+        .iter()
+        .filter(|entry| {
+            entry.job.job_id != job_id
+                && entry.result.is_some()
+                && entry.job.sha256.as_deref() == Some(sha256)
+        })
+        .collect()
+}
+
+/// Finds the completed transcription of the other channel of a stereo recording
+/// whose channel was transcribed by `job_id`: the one submitted closest to it, as
+/// the recording may have been split more than once.
+pub fn find_other_channel<'a>(
+    history: &'a [HistoryEntry],
+    job_id: &str,
+) -> Option<&'a HistoryEntry> {
+    let job = &find(history, job_id)?.job;
+    let (channel, source) = (job.channel?, job.source_sha256.as_deref()?);
+    let submitted_at = job.submitted_at.unwrap_or_default();
+    history
+        .iter()
+        .filter(|entry| {
+            entry.result.is_some()
+                && entry.job.channel == Some(channel.other())
+                && entry.job.source_sha256.as_deref() == Some(source)
+        })
+        .min_by_key(|entry| {
+            (entry.job.submitted_at.unwrap_or_default() - submitted_at)
+                .num_seconds()
+                .abs()
+        })
+}
+
+/// Finds the latest completed transcription of a file, by its checksum.
+pub fn find_transcribed<'a>(history: &'a [HistoryEntry], sha256: &str) -> Option<&'a HistoryEntry> {
+    history
+        .iter()
+        .find(|entry| entry.result.is_some() && entry.job.sha256.as_deref() == Some(sha256))
+}
+
+/// Finds a recent job of the same file which has not completed yet, and may still
+/// be queued or processing on the server.
+pub fn find_unfinished_duplicate<'a>(
+    history: &'a [HistoryEntry],
+    sha256: &str,
+    now: DateTime<Utc>,
+) -> Option<&'a HistoryEntry> {
+    history.iter().find(|entry| {
+        entry.completed_at.is_none()
+            && entry.job.sha256.as_deref() == Some(sha256)
+            && entry.job.submitted_at.is_some_and(|submitted_at| {
+                now - submitted_at < Duration::hours(DUPLICATE_GUARD_WINDOW_HOURS)
+            })
+    })
+}
@@ -0,0 +1,274 @@
+//! IndexedDB storage hook for Leontine
+//! Stores the large values (the history with its results, the trash) in IndexedDB,
+//! whose quota is far larger than the few megabytes of localStorage.
+//! IndexedDB is asynchronous: the values are loaded once before the application
+//! starts (see [`preload`]), so that the hook reads them synchronously, with the same
+//! ergonomics as `UsePersistent`. Writes complete in the background.
+//! Values kept in localStorage by previous versions are moved over on load. When
+//! IndexedDB is unavailable, values stay in localStorage.
+//! Other tabs are told of every write, and load the value again.
+
+use crate::document::eval;
+use crate::storage::{self, StorageError, StorageKey};
+use dioxus::prelude::*;
+use gloo_storage::{LocalStorage, Storage};
+use log::warn;
+use serde::{de::DeserializeOwned, Serialize};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+/// Opens the database once per page, and runs a request in a transaction, resolving
+/// to its result. Transactions run in the order they are requested.
+const STORE_HELPERS: &str = r#"
+    window.leontineDataStore ??= new Promise((resolve, reject) => {
+        const request = indexedDB.open('leontine-data', 1);
+        request.onupgradeneeded = () => request.result.createObjectStore('entries');
+        request.onsuccess = () => resolve(request.result);
+        request.onerror = () => reject(request.error);
+    });
+    const run = (mode, action) => window.leontineDataStore.then((db) => new Promise((resolve, reject) => {
+        const transaction = db.transaction('entries', mode);
+        const request = action(transaction.objectStore('entries'));
+        transaction.oncomplete = () => resolve(request.result);
+        transaction.onerror = () => reject(transaction.error);
+    }));
+    window.leontineDataChannel ??= 'BroadcastChannel' in window ? new BroadcastChannel('leontine-data') : null;
+"#;
+
+/// Loads the values of the keys, moving over those found in localStorage.
+/// Answers `null` when IndexedDB cannot be used.
+const PRELOAD_SCRIPT: &str = r#"
+    const keys = await dioxus.recv();
+    try {
+        const values = {};
+        for (const key of keys) {
+            let value = await run('readonly', (store) => store.get(key));
+            const legacy = localStorage.getItem(key);
+            if (value === undefined && legacy !== null) {
+                await run('readwrite', (store) => store.put(legacy, key));
+                value = legacy;
+            }
+            localStorage.removeItem(key);
+            if (value !== undefined) values[key] = value;
+        }
+        dioxus.send(values);
+    } catch (error) {
+        dioxus.send(null);
+    }
+"#;
+
+/// Writes a value, or deletes it when `null`, then tells the other tabs.
+/// Answers the name of the error when the write fails.
+const WRITE_SCRIPT: &str = r#"
+    const [key, value] = await dioxus.recv();
+    try {
+        await run('readwrite', (store) => value === null ? store.delete(key) : store.put(value, key));
+        window.leontineDataChannel?.postMessage(key);
+        dioxus.send(null);
+    } catch (error) {
+        dioxus.send(error?.name ?? String(error));
+    }
+"#;
+
+/// Sends the value of the key again whenever another tab writes it.
+const FOLLOW_SCRIPT: &str = r#"
+    const key = await dioxus.recv();
+    if (!('BroadcastChannel' in window)) return;
+    const channel = new BroadcastChannel('leontine-data');
+    channel.onmessage = async (event) => {
+        if (event.data === key) dioxus.send((await run('readonly', (store) => store.get(key))) ?? null);
+    };
+    await new Promise(() => {});
+"#;
+
+thread_local! {
+    /// Whether IndexedDB could be opened by [`preload`].
+    static AVAILABLE: Cell<bool> = const { Cell::new(false) };
+    /// The serialized values stored in IndexedDB, by storage key.
+    static CACHE: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+}
+
+/// Loads the values kept in IndexedDB, moving over those previous versions kept in
+/// localStorage. Must complete before any hook reads them.
+pub async fn preload() {
+    let keys: Vec<String> = StorageKey::ALL
+        .into_iter()
+        .filter(StorageKey::is_large)
+        .map(|key| key.to_string())
+        .collect();
+    let mut script = eval(&format!("{}{}", STORE_HELPERS, PRELOAD_SCRIPT));
+    if let Err(e) = script.send(keys) {
+        warn!("Failed to load the values stored in IndexedDB: {:?}", e);
+        return;
+    }
+    match script.recv::<Option<HashMap<String, String>>>().await {
+        Ok(Some(values)) => {
+            AVAILABLE.with(|available| available.set(true));
+            CACHE.with(|cache| *cache.borrow_mut() = values);
+        }
+        _ => warn!("IndexedDB is unavailable, large values are kept in localStorage"),
+    }
+}
+
+/// Whether values are stored in IndexedDB rather than localStorage.
+pub fn is_available() -> bool {
+    AVAILABLE.with(Cell::get)
+}
+
+/// The serialized value stored in IndexedDB under a storage key, if any.
+pub fn stored_value(key: &str) -> Option<String> {
+    CACHE.with(|cache| cache.borrow().get(key).cloned())
+}
+
+/// The storage keys of the values stored in IndexedDB.
+pub fn stored_keys() -> Vec<String> {
+    CACHE.with(|cache| cache.borrow().keys().cloned().collect())
+}
+
+/// Deletes the database, with every value stored in it.
+pub fn delete_all() {
+    CACHE.with(|cache| cache.borrow_mut().clear());
+    let _ = eval("indexedDB.deleteDatabase('leontine-data');");
+}
+
+/// An IndexedDB storage hook, for values too large for localStorage.
+/// The values must have been loaded by [`preload`] beforehand.
+pub fn use_indexed_db<T: Serialize + DeserializeOwned + Default + Clone + 'static>(
+    // The storage key of the value
+    key: StorageKey,
+    // A function that returns the initial value if nothing is stored
+    init: impl FnOnce() -> T,
+) -> UseIndexedDb<T> {
+    let state = use_signal(move || {
+        let key = key.to_string();
+        let value = if is_available() {
+            stored_value(&key)
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_else(init)
+        } else if storage::is_available() {
+            LocalStorage::get(key.as_str()).unwrap_or_else(|_| init())
+        } else {
+            init()
+        };
+        Entry { key, value }
+    });
+
+    // Follow the writes of the other tabs of the application
+    use_hook(move || {
+        if is_available() {
+            spawn(follow_other_tabs(state));
+        }
+    });
+
+    UseIndexedDb { inner: state }
+}
+
+/// Updates the value whenever another tab writes it.
+async fn follow_other_tabs<T: DeserializeOwned + Default + 'static>(mut state: Signal<Entry<T>>) {
+    let key = state.peek().key.clone();
+    let mut writes = eval(&format!("{}{}", STORE_HELPERS, FOLLOW_SCRIPT));
+    if writes.send(&key).is_err() {
+        return;
+    }
+    while let Ok(json) = writes.recv::<Option<String>>().await {
+        let value = match json.as_deref().map(serde_json::from_str) {
+            Some(Ok(value)) => value,
+            Some(Err(e)) => {
+                warn!(
+                    "Ignoring the value of {} written by another tab: {}",
+                    key, e
+                );
+                continue;
+            }
+            None => T::default(),
+        };
+        CACHE.with(|cache| match json {
+            Some(json) => cache.borrow_mut().insert(key.clone(), json),
+            None => cache.borrow_mut().remove(&key),
+        });
+        state.write().value = value;
+    }
+}
+
+/// Writes a serialized value, or deletes it, in the background.
+/// Failures are reported to the global storage error handler.
+fn write(key: &str, json: Option<String>) {
+    CACHE.with(|cache| match &json {
+        Some(json) => cache.borrow_mut().insert(key.to_string(), json.clone()),
+        None => cache.borrow_mut().remove(key),
+    });
+    let mut script = eval(&format!("{}{}", STORE_HELPERS, WRITE_SCRIPT));
+    if let Err(e) = script.send((key, json)) {
+        storage::report_write_error(key, &StorageError::Unavailable(format!("{:?}", e)));
+        return;
+    }
+    let key = key.to_string();
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Ok(Some(error)) = script.recv::<Option<String>>().await {
+            let error = if error == "QuotaExceededError" {
+                StorageError::QuotaExceeded
+            } else {
+                StorageError::Unavailable(error)
+            };
+            storage::report_write_error(&key, &error);
+        }
+    });
+}
+
+/// Internal storage entry structure
+struct Entry<T> {
+    key: String,
+    value: T,
+}
+
+/// A value stored in IndexedDB
+#[derive(PartialEq)]
+pub struct UseIndexedDb<T: 'static> {
+    inner: Signal<Entry<T>>,
+}
+
+impl<T> Clone for UseIndexedDb<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseIndexedDb<T> {}
+
+impl<T: Serialize + DeserializeOwned + Default + Clone + 'static> UseIndexedDb<T> {
+    /// Gets the current value
+    pub fn get(&self) -> T {
+        self.inner.read().value.clone()
+    }
+
+    /// Sets the value and stores it. The value is kept in memory even when it cannot
+    /// be stored; the failure is reported to the global storage error handler.
+    pub fn set(&mut self, value: T) {
+        let mut state = self.inner.write();
+        if is_available() {
+            match serde_json::to_string(&value) {
+                Ok(json) => write(&state.key, Some(json)),
+                Err(e) => storage::report_write_error(
+                    &state.key,
+                    &StorageError::Serialization(e.to_string()),
+                ),
+            }
+        } else if storage::is_available() {
+            if let Err(e) = LocalStorage::set(state.key.as_str(), &value) {
+                storage::report_write_error(&state.key, &e.into());
+            }
+        }
+        state.value = value;
+    }
+
+    /// Removes the stored value and resets it to its default
+    pub fn remove(&mut self) {
+        let mut state = self.inner.write();
+        if is_available() {
+            write(&state.key, None);
+        } else if storage::is_available() {
+            LocalStorage::delete(state.key.as_str());
+        }
+        state.value = T::default();
+    }
+}
@@ -1,4 +1,5 @@
 //! Custom hooks for the Leontine application
 //! This module contains reusable hooks used across the application
 
+pub mod indexed_db;
 pub mod persistent;
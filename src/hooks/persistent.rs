@@ -1,10 +1,15 @@
 //! Persistent storage hook for Leontine
 //! Provides a way to store data in localStorage that persists across page reloads
+//! When localStorage is unavailable, values transparently live in memory only.
+//! Changes made by the other tabs of the application are followed, so they stay consistent.
 //!
 // ref : https://dioxuslabs.com/learn/0.6/cookbook/state/custom_hooks/#composing-hooks
 
+use crate::document::eval;
+use crate::storage::{self, StorageError};
 use dioxus::prelude::*;
 use gloo_storage::{LocalStorage, Storage};
+use log::warn;
 use serde::{de::DeserializeOwned, Serialize};
 
 /// A persistent storage hook that can be used to store data across application reloads.
@@ -19,18 +24,71 @@ pub fn use_persistent<T: Serialize + DeserializeOwned + Default + Clone + 'stati
     let state = use_signal(move || {
         // This closure will run when the hook is created
         let key = key.to_string();
-        let value = LocalStorage::get(key.as_str()).unwrap_or_else(|_| init());
-        StorageEntry { key, value }
+        let value = if storage::is_available() {
+            LocalStorage::get(key.as_str()).unwrap_or_else(|_| init())
+        } else {
+            init()
+        };
+        StorageEntry {
+            key,
+            value,
+            detached: false,
+        }
+    });
+
+    // Follow the changes made to the entry by the other tabs of the application
+    use_hook(move || {
+        if storage::is_available() {
+            spawn(follow_other_tabs(state));
+        }
     });
 
     // Wrap the state in a new struct with a custom API
     UsePersistent { inner: state }
 }
 
+/// Updates the entry whenever another tab changes it in localStorage.
+/// Storage events only reach the other tabs, so the entry is never written back here.
+async fn follow_other_tabs<T: DeserializeOwned + Default + 'static>(
+    mut state: Signal<StorageEntry<T>>,
+) {
+    let key = state.peek().key.clone();
+    let mut events = eval(&format!(
+        r#"
+        const key = {};
+        window.addEventListener('storage', (event) => {{
+            if (event.storageArea === localStorage && (event.key === key || event.key === null)) {{
+                dioxus.send(event.key === null ? null : event.newValue);
+            }}
+        }});
+        await new Promise(() => {{}});
+        "#,
+        serde_json::to_string(&key).unwrap_or_default()
+    ));
+    while let Ok(new_value) = events.recv::<Option<String>>().await {
+        if state.peek().detached {
+            continue;
+        }
+        let value = match new_value {
+            Some(json) => match serde_json::from_str(&json) {
+                Ok(value) => value,
+                Err(e) => {
+                    warn!("Ignoring the value of {} set by another tab: {}", key, e);
+                    continue;
+                }
+            },
+            None => T::default(),
+        };
+        state.write().value = value;
+    }
+}
+
 /// Internal storage entry structure
 pub(crate) struct StorageEntry<T> {
     key: String,
     value: T,
+    /// Whether the value is only kept for this page, see [`UsePersistent::detach`].
+    detached: bool,
 }
 
 /// Storage that persists across application reloads
@@ -41,9 +99,7 @@ pub struct UsePersistent<T: 'static> {
 
 impl<T> Clone for UsePersistent<T> {
     fn clone(&self) -> Self {
-        Self {
-            inner: self.inner.clone(),
-        }
+        *self
     }
 }
 
@@ -55,13 +111,46 @@ impl<T: Serialize + DeserializeOwned + Clone + 'static> UsePersistent<T> {
         self.inner.read().value.clone()
     }
 
-    /// Sets the value and persists it to localStorage
+    /// Sets the value and persists it to localStorage.
+    /// The value is kept in memory even when it cannot be persisted;
+    /// the failure is reported to the global storage error handler.
     pub fn set(&mut self, value: T) {
+        if let Err(err) = self.try_set(value.clone()) {
+            let mut state = self.inner.write();
+            storage::report_write_error(&state.key, &err);
+            state.value = value;
+        }
+    }
+
+    /// Sets the value and persists it to localStorage, leaving the current
+    /// value untouched when it cannot be persisted.
+    pub fn try_set(&mut self, value: T) -> Result<(), StorageError> {
+        let mut state = self.inner.write();
+        if storage::is_available() && !state.detached {
+            LocalStorage::set(state.key.as_str(), &value)?;
+        }
+        state.value = value;
+        Ok(())
+    }
+
+    /// Sets a value for this page only: from then on, the entry is neither written
+    /// to localStorage nor updated by the other tabs, whose value stays as it was.
+    pub fn detach(&mut self, value: T) {
         let mut state = self.inner.write();
-        // Write the new value to local storage
-        LocalStorage::set(state.key.as_str(), &value).expect("failed to write to localStorage");
+        state.detached = true;
         state.value = value;
     }
 }
 
+impl<T: Serialize + DeserializeOwned + Default + Clone + 'static> UsePersistent<T> {
+    /// Removes the entry from localStorage and resets the value to its default
+    pub fn remove(&mut self) {
+        let mut state = self.inner.write();
+        if storage::is_available() && !state.detached {
+            LocalStorage::delete(state.key.as_str());
+        }
+        state.value = T::default();
+    }
+}
+
 // No longer need Deref implementation since we're using explicit get/set methods
@@ -0,0 +1,857 @@
+//! French translations
+//! Messages are kept in the order of the interface, grouped by component.
+
+/// The French translation of an English message, if any.
+pub fn lookup(text: &str) -> Option<&'static str> {
+    Some(match text {
+        // Header
+        "Leontine - Audio Transcription" => "Léontine - Transcription audio",
+        "Theme: {}" => "Thème : {}",
+        "Light" => "Clair",
+        "Dark" => "Sombre",
+        "System" => "Système",
+        "Switch to the next theme" => "Passer au thème suivant",
+        "Endpoint profile" => "Profil de serveur",
+        "Install app" => "Installer l'application",
+        "Install Leontine to open it like an application, even offline" => {
+            "Installer Léontine pour l'ouvrir comme une application, même hors ligne"
+        }
+
+        // Settings
+        "Settings" => "Paramètres",
+        "API URL:" => "URL de l'API :",
+        "Save URL" => "Enregistrer l'URL",
+        "Saved" => "Enregistrée",
+        "Detect API" => "Détecter l'API",
+        "Detecting..." => "Détection...",
+        "Probe the usual API paths on this server" => {
+            "Tester les chemins habituels de l'API sur ce serveur"
+        }
+        "Checking API status..." => "Vérification de l'état de l'API...",
+        "API is online" => "L'API est en ligne",
+        "API is not responding" => "L'API ne répond pas",
+        "API is under maintenance" => "L'API est en maintenance",
+        "Access denied: check the API token" => "Accès refusé : vérifiez le jeton d'API",
+        "API is unreachable" => "L'API est injoignable",
+        "This server requires authorization: set the API token in the endpoint options." => {
+            "Ce serveur exige une autorisation : renseignez le jeton d'API dans les options du serveur."
+        }
+        "Language" => "Langue",
+        "Browser language" => "Langue du navigateur",
+        " Low-bandwidth mode" => " Mode faible débit",
+        " (checks the server less often, for slow or metered connections)" => {
+            " (interroge le serveur moins souvent, pour les connexions lentes ou limitées)"
+        }
+        " Desktop notifications" => " Notifications de bureau",
+        " (when a transcription completes or fails)" => {
+            " (quand une transcription se termine ou échoue)"
+        }
+        "Notifications are blocked: allow them in the browser's site settings." => {
+            "Les notifications sont bloquées : autorisez-les dans les paramètres du site du navigateur."
+        }
+        "Endpoint options" => "Options du serveur",
+        "Accepted file types" => "Types de fichiers acceptés",
+        "Extensions such as .m4a, or types such as audio/* or video/mp4, separated by commas. Files of other types are refused." => {
+            "Des extensions comme .m4a, ou des types comme audio/* ou video/mp4, séparés par des virgules. Les fichiers d'autres types sont refusés."
+        }
+        "Use the types of the deployment" => "Utiliser les types du déploiement",
+
+        // Profiles
+        "Profiles" => "Profils",
+        "Saved endpoints, to switch between a local server and a shared one." => {
+            "Serveurs enregistrés, pour passer d'un serveur local à un serveur partagé."
+        }
+        "No profile saved yet." => "Aucun profil enregistré.",
+        " In use" => " Utilisé",
+        "Use" => "Utiliser",
+        "Remove" => "Supprimer",
+        "Check all" => "Tout vérifier",
+        "Name" => "Nom",
+        "API URL" => "URL de l'API",
+        "API token (optional)" => "Jeton d'API (facultatif)",
+        "Save profile" => "Enregistrer le profil",
+        "Another profile already has this name." => "Un autre profil porte déjà ce nom.",
+
+        // Transcription panel
+        "Transcription" => "Transcription",
+        "Selected file: " => "Fichier sélectionné : ",
+        "Drag and drop audio files here, select them below, or record from the microphone." => {
+            "Déposez des fichiers audio ici, sélectionnez-les ci-dessous ou enregistrez depuis le microphone."
+        }
+        "Select Audio Files" => "Sélectionner des fichiers audio",
+        "Retry Submission" => "Réessayer l'envoi",
+        "Submit Anyway" => "Envoyer quand même",
+        "Transcribe Again" => "Transcrire à nouveau",
+        "Transcribe Audio" => "Transcrire l'audio",
+        "Job is queued at position {}." => "La tâche est en file d'attente, en position {}.",
+        "Job is being processed..." => "La tâche est en cours de traitement...",
+        "The server reports the job as \"{}\", still checking..." => {
+            "Le serveur indique la tâche comme « {} », vérification en cours..."
+        }
+        "Waiting for status update..." => "En attente de l'état de la tâche...",
+        "Error polling job status: {}. Retrying..." => {
+            "Erreur lors de la vérification de la tâche : {}. Nouvel essai..."
+        }
+        "Checking job status..." => "Vérification de l'état de la tâche...",
+        "Cancelling..." => "Annulation...",
+        "Cancel Job" => "Annuler la tâche",
+        "Clear Selection" => "Effacer la sélection",
+        "Submitting job... Please wait." => "Envoi de la tâche... Veuillez patienter.",
+        "This job keeps being monitored while you transcribe other files." => {
+            "Cette tâche reste suivie pendant que vous transcrivez d'autres fichiers."
+        }
+        "Start New Transcription" => "Nouvelle transcription",
+        "Transcribe Another File" => "Transcrire un autre fichier",
+        "Transcription successful!" => "Transcription réussie !",
+        "Resubmit File" => "Renvoyer le fichier",
+        "Resume Monitoring" => "Reprendre le suivi",
+        "Report Issue" => "Signaler un problème",
+        "Issue report copied to the clipboard." => "Rapport copié dans le presse-papiers.",
+        "The transcription job was cancelled." => "La tâche de transcription a été annulée.",
+        "Stereo channels " => "Canaux stéréo ",
+        "Both channels mixed" => "Les deux canaux mélangés",
+        "Each channel separately" => "Chaque canal séparément",
+        "Left channel only" => "Canal gauche seulement",
+        "Right channel only" => "Canal droit seulement",
+        "Each channel is transcribed as a job of its own; the result then shows both channels interleaved, one speaker per channel." => {
+            "Chaque canal est transcrit par une tâche distincte ; le résultat montre ensuite les deux canaux entremêlés, un locuteur par canal."
+        }
+        "The API is unreachable: the jobs will be checked again as soon as it is back." => {
+            "L'API est injoignable : les tâches seront vérifiées à nouveau dès son retour."
+        }
+        "{} files in the queue, {} finished." => "{} fichiers dans la file, {} terminés.",
+        "Stop Queue" => "Arrêter la file",
+        "The jobs already submitted keep being monitored when the queue stops." => {
+            "Les tâches déjà envoyées restent suivies quand la file s'arrête."
+        }
+        "Pause" => "Mettre en pause",
+        "Pausing frees the bandwidth: no further file is sent until the queue resumes." => {
+            "La pause libère la bande passante : aucun autre fichier n'est envoyé avant la reprise."
+        }
+        "The queue is paused. The file being uploaded is sent again on resume; the others are kept as they stand." => {
+            "La file est en pause. Le fichier en cours d'envoi sera renvoyé à la reprise ; les autres restent en l'état."
+        }
+        " Run up to {} jobs at once, as many as the server processes concurrently" => {
+            " Lancer jusqu'à {} tâches à la fois, autant que le serveur en traite simultanément"
+        }
+        "Clear Queue" => "Vider la file",
+        "Add Files" => "Ajouter des fichiers",
+        "Transcribe All" => "Tout transcrire",
+        "Download the {} Transcripts" => "Télécharger les {} transcriptions",
+        "Waiting" => "En attente d'envoi",
+        "Uploading ({}%)" => "Envoi ({} %)",
+        "Done" => "Terminé",
+        "Failed" => "Échec",
+
+        // Jobs in progress
+        "Jobs in Progress" => "Tâches en cours",
+        "Monitoring stopped after repeated errors" => "Suivi arrêté après des erreurs répétées",
+        "Queued at position {}" => "En attente, position {}",
+        "Queued" => "En attente",
+        "Processing" => "En traitement",
+        "Server status: {}" => "État sur le serveur : {}",
+        "Finishing" => "Finalisation",
+        "Status check failed, retrying" => "Échec de la vérification, nouvel essai",
+        "Checking status" => "Vérification de l'état",
+
+        // Endpoint options
+        "Extra form fields" => "Champs de formulaire supplémentaires",
+        "Custom headers" => "En-têtes personnalisés",
+        "Sent with every transcription submitted to this endpoint (e.g. project tag, cost center)." => {
+            "Envoyés avec chaque transcription soumise à ce serveur (par ex. étiquette de projet, centre de coût)."
+        }
+        "Added to every request sent to this endpoint (e.g. X-Api-Key, tenant ID)." => {
+            "Ajoutés à chaque requête envoyée à ce serveur (par ex. X-Api-Key, identifiant de locataire)."
+        }
+        "Value" => "Valeur",
+        "Reserved name, this entry will be ignored." => "Nom réservé, cette entrée sera ignorée.",
+        "Add entry" => "Ajouter une entrée",
+        "API token" => "Jeton d'API",
+        "For servers with authorization enabled. Sent as a bearer token with every request to the API, and never to other servers. Leave empty to disable." => {
+            "Pour les serveurs avec autorisation. Envoyé comme jeton « bearer » avec chaque requête à l'API, et jamais à d'autres serveurs. Laisser vide pour désactiver."
+        }
+        "HTTP Basic credentials are set for this endpoint. Setting a token disables them, as both use the same header." => {
+            "Des identifiants HTTP Basic sont définis pour ce serveur. Définir un jeton les désactive, car les deux utilisent le même en-tête."
+        }
+        "Token" => "Jeton",
+        "HTTP Basic authentication" => "Authentification HTTP Basic",
+        "For endpoints behind a reverse proxy requiring a login. Leave empty to disable." => {
+            "Pour les serveurs derrière un proxy inverse exigeant une connexion. Laisser vide pour désactiver."
+        }
+        "Disabled while an API token is set: both use the same header. Clear the token to send these credentials." => {
+            "Désactivée tant qu'un jeton d'API est défini : les deux utilisent le même en-tête. Effacez le jeton pour envoyer ces identifiants."
+        }
+        "Username" => "Nom d'utilisateur",
+        "Password" => "Mot de passe",
+        "Upload integrity" => "Intégrité des envois",
+        " Send a SHA-256 checksum of uploaded files" => {
+            " Envoyer une somme de contrôle SHA-256 des fichiers envoyés"
+        }
+        "Sent in the {} header, for servers verifying uploads. The header must be allowed by the server CORS policy." => {
+            "Envoyée dans l'en-tête {}, pour les serveurs vérifiant les envois. L'en-tête doit être autorisé par la politique CORS du serveur."
+        }
+        "Feedback endpoint" => "Adresse des avis",
+        "Ratings of completed transcriptions are posted as JSON to this URL. Leave empty to keep them local." => {
+            "Les notes des transcriptions terminées sont envoyées en JSON à cette URL. Laisser vide pour les garder en local."
+        }
+        "Metrics endpoint" => "Adresse des métriques",
+        "Prometheus metrics of a self-hosted server (queue length, job durations), shown as trends in the API status. Leave empty to disable." => {
+            "Métriques Prometheus d'un serveur auto-hébergé (longueur de la file, durée des tâches), affichées comme tendances dans l'état de l'API. Laisser vide pour désactiver."
+        }
+        "Server administration" => "Administration du serveur",
+        "For operators of this server: a token with admin scope shows the server queue. Leave empty to disable." => {
+            "Pour les opérateurs de ce serveur : un jeton d'administration affiche la file du serveur. Laisser vide pour désactiver."
+        }
+        "Admin token" => "Jeton d'administration",
+        "name@example.org or https://example.org/hook" => {
+            "nom@example.org ou https://example.org/hook"
+        }
+        "an email address or a webhook URL" => "une adresse e-mail ou une URL de webhook",
+        "an email address" => "une adresse e-mail",
+        "a webhook URL" => "une URL de webhook",
+        "Enter {}." => "Saisissez {}.",
+        "This server only accepts {}." => "Ce serveur n'accepte que {}.",
+        "Notifications" => "Notifications",
+        "The server notifies {} when a job finishes. Registered with every submission. Leave empty to disable." => {
+            "Le serveur prévient {} quand une tâche se termine. Enregistré avec chaque envoi. Laisser vide pour désactiver."
+        }
+
+        // Polling
+        "Polling" => "Interrogation du serveur",
+        "Check jobs every " => "Vérifier les tâches toutes les ",
+        "Check the API every " => "Vérifier l'API toutes les ",
+        "Shorter intervals show progress sooner but load the server more. Low-bandwidth mode multiplies both." => {
+            "Des intervalles plus courts montrent la progression plus tôt mais chargent davantage le serveur. Le mode faible débit les multiplie tous les deux."
+        }
+        "Restore Defaults" => "Rétablir les valeurs par défaut",
+
+        // History retention
+        "History retention" => "Conservation de l'historique",
+        "Keep at most " => "Garder au plus ",
+        " entries" => " entrées",
+        "Delete entries older than " => "Supprimer les entrées de plus de ",
+        "never" => "jamais",
+        " days" => " jours",
+        "Applying this policy deletes {} history entries now." => {
+            "Appliquer cette règle supprime maintenant {} entrées de l'historique."
+        }
+        "Delete {} Entries and Apply" => "Supprimer {} entrées et appliquer",
+        "Apply" => "Appliquer",
+
+        // Your data
+        "Your data" => "Vos données",
+        "Everything below is stored in this browser only. Recordings are never stored, only references to them." => {
+            "Tout ce qui suit est stocké dans ce navigateur uniquement. Les enregistrements ne sont jamais stockés, seulement des références vers eux."
+        }
+        "No longer used" => "Plus utilisée",
+        "Jobs being transcribed" => "Tâches en cours de transcription",
+        "Times of recent submissions" => "Heures des envois récents",
+        "Endpoint options, including credentials" => "Options des serveurs, identifiants compris",
+        "Endpoint profile in use" => "Profil de serveur utilisé",
+        "Transcription history, with the results" => {
+            "Historique des transcriptions, avec les résultats"
+        }
+        "Deleted history entries" => "Entrées supprimées de l'historique",
+        "Low-bandwidth mode" => "Mode faible débit",
+        "Post-processing steps" => "Étapes de post-traitement",
+        "Subtitle limits" => "Limites des sous-titres",
+        "Output format" => "Format de sortie",
+        "Speaker options" => "Options des locuteurs",
+        "Panel layout" => "Disposition des panneaux",
+        "Polling intervals" => "Intervalles d'interrogation",
+        "Accepted upload consents" => "Consentements d'envoi acceptés",
+        "Desktop notifications" => "Notifications de bureau",
+        "Unsaved transcript corrections" => "Corrections de transcription non enregistrées",
+        "Observed processing speed of the servers" => "Vitesse de traitement observée des serveurs",
+        "Color theme" => "Thème de couleurs",
+        "Interface language" => "Langue de l'interface",
+        "Audio output device of the players" => "Sortie audio des lecteurs",
+        "{} entries, {} in total." => "{} entrées, {} au total.",
+        "Export as JSON" => "Exporter en JSON",
+        "Remove orphaned entries" => "Supprimer les entrées orphelines",
+        "Delete Everything" => "Tout supprimer",
+        "Settings, credentials, history, results and consents will be deleted from this browser, and the page reloaded. Jobs still on the server are not cancelled." => {
+            "Les paramètres, identifiants, l'historique, les résultats et les consentements seront supprimés de ce navigateur, et la page rechargée. Les tâches encore sur le serveur ne sont pas annulées."
+        }
+        "Cancel" => "Annuler",
+
+        // Post-processing
+        "Transcript post-processing" => "Post-traitement des transcriptions",
+        "Steps applied in order to each completed transcript. The raw transcript is kept alongside the processed one." => {
+            "Étapes appliquées dans l'ordre à chaque transcription terminée. La transcription brute est conservée à côté de la version traitée."
+        }
+        "Reflow" => "Remise en forme",
+        "Sentence casing" => "Majuscules des phrases",
+        "Glossary" => "Glossaire",
+        "Redaction" => "Masquage des données personnelles",
+        "Profanity filter" => "Filtre de grossièretés",
+        "Custom regex" => "Expression régulière",
+        "Move Up" => "Monter",
+        "Move Down" => "Descendre",
+        "Add Step" => "Ajouter l'étape",
+        "Joins the lines of each paragraph and collapses repeated spaces." => {
+            "Joint les lignes de chaque paragraphe et réduit les espaces répétées."
+        }
+        "Capitalizes sentences and fixes the spacing around punctuation, for lowercase outputs." => {
+            "Met une majuscule aux phrases et corrige les espaces autour de la ponctuation, pour les sorties en minuscules."
+        }
+        "Replaces e-mail addresses and phone numbers with [redacted]." => {
+            "Remplace les adresses e-mail et les numéros de téléphone par [redacted]."
+        }
+        "One \"term = replacement\" per line. Terms match whole words, in any case." => {
+            "Une ligne « terme = remplacement » par terme. Les termes correspondent à des mots entiers, sans tenir compte de la casse."
+        }
+        "Words to mask, separated by commas or new lines." => {
+            "Mots à masquer, séparés par des virgules ou des retours à la ligne."
+        }
+        "Pattern" => "Motif",
+        "Replacement ($1 for groups)" => "Remplacement ($1 pour les groupes)",
+        "Invalid pattern, this step will be skipped: {}" => {
+            "Motif invalide, cette étape sera ignorée : {}"
+        }
+
+        // History
+        "History" => "Historique",
+        "Starred only" => "Favoris seulement",
+        "Imported from a file" => "Importée d'un fichier",
+        "Submitted to {}" => "Soumise à {}",
+        "Starred entries are kept" => "Les entrées favorites sont conservées",
+        "Clear History" => "Vider l'historique",
+        "Unstar (allow cleanup)" => "Retirer des favoris (autoriser le nettoyage)",
+        "Star (keep during cleanups)" => "Ajouter aux favoris (conserver lors des nettoyages)",
+        "Could not access the clipboard." => "Impossible d'accéder au presse-papiers.",
+        "{} moved to the trash." => "{} placée dans la corbeille.",
+        "Wait for the submission to finish before opening a result." => {
+            "Attendez la fin de l'envoi avant d'ouvrir un résultat."
+        }
+        "Settings of {} restored: transcribe the file again to re-run it." => {
+            "Paramètres de {} restaurés : transcrivez à nouveau le fichier pour la relancer."
+        }
+        "Open Result" => "Ouvrir le résultat",
+        "Rename" => "Renommer",
+        "Download as {}" => "Télécharger en {}",
+        "Re-run with the Same Settings" => "Relancer avec les mêmes paramètres",
+        "Copy Job ID" => "Copier l'identifiant de la tâche",
+        "Copy Text" => "Copier le texte",
+        "Delete" => "Supprimer",
+        "Select all shown" => "Tout sélectionner parmi les entrées affichées",
+        "{} selected" => "{} sélectionnée(s)",
+        "Export Transcripts" => "Exporter les transcriptions",
+        "Export Entries" => "Exporter les entrées",
+        "Re-run" => "Relancer",
+        "Move {} entries to the trash" => "Placer {} entrées dans la corbeille",
+        ", {} of them starred" => ", dont {} favorites",
+        ". Their {} transcripts will be deleted permanently after {} days: export them first to keep them" => {
+            ". Leurs {} transcriptions seront supprimées définitivement après {} jours : exportez-les d'abord pour les conserver"
+        }
+        "Export the {} transcripts of the {} entries in a text file; entries without result are skipped." => {
+            "Exporter les {} transcriptions des {} entrées dans un fichier texte ; les entrées sans résultat sont ignorées."
+        }
+        "Export the {} entries, with their results, in a JSON file." => {
+            "Exporter les {} entrées, avec leurs résultats, dans un fichier JSON."
+        }
+        "Restore the settings of the newest entry. The {} files must then be transcribed again, one by one." => {
+            "Restaurer les paramètres de l'entrée la plus récente. Les {} fichiers devront ensuite être transcrits à nouveau, un par un."
+        }
+        "Settings restored: transcribe again {}." => {
+            "Paramètres restaurés : transcrivez à nouveau {}."
+        }
+        "Confirm" => "Confirmer",
+        "Transcripts made elsewhere (SRT, VTT, TSV, TXT, JSON) or an export of entries" => {
+            "Transcriptions réalisées ailleurs (SRT, VTT, TSV, TXT, JSON) ou export d'entrées"
+        }
+        "Import Transcripts" => "Importer des transcriptions",
+        "Could not read {}." => "Impossible de lire {}.",
+        "{} entries imported into the history." => "{} entrées importées dans l'historique.",
+        "Trash ({})" => "Corbeille ({})",
+        "Deleted entries are kept {} days before they are deleted permanently." => {
+            "Les entrées supprimées sont conservées {} jours avant d'être supprimées définitivement."
+        }
+        "Deleted permanently on {}" => "Suppression définitive le {}",
+        "Restore" => "Restaurer",
+        "Delete Now" => "Supprimer maintenant",
+        "Empty Trash" => "Vider la corbeille",
+
+        // Notifications
+        "The job could not be cancelled: {}" => "La tâche n'a pas pu être annulée : {}",
+        "Job cancelled." => "Tâche annulée.",
+        "Could not cancel the job: {}" => "Impossible d'annuler la tâche : {}",
+        "The recording could not be re-opened: locate the file again." => {
+            "L'enregistrement n'a pas pu être rouvert : localisez à nouveau le fichier."
+        }
+        "{} differs from the transcribed file ({}): timestamps may not match." => {
+            "{} diffère du fichier transcrit ({}) : les horodatages peuvent ne pas correspondre."
+        }
+        "The API is back online: transcription is available again." => {
+            "L'API est de nouveau en ligne : la transcription est à nouveau disponible."
+        }
+        "Monitoring of {} stopped after repeated errors: resume it from the job list." => {
+            "Le suivi de {} s'est arrêté après des erreurs répétées : reprenez-le depuis la liste des tâches."
+        }
+        "Transcription of {} completed: open it from the history." => {
+            "Transcription de {} terminée : ouvrez-la depuis l'historique."
+        }
+        "Transcription of {} failed." => "La transcription de {} a échoué.",
+
+        // Transcription notes
+        "The server accepts files up to {}." => "Le serveur accepte les fichiers jusqu'à {}.",
+        "Result format " => "Format du résultat ",
+        " Identify speakers" => " Identifier les locuteurs",
+        " from " => " de ",
+        "any" => "indifférent",
+        " speakers" => " locuteurs",
+        "This file appears to already be transcribing (job " => {
+            "Ce fichier semble déjà en cours de transcription (tâche "
+        }
+        ", submitted at {}). Submit it anyway?" => ", soumise à {}). La soumettre quand même ?",
+        " on {} at {}" => " le {} à {}",
+        "the default options" => "les options par défaut",
+        "You transcribed this file{} with {}." => "Vous avez transcrit ce fichier{} avec {}.",
+        "Open Previous Result" => "Ouvrir le résultat précédent",
+        "The upload ran at about {}/s. " => "L'envoi s'est fait à environ {}/s. ",
+        "On a slow or metered connection, the low-bandwidth mode checks the server less often." => {
+            "Sur une connexion lente ou limitée, le mode économie de bande passante interroge le serveur moins souvent."
+        }
+        "Enable Low-Bandwidth Mode" => "Activer le mode économie de bande passante",
+        "The API is unreachable, so transcription is disabled. " => {
+            "L'API est injoignable, la transcription est donc désactivée. "
+        }
+        "It will be enabled again as soon as a status check succeeds; check the settings if it persists." => {
+            "Elle sera réactivée dès qu'une vérification de l'état réussira ; vérifiez les paramètres si cela persiste."
+        }
+        "Submissions are paused during the server maintenance. " => {
+            "Les soumissions sont suspendues pendant la maintenance du serveur. "
+        }
+        "They will be enabled again as soon as the server reports the maintenance is over." => {
+            "Elles seront réactivées dès que le serveur signalera la fin de la maintenance."
+        }
+        "This recording lasts " => "Cet enregistrement dure ",
+        ", longer than the recommended maximum of {}. " => {
+            ", plus que le maximum recommandé de {}. "
+        }
+        "The job may exceed the server timeouts." => {
+            "La tâche risque de dépasser les délais du serveur."
+        }
+        " Splitting it into {} parts of about {} each is suggested." => {
+            " Il est conseillé de le découper en {} parties d'environ {} chacune."
+        }
+        "Split into {} parts and submit" => "Découper en {} parties et soumettre",
+        "Splitting the recording..." => "Découpage de l'enregistrement...",
+        " ({} per {} minutes, {}). " => " ({} par {} minutes, {}). ",
+        "You can submit again in " => "Vous pourrez soumettre à nouveau dans ",
+        "The {} channel of this recording is nearly silent. " => {
+            "Le canal {} de cet enregistrement est presque silencieux. "
+        }
+        "Only the {} channel will be transcribed." => "Seul le canal {} sera transcrit.",
+        "Transcribe the {} channel only" => "Transcrire uniquement le canal {}",
+        "Left" => "Gauche",
+        "Right" => "Droit",
+        "about {} left" => "environ {} restant",
+        "taking longer than usual" => "plus long que d'habitude",
+        "Estimate from the past jobs of this server: {}%, {}" => {
+            "Estimation d'après les tâches passées de ce serveur : {} %, {}"
+        }
+        " even though the API accepts up to {}" => " alors que l'API accepte jusqu'à {}",
+        "A proxy in front of the API rejected uploads of {}{}. " => {
+            "Un proxy devant l'API a refusé des envois de {}{}. "
+        }
+        "This file ({}) will likely be rejected." => "Ce fichier ({}) sera probablement refusé.",
+        "Checking upload limit..." => "Vérification de la limite d'envoi...",
+        "Check proxy upload limit" => "Vérifier la limite d'envoi du proxy",
+        " Optional: sends up to {} of test data to detect a reverse-proxy limit." => {
+            " Facultatif : envoie jusqu'à {} de données de test pour détecter une limite de proxy inverse."
+        }
+        "Preparing the file for upload..." => "Préparation du fichier pour l'envoi...",
+        "{} of {} ({}%)" => "{} sur {} ({} %)",
+        " — waiting for the server" => " — en attente du serveur",
+        " — about {} left" => " — environ {} restant",
+
+        // Result
+        "Fix casing and punctuation (display only)" => {
+            "Corriger la casse et la ponctuation (affichage seulement)"
+        }
+        "Raw transcript" => "Transcription brute",
+        "Proofread" => "Relire",
+        "Resume Editing" => "Reprendre l'édition",
+        "Edit Transcript" => "Modifier la transcription",
+        "Download Result" => "Télécharger le résultat",
+        "Undo Correction" => "Annuler la correction",
+        "Timestamps" => "Horodatages",
+        "Speakers" => "Locuteurs",
+        "Time" => "Temps",
+        "Speaker" => "Locuteur",
+        "Text" => "Texte",
+        "Copy" => "Copier",
+        "Copied!" => "Copié !",
+        "Copy failed" => "Échec de la copie",
+        "Copy Transcript" => "Copier la transcription",
+        "Copy as Markdown" => "Copier en Markdown",
+        "Copy ID" => "Copier l'identifiant",
+        "Copy link" => "Copier le lien",
+        "Copy app link" => "Copier le lien de l'application",
+        "Copy snippet" => "Copier l'extrait",
+        "Find and Replace" => "Rechercher et remplacer",
+        "Find" => "Rechercher",
+        "Replace with" => "Remplacer par",
+        "Regular expression" => "Expression régulière",
+        "Match case" => "Respecter la casse",
+        "Invalid pattern: {}" => "Motif invalide : {}",
+        "{} matches" => "{} occurrence(s)",
+        "and {} more." => "et {} de plus.",
+        "Replace All" => "Tout remplacer",
+        "Export Subtitles" => "Exporter les sous-titres",
+        "The subtitles follow the timed segments, with the post-processing steps applied: corrections made to the transcript text are not included." => {
+            "Les sous-titres suivent les segments horodatés, avec les étapes de post-traitement appliquées : les corrections apportées au texte de la transcription ne sont pas incluses."
+        }
+        "Characters per line " => "Caractères par ligne ",
+        "Lines per cue " => "Lignes par sous-titre ",
+        "Cue duration from " => "Durée d'un sous-titre de ",
+        " to " => " à ",
+        "{} cues, all within the constraints." => "{} sous-titres, tous dans les contraintes.",
+        "{} violations remain in {} cues:" => "{} dépassements restent dans {} sous-titres :",
+        "Cue {}: a line has {} characters" => "Sous-titre {} : une ligne a {} caractères",
+        "Cue {}: {} lines" => "Sous-titre {} : {} lignes",
+        "Cue {}: lasts {} s" => "Sous-titre {} : dure {} s",
+        "Cue {}: lasts only {} s" => "Sous-titre {} : ne dure que {} s",
+        "Hide Preview" => "Masquer l'aperçu",
+        "Preview on Video" => "Aperçu sur la vidéo",
+        "Hide Player" => "Masquer le lecteur",
+        "Play with Subtitles" => "Lire avec les sous-titres",
+        "Download SRT" => "Télécharger en SRT",
+        "Download VTT" => "Télécharger en VTT",
+        "Both Channels" => "Les deux canaux",
+        "The {} channel interleaved with the {} one, by time." => {
+            "Le canal {} entrelacé avec le canal {}, dans l'ordre du temps."
+        }
+        "Download Conversation" => "Télécharger la conversation",
+        "Download JSON" => "Télécharger en JSON",
+        "Bilingual View" => "Vue bilingue",
+        "Paired with " => "Associée à ",
+        "Job {}" => "Tâche {}",
+        "Job {} ({})" => "Tâche {} ({})",
+        "Download Two-Column Document" => "Télécharger le document sur deux colonnes",
+        "Download Bilingual SRT" => "Télécharger le SRT bilingue",
+        "Chapters ({})" => "Chapitres ({})",
+        "No topic change detected in this transcript." => {
+            "Aucun changement de sujet détecté dans cette transcription."
+        }
+        "Include table of contents" => "Inclure la table des matières",
+        "Restore Detected Titles" => "Restaurer les titres détectés",
+        "Download Text" => "Télécharger le texte",
+
+        // Editor
+        "Restored your draft from {}." => "Votre brouillon du {} a été restauré.",
+        "Draft saved at {}." => "Brouillon enregistré à {}.",
+        "Drafts are saved in this browser as you type." => {
+            "Les brouillons sont enregistrés dans ce navigateur au fil de la saisie."
+        }
+        "Revert to the transcript returned by the server? Your corrections will be lost." => {
+            "Revenir à la transcription renvoyée par le serveur ? Vos corrections seront perdues."
+        }
+        "Apply Corrections" => "Appliquer les corrections",
+        "Revert to Original" => "Revenir à l'original",
+        "Close" => "Fermer",
+
+        // Proofreading
+        "Speed " => "Vitesse ",
+        "Output " => "Sortie ",
+        "Default device" => "Périphérique par défaut",
+        "{} (unavailable)" => "{} (indisponible)",
+        " Device names are shown once the microphone is allowed." => {
+            " Les noms des périphériques s'affichent une fois le micro autorisé."
+        }
+        "Measuring the recording..." => "Mesure de l'enregistrement...",
+        "This recording cannot be measured." => "Cet enregistrement ne peut pas être mesuré.",
+        "Already at a comfortable level." => "Déjà à un niveau confortable.",
+        "Boost quiet recording" => "Amplifier un enregistrement faible",
+        "The recording ({}) is no longer selected." => {
+            "L'enregistrement ({}) n'est plus sélectionné."
+        }
+        "No recording is attached to this transcript." => {
+            "Aucun enregistrement n'est associé à cette transcription."
+        }
+        "Re-open Recording" => "Rouvrir l'enregistrement",
+        "The file will be re-opened directly next time" => {
+            "Le fichier sera rouvert directement la prochaine fois"
+        }
+        "Locate Audio File" => "Localiser le fichier audio",
+        "Tab / Shift+Tab: next / previous segment. Ctrl+Space: play or pause." => {
+            "Tab / Maj+Tab : segment suivant / précédent. Ctrl+Espace : lecture ou pause."
+        }
+        "Confidence heatmap" => "Carte de confiance",
+        "Next Low-Confidence Segment ({}/{})" => "Segment peu fiable suivant ({}/{})",
+        "Jump to Lowest-Confidence Segments" => "Aller aux segments les moins fiables",
+        "This result has no timestamps." => "Ce résultat n'a pas d'horodatage.",
+        "Save Corrections" => "Enregistrer les corrections",
+        "Confidence: {}%" => "Confiance : {} %",
+
+        // Errors
+        "Technical details" => "Détails techniques",
+        "The microphone could not be recorded." => "Le micro n'a pas pu être enregistré.",
+        "Allow this site to use the microphone in the browser settings, then try again." => {
+            "Autorisez ce site à utiliser le micro dans les paramètres du navigateur, puis réessayez."
+        }
+        "The server could not transcribe this file." => {
+            "Le serveur n'a pas pu transcrire ce fichier."
+        }
+        "Check that the file is a valid audio recording, then submit it again." => {
+            "Vérifiez que le fichier est un enregistrement audio valide, puis soumettez-le à nouveau."
+        }
+        "The job was not found on the server. It may have expired." => {
+            "La tâche est introuvable sur le serveur. Elle a peut-être expiré."
+        }
+        "Submit the file again to start a new transcription." => {
+            "Soumettez à nouveau le fichier pour lancer une nouvelle transcription."
+        }
+        "The API URL is not configured." => "L'URL de l'API n'est pas configurée.",
+        "Enter the WhisperX API URL in the settings and save it." => {
+            "Saisissez l'URL de l'API WhisperX dans les paramètres et enregistrez-la."
+        }
+        "The API could not be reached." => "L'API est injoignable.",
+        "Check your network connection and the API URL, then try again." => {
+            "Vérifiez votre connexion réseau et l'URL de l'API, puis réessayez."
+        }
+        "The API endpoint was not found." => "Le point d'accès de l'API est introuvable.",
+        "Check that the API URL points to the WhisperX API base path." => {
+            "Vérifiez que l'URL de l'API pointe vers le chemin de base de l'API WhisperX."
+        }
+        "The server refused access to the API: the API token is missing or was rejected." => {
+            "Le serveur a refusé l'accès à l'API : le jeton d'API est absent ou a été refusé."
+        }
+        "Set the API token in the endpoint options, or ask the server administrator for one." => {
+            "Renseignez le jeton d'API dans les options du serveur, ou demandez-en un à l'administrateur du serveur."
+        }
+        "The file is too large for the server." => {
+            "Le fichier est trop volumineux pour le serveur."
+        }
+        "Compress the recording or split it into shorter parts." => {
+            "Compressez l'enregistrement ou découpez-le en parties plus courtes."
+        }
+        "The server received too many requests." => "Le serveur a reçu trop de requêtes.",
+        "Wait a few minutes before trying again." => {
+            "Patientez quelques minutes avant de réessayer."
+        }
+        "The server encountered an internal error." => "Le serveur a rencontré une erreur interne.",
+        "Try again later. If the problem persists, contact the server administrator." => {
+            "Réessayez plus tard. Si le problème persiste, contactez l'administrateur du serveur."
+        }
+        "The server rejected the request." => "Le serveur a refusé la requête.",
+        "Check the selected file and settings, then try again." => {
+            "Vérifiez le fichier sélectionné et les paramètres, puis réessayez."
+        }
+        "The server returned an unexpected response — are you pointing at the right URL?" => {
+            "Le serveur a renvoyé une réponse inattendue — l'URL est-elle la bonne ?"
+        }
+        "Check that the API URL points to the WhisperX API base path, not to a web page." => {
+            "Vérifiez que l'URL de l'API pointe vers le chemin de base de l'API WhisperX, et non vers une page web."
+        }
+        "This URL serves a web page, not the WhisperX API." => {
+            "Cette URL sert une page web, pas l'API WhisperX."
+        }
+        "Use the API base URL (it often ends with /api). The settings panel suggests corrections." => {
+            "Utilisez l'URL de base de l'API (elle se termine souvent par /api). Le panneau des paramètres propose des corrections."
+        }
+        "The server returned an unexpected response." => {
+            "Le serveur a renvoyé une réponse inattendue."
+        }
+        "Check that the API URL points to a WhisperX API server." => {
+            "Vérifiez que l'URL de l'API pointe vers un serveur d'API WhisperX."
+        }
+        "The server is under maintenance." => "Le serveur est en maintenance.",
+        "Submissions are paused until the maintenance ends. Jobs already submitted are still monitored." => {
+            "Les soumissions sont suspendues jusqu'à la fin de la maintenance. Les tâches déjà soumises restent suivies."
+        }
+        "The selected file could not be read." => "Le fichier sélectionné n'a pas pu être lu.",
+        "Select the file again. It may have been moved or deleted." => {
+            "Sélectionnez à nouveau le fichier. Il a peut-être été déplacé ou supprimé."
+        }
+        "You have reached the submission limit." => "Vous avez atteint la limite de soumissions.",
+        "Wait until the countdown ends before submitting again." => {
+            "Attendez la fin du compte à rebours avant de soumettre à nouveau."
+        }
+        "This recording is longer than the recommended maximum." => {
+            "Cet enregistrement dépasse la durée maximale recommandée."
+        }
+        "Split the recording into shorter parts before submitting." => {
+            "Découpez l'enregistrement en parties plus courtes avant de le soumettre."
+        }
+        "This file is larger than the server accepts." => {
+            "Ce fichier dépasse la taille acceptée par le serveur."
+        }
+        "This type of file is not accepted." => "Ce type de fichier n'est pas accepté.",
+        "Select an audio file, such as MP3, WAV, M4A, OGG or FLAC, or a video if accepted." => {
+            "Sélectionnez un fichier audio, tel que MP3, WAV, M4A, OGG ou FLAC, ou une vidéo si elle est acceptée."
+        }
+        "The channels of this recording could not be separated." => {
+            "Les canaux de cet enregistrement n'ont pas pu être séparés."
+        }
+        "Submit both channels mixed, or convert the recording to WAV first." => {
+            "Soumettez les deux canaux mixés, ou convertissez d'abord l'enregistrement en WAV."
+        }
+        "This recording is heavily clipped." => "Cet enregistrement est fortement saturé.",
+        "Distorted speech is transcribed less accurately. Record again with a lower input gain if possible." => {
+            "Une voix distordue est transcrite moins fidèlement. Enregistrez à nouveau avec un gain d'entrée plus faible si possible."
+        }
+        "This recording is very quiet." => "Cet enregistrement est très faible.",
+        "Quiet speech is transcribed less accurately. Amplify or normalize the recording with an audio editor before submitting it." => {
+            "Une voix faible est transcrite moins fidèlement. Amplifiez ou normalisez l'enregistrement avec un éditeur audio avant de le soumettre."
+        }
+        "This recording could not be split into parts." => {
+            "Cet enregistrement n'a pas pu être découpé en parties."
+        }
+        "Split the recording with an audio editor, or submit it as is." => {
+            "Découpez l'enregistrement avec un éditeur audio, ou soumettez-le tel quel."
+        }
+
+        // API status
+        "API Online" => "API en ligne",
+        "API Not Responding" => "L'API ne répond pas",
+        "API Unavailable" => "API indisponible",
+        "API Under Maintenance" => "API en maintenance",
+        "{} jobs in queue, {} jobs processing" => {
+            "{} tâches en file d'attente, {} en cours de traitement"
+        }
+        "Last checked at {}" => "Dernière vérification à {}",
+        "Last check failed at {}" => "Échec de la dernière vérification à {}",
+        "Last successful check {} ({} failed since) — data may be stale" => {
+            "Dernière vérification réussie {} ({} échecs depuis) — les données peuvent être périmées"
+        }
+        "{} s ago" => "il y a {} s",
+        "{} min ago" => "il y a {} min",
+        "{} h ago" => "il y a {} h",
+        "Features: " => "Fonctionnalités : ",
+        "Speaker diarization" => "Identification des locuteurs",
+        "Live job updates" => "Suivi des tâches en direct",
+        "Batch job status" => "État des tâches par lot",
+        "Job cancellation" => "Annulation des tâches",
+        "Model list" => "Liste des modèles",
+        "Email notifications" => "Notifications par e-mail",
+        "Webhook notifications" => "Notifications par webhook",
+        "GPU memory" => "Mémoire GPU",
+        "Memory" => "Mémoire",
+        "Metrics unavailable: {}" => "Métriques indisponibles : {}",
+        "Queue length" => "Longueur de la file d'attente",
+        "Mean job duration" => "Durée moyenne des tâches",
+        "Embed in another page" => "Intégrer dans une autre page",
+        "A badge with the availability and queue of the API, for a dashboard page:" => {
+            "Un badge avec la disponibilité et la file d'attente de l'API, pour une page de tableau de bord :"
+        }
+        "The uploader alone, for another tool (the format, diarize, min_speakers and max_speakers parameters fix the options):" => {
+            "Le formulaire d'envoi seul, pour un autre outil (les paramètres format, diarize, min_speakers et max_speakers fixent les options) :"
+        }
+        "Transcription Online" => "Transcription en ligne",
+        "Transcription Not responding" => "La transcription ne répond pas",
+        "Transcription Maintenance" => "Transcription en maintenance",
+        "Transcription Unavailable" => "Transcription indisponible",
+        "Transcription Checking..." => "Transcription : vérification...",
+        "{} queued, {} processing" => "{} en attente, {} en cours",
+        "Server Queue" => "File d'attente du serveur",
+        "Refresh" => "Actualiser",
+        "The queue is empty." => "La file d'attente est vide.",
+        "Job" => "Tâche",
+        "Status" => "État",
+        "Age" => "Âge",
+        "Size" => "Taille",
+        "Completed" => "Terminée",
+
+        // Receipt
+        "Job ID" => "Identifiant de la tâche",
+        "File" => "Fichier",
+        "Submitted" => "Soumise",
+        "Server message" => "Message du serveur",
+        "Parameters" => "Paramètres",
+        "Integrity" => "Intégrité",
+        "Verified by the server" => "Vérifiée par le serveur",
+        "Checksum mismatch" => "Somme de contrôle différente",
+        "Not acknowledged" => "Non confirmée",
+        "Result URL" => "URL du résultat",
+        "Job " => "Tâche ",
+        "Raw status of the job, as returned by the API" => {
+            "État brut de la tâche, tel que renvoyé par l'API"
+        }
+        "Status endpoint" => "Point d'accès de l'état",
+        "Continue on another device" => "Continuer sur un autre appareil",
+        "Scan this code to follow the job from your phone. " => {
+            "Scannez ce code pour suivre la tâche depuis votre téléphone. "
+        }
+
+        // Banners
+        "Dismiss" => "Ignorer",
+        "This server does not support some of the features you set up:" => {
+            "Ce serveur ne prend pas en charge certaines des fonctionnalités que vous avez configurées :"
+        }
+        "transcripts will not be labeled with speakers." => {
+            "les transcriptions n'indiqueront pas les locuteurs."
+        }
+        "the target is not registered with new submissions." => {
+            "la cible n'est pas enregistrée avec les nouvelles soumissions."
+        }
+        "jobs run until they finish." => "les tâches s'exécutent jusqu'à leur fin.",
+        "This link opens job " => "Ce lien ouvre la tâche ",
+        " of another API. Switch the API to " => " d'une autre API. Passer à l'API ",
+        "?" => " ?",
+        "Your next uploads will be sent to that server. Only switch if you trust the link." => {
+            "Vos prochains envois seront adressés à ce serveur. Ne changez que si vous faites confiance au lien."
+        }
+        "Switch API and Open Job" => "Changer d'API et ouvrir la tâche",
+        "Ignore Link" => "Ignorer le lien",
+        "The server is under maintenance" => "Le serveur est en maintenance",
+        " until {}" => " jusqu'au {}",
+        "New submissions are paused. Jobs already submitted are still monitored, less often." => {
+            "Les nouvelles soumissions sont suspendues. Les tâches déjà soumises restent suivies, moins souvent."
+        }
+        "Browser storage is unavailable (private browsing or embedded view): " => {
+            "Le stockage du navigateur est indisponible (navigation privée ou vue intégrée) : "
+        }
+        "settings and the active job will not be kept after this page is closed." => {
+            "les paramètres et la tâche active ne seront pas conservés après la fermeture de cette page."
+        }
+        "The browser storage is full: your latest changes could not be saved." => {
+            "Le stockage du navigateur est plein : vos dernières modifications n'ont pas pu être enregistrées."
+        }
+        "Empty the history trash" => "Vider la corbeille de l'historique",
+        "Remove oldest history entries" => {
+            "Supprimer les entrées les plus anciennes de l'historique"
+        }
+        "Clear submission history" => "Effacer l'historique des soumissions",
+
+        // Layout
+        "History & Jobs" => "Historique et tâches",
+        "Collapse {}" => "Replier {}",
+        "Expand {}" => "Déplier {}",
+        "Drag to resize" => "Faire glisser pour redimensionner",
+        "Powered by " => "Propulsé par ",
+
+        // Recorder
+        "Record Audio" => "Enregistrer",
+        "Allow the use of the microphone to start recording." => {
+            "Autorisez l'utilisation du micro pour commencer l'enregistrement."
+        }
+        "Resume" => "Reprendre",
+        "Stop" => "Arrêter",
+        "Discard" => "Abandonner",
+
+        // Feedback
+        "Thanks for your feedback: rated {}/5." => "Merci pour votre avis : note de {}/5.",
+        "Rate this transcript:" => "Notez cette transcription :",
+        "Describe any issue (optional)" => "Décrivez les problèmes éventuels (facultatif)",
+        "Send Feedback" => "Envoyer l'avis",
+
+        // API detection
+        "API found at " => "API trouvée à ",
+        "Use it" => "L'utiliser",
+        "No API found on this server." => "Aucune API trouvée sur ce serveur.",
+        "This URL does not look like the WhisperX API. Did you mean:" => {
+            "Cette URL ne ressemble pas à l'API WhisperX. Vouliez-vous dire :"
+        }
+        " responds as an API " => " répond comme une API ",
+        " no API found " => " aucune API trouvée ",
+        "Use this URL" => "Utiliser cette URL",
+        "Checking..." => "Vérification...",
+        "Check these URLs" => "Vérifier ces URL",
+        " A status request is sent to each suggested URL." => {
+            " Une requête d'état est envoyée à chaque URL suggérée."
+        }
+        _ => return None,
+    })
+}
@@ -0,0 +1,120 @@
+//! Internationalization
+//! The interface is written in English, and the English text of each message is its
+//! key in the translation tables. Messages without a translation are shown in English.
+//! The locale follows the browser unless the user picks one in the settings.
+
+mod fr;
+
+use crate::document::eval;
+use crate::hooks::persistent::UsePersistent;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A language of the interface.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Fr,
+}
+
+impl Locale {
+    /// Every locale offered for selection.
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Fr];
+
+    /// Language code, as in the `lang` attribute.
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Fr => "fr",
+        }
+    }
+
+    /// Name of the language, in the language itself.
+    pub fn label(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Fr => "Français",
+        }
+    }
+
+    /// Finds the locale of a language code, such as `fr-FR`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code.split(['-', '_']).next()?.to_lowercase();
+        Locale::ALL
+            .into_iter()
+            .find(|locale| locale.code() == language)
+    }
+
+    /// The first locale among the preferred languages of the browser, or English.
+    pub fn detect() -> Self {
+        let Some(navigator) = web_sys::window().map(|window| window.navigator()) else {
+            return Locale::default();
+        };
+        navigator
+            .languages()
+            .iter()
+            .filter_map(|language| language.as_string())
+            .chain(navigator.language())
+            .find_map(|code| Locale::from_code(&code))
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the translation of an English message.
+pub fn translate(locale: Locale, text: &'static str) -> &'static str {
+    match locale {
+        Locale::En => text,
+        Locale::Fr => fr::lookup(text).unwrap_or(text),
+    }
+}
+
+/// Translates the messages of the interface into the current locale.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Translator {
+    locale: Memo<Locale>,
+}
+
+impl Translator {
+    /// The current locale.
+    pub fn locale(&self) -> Locale {
+        (self.locale)()
+    }
+
+    /// The translation of an English message.
+    pub fn t(&self, text: &'static str) -> &'static str {
+        translate(self.locale(), text)
+    }
+
+    /// The translation of an English message with `{}` placeholders, filled in order.
+    pub fn format(&self, text: &'static str, args: &[&dyn std::fmt::Display]) -> String {
+        let mut formatted = String::new();
+        for (index, part) in self.t(text).split("{}").enumerate() {
+            if let Some(arg) = index.checked_sub(1).and_then(|index| args.get(index)) {
+                formatted.push_str(&arg.to_string());
+            }
+            formatted.push_str(part);
+        }
+        formatted
+    }
+}
+
+/// Provides the translator to the child components, for the locale picked in the
+/// settings or else the browser's. Must be called once, from the `App` component or the
+/// root of an embedded view.
+pub fn use_translator_provider(preferred: UsePersistent<Option<Locale>>) -> Translator {
+    let locale = use_memo(move || preferred.get().unwrap_or_else(Locale::detect));
+    use_effect(move || {
+        let _ = eval(&format!(
+            "document.documentElement.setAttribute('lang', '{}');",
+            locale().code()
+        ));
+    });
+    use_context_provider(|| Translator { locale })
+}
+
+/// The translator of the application.
+pub fn use_translator() -> Translator {
+    use_context::<Translator>()
+}
@@ -0,0 +1,73 @@
+//! Panel layout preferences
+//! The page is split into a left sidebar (history and jobs), the main content
+//! and a right panel (settings). Sidebars can be collapsed and resized.
+
+use crate::config::{
+    DEFAULT_LEFT_PANEL_WIDTH_PX, DEFAULT_RIGHT_PANEL_WIDTH_PX, MAX_PANEL_WIDTH_PX,
+    MIN_PANEL_WIDTH_PX,
+};
+use serde::{Deserialize, Serialize};
+
+/// A sidebar of the layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// State of the sidebars, persisted across sessions.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PanelLayout {
+    pub left_open: bool,
+    pub right_open: bool,
+    /// Width of the left sidebar, in CSS pixels.
+    pub left_width: u32,
+    /// Width of the right panel, in CSS pixels.
+    pub right_width: u32,
+}
+
+impl Default for PanelLayout {
+    fn default() -> Self {
+        PanelLayout {
+            left_open: true,
+            right_open: true,
+            left_width: DEFAULT_LEFT_PANEL_WIDTH_PX,
+            right_width: DEFAULT_RIGHT_PANEL_WIDTH_PX,
+        }
+    }
+}
+
+impl PanelLayout {
+    pub fn is_open(&self, side: Side) -> bool {
+        match side {
+            Side::Left => self.left_open,
+            Side::Right => self.right_open,
+        }
+    }
+
+    pub fn width(&self, side: Side) -> u32 {
+        match side {
+            Side::Left => self.left_width,
+            Side::Right => self.right_width,
+        }
+    }
+
+    /// Returns the layout with a sidebar opened or collapsed.
+    pub fn toggled(mut self, side: Side) -> Self {
+        match side {
+            Side::Left => self.left_open = !self.left_open,
+            Side::Right => self.right_open = !self.right_open,
+        }
+        self
+    }
+
+    /// Returns the layout with a sidebar resized, within the allowed widths.
+    pub fn resized(mut self, side: Side, width: f64) -> Self {
+        let width = width.clamp(MIN_PANEL_WIDTH_PX as f64, MAX_PANEL_WIDTH_PX as f64) as u32;
+        match side {
+            Side::Left => self.left_width = width,
+            Side::Right => self.right_width = width,
+        }
+        self
+    }
+}
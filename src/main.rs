@@ -1,110 +1,264 @@
 mod api;
+mod badge;
+mod checksum;
+mod clipboard;
+mod clock;
 mod components;
 mod config;
+mod consent;
+mod deeplink;
+mod diagnostics;
+mod drafts;
+mod embed;
+mod errors;
+mod find_replace;
+mod history;
 mod hooks;
+mod i18n;
+mod layout;
+mod media;
+mod metrics;
+mod notifications;
+mod postprocess;
+mod profile;
+mod pwa;
+mod runtime_config;
+mod services;
 pub mod state;
+mod storage;
+mod theme;
+mod throttle;
+mod throughput;
+mod transcript;
 
-use crate::config::{API_STATUS_CHECK_INTERVAL_MS, DEFAULT_API_URL};
-use crate::hooks::persistent::use_persistent;
-use crate::state::{ApiConnectionStatus, AppState, TranscriptionUiStatus};
+use crate::api::capabilities::{self, Capabilities};
+use crate::embed::EmbedView;
+use crate::hooks::indexed_db;
+use crate::state::{ApiConnectionStatus, AppState};
 use dioxus::prelude::*;
-use gloo_timers::callback::Interval;
 
 fn main() {
     wasm_logger::init(wasm_logger::Config::default());
     log::info!("Logger initialized. Starting Leontine application...");
-    dioxus::launch(App);
+    // Persisted values must be under their current keys before any hook reads them.
+    storage::migrate_legacy_keys();
+    storage::migrate_single_active_job();
+    dioxus::launch(Root);
 }
 
+/// Renders the embedded view requested by the address, or the whole application.
 #[component]
-fn App() -> Element {
-    let title = "Leontine - Audio Transcription";
+fn Root() -> Element {
+    // The values stored in IndexedDB are loaded before the application reads them.
+    let preloaded = use_resource(indexed_db::preload);
+    match embed::current_view() {
+        Some(EmbedView::StatusBadge) => rsx! {
+            head {
+                title { "Leontine - API Status" }
+                style { {include_str!("../assets/main.css")} }
+            }
+            components::status_badge::StatusBadge {}
+        },
+        _ if preloaded.read().is_none() => rsx! {},
+        _ => rsx! { App {} },
+    }
+}
 
+#[component]
+fn App() -> Element {
     // --- Global State Initialization ---
     // All shared state is created here and provided to the context.
-    let mut app_state = AppState {
-        api_url: use_persistent("api_url", || DEFAULT_API_URL.to_string()),
-        active_job: use_persistent("leontine-active-job", || None),
-        api_connection_status: use_signal(ApiConnectionStatus::default),
-        job_state: use_signal(|| None),
-        transcription_ui_status: use_signal(TranscriptionUiStatus::default),
-    };
+    let mut app_state = AppState::use_new();
 
     use_context_provider(|| app_state);
 
-    // This resource will fetch the API status. It automatically re-runs whenever
-    // its dependencies change (in this case, when api_url changes).
-    let mut api_status_resource = use_resource(move || async move {
-        let api_url = app_state.api_url.get();
-        if api_url.is_empty() {
-            return ApiConnectionStatus::Unavailable(
-                crate::api::ApiError::RequestFailed("API URL is not configured".to_string()),
-                chrono::Utc::now(),
-            );
+    // Messages follow the language picked in the settings, or else the browser's.
+    let i18n = i18n::use_translator_provider(app_state.locale);
+    let title = i18n.t("Leontine - Audio Transcription");
+
+    // The theme applies to the whole document, the upload view of embedding pages included.
+    use_effect(move || theme::apply(app_state.theme.get()));
+
+    // The upload view of an embedding page works with its own API URL and options.
+    let is_upload_view = use_hook(|| embed::current_view() == Some(EmbedView::Upload));
+    use_context_provider(|| {
+        if is_upload_view {
+            embed::fixed_options()
+        } else {
+            embed::FixedOptions::default()
         }
-        match crate::api::get_status(&api_url).await {
-            Ok(status) => ApiConnectionStatus::Available(status, chrono::Utc::now()),
-            Err(err) => ApiConnectionStatus::Unavailable(err, chrono::Utc::now()),
+    });
+    // Its API URL only holds for the page: the one saved for the application is kept.
+    use_hook(move || {
+        if let Some(api_url) = embed::api_url_param().filter(|_| is_upload_view) {
+            app_state.api_url.detach(api_url);
         }
     });
 
-    // When the resource finishes fetching, we update the global state.
-    // This effect runs whenever api_status_resource changes.
-    use_effect(move || {
-        if let Some(status) = api_status_resource.value().read().clone() {
-            app_state.api_connection_status.set(status);
+    // The deployment may adapt the application with its runtime configuration.
+    use_hook(move || {
+        spawn(async move {
+            let config = runtime_config::load().await;
+            app_state.runtime_config.set(Some(config));
+        });
+    });
+
+    // The service worker caches the application shell, so it loads offline.
+    use_hook(pwa::register_service_worker);
+
+    // The cleanup service drops the expired history entries and empties the trash.
+    services::cleanup::use_cleanup(app_state);
+
+    // The badge of the application icon counts the results not opened yet.
+    services::badge::use_app_badge(app_state);
+
+    // A job deep link (e.g. scanned from another device) becomes the focused job.
+    // A link to another API waits for the user to confirm the switch.
+    let mut pending_link = use_signal(|| None);
+    use_hook(move || {
+        if let Some(link) = deeplink::take_from_location() {
+            pending_link.set(components::deep_link_prompt::open_or_confirm(
+                app_state, link,
+            ));
         }
     });
 
-    // Set up a simple interval timer to periodically refresh the resource,
-    // which keeps the API status up-to-date.
+    // The job manager monitors the active jobs for the whole application,
+    // whatever components are currently displayed.
+    services::job_manager::use_job_manager(app_state);
+
+    // The status monitor checks the API for every component showing its status,
+    // starting over when the URL or the endpoint options change.
+    let status_monitor = services::status_monitor::use_status_monitor(
+        app_state.api_connection_status,
+        move || (app_state.api_url.get(), app_state.current_profile()),
+        move || app_state.status_check_interval_ms(),
+    );
+    // The application itself always needs the status, to lock or unlock submissions.
+    status_monitor.use_subscription();
+
+    // The capabilities follow the status; cancellation is probed when not advertised.
+    let advertised = use_memo(move || {
+        app_state
+            .api_connection_status
+            .read()
+            .api_status()
+            .map(Capabilities::from_status)
+    });
     use_effect(move || {
-        let timer = Interval::new(API_STATUS_CHECK_INTERVAL_MS as u32, move || {
-            // `restart` will cause the `use_resource` to run its future again.
-            api_status_resource.restart();
-        });
+        let Some(capabilities) = advertised() else {
+            return;
+        };
+        app_state.capabilities.set(capabilities.clone());
+        if capabilities.cancellation.is_none() {
+            spawn(async move {
+                let api_url = app_state.api_url.get();
+                let profile = app_state.current_profile();
+                let cancellation = capabilities::probe_cancellation(&api_url, &profile).await;
+                // The server may have advertised other capabilities meanwhile.
+                if advertised.peek().as_ref() == Some(&capabilities) {
+                    app_state.capabilities.set(Capabilities {
+                        cancellation,
+                        ..capabilities
+                    });
+                }
+            });
+        }
+    });
 
-        // The on_cleanup function is crucial to prevent memory leaks.
-        // It runs when the component is unmounted.
-        use_drop(move || {
-            timer.cancel();
-        });
+    // The last known status belongs to the previous URL: start over when it changes.
+    use_effect(move || {
+        let _ = app_state.api_url.get();
+        app_state
+            .api_connection_status
+            .set(ApiConnectionStatus::Pending);
     });
 
+    if is_upload_view {
+        return rsx! {
+            head {
+                title { "{title}" }
+                style { {include_str!("../assets/main.css")} }
+            }
+            div {
+                class: "embedded-upload",
+                components::maintenance_banner::MaintenanceBanner {}
+                components::capability_banner::CapabilityBanner {}
+                components::toast::ToastHost {}
+                components::transcription::TranscriptionPanel {
+                    api_url: use_context::<AppState>().api_url
+                }
+            }
+        };
+    }
+
     rsx! {
         head {
             title { "{title}" }
+            link { rel: "manifest", href: "manifest.webmanifest" }
             link { rel: "stylesheet", href: "https://unpkg.com/sakura.css/css/sakura.css" }
             style { {include_str!("../assets/main.css")} }
         }
 
+        components::activity_indicator::ActivityIndicator {}
+
         div {
             class: "app-container",
-            header { class: "app-header", h1 { "{title}" } }
-
-            section {
-                class: "settings-section",
-                components::settings::SettingsPanel {
-                    api_url: use_context::<AppState>().api_url
-                }
+            header {
+                class: "app-header",
+                h1 { "{title}" }
+                components::profile_switcher::ProfileSwitcher {}
+                components::theme_toggle::ThemeToggle {}
+                components::install_prompt::InstallPrompt {}
             }
 
-            section {
-                class: "api-status-section",
-                components::api_status::ApiStatusDisplay {}
-            }
+            components::maintenance_banner::MaintenanceBanner {}
+            components::capability_banner::CapabilityBanner {}
+            components::storage_banner::StorageBanner {}
+            components::deep_link_prompt::DeepLinkPrompt { link: pending_link }
+            components::toast::ToastHost {}
 
-            section {
-                class: "transcription-section",
-                components::transcription::TranscriptionPanel {
-                    api_url: use_context::<AppState>().api_url
-                }
+            components::layout::ThreePanelLayout {
+                left: rsx! {
+                    section {
+                        class: "jobs-section",
+                        components::job_list::JobList {}
+                    }
+                    section {
+                        class: "admin-section",
+                        components::admin_queue::AdminQueuePanel {}
+                    }
+                    section {
+                        class: "history-section",
+                        components::history::HistoryPanel {}
+                    }
+                },
+                main: rsx! {
+                    section {
+                        class: "api-status-section",
+                        components::api_status::ApiStatusDisplay {}
+                    }
+                    section {
+                        class: "transcription-section",
+                        components::transcription::TranscriptionPanel {
+                            api_url: use_context::<AppState>().api_url
+                        }
+                    }
+                },
+                right: rsx! {
+                    section {
+                        class: "settings-section",
+                        components::settings::SettingsPanel {
+                            api_url: use_context::<AppState>().api_url
+                        }
+                    }
+                },
             }
 
             footer {
                 class: "app-footer",
                 p {
-                    "Powered by ",
+                    {i18n.t("Powered by ")}
                     a {
                         href: "https://github.com/jbousquie/whisper_api",
                         target: "_blank",
@@ -0,0 +1,123 @@
+//! Stereo channels
+//! Call recorders often put each speaker on a channel of a stereo file. The channels
+//! can be transcribed separately: the browser decodes the recording and encodes each
+//! channel as a mono WAV file, resampled to what the transcription model works at.
+
+use super::excerpts::{self, Excerpt};
+use crate::dioxus_elements::FileEngine;
+use crate::errors::ValidationError;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// A channel of a stereo recording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StereoChannel {
+    Left,
+    Right,
+}
+
+impl StereoChannel {
+    /// Index of the channel in the decoded audio.
+    pub fn index(&self) -> u32 {
+        match self {
+            StereoChannel::Left => 0,
+            StereoChannel::Right => 1,
+        }
+    }
+
+    /// Label of the channel, also given to its speaker in the merged transcript.
+    pub fn label(&self) -> &'static str {
+        match self {
+            StereoChannel::Left => "Left",
+            StereoChannel::Right => "Right",
+        }
+    }
+
+    /// The other channel.
+    pub fn other(&self) -> Self {
+        match self {
+            StereoChannel::Left => StereoChannel::Right,
+            StereoChannel::Right => StereoChannel::Left,
+        }
+    }
+}
+
+/// What is submitted of a recording's channels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChannelSelection {
+    /// The file as is, its channels mixed by the server.
+    #[default]
+    Mixed,
+    /// Each channel as a job of its own.
+    Split,
+    /// Only one of the channels.
+    Only(StereoChannel),
+}
+
+impl ChannelSelection {
+    /// Every selection, in the order of the selector.
+    pub const ALL: [ChannelSelection; 4] = [
+        ChannelSelection::Mixed,
+        ChannelSelection::Split,
+        ChannelSelection::Only(StereoChannel::Left),
+        ChannelSelection::Only(StereoChannel::Right),
+    ];
+
+    /// The channels to submit separately, none to submit the file as is.
+    pub fn channels(&self) -> Vec<StereoChannel> {
+        match self {
+            ChannelSelection::Mixed => Vec::new(),
+            ChannelSelection::Split => vec![StereoChannel::Left, StereoChannel::Right],
+            ChannelSelection::Only(channel) => vec![*channel],
+        }
+    }
+
+    /// Identifier of the selection in the selector.
+    pub fn value(&self) -> &'static str {
+        match self {
+            ChannelSelection::Mixed => "mixed",
+            ChannelSelection::Split => "split",
+            ChannelSelection::Only(StereoChannel::Left) => "left",
+            ChannelSelection::Only(StereoChannel::Right) => "right",
+        }
+    }
+
+    /// The selection of an identifier of the selector.
+    pub fn from_value(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|selection| selection.value() == value)
+    }
+
+    /// Label of the selection, in English.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChannelSelection::Mixed => "Both channels mixed",
+            ChannelSelection::Split => "Each channel separately",
+            ChannelSelection::Only(StereoChannel::Left) => "Left channel only",
+            ChannelSelection::Only(StereoChannel::Right) => "Right channel only",
+        }
+    }
+}
+
+/// Decodes the first file of the file engine and encodes each of the `channels` as a
+/// mono WAV file, in the same order. Fails when the recording is not stereo or the
+/// browser cannot decode it.
+pub async fn split(
+    file_engine: &Arc<dyn FileEngine>,
+    channels: &[StereoChannel],
+) -> Result<Vec<Arc<dyn FileEngine>>, ValidationError> {
+    let excerpts: Vec<Excerpt> = channels
+        .iter()
+        .map(|channel| Excerpt {
+            channel: Some(channel.index()),
+            start: 0.0,
+            end: None,
+            label: channel.label().to_lowercase(),
+        })
+        .collect();
+    excerpts::extract(file_engine, &excerpts)
+        .await
+        .map_err(ValidationError::ChannelSplit)
+}
@@ -0,0 +1,132 @@
+//! Excerpts of a recording
+//! The browser decodes a recording once and encodes excerpts of it, a channel or a
+//! time range, as mono WAV files resampled to what the transcription model works at.
+//! Used to submit the channels of a stereo recording, or a long recording in parts.
+
+use super::native_file::NativeFile;
+use crate::config::SPLIT_CHANNEL_SAMPLE_RATE;
+use crate::dioxus_elements::FileEngine;
+use crate::document::eval;
+use serde::Serialize;
+use std::sync::Arc;
+use web_sys::js_sys::Reflect;
+
+/// Global under which the recording is handed to the script.
+const SOURCE_GLOBAL: &str = "leontineExcerptSource";
+
+/// Global under which the script leaves the excerpt files.
+const EXCERPTS_GLOBAL: &str = "leontineExcerpts";
+
+/// An excerpt of a recording.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Excerpt {
+    /// Index of the channel kept, or `None` to mix all the channels.
+    pub channel: Option<u32>,
+    /// Start of the excerpt, in seconds.
+    pub start: f64,
+    /// End of the excerpt, in seconds, or `None` for the end of the recording.
+    pub end: Option<f64>,
+    /// Appended to the name of the recording to name the excerpt.
+    pub label: String,
+}
+
+/// Decodes the first file of the file engine and encodes each excerpt as a mono WAV
+/// file, in the same order. Fails with the reason when the browser cannot decode the
+/// recording, or when a channel is asked of a recording having a single one.
+pub async fn extract(
+    file_engine: &Arc<dyn FileEngine>,
+    excerpts: &[Excerpt],
+) -> Result<Vec<Arc<dyn FileEngine>>, String> {
+    let file_name = file_engine
+        .files()
+        .first()
+        .cloned()
+        .ok_or("No file is selected.")?;
+    let file = file_engine
+        .get_native_file(&file_name)
+        .await
+        .and_then(|file| file.downcast::<web_sys::File>().ok())
+        .ok_or("The file could not be read.")?;
+
+    // The file is handed over as a global, as it cannot go through the channel.
+    let window = web_sys::window().ok_or("No browser window.")?;
+    let _ = Reflect::set(&window, &SOURCE_GLOBAL.into(), file.as_ref());
+
+    let mut extractor = eval(
+        r#"
+        const [excerpts, sampleRate, source, global] = await dioxus.recv();
+        const file = window[source];
+        delete window[source];
+        let buffer;
+        try {
+            const context = new OfflineAudioContext(1, 1, sampleRate);
+            buffer = await context.decodeAudioData(await file.arrayBuffer());
+        } catch (e) {
+            dioxus.send('The browser cannot decode this recording.');
+            return;
+        }
+        if (excerpts.some(excerpt => excerpt.channel !== null) && buffer.numberOfChannels < 2) {
+            dioxus.send('The recording has a single channel.');
+            return;
+        }
+        const stem = file.name.replace(/\.[^.]*$/, '');
+        const all = [...Array(buffer.numberOfChannels).keys()];
+        window[global] = excerpts.map(excerpt => {
+            const at = seconds => Math.min(buffer.length, Math.round(seconds * buffer.sampleRate));
+            const from = at(excerpt.start);
+            const to = excerpt.end === null ? buffer.length : Math.max(from, at(excerpt.end));
+            const channels = (excerpt.channel === null ? all : [excerpt.channel])
+                .map(index => buffer.getChannelData(index).subarray(from, to));
+            const length = to - from;
+            const wav = new DataView(new ArrayBuffer(44 + length * 2));
+            const text = (offset, value) => [...value].forEach((c, i) => wav.setUint8(offset + i, c.charCodeAt(0)));
+            text(0, 'RIFF');
+            wav.setUint32(4, 36 + length * 2, true);
+            text(8, 'WAVE');
+            text(12, 'fmt ');
+            wav.setUint32(16, 16, true);
+            wav.setUint16(20, 1, true);
+            wav.setUint16(22, 1, true);
+            wav.setUint32(24, buffer.sampleRate, true);
+            wav.setUint32(28, buffer.sampleRate * 2, true);
+            wav.setUint16(32, 2, true);
+            wav.setUint16(34, 16, true);
+            text(36, 'data');
+            wav.setUint32(40, length * 2, true);
+            for (let i = 0; i < length; i++) {
+                const mixed = channels.reduce((sum, samples) => sum + samples[i], 0) / channels.length;
+                const sample = Math.max(-1, Math.min(1, mixed));
+                wav.setInt16(44 + i * 2, sample < 0 ? sample * 0x8000 : sample * 0x7fff, true);
+            }
+            return new File([wav], `${stem} (${excerpt.label}).wav`, { type: 'audio/wav' });
+        });
+        dioxus.send(null);
+        "#,
+    );
+    extractor
+        .send((
+            excerpts,
+            SPLIT_CHANNEL_SAMPLE_RATE,
+            SOURCE_GLOBAL,
+            EXCERPTS_GLOBAL,
+        ))
+        .map_err(|_| "The recording could not be decoded.")?;
+    if let Some(reason) = extractor
+        .recv::<Option<String>>()
+        .await
+        .map_err(|_| "The recording could not be decoded.")?
+    {
+        return Err(reason);
+    }
+
+    // File engines are shared as `Arc`, even on the single-threaded web target.
+    #[allow(clippy::arc_with_non_send_sync)]
+    let files: Vec<Arc<dyn FileEngine>> = NativeFile::take_all(EXCERPTS_GLOBAL)
+        .into_iter()
+        .map(|file| Arc::new(file) as Arc<dyn FileEngine>)
+        .collect();
+    if files.len() != excerpts.len() {
+        return Err("The excerpts could not be encoded.".to_string());
+    }
+    Ok(files)
+}
@@ -0,0 +1,57 @@
+//! Accepted file types
+//! The files accepted for transcription are listed as in the HTML `accept` attribute:
+//! extensions (`.m4a`), MIME types (`audio/mpeg`) or MIME families (`video/*`). The
+//! list is set by the deployment, and may be replaced in the settings. It applies to
+//! the file picker, and to every file selected, dropped or recorded.
+
+use crate::config::DEFAULT_ACCEPTED_FILE_TYPES;
+
+/// The file types accepted when the deployment does not set its own.
+pub fn defaults() -> Vec<String> {
+    DEFAULT_ACCEPTED_FILE_TYPES
+        .iter()
+        .map(|file_type| file_type.to_string())
+        .collect()
+}
+
+/// Parses a list of file types separated by commas or spaces. Extensions are given
+/// their leading dot when missing.
+pub fn parse(text: &str) -> Vec<String> {
+    text.split(|c: char| c == ',' || c.is_whitespace())
+        .map(|file_type| file_type.trim().to_lowercase())
+        .filter(|file_type| !file_type.is_empty())
+        .map(|file_type| {
+            if file_type.starts_with('.') || file_type.contains('/') {
+                file_type
+            } else {
+                format!(".{}", file_type)
+            }
+        })
+        .collect()
+}
+
+/// The value of the `accept` attribute of the file picker.
+pub fn accept_attribute(accepted: &[String]) -> String {
+    accepted.join(",")
+}
+
+/// Whether a file matches one of the accepted types, by its extension or its MIME type.
+/// Files of unknown type are accepted unless the list names extensions, as browsers
+/// do not know every audio format.
+pub fn accepts(accepted: &[String], file_name: &str, mime_type: &str) -> bool {
+    let file_name = file_name.to_lowercase();
+    let mime_type = mime_type.to_lowercase();
+    let matches = accepted.iter().any(|file_type| {
+        if file_type.starts_with('.') {
+            file_name.ends_with(file_type.as_str())
+        } else if let Some(family) = file_type.strip_suffix("/*") {
+            mime_type
+                .split_once('/')
+                .is_some_and(|(prefix, _)| prefix == family)
+        } else {
+            mime_type == *file_type
+        }
+    });
+    matches
+        || (mime_type.is_empty() && !accepted.iter().any(|file_type| file_type.starts_with('.')))
+}
@@ -0,0 +1,118 @@
+//! Audio file handles
+//! Keeps File System Access handles of the transcribed files in IndexedDB, by job id,
+//! so that a recording can be re-opened later without selecting it again.
+//! Handles cannot be serialized, hence IndexedDB rather than localStorage.
+//! Only browsers exposing `showOpenFilePicker` support them.
+
+use crate::document::eval;
+use serde::Deserialize;
+use web_sys::js_sys::Reflect;
+use web_sys::wasm_bindgen::JsValue;
+
+/// Opens the handle store and runs a request in a transaction, resolving to its result.
+const STORE_HELPERS: &str = r#"
+    const openStore = () => new Promise((resolve, reject) => {
+        const request = indexedDB.open('leontine-audio', 1);
+        request.onupgradeneeded = () => request.result.createObjectStore('handles');
+        request.onsuccess = () => resolve(request.result);
+        request.onerror = () => reject(request.error);
+    });
+    const run = (mode, action) => openStore().then((db) => new Promise((resolve, reject) => {
+        const transaction = db.transaction('handles', mode);
+        const request = action(transaction.objectStore('handles'));
+        transaction.oncomplete = () => resolve(request.result);
+        transaction.onerror = () => reject(transaction.error);
+    }));
+    const opened = (file) => ({ url: URL.createObjectURL(file), name: file.name, size: file.size });
+"#;
+
+/// Lets the user pick the file, then stores its handle under the job id.
+const PICK_SCRIPT: &str = r#"
+    const jobId = await dioxus.recv();
+    try {
+        const [handle] = await window.showOpenFilePicker({
+            types: [{ description: 'Audio or video', accept: { 'audio/*': [], 'video/*': [] } }],
+        });
+        await run('readwrite', (store) => store.put(handle, jobId));
+        dioxus.send(opened(await handle.getFile()));
+    } catch (error) {
+        dioxus.send(null);
+    }
+"#;
+
+/// Opens the file of the stored handle, asking for the read permission again if needed.
+const REOPEN_SCRIPT: &str = r#"
+    const jobId = await dioxus.recv();
+    try {
+        const handle = await run('readonly', (store) => store.get(jobId));
+        const granted = handle && ((await handle.queryPermission({ mode: 'read' })) === 'granted'
+            || (await handle.requestPermission({ mode: 'read' })) === 'granted');
+        dioxus.send(granted ? opened(await handle.getFile()) : null);
+    } catch (error) {
+        dioxus.send(null);
+    }
+"#;
+
+/// Deletes the handles of the jobs which are not kept.
+const PRUNE_SCRIPT: &str = r#"
+    const kept = new Set(await dioxus.recv());
+    const keys = await run('readonly', (store) => store.getAllKeys());
+    await run('readwrite', (store) => {
+        keys.filter((key) => !kept.has(key)).forEach((key) => store.delete(key));
+        return store.count();
+    });
+"#;
+
+/// A file opened from a handle, ready for playback.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct OpenedFile {
+    /// Object URL of the file, to release with [`super::revoke_object_url`].
+    pub url: String,
+    pub name: String,
+    pub size: u64,
+}
+
+/// Whether the browser can pick files as persistent handles.
+pub fn is_supported() -> bool {
+    web_sys::window().is_some_and(|window| {
+        Reflect::has(&window, &JsValue::from_str("showOpenFilePicker")).unwrap_or(false)
+    })
+}
+
+/// Runs a script of the handle store with an argument, and returns its answer.
+async fn run_script(script: &str, argument: impl serde::Serialize) -> Option<OpenedFile> {
+    let mut script = eval(&format!("{}{}", STORE_HELPERS, script));
+    if let Err(e) = script.send(argument) {
+        log::warn!("Failed to access the audio file handles: {:?}", e);
+        return None;
+    }
+    script.recv::<Option<OpenedFile>>().await.ok().flatten()
+}
+
+/// Lets the user pick the recording of a job, and keeps its handle to re-open it later.
+/// Returns `None` if the user cancels the picker.
+pub async fn pick(job_id: &str) -> Option<OpenedFile> {
+    run_script(PICK_SCRIPT, job_id).await
+}
+
+/// Re-opens the recording of a job from its stored handle.
+/// Returns `None` if there is no handle, the file is gone, or the permission is refused.
+pub async fn reopen(job_id: &str) -> Option<OpenedFile> {
+    run_script(REOPEN_SCRIPT, job_id).await
+}
+
+/// Deletes the stored handles of every job but the kept ones.
+pub fn prune(kept_job_ids: Vec<String>) {
+    if !is_supported() {
+        return;
+    }
+    let script = eval(&format!("{}{}", STORE_HELPERS, PRUNE_SCRIPT));
+    if let Err(e) = script.send(kept_job_ids) {
+        log::warn!("Failed to prune the audio file handles: {:?}", e);
+    }
+}
+
+/// Deletes the handle store, with every stored handle.
+pub fn delete_all() {
+    let _ = eval("indexedDB.deleteDatabase('leontine-audio');");
+}
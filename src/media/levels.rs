@@ -0,0 +1,125 @@
+//! Audio levels
+//! Measures the levels of the selected recording, to warn before submission about
+//! heavy clipping or a very low level, which make transcriptions less accurate.
+//! The recording is decoded entirely, so long recordings are not analyzed.
+
+use super::channels::StereoChannel;
+use crate::config::{
+    CHANNEL_IMBALANCE_DB, CLIPPING_SAMPLE_LEVEL, LEVEL_ANALYSIS_SAMPLE_RATE, MAX_CLIPPED_RATIO,
+    MAX_LEVEL_ANALYSIS_DURATION_SECS, MIN_RMS_DBFS,
+};
+use crate::dioxus_elements::FileEngine;
+use crate::document::eval;
+use log::warn;
+use serde::Deserialize;
+use std::sync::Arc;
+use web_sys::js_sys::Reflect;
+
+/// Global under which the recording to analyze is handed to the analysis script.
+const SOURCE_GLOBAL: &str = "leontineLevelSource";
+
+/// Levels of a recording, measured per channel.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AudioLevels {
+    /// RMS level of each channel, between 0 and 1.
+    pub rms: Vec<f64>,
+    /// Share of clipped samples of each channel.
+    pub clipped: Vec<f64>,
+}
+
+/// What the levels of a recording may hurt transcription with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LevelIssue {
+    /// Too many samples are clipped, with their share.
+    Clipping(f64),
+    /// The recording is very quiet, with its RMS level in dBFS.
+    TooQuiet(f64),
+    /// A channel of a stereo recording is nearly silent: the other one is worth
+    /// transcribing alone.
+    SilentChannel(StereoChannel),
+}
+
+impl AudioLevels {
+    /// The issues of the levels, most serious first. A nearly silent channel is not
+    /// counted when telling whether the recording is quiet.
+    pub fn issues(&self) -> Vec<LevelIssue> {
+        let mut issues = Vec::new();
+        let clipped = self.clipped.iter().copied().fold(0.0, f64::max);
+        if clipped >= MAX_CLIPPED_RATIO {
+            issues.push(LevelIssue::Clipping(clipped));
+        }
+        let loudest = self.rms.iter().copied().fold(0.0, f64::max);
+        let loudest_db = to_dbfs(loudest);
+        if loudest_db < MIN_RMS_DBFS {
+            issues.push(LevelIssue::TooQuiet(loudest_db));
+        } else if let [left, right] = self.rms[..] {
+            if to_dbfs(left) < to_dbfs(right) - CHANNEL_IMBALANCE_DB {
+                issues.push(LevelIssue::SilentChannel(StereoChannel::Left));
+            } else if to_dbfs(right) < to_dbfs(left) - CHANNEL_IMBALANCE_DB {
+                issues.push(LevelIssue::SilentChannel(StereoChannel::Right));
+            }
+        }
+        issues
+    }
+}
+
+/// Converts a level between 0 and 1 to dBFS.
+pub fn to_dbfs(level: f64) -> f64 {
+    20.0 * level.max(1e-9).log10()
+}
+
+/// Measures the levels of the first file of the file engine, when its `duration` (in
+/// seconds) is known and short enough to be decoded. Returns `None` when the recording
+/// is not analyzed or the browser cannot decode it.
+pub async fn analyze(
+    file_engine: &Arc<dyn FileEngine>,
+    duration: Option<f64>,
+) -> Option<AudioLevels> {
+    duration.filter(|duration| *duration <= MAX_LEVEL_ANALYSIS_DURATION_SECS)?;
+    let file_name = file_engine.files().first().cloned()?;
+    let file = file_engine
+        .get_native_file(&file_name)
+        .await?
+        .downcast::<web_sys::File>()
+        .ok()?;
+    // The file is handed over as a global, as it cannot go through the channel.
+    let window = web_sys::window()?;
+    let _ = Reflect::set(&window, &SOURCE_GLOBAL.into(), file.as_ref());
+
+    let mut analysis = eval(
+        r#"
+        const [source, sampleRate, clippingLevel] = await dioxus.recv();
+        const file = window[source];
+        delete window[source];
+        try {
+            const context = new OfflineAudioContext(1, 1, sampleRate);
+            const buffer = await context.decodeAudioData(await file.arrayBuffer());
+            const rms = [];
+            const clipped = [];
+            for (let channel = 0; channel < buffer.numberOfChannels; channel++) {
+                const samples = buffer.getChannelData(channel);
+                let sum = 0;
+                let count = 0;
+                for (let i = 0; i < samples.length; i++) {
+                    sum += samples[i] * samples[i];
+                    if (Math.abs(samples[i]) >= clippingLevel) count++;
+                }
+                rms.push(Math.sqrt(sum / Math.max(samples.length, 1)));
+                clipped.push(count / Math.max(samples.length, 1));
+            }
+            dioxus.send({ rms, clipped });
+        } catch (e) {
+            dioxus.send(null);
+        }
+        "#,
+    );
+    if let Err(e) = analysis.send((
+        SOURCE_GLOBAL,
+        LEVEL_ANALYSIS_SAMPLE_RATE,
+        CLIPPING_SAMPLE_LEVEL,
+    )) {
+        warn!("Failed to analyze the levels of {}: {:?}", file_name, e);
+        return None;
+    }
+    analysis.recv::<Option<AudioLevels>>().await.ok().flatten()
+}
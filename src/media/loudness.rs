@@ -0,0 +1,91 @@
+//! Loudness normalization of playback
+//! Boosts quiet recordings while proofreading them: the peak of the recording is
+//! measured once, and the player is routed through a WebAudio gain node bringing it
+//! to a comfortable level. The uploaded file itself is never altered.
+
+use crate::config::{MAX_NORMALIZATION_GAIN, NORMALIZATION_TARGET_PEAK};
+use crate::document::eval;
+use log::warn;
+
+/// Measures the peak amplitude (between 0 and 1) of the recording at `audio_url`,
+/// decoding it entirely. Returns `None` when the browser cannot decode it.
+pub async fn measure_peak(audio_url: &str) -> Option<f64> {
+    let mut measure = eval(
+        r#"
+        const url = await dioxus.recv();
+        try {
+            const data = await (await fetch(url)).arrayBuffer();
+            const context = new OfflineAudioContext(1, 1, 44100);
+            const buffer = await context.decodeAudioData(data);
+            let peak = 0;
+            for (let channel = 0; channel < buffer.numberOfChannels; channel++) {
+                const samples = buffer.getChannelData(channel);
+                for (let i = 0; i < samples.length; i++) {
+                    const sample = Math.abs(samples[i]);
+                    if (sample > peak) peak = sample;
+                }
+            }
+            dioxus.send(peak);
+        } catch (e) {
+            dioxus.send(null);
+        }
+        "#,
+    );
+    if let Err(e) = measure.send(audio_url) {
+        warn!("Failed to send the recording to measure: {:?}", e);
+        return None;
+    }
+    measure.recv::<Option<f64>>().await.ok().flatten()
+}
+
+/// The gain bringing a recording of the given peak to the target level.
+/// Recordings are only made louder, and silent ones are not boosted without limit.
+pub fn normalization_gain(peak: f64) -> f64 {
+    if peak <= 0.0 {
+        return 1.0;
+    }
+    (NORMALIZATION_TARGET_PEAK / peak).clamp(1.0, MAX_NORMALIZATION_GAIN)
+}
+
+/// The gain expressed in decibels, for display.
+pub fn gain_db(gain: f64) -> f64 {
+    20.0 * gain.log10()
+}
+
+/// Applies the gain to the playback of the audio element `audio_id`.
+/// The element is routed through WebAudio the first time, and stays so: a gain of 1
+/// plays it unchanged. The output device chosen for the element is kept.
+pub fn set_gain(audio_id: &str, gain: f64) {
+    let _ = eval(&format!(
+        r#"
+        const audio = document.getElementById('{audio_id}');
+        if (!audio) return;
+        window.leontineGain ??= {{}};
+        let graph = window.leontineGain['{audio_id}'];
+        if (!graph || graph.audio !== audio) {{
+            graph?.context.close();
+            const context = new AudioContext();
+            const gain = context.createGain();
+            context.createMediaElementSource(audio).connect(gain).connect(context.destination);
+            graph = {{ audio, context, gain }};
+            window.leontineGain['{audio_id}'] = graph;
+            if (audio.sinkId && context.setSinkId) context.setSinkId(audio.sinkId).catch(() => {{}});
+        }}
+        graph.gain.gain.value = {gain};
+        graph.context.resume();
+        "#,
+    ));
+}
+
+/// Releases the audio graph of the element `audio_id`, once the player is closed.
+pub fn release(audio_id: &str) {
+    let _ = eval(&format!(
+        r#"
+        const graph = window.leontineGain?.['{audio_id}'];
+        if (graph) {{
+            graph.context.close();
+            delete window.leontineGain['{audio_id}'];
+        }}
+        "#,
+    ));
+}
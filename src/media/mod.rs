@@ -0,0 +1,204 @@
+//! Media inspection helpers for the Leontine application
+//! Reads metadata from the selected audio file using the browser's media stack.
+
+pub mod channels;
+pub mod excerpts;
+pub mod file_types;
+pub mod handles;
+pub mod levels;
+pub mod loudness;
+pub mod native_file;
+pub mod output;
+
+use crate::config::SUGGESTED_CHUNK_DURATION_SECS;
+use crate::dioxus_elements::FileEngine;
+use crate::document::eval;
+use crate::errors::ValidationError;
+use excerpts::Excerpt;
+use log::warn;
+use std::sync::Arc;
+use web_sys::js_sys::encode_uri_component;
+use web_sys::Url;
+
+/// Probes the duration (in seconds) of the first file of the file engine.
+/// The browser decodes only the file metadata through a detached `<audio>` element.
+/// Returns `None` if the file cannot be read or its duration is unknown.
+pub async fn probe_duration(file_engine: &Arc<dyn FileEngine>) -> Option<f64> {
+    let file_name = file_engine.files().first().cloned()?;
+    let native_file = file_engine.get_native_file(&file_name).await?;
+    let file = native_file.downcast::<web_sys::File>().ok()?;
+    let url = Url::create_object_url_with_blob(&file).ok()?;
+
+    let mut probe = eval(
+        r#"
+        const url = await dioxus.recv();
+        const audio = document.createElement('audio');
+        audio.preload = 'metadata';
+        audio.onloadedmetadata = () => dioxus.send(isFinite(audio.duration) ? audio.duration : null);
+        audio.onerror = () => dioxus.send(null);
+        audio.src = url;
+        "#,
+    );
+
+    let duration = match probe.send(url.clone()) {
+        Ok(()) => probe.recv::<Option<f64>>().await.ok().flatten(),
+        Err(e) => {
+            warn!("Failed to probe duration of {}: {:?}", file_name, e);
+            None
+        }
+    };
+
+    let _ = Url::revoke_object_url(&url);
+    duration
+}
+
+/// Creates an object URL of the first file of the file engine, for playback.
+/// The URL must be released with [`revoke_object_url`] once no longer used.
+pub async fn object_url(file_engine: &Arc<dyn FileEngine>) -> Option<String> {
+    let file_name = file_engine.files().first().cloned()?;
+    let native_file = file_engine.get_native_file(&file_name).await?;
+    let file = native_file.downcast::<web_sys::File>().ok()?;
+    Url::create_object_url_with_blob(&file).ok()
+}
+
+/// Whether the first file of the file engine is a video, according to its MIME type.
+pub async fn is_video(file_engine: &Arc<dyn FileEngine>) -> bool {
+    let Some(file_name) = file_engine.files().first().cloned() else {
+        return false;
+    };
+    let Some(native_file) = file_engine.get_native_file(&file_name).await else {
+        return false;
+    };
+    native_file
+        .downcast::<web_sys::File>()
+        .is_ok_and(|file| file.type_().starts_with("video/"))
+}
+
+/// The MIME type of the first file of the file engine, empty when the browser does not know it.
+pub async fn mime_type(file_engine: &Arc<dyn FileEngine>) -> Option<String> {
+    let file_name = file_engine.files().first().cloned()?;
+    let native_file = file_engine.get_native_file(&file_name).await?;
+    native_file
+        .downcast::<web_sys::File>()
+        .ok()
+        .map(|file| file.type_())
+}
+
+/// Checks a file against the accepted file types and the limits of the server
+/// before it is selected. `max_file_size` is the limit advertised by the server,
+/// zero or `None` for no limit.
+pub fn validate(
+    file_name: &str,
+    size: Option<u64>,
+    mime_type: &str,
+    accepted: &[String],
+    max_file_size: Option<u64>,
+) -> Result<(), ValidationError> {
+    if !file_types::accepts(accepted, file_name, mime_type) {
+        return Err(ValidationError::UnsupportedFileType {
+            file_type: if mime_type.is_empty() {
+                file_name.to_string()
+            } else {
+                mime_type.to_string()
+            },
+            accepted: accepted.to_vec(),
+        });
+    }
+    match (size, max_file_size.filter(|limit| *limit > 0)) {
+        (Some(size), Some(limit)) if size > limit => {
+            Err(ValidationError::FileTooLarge { size, limit })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Releases an object URL created by [`object_url`].
+pub fn revoke_object_url(url: &str) {
+    let _ = Url::revoke_object_url(url);
+}
+
+/// Encodes a text file as a data URL, for download links.
+pub fn text_data_url(text: &str) -> String {
+    data_url("text/plain", text)
+}
+
+/// Encodes a text document of the given MIME type as a data URL, for download links.
+pub fn data_url(mime_type: &str, text: &str) -> String {
+    format!(
+        "data:{};charset=utf-8,{}",
+        mime_type,
+        String::from(encode_uri_component(text))
+    )
+}
+
+/// Starts the download of a text document, as the download links do.
+pub fn download(file_name: &str, mime_type: &str, text: &str) {
+    let href = data_url(mime_type, text);
+    let (Ok(href), Ok(file_name)) = (
+        serde_json::to_string(&href),
+        serde_json::to_string(file_name),
+    ) else {
+        return;
+    };
+    let _ = eval(&format!(
+        "const link = document.createElement('a'); link.href = {}; link.download = {}; link.click();",
+        href, file_name
+    ));
+}
+
+/// Suggests how to split a recording of the given duration (in seconds), in parts no
+/// longer than `max_duration`: returns the number of parts and the duration of each part.
+pub fn suggest_chunks(duration: f64, max_duration: f64) -> (u32, f64) {
+    let chunk_duration = SUGGESTED_CHUNK_DURATION_SECS.min(max_duration);
+    let parts = (duration / chunk_duration).ceil().max(1.0) as u32;
+    (parts, duration / parts as f64)
+}
+
+/// Splits the first file of the file engine into the parts suggested by
+/// [`suggest_chunks`], each encoded as a mono WAV file, in order.
+pub async fn split_in_parts(
+    file_engine: &Arc<dyn FileEngine>,
+    duration: f64,
+    max_duration: f64,
+) -> Result<Vec<Arc<dyn FileEngine>>, ValidationError> {
+    let (parts, part_duration) = suggest_chunks(duration, max_duration);
+    let excerpts: Vec<Excerpt> = (0..parts)
+        .map(|part| Excerpt {
+            channel: None,
+            start: part as f64 * part_duration,
+            // The last part runs to the end, whatever the rounding of the duration.
+            end: (part + 1 < parts).then(|| (part + 1) as f64 * part_duration),
+            label: format!("part {} of {}", part + 1, parts),
+        })
+        .collect();
+    excerpts::extract(file_engine, &excerpts)
+        .await
+        .map_err(ValidationError::RecordingSplit)
+}
+
+/// Formats a duration in seconds as a compact human-readable string (e.g. `1h 05m`).
+pub fn format_duration(seconds: f64) -> String {
+    let total = seconds.round() as u64;
+    let (hours, minutes, secs) = (total / 3600, (total % 3600) / 60, total % 60);
+    if hours > 0 {
+        format!("{}h {:02}m", hours, minutes)
+    } else {
+        format!("{}m {:02}s", minutes, secs)
+    }
+}
+
+/// Formats a size in bytes as a human-readable string (e.g. `12.3 MB`).
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
@@ -0,0 +1,98 @@
+//! Native files
+//! Exposes a browser file produced by the application (a recording, a channel of a
+//! recording) as a file engine, so it goes through the same inspection and submission
+//! as the files selected by the user.
+
+use crate::dioxus_elements::FileEngine;
+use async_trait::async_trait;
+use std::any::Any;
+use std::sync::Arc;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::js_sys::{Reflect, Uint8Array};
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+
+/// A file held by the browser.
+pub struct NativeFile {
+    file: web_sys::File,
+}
+
+impl NativeFile {
+    /// Wraps a browser file.
+    pub fn new(file: web_sys::File) -> Self {
+        NativeFile { file }
+    }
+
+    /// Separates the files of a selection, so each can be submitted on its own.
+    pub async fn from_selection(file_engine: &Arc<dyn FileEngine>) -> Vec<Self> {
+        let mut files = Vec::new();
+        for file_name in file_engine.files() {
+            let Some(native_file) = file_engine.get_native_file(&file_name).await else {
+                continue;
+            };
+            if let Ok(file) = native_file.downcast::<web_sys::File>() {
+                files.push(Self::new(*file));
+            }
+        }
+        files
+    }
+
+    /// Takes the file a script left in the global `name`, if any.
+    pub fn take(name: &str) -> Option<Self> {
+        take_global(name)?
+            .dyn_into::<web_sys::File>()
+            .ok()
+            .map(Self::new)
+    }
+
+    /// Takes the files a script left as an array in the global `name`.
+    pub fn take_all(name: &str) -> Vec<Self> {
+        let Some(files) =
+            take_global(name).and_then(|value| value.dyn_into::<web_sys::js_sys::Array>().ok())
+        else {
+            return Vec::new();
+        };
+        files
+            .iter()
+            .filter_map(|file| file.dyn_into::<web_sys::File>().ok())
+            .map(Self::new)
+            .collect()
+    }
+}
+
+/// Removes the global `name` from the window, returning its value.
+fn take_global(name: &str) -> Option<JsValue> {
+    let window = web_sys::window()?;
+    let key = JsValue::from_str(name);
+    let value = Reflect::get(&window, &key).ok()?;
+    let _ = Reflect::delete_property(&window, &key);
+    Some(value)
+}
+
+#[async_trait(?Send)]
+impl FileEngine for NativeFile {
+    fn files(&self) -> Vec<String> {
+        vec![self.file.name()]
+    }
+
+    async fn file_size(&self, file: &str) -> Option<u64> {
+        (file == self.file.name()).then(|| self.file.size() as u64)
+    }
+
+    async fn read_file(&self, file: &str) -> Option<Vec<u8>> {
+        if file != self.file.name() {
+            return None;
+        }
+        let buffer = JsFuture::from(self.file.array_buffer()).await.ok()?;
+        Some(Uint8Array::new(&buffer).to_vec())
+    }
+
+    async fn read_file_to_string(&self, file: &str) -> Option<String> {
+        self.read_file(file)
+            .await
+            .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+    }
+
+    async fn get_native_file(&self, file: &str) -> Option<Box<dyn Any>> {
+        (file == self.file.name()).then(|| Box::new(self.file.clone()) as Box<dyn Any>)
+    }
+}
@@ -0,0 +1,65 @@
+//! Audio output devices
+//! Routes playback to a chosen output device (e.g. a headset) with `setSinkId`, on the
+//! browsers supporting it. Device ids change between sessions, so devices are known by
+//! their label and matched again each time the choice is applied.
+
+use crate::document::eval;
+use log::warn;
+
+/// Lists the labels of the audio output devices, or `None` when the browser cannot
+/// route playback. Browsers only reveal the labels once the microphone was allowed.
+pub async fn list_devices() -> Option<Vec<String>> {
+    eval(
+        r#"
+        if (!('setSinkId' in HTMLMediaElement.prototype) || !navigator.mediaDevices?.enumerateDevices) {
+            return null;
+        }
+        const devices = await navigator.mediaDevices.enumerateDevices();
+        return devices
+            .filter((device) => device.kind === 'audiooutput' && device.label)
+            .map((device) => device.label);
+        "#,
+    )
+    .join::<Option<Vec<String>>>()
+    .await
+    .ok()
+    .flatten()
+}
+
+/// Routes the playback of the audio element `audio_id` to the device labelled `label`,
+/// or to the default device. Returns whether the device was found and selected.
+pub async fn apply(audio_id: &str, label: Option<String>) -> bool {
+    let mut output = eval(
+        r#"
+        const [audioId, label] = await dioxus.recv();
+        const audio = document.getElementById(audioId);
+        if (!audio || !audio.setSinkId) {
+            dioxus.send(false);
+            return;
+        }
+        let sinkId = '';
+        if (label) {
+            const devices = await navigator.mediaDevices.enumerateDevices();
+            const device = devices.find((device) => device.kind === 'audiooutput' && device.label === label);
+            if (!device) {
+                dioxus.send(false);
+                return;
+            }
+            sinkId = device.deviceId;
+        }
+        try {
+            await audio.setSinkId(sinkId);
+            // Playback boosted by the loudness normalization goes through its audio context.
+            await window.leontineGain?.[audioId]?.context.setSinkId?.(sinkId);
+            dioxus.send(true);
+        } catch (e) {
+            dioxus.send(false);
+        }
+        "#,
+    );
+    if let Err(e) = output.send((audio_id, label)) {
+        warn!("Failed to send the output device to the script: {:?}", e);
+        return false;
+    }
+    output.recv::<bool>().await.unwrap_or(false)
+}
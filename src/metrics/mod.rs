@@ -0,0 +1,204 @@
+//! Prometheus metrics
+//! Parses the subset of the Prometheus text exposition format relevant to the
+//! transcription server (queue length, job durations), for self-hosted monitoring.
+
+use crate::config::{
+    METRICS_HISTORY_SAMPLES, METRICS_JOB_DURATION_SUFFIX, METRICS_QUEUE_LENGTH_SUFFIXES,
+};
+use chrono::{DateTime, Utc};
+
+/// A sample of a metric: its name, labels excluded, and its value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub name: String,
+    pub value: f64,
+}
+
+/// Parses the samples of a Prometheus text exposition.
+/// Comments, labels and timestamps are skipped, as are lines that cannot be parsed.
+pub fn parse(text: &str) -> Vec<Sample> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(parse_line)
+        .collect()
+}
+
+/// Parses a `name{labels} value [timestamp]` line.
+fn parse_line(line: &str) -> Option<Sample> {
+    let (name, rest) = match line.find('{') {
+        Some(open) => {
+            let close = line[open..].find('}')? + open;
+            (&line[..open], &line[close + 1..])
+        }
+        None => line.split_once(char::is_whitespace)?,
+    };
+    let value = rest.split_whitespace().next()?;
+    let value = match value {
+        "+Inf" => f64::INFINITY,
+        "-Inf" => f64::NEG_INFINITY,
+        value => value.parse().ok()?,
+    };
+    Some(Sample {
+        name: name.trim().to_string(),
+        value,
+    })
+}
+
+/// Sums the samples whose name satisfies `matches`, or `None` if there are none.
+fn total(samples: &[Sample], matches: impl Fn(&str) -> bool) -> Option<f64> {
+    samples
+        .iter()
+        .filter(|sample| matches(&sample.name))
+        .map(|sample| sample.value)
+        .reduce(|a, b| a + b)
+}
+
+/// The measures of the server extracted from one metrics scrape.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub scraped_at: DateTime<Utc>,
+    /// Number of jobs waiting in the queue.
+    pub queue_length: Option<f64>,
+    /// Cumulated duration of the finished jobs, in seconds.
+    pub job_duration_sum: Option<f64>,
+    /// Number of finished jobs.
+    pub job_duration_count: Option<f64>,
+}
+
+impl Snapshot {
+    /// Extracts the relevant measures from the samples of a scrape.
+    pub fn from_samples(samples: &[Sample], scraped_at: DateTime<Utc>) -> Self {
+        let duration_sum = format!("{}_sum", METRICS_JOB_DURATION_SUFFIX);
+        let duration_count = format!("{}_count", METRICS_JOB_DURATION_SUFFIX);
+        Snapshot {
+            scraped_at,
+            queue_length: total(samples, |name| {
+                METRICS_QUEUE_LENGTH_SUFFIXES
+                    .iter()
+                    .any(|suffix| name.ends_with(suffix))
+            }),
+            job_duration_sum: total(samples, |name| name.ends_with(&duration_sum)),
+            job_duration_count: total(samples, |name| name.ends_with(&duration_count)),
+        }
+    }
+
+    /// Mean duration of the jobs finished since `previous`, or of all jobs without
+    /// a previous snapshot. `None` when no job finished in between.
+    pub fn mean_job_duration(&self, previous: Option<&Snapshot>) -> Option<f64> {
+        let (sum, count) = (self.job_duration_sum?, self.job_duration_count?);
+        let (sum, count) = match previous
+            .and_then(|previous| Some((previous.job_duration_sum?, previous.job_duration_count?)))
+        {
+            // Counters reset when the server restarts: fall back to the totals.
+            Some((previous_sum, previous_count)) if count >= previous_count => {
+                (sum - previous_sum, count - previous_count)
+            }
+            _ => (sum, count),
+        };
+        (count > 0.0).then(|| sum / count)
+    }
+}
+
+/// Appends a snapshot to the trend, keeping the last `METRICS_HISTORY_SAMPLES` ones.
+pub fn push(trend: &mut Vec<Snapshot>, snapshot: Snapshot) {
+    trend.push(snapshot);
+    if trend.len() > METRICS_HISTORY_SAMPLES {
+        trend.drain(..trend.len() - METRICS_HISTORY_SAMPLES);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const EXPOSITION: &str = "\
+# HELP whisperx_queue_length Jobs waiting in the queue
+# TYPE whisperx_queue_length gauge
+whisperx_queue_length 3
+whisperx_job_duration_seconds_sum{model=\"large\"} 120.5 1700000000000
+whisperx_job_duration_seconds_count{model=\"large\"} 4
+whisperx_job_duration_seconds_sum{model=\"small\"} 9.5
+whisperx_job_duration_seconds_count{model=\"small\"} 1
+whisperx_job_duration_seconds_bucket{le=\"+Inf\"} +Inf
+not a sample
+";
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(secs, 0).unwrap()
+    }
+
+    fn snapshot(sum: f64, count: f64) -> Snapshot {
+        Snapshot {
+            scraped_at: at(0),
+            queue_length: None,
+            job_duration_sum: Some(sum),
+            job_duration_count: Some(count),
+        }
+    }
+
+    #[test]
+    fn parses_samples_skipping_comments_labels_and_timestamps() {
+        let samples = parse(EXPOSITION);
+        assert_eq!(samples.len(), 6);
+        assert_eq!(
+            samples[0],
+            Sample {
+                name: "whisperx_queue_length".to_string(),
+                value: 3.0
+            }
+        );
+        assert_eq!(samples[1].name, "whisperx_job_duration_seconds_sum");
+        assert_eq!(samples[1].value, 120.5);
+        assert_eq!(samples[5].value, f64::INFINITY);
+    }
+
+    #[test]
+    fn snapshot_sums_the_series_of_each_measure() {
+        let snapshot = Snapshot::from_samples(&parse(EXPOSITION), at(0));
+        assert_eq!(snapshot.queue_length, Some(3.0));
+        assert_eq!(snapshot.job_duration_sum, Some(130.0));
+        assert_eq!(snapshot.job_duration_count, Some(5.0));
+    }
+
+    #[test]
+    fn snapshot_without_the_measures() {
+        let snapshot = Snapshot::from_samples(&parse("other_metric 1"), at(0));
+        assert_eq!(snapshot.queue_length, None);
+        assert_eq!(snapshot.mean_job_duration(None), None);
+    }
+
+    #[test]
+    fn mean_job_duration_since_the_previous_snapshot() {
+        let previous = snapshot(100.0, 4.0);
+        assert_eq!(
+            snapshot(130.0, 5.0).mean_job_duration(Some(&previous)),
+            Some(30.0)
+        );
+        assert_eq!(
+            snapshot(100.0, 4.0).mean_job_duration(Some(&previous)),
+            None
+        );
+        assert_eq!(snapshot(100.0, 4.0).mean_job_duration(None), Some(25.0));
+    }
+
+    #[test]
+    fn mean_job_duration_after_a_counter_reset() {
+        let previous = snapshot(100.0, 4.0);
+        assert_eq!(
+            snapshot(20.0, 2.0).mean_job_duration(Some(&previous)),
+            Some(10.0)
+        );
+    }
+
+    #[test]
+    fn trend_keeps_the_latest_snapshots() {
+        let mut trend = Vec::new();
+        for count in 0..METRICS_HISTORY_SAMPLES + 5 {
+            push(&mut trend, snapshot(0.0, count as f64));
+        }
+        assert_eq!(trend.len(), METRICS_HISTORY_SAMPLES);
+        assert_eq!(trend[0].job_duration_count, Some(5.0));
+    }
+}
@@ -0,0 +1,61 @@
+//! Desktop notifications
+//! Tells the user about finished transcriptions with the browser's Notification API,
+//! so that they learn about it while working in another tab. Notifications are opt-in:
+//! the permission is only requested when the user enables them in the settings.
+
+use log::warn;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::js_sys::Reflect;
+use web_sys::wasm_bindgen::JsValue;
+use web_sys::{Notification, NotificationOptions, NotificationPermission};
+
+/// Whether the browser can show desktop notifications.
+pub fn is_supported() -> bool {
+    web_sys::window().is_some_and(|window| {
+        Reflect::has(&window, &JsValue::from_str("Notification")).unwrap_or(false)
+    })
+}
+
+/// Whether the user allowed the application to show notifications.
+pub fn is_granted() -> bool {
+    is_supported() && Notification::permission() == NotificationPermission::Granted
+}
+
+/// Whether the user refused notifications: the browser will not ask again.
+pub fn is_denied() -> bool {
+    is_supported() && Notification::permission() == NotificationPermission::Denied
+}
+
+/// Asks the user for the permission to show notifications, unless already answered.
+/// Returns whether notifications are allowed.
+pub async fn request_permission() -> bool {
+    if !is_supported() {
+        return false;
+    }
+    if Notification::permission() != NotificationPermission::Default {
+        return is_granted();
+    }
+    let promise = match Notification::request_permission() {
+        Ok(promise) => promise,
+        Err(e) => {
+            warn!("Failed to request the notification permission: {:?}", e);
+            return false;
+        }
+    };
+    let _ = JsFuture::from(promise).await;
+    is_granted()
+}
+
+/// Shows a desktop notification, if allowed. Notifications sharing a `tag`
+/// replace each other instead of piling up.
+pub fn show(title: &str, body: &str, tag: &str) {
+    if !is_granted() {
+        return;
+    }
+    let options = NotificationOptions::new();
+    options.set_body(body);
+    options.set_tag(tag);
+    if let Err(e) = Notification::new_with_options(title, &options) {
+        warn!("Failed to show a notification: {:?}", e);
+    }
+}
@@ -0,0 +1,194 @@
+//! Transcript post-processing
+//! Completed transcripts go through an ordered, user-configurable pipeline of steps
+//! (reflow, glossary, redaction...). The pipeline configuration is persisted;
+//! each step is built from it when the pipeline runs.
+
+mod steps;
+
+use crate::transcript::TranscriptSegment;
+use serde::{Deserialize, Serialize};
+use steps::{CustomRegex, Glossary, Profanity, Redaction, Reflow, SentenceCasing};
+
+/// A transformation of a transcript.
+pub trait Step {
+    fn apply(&self, text: &str) -> String;
+}
+
+/// The configuration of a pipeline step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StepConfig {
+    /// Joins the lines of each paragraph and collapses repeated whitespace.
+    Reflow,
+    /// Restores sentence casing and spacing around punctuation.
+    SentenceCasing,
+    /// Replaces terms (whole words, any case) by their preferred spelling.
+    Glossary { terms: Vec<(String, String)> },
+    /// Masks e-mail addresses and phone numbers.
+    Redaction,
+    /// Masks the listed words.
+    Profanity { words: Vec<String> },
+    /// Replaces the matches of a regular expression.
+    CustomRegex {
+        pattern: String,
+        replacement: String,
+    },
+}
+
+impl StepConfig {
+    /// A new step of each kind, as offered when adding a step.
+    pub fn kinds() -> [StepConfig; 6] {
+        [
+            StepConfig::Reflow,
+            StepConfig::SentenceCasing,
+            StepConfig::Glossary { terms: Vec::new() },
+            StepConfig::Redaction,
+            StepConfig::Profanity { words: Vec::new() },
+            StepConfig::CustomRegex {
+                pattern: String::new(),
+                replacement: String::new(),
+            },
+        ]
+    }
+
+    /// The name of the step kind, as displayed.
+    pub fn label(&self) -> &'static str {
+        match self {
+            StepConfig::Reflow => "Reflow",
+            StepConfig::SentenceCasing => "Sentence casing",
+            StepConfig::Glossary { .. } => "Glossary",
+            StepConfig::Redaction => "Redaction",
+            StepConfig::Profanity { .. } => "Profanity filter",
+            StepConfig::CustomRegex { .. } => "Custom regex",
+        }
+    }
+
+    /// Builds the step. Fails when a pattern is not a valid regular expression.
+    pub fn build(&self) -> Result<Box<dyn Step>, regex::Error> {
+        Ok(match self {
+            StepConfig::Reflow => Box::new(Reflow),
+            StepConfig::SentenceCasing => Box::new(SentenceCasing),
+            StepConfig::Glossary { terms } => Box::new(Glossary::new(terms)?),
+            StepConfig::Redaction => Box::new(Redaction::new()),
+            StepConfig::Profanity { words } => Box::new(Profanity::new(words)?),
+            StepConfig::CustomRegex {
+                pattern,
+                replacement,
+            } => Box::new(CustomRegex::new(pattern, replacement)?),
+        })
+    }
+}
+
+/// A step of the pipeline, which can be disabled without losing its configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipelineStep {
+    pub enabled: bool,
+    pub config: StepConfig,
+}
+
+/// Runs the enabled steps of the pipeline over a transcript, in order.
+/// Returns `None` when no step is enabled, so that only the raw transcript is kept.
+/// Steps that cannot be built are skipped.
+pub fn run(pipeline: &[PipelineStep], text: &str) -> Option<String> {
+    let steps = build_enabled(pipeline)?;
+    Some(apply_all(&steps, text))
+}
+
+/// Runs the enabled steps of the pipeline over the text of each segment, keeping
+/// their timing, so that timed exports get the same corrections as the transcript.
+pub fn run_on_segments(
+    pipeline: &[PipelineStep],
+    segments: &[TranscriptSegment],
+) -> Vec<TranscriptSegment> {
+    let Some(steps) = build_enabled(pipeline) else {
+        return segments.to_vec();
+    };
+    segments
+        .iter()
+        .map(|segment| TranscriptSegment {
+            text: apply_all(&steps, &segment.text),
+            ..segment.clone()
+        })
+        .collect()
+}
+
+/// Builds the enabled steps, or returns `None` when no step is enabled.
+fn build_enabled(pipeline: &[PipelineStep]) -> Option<Vec<Box<dyn Step>>> {
+    let mut enabled = pipeline.iter().filter(|step| step.enabled).peekable();
+    enabled.peek()?;
+    Some(
+        enabled
+            .filter_map(|step| match step.config.build() {
+                Ok(built) => Some(built),
+                Err(err) => {
+                    log::warn!(
+                        "Skipping post-processing step {}: {}",
+                        step.config.label(),
+                        err
+                    );
+                    None
+                }
+            })
+            .collect(),
+    )
+}
+
+fn apply_all(steps: &[Box<dyn Step>], text: &str) -> String {
+    steps
+        .iter()
+        .fold(text.to_string(), |text, step| step.apply(&text))
+}
+
+/// Restores sentence casing and punctuation spacing for display only,
+/// leaving the stored transcript unchanged.
+pub fn tidy_for_display(text: &str) -> String {
+    SentenceCasing.apply(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            start,
+            end: start + 1.0,
+            speaker: None,
+            text: text.to_string(),
+            score: None,
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn segments_keep_their_timing() {
+        let pipeline = [
+            PipelineStep {
+                enabled: true,
+                config: StepConfig::Redaction,
+            },
+            PipelineStep {
+                enabled: false,
+                config: StepConfig::SentenceCasing,
+            },
+        ];
+        let segments = [segment(0.0, "mail jane@example.com"), segment(1.0, "bye")];
+        assert_eq!(
+            run_on_segments(&pipeline, &segments),
+            [segment(0.0, "mail [redacted]"), segment(1.0, "bye")]
+        );
+    }
+
+    #[test]
+    fn a_pipeline_without_enabled_steps_keeps_the_raw_transcript() {
+        let pipeline = [PipelineStep {
+            enabled: false,
+            config: StepConfig::Reflow,
+        }];
+        assert_eq!(run(&pipeline, "raw  text"), None);
+        assert_eq!(
+            run_on_segments(&pipeline, &[segment(0.0, "a  b")])[0].text,
+            "a  b"
+        );
+    }
+}
@@ -0,0 +1,300 @@
+//! Post-processing steps
+//! Implementations of the steps configurable in the pipeline.
+
+use super::Step;
+use regex::{NoExpand, Regex};
+
+/// Replacement of redacted personal data.
+const REDACTED: &str = "[redacted]";
+
+/// Joins the lines of each paragraph and collapses repeated whitespace.
+/// Paragraphs, separated by blank lines, are kept.
+pub struct Reflow;
+
+impl Step for Reflow {
+    fn apply(&self, text: &str) -> String {
+        text.split("\n\n")
+            .map(|paragraph| paragraph.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|paragraph| !paragraph.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+/// Restores sentence casing and spacing around punctuation, for models that
+/// output lowercase text: no space before a `,` or a final `.`, and a capital letter
+/// at the start of each sentence. A sentence only ends at punctuation followed by
+/// whitespace, and not after abbreviations such as "e.g.", so that URLs, decimals
+/// and times are left as they are. No space is ever inserted.
+pub struct SentenceCasing;
+
+impl Step for SentenceCasing {
+    fn apply(&self, text: &str) -> String {
+        let mut output = String::with_capacity(text.len());
+        let mut capitalize = true;
+        // Abbreviations are words of short parts separated by dots, such as "e.g.":
+        // whether the word being written contains a dot, and the length of its last part.
+        let mut dotted_word = false;
+        let mut part_len = 0;
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == ' ' && ends_clause(chars.clone()) {
+                continue;
+            }
+            if capitalize && c.is_alphabetic() {
+                output.extend(c.to_uppercase());
+                capitalize = false;
+            } else {
+                output.push(c);
+            }
+            let followed_by_space = chars.peek().is_none_or(|next| next.is_whitespace());
+            if c.is_whitespace() {
+                dotted_word = false;
+                part_len = 0;
+                continue;
+            }
+            if c == '.' {
+                capitalize = followed_by_space && !(dotted_word && part_len <= 2);
+                dotted_word = true;
+                part_len = 0;
+                continue;
+            }
+            part_len += 1;
+            if matches!(c, '!' | '?') {
+                capitalize = followed_by_space;
+            } else if c.is_alphanumeric() {
+                capitalize = false;
+            }
+        }
+        output
+    }
+}
+
+/// Whether the characters start with a `,`, or with a `.` ending a word, which
+/// the space before them should stick to.
+fn ends_clause(mut chars: impl Iterator<Item = char>) -> bool {
+    match chars.next() {
+        Some(',') => true,
+        Some('.') => chars.next().is_none_or(char::is_whitespace),
+        _ => false,
+    }
+}
+
+/// Builds a case-insensitive regular expression matching whole words.
+fn whole_words<'a>(words: impl Iterator<Item = &'a str>) -> Result<Option<Regex>, regex::Error> {
+    let alternatives: Vec<String> = words
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .map(regex::escape)
+        .collect();
+    if alternatives.is_empty() {
+        return Ok(None);
+    }
+    Regex::new(&format!(r"(?i)\b(?:{})\b", alternatives.join("|"))).map(Some)
+}
+
+/// Replaces terms by their preferred spelling.
+pub struct Glossary {
+    terms: Vec<(Regex, String)>,
+}
+
+impl Glossary {
+    pub fn new(terms: &[(String, String)]) -> Result<Self, regex::Error> {
+        let mut compiled = Vec::new();
+        for (term, replacement) in terms {
+            if let Some(pattern) = whole_words(std::iter::once(term.as_str()))? {
+                compiled.push((pattern, replacement.clone()));
+            }
+        }
+        Ok(Glossary { terms: compiled })
+    }
+}
+
+impl Step for Glossary {
+    fn apply(&self, text: &str) -> String {
+        self.terms
+            .iter()
+            .fold(text.to_string(), |text, (pattern, replacement)| {
+                pattern
+                    .replace_all(&text, NoExpand(replacement))
+                    .into_owned()
+            })
+    }
+}
+
+/// Masks e-mail addresses and phone numbers.
+pub struct Redaction {
+    patterns: [Regex; 2],
+}
+
+impl Redaction {
+    pub fn new() -> Self {
+        Redaction {
+            patterns: [
+                Regex::new(r"[\w.+-]+@[\w-]+(?:\.[\w-]+)+").expect("valid e-mail pattern"),
+                Regex::new(r"\+?\d(?:[\s.-]?\d){8,}").expect("valid phone pattern"),
+            ],
+        }
+    }
+}
+
+impl Step for Redaction {
+    fn apply(&self, text: &str) -> String {
+        self.patterns
+            .iter()
+            .fold(text.to_string(), |text, pattern| {
+                pattern.replace_all(&text, REDACTED).into_owned()
+            })
+    }
+}
+
+/// Masks the listed words, keeping their first letter.
+pub struct Profanity {
+    pattern: Option<Regex>,
+}
+
+impl Profanity {
+    pub fn new(words: &[String]) -> Result<Self, regex::Error> {
+        Ok(Profanity {
+            pattern: whole_words(words.iter().map(String::as_str))?,
+        })
+    }
+}
+
+impl Step for Profanity {
+    fn apply(&self, text: &str) -> String {
+        let Some(pattern) = &self.pattern else {
+            return text.to_string();
+        };
+        pattern
+            .replace_all(text, |captures: &regex::Captures| {
+                let word = &captures[0];
+                let mut chars = word.chars();
+                let first = chars.next().map(String::from).unwrap_or_default();
+                first + &"*".repeat(chars.count())
+            })
+            .into_owned()
+    }
+}
+
+/// Replaces the matches of a user-defined regular expression.
+/// The replacement may refer to capture groups (`$1`, `${name}`).
+/// An empty pattern, which would match everywhere, leaves the text unchanged.
+pub struct CustomRegex {
+    pattern: Option<Regex>,
+    replacement: String,
+}
+
+impl CustomRegex {
+    pub fn new(pattern: &str, replacement: &str) -> Result<Self, regex::Error> {
+        let pattern = match pattern {
+            "" => None,
+            pattern => Some(Regex::new(pattern)?),
+        };
+        Ok(CustomRegex {
+            pattern,
+            replacement: replacement.to_string(),
+        })
+    }
+}
+
+impl Step for CustomRegex {
+    fn apply(&self, text: &str) -> String {
+        match &self.pattern {
+            Some(pattern) => pattern
+                .replace_all(text, self.replacement.as_str())
+                .into_owned(),
+            None => text.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reflow_joins_lines_and_keeps_paragraphs() {
+        let text = "first  line\nsecond line\n\n\n\nnext   paragraph\n";
+        assert_eq!(
+            Reflow.apply(text),
+            "first line second line\n\nnext paragraph"
+        );
+    }
+
+    #[test]
+    fn sentence_casing_capitalizes_sentences() {
+        assert_eq!(
+            SentenceCasing.apply("hello world , how are you ? fine ."),
+            "Hello world, how are you ? Fine."
+        );
+    }
+
+    #[test]
+    fn sentence_casing_leaves_urls_intact() {
+        assert_eq!(
+            SentenceCasing.apply("see https://example.com/a,b?x=1 or leontine.example.org. thanks"),
+            "See https://example.com/a,b?x=1 or leontine.example.org. Thanks"
+        );
+        assert_eq!(
+            SentenceCasing.apply("open the .env file"),
+            "Open the .env file"
+        );
+    }
+
+    #[test]
+    fn sentence_casing_leaves_abbreviations_intact() {
+        assert_eq!(
+            SentenceCasing.apply("some fruits, e.g. apples, i.e.pears"),
+            "Some fruits, e.g. apples, i.e.pears"
+        );
+    }
+
+    #[test]
+    fn sentence_casing_leaves_decimals_and_times_intact() {
+        assert_eq!(
+            SentenceCasing.apply("it costs 3.50 euros,at 10:30am. then 1,5 kg"),
+            "It costs 3.50 euros,at 10:30am. Then 1,5 kg"
+        );
+    }
+
+    #[test]
+    fn glossary_replaces_whole_words_in_any_case() {
+        let glossary = Glossary::new(&[
+            ("whisperx".to_string(), "WhisperX".to_string()),
+            ("gpu".to_string(), "GPU ($1)".to_string()),
+        ])
+        .unwrap();
+        assert_eq!(
+            glossary.apply("Whisperx and WHISPERX, not whisperxl"),
+            "WhisperX and WhisperX, not whisperxl"
+        );
+        // Replacements are literal: capture group references are not expanded.
+        assert_eq!(glossary.apply("gpu time"), "GPU ($1) time");
+    }
+
+    #[test]
+    fn redaction_masks_emails_and_phone_numbers() {
+        assert_eq!(
+            Redaction::new()
+                .apply("write to jane.doe+work@example.co.uk or call +33 6 12 34 56 78"),
+            "write to [redacted] or call [redacted]"
+        );
+        assert_eq!(Redaction::new().apply("room 1234"), "room 1234");
+    }
+
+    #[test]
+    fn profanity_keeps_the_first_letter() {
+        let profanity = Profanity::new(&["darn".to_string(), " ".to_string()]).unwrap();
+        assert_eq!(profanity.apply("Darn it, darned"), "D*** it, darned");
+        assert_eq!(Profanity::new(&[]).unwrap().apply("darn"), "darn");
+    }
+
+    #[test]
+    fn custom_regex_expands_capture_groups() {
+        let step = CustomRegex::new(r"(\d+)h(\d+)", "$1:$2").unwrap();
+        assert_eq!(step.apply("at 10h30"), "at 10:30");
+        assert_eq!(CustomRegex::new("", "x").unwrap().apply("text"), "text");
+        assert!(CustomRegex::new("(", "").is_err());
+    }
+}
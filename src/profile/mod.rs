@@ -0,0 +1,291 @@
+//! Endpoint profiles for the Leontine application
+//! A profile holds the options that only make sense for one API endpoint
+//! (extra form fields, credentials, headers...). Profiles are persisted
+//! in localStorage, keyed by profile id. The options of an API URL without a named
+//! profile are keyed by the URL itself. Named profiles have their own id and URL, so
+//! that several of them may target the same endpoint, e.g. with different tokens.
+//! They are offered for a quick switch between endpoints.
+
+use crate::hooks::persistent::UsePersistent;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Form field names used by the submission itself, which extra fields cannot override.
+pub const RESERVED_FORM_FIELDS: &[&str] = &["file", "params", "notify_email", "callback_url"];
+
+/// Options attached to a single API endpoint.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EndpointProfile {
+    /// Name given by the user, listing the endpoint among the saved profiles.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// API URL of a named profile. Empty for the options of an API URL without a
+    /// named profile, keyed by the URL itself.
+    #[serde(default)]
+    pub api_url: String,
+    /// Additional key/value fields appended to the transcription form data.
+    #[serde(default)]
+    pub extra_form_fields: Vec<(String, String)>,
+    /// Bearer token sent with every request, for servers with authorization enabled.
+    #[serde(default)]
+    pub api_token: Option<Token>,
+    /// HTTP Basic credentials, for endpoints behind an authenticating reverse proxy.
+    #[serde(default)]
+    pub basic_auth: Option<BasicAuth>,
+    /// Additional HTTP headers sent with every request (e.g. `X-Api-Key`, tenant IDs).
+    #[serde(default)]
+    pub custom_headers: Vec<(String, String)>,
+    /// Result of the last reverse-proxy upload limit probe.
+    #[serde(default)]
+    pub upload_limit_probe: Option<UploadLimitProbe>,
+    /// Whether a SHA-256 checksum of the uploaded file is sent, for servers verifying integrity.
+    #[serde(default)]
+    pub send_checksum: bool,
+    /// Endpoint receiving transcription quality feedback, if the deployment collects it.
+    #[serde(default)]
+    pub feedback_url: Option<String>,
+    /// Prometheus metrics endpoint of the server, for self-hosted monitoring.
+    #[serde(default)]
+    pub metrics_url: Option<String>,
+    /// Token with admin scope, enabling the server queue panel.
+    #[serde(default)]
+    pub admin_token: Option<Token>,
+    /// Email address or webhook URL notified of finished jobs, for servers supporting callbacks.
+    #[serde(default)]
+    pub notification_target: Option<String>,
+}
+
+/// Upload sizes observed while probing the effective upload limit of an endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UploadLimitProbe {
+    /// Largest probe body that went through.
+    pub accepted_up_to: Option<u64>,
+    /// Smallest probe body that was rejected, if any.
+    pub rejected_at: Option<u64>,
+    pub probed_at: DateTime<Utc>,
+}
+
+/// HTTP Basic authentication credentials.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BasicAuth {
+    pub username: String,
+    pub password: String,
+}
+
+/// Credentials must never end up in logs: the password is redacted.
+impl fmt::Debug for BasicAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BasicAuth")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Bearer token granting access to the API or to its admin endpoints.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Token(pub String);
+
+/// Like credentials, tokens are redacted from logs.
+impl fmt::Debug for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Token(<redacted>)")
+    }
+}
+
+impl EndpointProfile {
+    /// Returns the extra form fields that can actually be sent:
+    /// fields with an empty or reserved name are skipped.
+    pub fn sendable_form_fields(&self) -> Vec<(String, String)> {
+        self.extra_form_fields
+            .iter()
+            .filter(|(key, _)| {
+                let key = key.trim();
+                !key.is_empty() && !RESERVED_FORM_FIELDS.contains(&key)
+            })
+            .map(|(key, value)| (key.trim().to_string(), value.clone()))
+            .collect()
+    }
+
+    /// Returns the custom headers that can actually be sent:
+    /// headers with an empty name are skipped.
+    pub fn sendable_headers(&self) -> Vec<(String, String)> {
+        self.custom_headers
+            .iter()
+            .filter(|(name, _)| !name.trim().is_empty())
+            .map(|(name, value)| (name.trim().to_string(), value.clone()))
+            .collect()
+    }
+}
+
+/// All endpoint profiles, keyed by profile id.
+pub type EndpointProfiles = BTreeMap<String, EndpointProfile>;
+
+/// The API URL of a profile: its own, or its id for the options of an unnamed URL.
+fn url_of<'a>(id: &'a str, profile: &'a EndpointProfile) -> &'a str {
+    if profile.api_url.is_empty() {
+        id
+    } else {
+        &profile.api_url
+    }
+}
+
+/// The id of the profile used for `api_url`: the `selected` profile if it targets that
+/// URL, otherwise the options of the URL, otherwise its first named profile. The URL
+/// itself when no profile targets it, for its options to be saved on the first change.
+pub fn profile_id_for(
+    profiles: &EndpointProfiles,
+    selected: Option<&str>,
+    api_url: &str,
+) -> String {
+    let selected = selected.filter(|id| {
+        profiles
+            .get(*id)
+            .is_some_and(|profile| url_of(id, profile) == api_url)
+    });
+    if let Some(id) = selected {
+        return id.to_string();
+    }
+    if profiles.contains_key(api_url) {
+        return api_url.to_string();
+    }
+    named_profiles(profiles)
+        .into_iter()
+        .find(|(_, profile)| profile.api_url == api_url)
+        .map_or_else(|| api_url.to_string(), |(id, _)| id)
+}
+
+/// Returns the profile used for `api_url`, see [`profile_id_for`], or the default
+/// profile if none was saved.
+pub fn profile_for(
+    profiles: &EndpointProfiles,
+    selected: Option<&str>,
+    api_url: &str,
+) -> EndpointProfile {
+    let id = profile_id_for(profiles, selected, api_url);
+    profiles.get(&id).cloned().unwrap_or_default()
+}
+
+/// Applies a change to the profile `id` and persists all profiles.
+/// A missing profile is created, as the options of the API URL `id`.
+pub fn update_profile(
+    mut profiles: UsePersistent<EndpointProfiles>,
+    id: &str,
+    change: impl FnOnce(&mut EndpointProfile),
+) {
+    let mut all = profiles.get();
+    let mut profile = all.get(id).cloned().unwrap_or_default();
+    change(&mut profile);
+    all.insert(id.to_string(), profile);
+    profiles.set(all);
+}
+
+/// Removes the profile `id`, with all its options, and persists the others.
+pub fn remove_profile(mut profiles: UsePersistent<EndpointProfiles>, id: &str) {
+    let mut all = profiles.get();
+    all.remove(id);
+    profiles.set(all);
+}
+
+/// A new id for a named profile.
+pub fn new_profile_id(now: DateTime<Utc>) -> String {
+    format!("profile-{}", now.timestamp_millis())
+}
+
+/// The named profiles, as id and profile with its API URL, sorted by name.
+pub fn named_profiles(profiles: &EndpointProfiles) -> Vec<(String, EndpointProfile)> {
+    let mut named: Vec<(String, EndpointProfile)> = profiles
+        .iter()
+        .filter(|(_, profile)| profile.name.is_some())
+        .map(|(id, profile)| {
+            let api_url = url_of(id, profile).to_string();
+            (
+                id.clone(),
+                EndpointProfile {
+                    api_url,
+                    ..profile.clone()
+                },
+            )
+        })
+        .collect();
+    named.sort_by_key(|(_, profile)| profile.name.clone().unwrap_or_default().to_lowercase());
+    named
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCAL: &str = "http://localhost:8000";
+    const SHARED: &str = "https://whisper.example.org";
+
+    fn named(name: &str, api_url: &str, token: &str) -> EndpointProfile {
+        EndpointProfile {
+            name: Some(name.to_string()),
+            api_url: api_url.to_string(),
+            api_token: Some(Token(token.to_string())),
+            ..Default::default()
+        }
+    }
+
+    fn profiles() -> EndpointProfiles {
+        EndpointProfiles::from([
+            ("profile-1".to_string(), named("Team", SHARED, "team")),
+            ("profile-2".to_string(), named("Admin", SHARED, "admin")),
+            (LOCAL.to_string(), EndpointProfile::default()),
+        ])
+    }
+
+    #[test]
+    fn several_profiles_may_target_the_same_url() {
+        let profiles = profiles();
+        assert_eq!(
+            profile_id_for(&profiles, Some("profile-1"), SHARED),
+            "profile-1"
+        );
+        assert_eq!(
+            profile_id_for(&profiles, Some("profile-2"), SHARED),
+            "profile-2"
+        );
+        let token = profile_for(&profiles, Some("profile-1"), SHARED).api_token;
+        assert_eq!(token, Some(Token("team".to_string())));
+    }
+
+    #[test]
+    fn a_selection_for_another_url_falls_back_to_the_url() {
+        let profiles = profiles();
+        assert_eq!(profile_id_for(&profiles, Some("profile-1"), LOCAL), LOCAL);
+        assert_eq!(profile_id_for(&profiles, None, SHARED), "profile-2");
+        assert_eq!(
+            profile_id_for(&profiles, Some("gone"), "http://other"),
+            "http://other"
+        );
+    }
+
+    #[test]
+    fn named_profiles_are_sorted_by_name_with_their_url() {
+        let mut profiles = profiles();
+        profiles.insert(
+            LOCAL.to_string(),
+            EndpointProfile {
+                name: Some("local".to_string()),
+                ..Default::default()
+            },
+        );
+        let named: Vec<(String, String)> = named_profiles(&profiles)
+            .into_iter()
+            .map(|(id, profile)| (id, profile.api_url))
+            .collect();
+        assert_eq!(
+            named,
+            vec![
+                ("profile-2".to_string(), SHARED.to_string()),
+                (LOCAL.to_string(), LOCAL.to_string()),
+                ("profile-1".to_string(), SHARED.to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Progressive web application
+//! Registers the service worker caching the application shell, so that Leontine can
+//! be installed and loads offline, and relays the browser's install prompt so the
+//! interface can offer the installation itself.
+
+use crate::config::SERVICE_WORKER_PATH;
+use crate::document::{eval, Eval};
+use log::warn;
+
+/// Registers the service worker, where the browser supports it.
+pub fn register_service_worker() {
+    let _ = eval(&format!(
+        r#"
+        if ('serviceWorker' in navigator) {{
+            navigator.serviceWorker.register('{}').catch((e) => console.warn('Service worker registration failed:', e));
+        }}
+        "#,
+        SERVICE_WORKER_PATH
+    ));
+}
+
+/// Keeps the install prompt of the browser for later, and reports whether the
+/// application can be installed: `true` when the prompt is available, `false`
+/// once the application is installed.
+const WATCH_SCRIPT: &str = r#"
+    if (window.leontineInstallPrompt) dioxus.send(true);
+    window.addEventListener('beforeinstallprompt', (event) => {
+        event.preventDefault();
+        window.leontineInstallPrompt = event;
+        dioxus.send(true);
+    });
+    window.addEventListener('appinstalled', () => {
+        window.leontineInstallPrompt = null;
+        dioxus.send(false);
+    });
+    await new Promise(() => {});
+"#;
+
+/// Starts watching the install prompt; receive `bool` values from the returned channel.
+pub fn watch_install_prompt() -> Eval {
+    eval(WATCH_SCRIPT)
+}
+
+/// Shows the install prompt kept by `watch_install_prompt`.
+/// Returns whether the user accepted the installation.
+pub async fn prompt_install() -> bool {
+    let result = eval(
+        r#"
+        const prompt = window.leontineInstallPrompt;
+        if (!prompt) return false;
+        window.leontineInstallPrompt = null;
+        prompt.prompt();
+        const choice = await prompt.userChoice;
+        return choice.outcome === 'accepted';
+        "#,
+    )
+    .join::<bool>()
+    .await;
+    result.unwrap_or_else(|e| {
+        warn!("Failed to show the install prompt: {:?}", e);
+        false
+    })
+}
@@ -0,0 +1,83 @@
+//! Runtime configuration
+//! Options set per deployment, in a JSON file served next to the application,
+//! so that institutions can adapt it without rebuilding it. The file is optional:
+//! without it, the defaults apply.
+
+use crate::config::RUNTIME_CONFIG_PATH;
+use gloo_net::http::Request;
+use log::{info, warn};
+use serde::Deserialize;
+
+/// The options of the deployment.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct RuntimeConfig {
+    /// Consent asked before uploading recordings, if the deployment requires it.
+    #[serde(default)]
+    pub consent: Option<ConsentConfig>,
+    /// File types accepted for transcription, as in the HTML `accept` attribute,
+    /// if the deployment restricts or extends the default ones.
+    #[serde(default)]
+    pub accepted_file_types: Option<Vec<String>>,
+    /// Maximum number of submissions per window, if the deployment changes the default
+    /// one (0 disables the throttle).
+    #[serde(default)]
+    pub max_submissions: Option<usize>,
+    /// Length of the submission throttle window in seconds, if the deployment changes
+    /// the default one.
+    #[serde(default)]
+    pub submission_window_secs: Option<i64>,
+    /// Duration in seconds above which recordings are suggested to be split, if the
+    /// deployment changes the default one.
+    #[serde(default)]
+    pub max_recommended_duration_secs: Option<f64>,
+}
+
+/// A statement users must accept before uploading a recording.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ConsentConfig {
+    /// The statement, e.g. that the user has the right to upload the recording.
+    pub text: String,
+    /// Whether consent is asked for every submission, rather than once.
+    #[serde(default)]
+    pub every_submission: bool,
+    /// Revision of the statement: changing it asks for consent again.
+    /// The text itself is used when absent.
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+impl ConsentConfig {
+    /// The revision of the statement accepted by the user.
+    pub fn revision(&self) -> &str {
+        self.version.as_deref().unwrap_or(&self.text)
+    }
+}
+
+/// Loads the runtime configuration of the deployment.
+/// A missing or invalid file leaves the defaults.
+pub async fn load() -> RuntimeConfig {
+    let response = match Request::get(RUNTIME_CONFIG_PATH).send().await {
+        Ok(response) if response.ok() => response,
+        Ok(response) => {
+            info!(
+                "No runtime configuration ({}), using the defaults",
+                response.status()
+            );
+            return RuntimeConfig::default();
+        }
+        Err(e) => {
+            warn!("Failed to load the runtime configuration: {}", e);
+            return RuntimeConfig::default();
+        }
+    };
+    match response.json::<RuntimeConfig>().await {
+        Ok(config) => {
+            info!("Runtime configuration loaded: {:?}", config);
+            config
+        }
+        Err(e) => {
+            warn!("Invalid runtime configuration, using the defaults: {}", e);
+            RuntimeConfig::default()
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! Badge service
+//! Counts the completed transcriptions whose result was not opened yet on the badge of
+//! the application icon. A result counts as opened once the panel displays it.
+
+use crate::badge;
+use crate::history;
+use crate::state::{AppState, TranscriptionUiStatus};
+use dioxus::prelude::*;
+
+/// Starts the badge service. Must be called once, from the `App` component.
+pub fn use_app_badge(app_state: AppState) {
+    // --- Mark the displayed result as viewed ---
+    use_effect(move || {
+        let TranscriptionUiStatus::Completed { job_id, .. } =
+            &*app_state.transcription_ui_status.read()
+        else {
+            return;
+        };
+        let mut history = app_state.history;
+        let current = history.get();
+        if history::find(&current, job_id).is_some_and(|entry| entry.unviewed) {
+            history.set(history::mark_viewed(&current, job_id));
+        }
+    });
+
+    // --- Show the number of unviewed results ---
+    let unviewed = use_memo(move || {
+        app_state
+            .history
+            .get()
+            .iter()
+            .filter(|entry| entry.unviewed)
+            .count()
+    });
+    use_effect(move || badge::set(unviewed()));
+}
@@ -0,0 +1,319 @@
+//! Batch queue service
+//! Submits the files of a queue one after the other, through the same steps as a single
+//! submission. At most one file is uploaded at a time; the next one waits until fewer
+//! jobs of the queue are running than allowed: one in sequential mode, or the number of
+//! jobs the server processes concurrently. The jobs are then monitored like any other,
+//! and the status of each file is derived from the active jobs and the history.
+//! A running queue can be paused, to free the bandwidth for a while: no further file is
+//! sent, and the queue resumes where it stopped, with the options it was started with.
+
+use crate::api::JobStatus;
+use crate::config::BATCH_CHECK_INTERVAL_MS;
+use crate::dioxus_elements::FileEngine;
+use crate::errors::AppError;
+use crate::history::{self, HistoryEntry};
+use crate::media;
+use crate::services::submission::{self, SubmissionRequest, SubmissionState, UploadProgress};
+use crate::state::AppState;
+use crate::throttle;
+use chrono::Utc;
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use log::{info, warn};
+use std::sync::Arc;
+
+/// The task submitting the files of the queue, if running.
+static TASK: GlobalSignal<Option<Task>> = Signal::global(|| None);
+
+/// The options the queue runs with, and whether it runs files in parallel.
+/// They are kept while the queue is paused.
+static OPTIONS: GlobalSignal<Option<(SubmissionRequest, bool)>> = Signal::global(|| None);
+
+/// A file of the batch queue.
+#[derive(Clone)]
+pub struct BatchItem {
+    /// Identifies the file in the queue.
+    pub id: usize,
+    pub file: Arc<dyn FileEngine>,
+    pub file_name: String,
+    pub size: Option<u64>,
+    pub state: BatchItemState,
+}
+
+/// Where a file of the queue stands on the client side.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchItemState {
+    /// Not submitted yet.
+    Waiting,
+    /// Being uploaded, with the progress published in `AppState::submission`.
+    Uploading,
+    /// Submitted as the job with this id.
+    Submitted(String),
+    /// Refused on selection, or its submission failed.
+    Failed(AppError),
+}
+
+/// Where a file of the queue stands, as shown by the panel.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchItemStatus {
+    Waiting,
+    Uploading(Option<UploadProgress>),
+    /// Queued on the server, at this position when known.
+    Queued(Option<u32>),
+    Processing,
+    Done,
+    /// Failed with this error, `None` when the job failed on the server.
+    Failed(Option<AppError>),
+}
+
+impl BatchItemStatus {
+    /// Whether the file will not change status anymore.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, BatchItemStatus::Done | BatchItemStatus::Failed(_))
+    }
+}
+
+/// Adds a file to the queue, with its size, and the reason it was refused if it was.
+pub fn add(
+    app_state: AppState,
+    file: Arc<dyn FileEngine>,
+    size: Option<u64>,
+    refusal: Option<AppError>,
+) {
+    let Some(file_name) = file.files().first().cloned() else {
+        return;
+    };
+    let mut batch = app_state.batch;
+    let mut items = batch.write();
+    let id = items.iter().map(|item| item.id + 1).max().unwrap_or(0);
+    items.push(BatchItem {
+        id,
+        file,
+        file_name,
+        size,
+        state: refusal.map_or(BatchItemState::Waiting, BatchItemState::Failed),
+    });
+}
+
+/// Removes a file not submitted yet from the queue.
+pub fn remove(app_state: AppState, id: usize) {
+    let mut batch = app_state.batch;
+    batch
+        .write()
+        .retain(|item| item.id != id || matches!(item.state, BatchItemState::Submitted(_)));
+}
+
+/// Stops the queue and empties it. The jobs already submitted keep being monitored.
+pub fn clear(app_state: AppState) {
+    stop(app_state);
+    let mut batch = app_state.batch;
+    batch.write().clear();
+}
+
+/// Whether the files of the queue are being submitted.
+pub fn is_running() -> bool {
+    TASK.read().is_some()
+}
+
+/// Whether the queue is paused, waiting to be resumed.
+pub fn is_paused() -> bool {
+    TASK.read().is_none() && OPTIONS.read().is_some()
+}
+
+/// Starts submitting the waiting files of the queue with the options of `options`,
+/// at most as many running at once as the server processes when `parallel`, one
+/// after the other otherwise. Returns whether it started.
+pub fn start(app_state: AppState, options: SubmissionRequest, parallel: bool) -> bool {
+    if TASK.peek().is_some() {
+        warn!("The batch queue is already running.");
+        return false;
+    }
+    *OPTIONS.write() = Some((options.clone(), parallel));
+    let task = spawn_forever(async move {
+        run(app_state, options, parallel).await;
+        *TASK.write() = None;
+        *OPTIONS.write() = None;
+    });
+    *TASK.write() = task;
+    true
+}
+
+/// Stops submitting the files of the queue. A file being uploaded waits again.
+pub fn stop(app_state: AppState) {
+    *OPTIONS.write() = None;
+    let Some(task) = TASK.write().take() else {
+        return;
+    };
+    info!("Stopping the batch queue.");
+    cancel(app_state, task);
+}
+
+/// Pauses the queue: the upload in flight is aborted, to be sent again on resume, and
+/// no further file is sent. The files already submitted keep their jobs.
+pub fn pause(app_state: AppState) {
+    let Some(task) = TASK.write().take() else {
+        return;
+    };
+    info!("Pausing the batch queue.");
+    cancel(app_state, task);
+}
+
+/// Resumes a paused queue with the options it was started with. Returns whether it resumed.
+pub fn resume(app_state: AppState) -> bool {
+    if TASK.peek().is_some() {
+        return false;
+    }
+    let Some((options, parallel)) = OPTIONS.peek().clone() else {
+        return false;
+    };
+    info!("Resuming the batch queue.");
+    start(app_state, options, parallel)
+}
+
+/// Cancels the task submitting the files of the queue; the file being uploaded waits again.
+fn cancel(app_state: AppState, task: Task) {
+    task.cancel();
+    let mut batch = app_state.batch;
+    for item in batch.write().iter_mut() {
+        if item.state == BatchItemState::Uploading {
+            item.state = BatchItemState::Waiting;
+        }
+    }
+    let mut submission = app_state.submission;
+    submission.set(SubmissionState::Idle);
+}
+
+/// Where a file of the queue stands, from the jobs being monitored and the history.
+pub fn status(app_state: AppState, item: &BatchItem) -> BatchItemStatus {
+    match &item.state {
+        BatchItemState::Waiting => BatchItemStatus::Waiting,
+        BatchItemState::Uploading => match *app_state.submission.read() {
+            SubmissionState::Uploading(progress) => BatchItemStatus::Uploading(progress),
+            _ => BatchItemStatus::Uploading(None),
+        },
+        BatchItemState::Failed(error) => BatchItemStatus::Failed(Some(error.clone())),
+        BatchItemState::Submitted(job_id) if is_active(app_state, job_id) => {
+            match app_state.job_states.read().get(job_id) {
+                Some(Ok(state)) if state.status == JobStatus::Processing => {
+                    BatchItemStatus::Processing
+                }
+                Some(Ok(state)) => BatchItemStatus::Queued(state.queue_position),
+                _ => BatchItemStatus::Queued(None),
+            }
+        }
+        BatchItemState::Submitted(job_id) => {
+            match history::find(&app_state.history.get(), job_id) {
+                Some(entry) if entry.result.is_some() => BatchItemStatus::Done,
+                _ => BatchItemStatus::Failed(None),
+            }
+        }
+    }
+}
+
+/// The history entries of the files of the queue transcribed successfully, in queue order.
+pub fn completed_entries(app_state: AppState) -> Vec<HistoryEntry> {
+    let history = app_state.history.get();
+    app_state
+        .batch
+        .read()
+        .iter()
+        .filter_map(|item| match &item.state {
+            BatchItemState::Submitted(job_id) => history::find(&history, job_id),
+            _ => None,
+        })
+        .filter(|entry| entry.result.is_some())
+        .cloned()
+        .collect()
+}
+
+/// Submits the waiting files in turn, waiting for a free slot before each.
+async fn run(app_state: AppState, options: SubmissionRequest, parallel: bool) {
+    let batch = app_state.batch;
+    let mut submission = app_state.submission;
+    loop {
+        let next = batch
+            .peek()
+            .iter()
+            .find(|item| item.state == BatchItemState::Waiting)
+            .cloned();
+        let Some(item) = next else {
+            info!("All the files of the batch queue were submitted.");
+            return;
+        };
+        if !can_submit(app_state, parallel) {
+            TimeoutFuture::new(BATCH_CHECK_INTERVAL_MS).await;
+            continue;
+        }
+
+        set_state(app_state, item.id, BatchItemState::Uploading);
+        submission.set(SubmissionState::Uploading(None));
+        let duration = media::probe_duration(&item.file).await;
+        let request = options.for_file(item.file.clone(), item.size, duration);
+        let outcome = submission::submit_jobs(app_state, &request).await;
+        submission.set(SubmissionState::Idle);
+        set_state(
+            app_state,
+            item.id,
+            match outcome {
+                Ok((job_ids, _)) => match job_ids.into_iter().next() {
+                    Some(job_id) => BatchItemState::Submitted(job_id),
+                    None => BatchItemState::Waiting,
+                },
+                Err(e) => BatchItemState::Failed(e),
+            },
+        );
+    }
+}
+
+/// Whether the next file can be submitted: the server accepts submissions, the
+/// submission limit is not reached, and fewer jobs of the queue are running than allowed.
+fn can_submit(app_state: AppState, parallel: bool) -> bool {
+    let status = app_state.api_connection_status.peek();
+    if !status.accepts_submissions()
+        || throttle::retry_after(
+            &app_state.submission_log.get(),
+            app_state.submission_limit(),
+            Utc::now(),
+        )
+        .is_some()
+    {
+        return false;
+    }
+    let max_running = if parallel {
+        status
+            .api_status()
+            .map(|status| status.processing.max_concurrent_jobs as usize)
+            .unwrap_or(1)
+            .max(1)
+    } else {
+        1
+    };
+    let running = app_state
+        .batch
+        .peek()
+        .iter()
+        .filter(|item| match &item.state {
+            BatchItemState::Uploading => true,
+            BatchItemState::Submitted(job_id) => is_active(app_state, job_id),
+            _ => false,
+        })
+        .count();
+    running < max_running
+}
+
+/// Whether a job is still monitored.
+fn is_active(app_state: AppState, job_id: &str) -> bool {
+    app_state
+        .active_jobs
+        .get()
+        .iter()
+        .any(|job| job.job_id == job_id)
+}
+
+fn set_state(app_state: AppState, id: usize, state: BatchItemState) {
+    let mut batch = app_state.batch;
+    let mut items = batch.write();
+    if let Some(item) = items.iter_mut().find(|item| item.id == id) {
+        item.state = state;
+    }
+}
@@ -0,0 +1,87 @@
+//! Cleanup service
+//! Periodically drops the history entries the retention policy no longer keeps,
+//! and permanently deletes the trashed entries whose retention period is over,
+//! along with the recording handles and transcript drafts of the deleted entries.
+
+use crate::config::CLEANUP_INTERVAL_MS;
+use crate::drafts;
+use crate::history;
+use crate::media::handles;
+use crate::state::AppState;
+use chrono::Utc;
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use log::info;
+
+/// Starts the cleanup service. Must be called once, from the `App` component.
+/// A first cleanup runs on load, then every `CLEANUP_INTERVAL_MS`.
+pub fn use_cleanup(app_state: AppState) {
+    use_hook(move || {
+        spawn(async move {
+            loop {
+                clean(app_state);
+                TimeoutFuture::new(CLEANUP_INTERVAL_MS).await;
+            }
+        });
+    });
+}
+
+/// Runs one cleanup of the history and the trash.
+fn clean(app_state: AppState) {
+    let now = Utc::now();
+
+    let mut history = app_state.history;
+    let current = history.get();
+    let kept = history::apply_retention(&current, &app_state.history_retention.get(), now);
+    if kept.len() != current.len() {
+        info!(
+            "Removed {} expired history entries",
+            current.len() - kept.len()
+        );
+        history.set(kept);
+    }
+
+    let mut trash = app_state.trash;
+    let current = trash.get();
+    let kept = history::purge_trash(&current, now);
+    if kept.len() != current.len() {
+        info!(
+            "Permanently deleted {} trashed history entries",
+            current.len() - kept.len()
+        );
+        trash.set(kept);
+    }
+
+    let kept_entries: Vec<_> = history
+        .get()
+        .into_iter()
+        .chain(trash.get().into_iter().map(|trashed| trashed.entry))
+        .collect();
+
+    let mut transcript_drafts = app_state.transcript_drafts;
+    let current = transcript_drafts.get();
+    let kept_job_ids = kept_entries
+        .iter()
+        .map(|entry| entry.job.job_id.clone())
+        .collect();
+    let kept = drafts::retain(&current, &kept_job_ids);
+    if kept.len() != current.len() {
+        info!(
+            "Dropped {} drafts of deleted history entries",
+            current.len() - kept.len()
+        );
+        transcript_drafts.set(kept);
+    }
+
+    let kept_job_ids = kept_entries
+        .iter()
+        .filter(|entry| {
+            entry
+                .audio
+                .as_ref()
+                .is_some_and(|audio| audio.handle_stored)
+        })
+        .map(|entry| entry.job.job_id.clone())
+        .collect();
+    handles::prune(kept_job_ids);
+}
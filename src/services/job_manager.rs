@@ -0,0 +1,596 @@
+//! Job manager service
+//! Monitors every submitted transcription job until it finishes. Each job is monitored
+//! by its own terminable task, so that a slow or failing job never holds the others
+//! back, unless the server answers for several jobs at once: they are then polled
+//! together by a single task. Components never poll themselves: they only read
+//! `active_jobs`, `job_states` and `transcription_ui_status`, and go through this
+//! module to cancel or resume a job.
+//! Each job is polled on the API it was submitted to, with the profile of that API,
+//! whatever API is in use when it is polled.
+//! When several tabs are open, only one of them polls; see `tab_coordinator`.
+
+use crate::api::{
+    self, events, http, ApiError, JobState, JobStatus, OutputFormat, TranscriptionJob,
+};
+use crate::components::toast;
+use crate::config::{
+    ADAPTIVE_POLL_MAX_FACTOR, ADAPTIVE_POLL_QUEUE_STEP, MAX_CONSECUTIVE_POLL_FAILURES,
+};
+use crate::errors::{AppError, Severity};
+use crate::history;
+use crate::notifications;
+use crate::postprocess;
+use crate::profile::{self, EndpointProfile};
+use crate::services::{submission, tab_coordinator};
+use crate::state::{AppState, TranscriptionUiStatus};
+use crate::throughput;
+use crate::transcript::{self, export};
+use chrono::Utc;
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use log::{error, info, warn};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+/// Starts the job manager. Must be called once, from the `App` component.
+pub fn use_job_manager(app_state: AppState) {
+    let mut ui_status = app_state.transcription_ui_status;
+    let mut job_states = app_state.job_states;
+    // Polling task of each polled job, by job id.
+    let mut tasks: Signal<HashMap<String, Task>> = use_signal(HashMap::new);
+    // Task polling several jobs at once, with the ids of its jobs.
+    let mut batch_task: Signal<Option<(HashSet<String>, Task)>> = use_signal(|| None);
+
+    let is_api_available = app_state.is_api_available;
+    let is_leader = tab_coordinator::use_tab_coordinator(app_state);
+
+    // --- Resume monitoring of persisted jobs on load ---
+    // The monitoring view shows even offline; polling waits for the API.
+    use_effect(move || {
+        if !app_state.active_jobs.get().is_empty()
+            && *ui_status.peek() == TranscriptionUiStatus::Idle
+        {
+            info!("Found active jobs on load, starting monitoring.");
+            ui_status.set(TranscriptionUiStatus::Monitoring);
+        }
+    });
+
+    // --- Leave the monitoring view once no job is left ---
+    use_effect(move || {
+        if app_state.active_jobs.get().is_empty()
+            && *ui_status.peek() == TranscriptionUiStatus::Monitoring
+        {
+            ui_status.set(TranscriptionUiStatus::Idle);
+        }
+    });
+
+    // --- Stop monitoring when the API becomes unreachable ---
+    // The jobs stay in the monitoring view, and are polled again once the API is back.
+    // A submission in flight is cancelled, so that it cannot register a job once the
+    // panel is idle.
+    use_effect(move || {
+        if !*is_api_available.read() && ui_status.peek().is_busy() {
+            info!("API unavailable, pausing job monitoring.");
+            if *ui_status.peek() == TranscriptionUiStatus::Submitting {
+                submission::cancel(app_state);
+            }
+            job_states.write().clear();
+        }
+    });
+
+    // --- Start or terminate the polling tasks ---
+    // Active jobs are polled while the API is reachable, except stalled ones, by the
+    // leading tab only. Several jobs of the API in use are polled together when it can
+    // answer for all of them at once: the capabilities of other APIs are not known.
+    use_effect(move || {
+        let jobs: Vec<TranscriptionJob> = if *is_api_available.read() && is_leader() {
+            let stalled = app_state.stalled_jobs.read();
+            app_state
+                .active_jobs
+                .get()
+                .into_iter()
+                .filter(|job| !stalled.contains(&job.job_id))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let current_api = app_state.api_url.get();
+        let mut batched: HashSet<String> = jobs
+            .iter()
+            .filter(|job| is_on_api(job, &current_api))
+            .map(|job| job.job_id.clone())
+            .collect();
+        if batched.len() < 2 || !supports_batch(app_state) {
+            batched.clear();
+        }
+
+        let mut batch_task = batch_task.write();
+        if batch_task
+            .as_ref()
+            .is_some_and(|(job_ids, _)| *job_ids != batched)
+        {
+            if let Some((_, task)) = batch_task.take() {
+                info!("Stopping the batch polling task.");
+                task.cancel();
+            }
+        }
+        if !batched.is_empty() && batch_task.is_none() {
+            info!("Starting the batch polling task of {} jobs.", batched.len());
+            let task = spawn(poll_batch(
+                app_state,
+                current_api,
+                batched.iter().cloned().collect(),
+            ));
+            *batch_task = Some((batched.clone(), task));
+        }
+
+        let polled: HashSet<String> = jobs
+            .into_iter()
+            .map(|job| job.job_id)
+            .filter(|job_id| !batched.contains(job_id))
+            .collect();
+        let mut tasks = tasks.write();
+        tasks.retain(|job_id, task| {
+            let keep = polled.contains(job_id);
+            if !keep {
+                info!("Stopping polling task of job {}.", job_id);
+                task.cancel();
+            }
+            keep
+        });
+        for job_id in polled {
+            if let Entry::Vacant(vacant) = tasks.entry(job_id) {
+                info!("Starting polling task of job {}.", vacant.key());
+                let task = spawn(poll_job(app_state, vacant.key().clone()));
+                vacant.insert(task);
+            }
+        }
+    });
+}
+
+/// Monitors a job until it finishes or stalls: from its event stream when the server
+/// offers one, polling otherwise, or once the stream is closed.
+/// A job deep in the queue is polled less often, and more often as it nears the front.
+/// After a change of status, the next poll comes at the base interval.
+async fn poll_job(app_state: AppState, job_id: String) {
+    let on_current_api =
+        find_job(app_state, &job_id).is_some_and(|job| is_on_api(&job, &app_state.api_url.get()));
+    if on_current_api && supports_events(app_state) {
+        if !follow_events(app_state, &job_id).await {
+            return;
+        }
+        info!("Event stream of job {} closed, polling it instead.", job_id);
+    }
+    let mut failures = 0;
+    let mut last_status = None;
+    while poll_once(app_state, &job_id, &mut failures).await {
+        let state = app_state
+            .job_states
+            .peek()
+            .get(&job_id)
+            .and_then(|state| state.as_ref().ok().cloned());
+        let status = state.as_ref().map(|state| state.status.clone());
+        let factor = if status != last_status {
+            1
+        } else {
+            adaptive_factor(state.as_ref())
+        };
+        last_status = status;
+        TimeoutFuture::new(app_state.job_poll_interval_ms() * factor).await;
+    }
+}
+
+/// Polls several jobs of `api_url` in one request per round, until none is left to poll.
+/// Jobs missing from an answer are polled on their own, which tells whether the
+/// server lost them. The interval is the shortest of the jobs' adaptive intervals.
+async fn poll_batch(app_state: AppState, api_url: String, mut job_ids: Vec<String>) {
+    let mut failures: HashMap<String, u32> = HashMap::new();
+    while !job_ids.is_empty() {
+        let profile = profile::profile_for(
+            &app_state.endpoint_profiles.get(),
+            app_state.profile_id.get().as_deref(),
+            &api_url,
+        );
+        let result = api::get_jobs_status(&api_url, &profile, &job_ids).await;
+        let mut still_polled = Vec::new();
+        for job_id in job_ids {
+            let job_failures = failures.entry(job_id.clone()).or_default();
+            let monitored = match &result {
+                Ok(states) => match states.get(&job_id) {
+                    Some(state) => find_job(app_state, &job_id).is_some_and(|job| {
+                        publish(app_state, &job, Ok(state.clone()), job_failures)
+                    }),
+                    None => poll_once(app_state, &job_id, job_failures).await,
+                },
+                Err(e) => find_job(app_state, &job_id)
+                    .is_some_and(|job| publish(app_state, &job, Err(e.clone()), job_failures)),
+            };
+            if monitored {
+                still_polled.push(job_id);
+            }
+        }
+        job_ids = still_polled;
+        let factor = {
+            let job_states = app_state.job_states.peek();
+            job_ids
+                .iter()
+                .map(|job_id| {
+                    let state = job_states.get(job_id).and_then(|state| state.as_ref().ok());
+                    adaptive_factor(state)
+                })
+                .min()
+                .unwrap_or(1)
+        };
+        TimeoutFuture::new(app_state.job_poll_interval_ms() * factor).await;
+    }
+}
+
+/// Factor applied to the polling interval of a job, given its last known state:
+/// growing with the number of jobs ahead of it in the queue.
+fn adaptive_factor(state: Option<&JobState>) -> u32 {
+    match state {
+        Some(JobState {
+            status: JobStatus::Queued,
+            queue_position: Some(position),
+            ..
+        }) => (1 + position / ADAPTIVE_POLL_QUEUE_STEP).min(ADAPTIVE_POLL_MAX_FACTOR),
+        _ => 1,
+    }
+}
+
+/// Whether the server answers for several jobs at once. Event streams take precedence.
+fn supports_batch(app_state: AppState) -> bool {
+    app_state.capabilities.peek().batch_status && !supports_events(app_state)
+}
+
+/// Whether job states can be pushed by the server: it advertises Server-Sent Events,
+/// and the endpoint needs no header, which `EventSource` cannot send.
+fn supports_events(app_state: AppState) -> bool {
+    app_state.capabilities.peek().sse && !http::has_profile_headers(&app_state.current_profile())
+}
+
+/// Follows the event stream of a job, publishing each state it pushes.
+/// Returns whether the job must still be monitored once the stream is closed.
+/// Only used for jobs of the API in use, whose capabilities are known.
+async fn follow_events(app_state: AppState, job_id: &str) -> bool {
+    let Some(mut subscription) = events::subscribe_job_status(&app_state.api_url.get(), job_id)
+    else {
+        return true;
+    };
+    info!("Following the event stream of job {}.", job_id);
+    let mut failures = 0;
+    while let Some(state) = subscription.next().await {
+        let Some(job) = find_job(app_state, job_id) else {
+            return false;
+        };
+        if !publish(app_state, &job, Ok(state), &mut failures) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Polls the status of a job once and publishes the result into the shared state.
+/// Returns whether the job must be polled again.
+async fn poll_once(app_state: AppState, job_id: &str, failures: &mut u32) -> bool {
+    let Some(job) = find_job(app_state, job_id) else {
+        return false;
+    };
+    let (api_url, profile) = job_endpoint(app_state, &job);
+    let result = api::get_job_status(&api_url, &profile, job_id).await;
+    publish(app_state, &job, result, failures)
+}
+
+/// Publishes a status of a job into the shared state, finishing the job when it is over.
+/// Returns whether the job must still be monitored.
+fn publish(
+    app_state: AppState,
+    job: &TranscriptionJob,
+    result: Result<JobState, ApiError>,
+    failures: &mut u32,
+) -> bool {
+    let mut job_states = app_state.job_states;
+    let job_id = job.job_id.as_str();
+
+    match result {
+        Ok(state) => {
+            *failures = 0;
+            tab_coordinator::broadcast(job_id, &state);
+            match state.status {
+                JobStatus::Completed => {
+                    let (result_data, processed) =
+                        completed_result(app_state, job.output_format, &state);
+                    let mut history = app_state.history;
+                    history.set(history::complete(
+                        &history.get(),
+                        job_id,
+                        &result_data,
+                        processed.as_deref(),
+                        Utc::now(),
+                    ));
+                    let completed = TranscriptionUiStatus::Completed {
+                        job_id: job_id.to_string(),
+                        result: result_data,
+                        processed,
+                    };
+                    record_throughput(app_state, job);
+                    notify(app_state, job, "Transcription completed");
+                    finish(app_state, job, completed, None);
+                    false
+                }
+                JobStatus::Failed => {
+                    error!("Job {} failed on server: {:?}", job_id, state.data);
+                    let mut history = app_state.history;
+                    history.set(history::finish(&history.get(), job_id, Utc::now()));
+                    let error = AppError::JobFailed(state.data.clone());
+                    notify(app_state, job, "Transcription failed");
+                    finish(
+                        app_state,
+                        job,
+                        TranscriptionUiStatus::Error(error),
+                        Some(Severity::Error),
+                    );
+                    false
+                }
+                JobStatus::Processing => {
+                    if job.processing_since.is_none() {
+                        mark_processing(app_state, job_id);
+                    }
+                    job_states.write().insert(job_id.to_string(), Ok(state));
+                    true
+                }
+                JobStatus::Unknown(ref status) => {
+                    warn!("Job {} has an unexpected status: {}", job_id, status);
+                    job_states.write().insert(job_id.to_string(), Ok(state));
+                    true
+                }
+                JobStatus::Queued => {
+                    // Just update state and continue
+                    job_states.write().insert(job_id.to_string(), Ok(state));
+                    true
+                }
+            }
+        }
+        Err(ApiError::HttpError(404, _)) => {
+            error!("Job {} not found on server. Clearing local job.", job_id);
+            let mut history = app_state.history;
+            history.set(history::finish(&history.get(), job_id, Utc::now()));
+            finish(
+                app_state,
+                job,
+                TranscriptionUiStatus::Error(AppError::JobNotFound),
+                Some(Severity::Error),
+            );
+            false
+        }
+        Err(ApiError::Maintenance(_)) => {
+            // The job is kept on the server during maintenance: keep polling,
+            // at the reduced rate, without counting a failure.
+            info!("Server under maintenance, job {} still pending.", job_id);
+            true
+        }
+        Err(e) => {
+            // Other errors are considered transient: polling continues until
+            // too many consecutive failures, then the user may resume monitoring.
+            error!("Error polling status of job {}: {:?}", job_id, e);
+            job_states
+                .write()
+                .insert(job_id.to_string(), Err(e.clone()));
+            *failures += 1;
+            if *failures < MAX_CONSECUTIVE_POLL_FAILURES {
+                return true;
+            }
+            let focused = is_focused(app_state, job_id);
+            let mut stalled_jobs = app_state.stalled_jobs;
+            stalled_jobs.write().insert(job_id.to_string());
+            if focused {
+                let mut ui_status = app_state.transcription_ui_status;
+                ui_status.set(TranscriptionUiStatus::Error(e.into()));
+            } else {
+                toast::show_with(
+                    "Monitoring of {} stopped after repeated errors: resume it from the job list.",
+                    &[&job_title(job)],
+                    Severity::Warning,
+                );
+            }
+            false
+        }
+    }
+}
+
+/// Follows a state of a job published by the leading tab. The leader records how
+/// finished jobs ended, which reaches this tab through local storage: the panel
+/// only shows the outcome here, when it was monitoring the job.
+pub fn follow(app_state: AppState, job_id: &str, state: JobState) {
+    let mut job_states = app_state.job_states;
+    let outcome = match state.status {
+        JobStatus::Completed => {
+            let output_format = app_state
+                .active_jobs
+                .get()
+                .iter()
+                .find(|job| job.job_id == job_id)
+                .and_then(|job| job.output_format);
+            let (result, processed) = completed_result(app_state, output_format, &state);
+            TranscriptionUiStatus::Completed {
+                job_id: job_id.to_string(),
+                result,
+                processed,
+            }
+        }
+        JobStatus::Failed => TranscriptionUiStatus::Error(AppError::JobFailed(state.data)),
+        _ => {
+            job_states.write().insert(job_id.to_string(), Ok(state));
+            return;
+        }
+    };
+    let focused = is_focused(app_state, job_id);
+    clear_state(app_state, job_id);
+    if focused {
+        let mut ui_status = app_state.transcription_ui_status;
+        ui_status.set(outcome);
+    }
+}
+
+/// The result of a completed job, and its post-processed version. The pipeline runs
+/// over the text of the segments of structured results, and over plain text results
+/// requested as `output_format`. Subtitles and tables are left as they are, as the
+/// steps would break their syntax.
+fn completed_result(
+    app_state: AppState,
+    output_format: Option<OutputFormat>,
+    state: &JobState,
+) -> (String, Option<String>) {
+    let result = state
+        .data
+        .clone()
+        .unwrap_or_else(|| "Transcription completed, but no data was returned.".to_string());
+    let pipeline = app_state.postprocess_pipeline.get();
+    let processed = match transcript::parse_segments(&result) {
+        Some(segments) => postprocess::run(&pipeline, &export::plain_text(&segments)),
+        None if output_format == Some(OutputFormat::Txt) => postprocess::run(&pipeline, &result),
+        None => None,
+    };
+    (result, processed)
+}
+
+/// Cancels a job on the server, then stops monitoring it.
+pub async fn cancel(app_state: AppState, job_id: &str) -> Result<(), ApiError> {
+    let (api_url, profile) = match find_job(app_state, job_id) {
+        Some(job) => job_endpoint(app_state, &job),
+        None => (app_state.api_url.get(), app_state.current_profile()),
+    };
+    api::cancel_job(&api_url, &profile, job_id).await?;
+    let mut history = app_state.history;
+    history.set(history::finish(&history.get(), job_id, Utc::now()));
+    forget(app_state, job_id);
+    Ok(())
+}
+
+/// Resumes the monitoring of a stalled job.
+pub fn resume(app_state: AppState, job_id: &str) {
+    clear_state(app_state, job_id);
+}
+
+/// Title of a job in notifications: the file name, or the job id.
+pub fn job_title(job: &TranscriptionJob) -> String {
+    job.file_name.clone().unwrap_or_else(|| job.job_id.clone())
+}
+
+/// The API a job was submitted to, and the profile to query it with: the profile it
+/// was submitted with, while it targets that API.
+fn job_endpoint(app_state: AppState, job: &TranscriptionJob) -> (String, EndpointProfile) {
+    let api_url = job
+        .api_url
+        .clone()
+        .unwrap_or_else(|| app_state.api_url.get());
+    let profile = profile::profile_for(
+        &app_state.endpoint_profiles.get(),
+        job.profile_id.as_deref(),
+        &api_url,
+    );
+    (api_url, profile)
+}
+
+/// Whether a job was submitted to `api_url`.
+fn is_on_api(job: &TranscriptionJob, api_url: &str) -> bool {
+    job.api_url
+        .as_deref()
+        .is_none_or(|job_api| job_api.trim_end_matches('/') == api_url.trim_end_matches('/'))
+}
+
+fn find_job(app_state: AppState, job_id: &str) -> Option<TranscriptionJob> {
+    app_state
+        .active_jobs
+        .get()
+        .into_iter()
+        .find(|job| job.job_id == job_id)
+}
+
+/// Whether the transcription panel is monitoring this job.
+fn is_focused(app_state: AppState, job_id: &str) -> bool {
+    *app_state.transcription_ui_status.peek() == TranscriptionUiStatus::Monitoring
+        && app_state
+            .focused_job()
+            .is_some_and(|job| job.job_id == job_id)
+}
+
+/// Stops monitoring a finished job. The panel shows the outcome when it was monitoring
+/// this job; otherwise, a notification tells about it, with `severity` for failures.
+fn finish(
+    app_state: AppState,
+    job: &TranscriptionJob,
+    outcome: TranscriptionUiStatus,
+    severity: Option<Severity>,
+) {
+    let focused = is_focused(app_state, &job.job_id);
+    forget(app_state, &job.job_id);
+    if focused {
+        let mut ui_status = app_state.transcription_ui_status;
+        ui_status.set(outcome);
+        return;
+    }
+    match severity {
+        None => toast::show_with(
+            "Transcription of {} completed: open it from the history.",
+            &[&job_title(job)],
+            Severity::Info,
+        ),
+        Some(severity) => {
+            toast::show_with("Transcription of {} failed.", &[&job_title(job)], severity)
+        }
+    }
+}
+
+/// Announces the end of a job by a desktop notification, when enabled in the settings.
+fn notify(app_state: AppState, job: &TranscriptionJob, title: &str) {
+    if app_state.desktop_notifications.get() {
+        notifications::show(title, &job_title(job), &job.job_id);
+    }
+}
+
+/// Records when a job was first seen processing, from which its progress is estimated.
+fn mark_processing(app_state: AppState, job_id: &str) {
+    let mut active_jobs = app_state.active_jobs;
+    let mut jobs = active_jobs.get();
+    for job in jobs.iter_mut().filter(|job| job.job_id == job_id) {
+        job.processing_since = Some(Utc::now());
+    }
+    active_jobs.set(jobs);
+}
+
+/// Records the throughput of the endpoint on a completed job, when its processing was timed.
+fn record_throughput(app_state: AppState, job: &TranscriptionJob) {
+    let (Some(duration), Some(since)) = (job.media_duration, job.processing_since) else {
+        return;
+    };
+    let elapsed = (Utc::now() - since).num_milliseconds() as f64 / 1000.0;
+    let (api_url, _) = job_endpoint(app_state, job);
+    let mut processing_rates = app_state.processing_rates;
+    processing_rates.set(throughput::record(
+        &processing_rates.get(),
+        &api_url,
+        duration,
+        elapsed,
+    ));
+}
+
+/// Removes a job from the active jobs and drops its state.
+fn forget(app_state: AppState, job_id: &str) {
+    let mut active_jobs = app_state.active_jobs;
+    let jobs: Vec<TranscriptionJob> = active_jobs
+        .get()
+        .into_iter()
+        .filter(|job| job.job_id != job_id)
+        .collect();
+    active_jobs.set(jobs);
+    clear_state(app_state, job_id);
+}
+
+/// Drops the last known state of a job, and its stalled mark.
+fn clear_state(app_state: AppState, job_id: &str) {
+    let mut job_states = app_state.job_states;
+    let mut stalled_jobs = app_state.stalled_jobs;
+    job_states.write().remove(job_id);
+    stalled_jobs.write().remove(job_id);
+}
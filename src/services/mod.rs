@@ -0,0 +1,11 @@
+//! Application-level services for the Leontine application
+//! Services run for the whole lifetime of the application, independently of the
+//! components currently displayed, and publish their results into `AppState`.
+
+pub mod badge;
+pub mod batch;
+pub mod cleanup;
+pub mod job_manager;
+pub mod status_monitor;
+pub mod submission;
+pub mod tab_coordinator;
@@ -0,0 +1,100 @@
+//! API status monitor service
+//! Checks the status of the API on behalf of every component showing it. Components
+//! subscribe to the monitor instead of requesting `/status` themselves: however many
+//! subscribe, a single check is in flight at a time, at the configured interval, and
+//! checks stop while no component is subscribed.
+
+use crate::api::{self, ApiError};
+use crate::profile::EndpointProfile;
+use crate::state::ApiConnectionStatus;
+use chrono::Utc;
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use log::info;
+
+/// The status monitor, provided as a context to the components it serves.
+#[derive(Clone, Copy, PartialEq)]
+pub struct StatusMonitor {
+    status: Signal<ApiConnectionStatus>,
+    subscribers: Signal<usize>,
+}
+
+impl StatusMonitor {
+    /// Subscribes the calling component to the status for as long as it is mounted,
+    /// and returns the status.
+    pub fn use_subscription(self) -> Signal<ApiConnectionStatus> {
+        let mut subscribers = self.subscribers;
+        use_hook(move || subscribers += 1);
+        use_drop(move || subscribers -= 1);
+        self.status
+    }
+}
+
+/// Starts the monitor publishing into `status`, and provides it to the child components.
+/// `endpoint` gives the API URL and profile to check, and `interval_ms` the interval
+/// between checks; both are reactive. A new endpoint is checked right away, while a
+/// new interval applies from the next check.
+pub fn use_status_monitor(
+    status: Signal<ApiConnectionStatus>,
+    endpoint: impl Fn() -> (String, EndpointProfile) + 'static,
+    interval_ms: impl Fn() -> u32 + 'static,
+) -> StatusMonitor {
+    let subscribers = use_signal(|| 0);
+    let monitor = use_context_provider(|| StatusMonitor {
+        status,
+        subscribers,
+    });
+    let is_watched = use_memo(move || subscribers() > 0);
+    let mut task: Signal<Option<Task>> = use_signal(|| None);
+    // Endpoint of the last check, to tell a new endpoint from a new interval.
+    let mut checked: Signal<Option<(String, EndpointProfile)>> = use_signal(|| None);
+
+    use_effect(move || {
+        let endpoint = endpoint();
+        let interval_ms = interval_ms();
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+        if !is_watched() {
+            info!("No component shows the API status, pausing its checks.");
+            checked.set(None);
+            return;
+        }
+        let check_now = checked.peek().as_ref() != Some(&endpoint);
+        checked.set(Some(endpoint.clone()));
+        let (api_url, profile) = endpoint;
+        task.set(Some(spawn(async move {
+            if !check_now {
+                TimeoutFuture::new(interval_ms).await;
+            }
+            loop {
+                check(status, &api_url, &profile).await;
+                TimeoutFuture::new(interval_ms).await;
+            }
+        })));
+    });
+
+    use_drop(move || {
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+    });
+
+    monitor
+}
+
+/// Subscribes the calling component to the API status of the nearest monitor.
+pub fn use_api_status() -> Signal<ApiConnectionStatus> {
+    use_context::<StatusMonitor>().use_subscription()
+}
+
+/// Checks the status once and publishes the outcome.
+async fn check(mut status: Signal<ApiConnectionStatus>, api_url: &str, profile: &EndpointProfile) {
+    let result = if api_url.is_empty() {
+        Err(ApiError::NotConfigured)
+    } else {
+        api::get_status(api_url, profile).await
+    };
+    let next = status.peek().after_check(result, Utc::now());
+    status.set(next);
+}
@@ -0,0 +1,402 @@
+//! Submission service
+//! Uploads a file and registers the job it creates. The upload runs as a task owned by
+//! the service rather than by a component, so that the state it updates is never left
+//! halfway when the panel goes away: the panel cancels the task instead. The progress
+//! and the outcome are published in `AppState::submission` for the panel to react to.
+//! The channels of a stereo recording may be submitted as separate jobs.
+//! The batch queue submits its files through the same steps, see [`submit_jobs`].
+
+use crate::api::{
+    self, ApiError, DiarizationOptions, JobOptions, NotificationTarget, OutputFormat,
+    TranscriptionJob,
+};
+use crate::checksum;
+use crate::dioxus_elements::FileEngine;
+use crate::errors::AppError;
+use crate::history::{self, AudioReference, HistoryEntry};
+use crate::media::{self, channels::ChannelSelection, channels::StereoChannel};
+use crate::profile;
+use crate::state::{AppState, TranscriptionUiStatus};
+use crate::throttle;
+use chrono::{DateTime, Utc};
+use dioxus::prelude::*;
+use log::{error, info, warn};
+use std::future::Future;
+use std::sync::Arc;
+
+/// The submission task in flight, if any. Only one submission runs at a time.
+static TASK: GlobalSignal<Option<Task>> = Signal::global(|| None);
+
+/// Progress of an upload in flight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UploadProgress {
+    pub sent: u64,
+    pub total: u64,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UploadProgress {
+    /// Average throughput since the start of the upload, in bytes per second.
+    pub fn speed(&self) -> Option<f64> {
+        let elapsed_ms = (self.updated_at - self.started_at).num_milliseconds();
+        (elapsed_ms > 0 && self.sent > 0).then(|| self.sent as f64 * 1000.0 / elapsed_ms as f64)
+    }
+
+    /// Estimated time left, in seconds, at the current throughput.
+    pub fn remaining_secs(&self) -> Option<f64> {
+        let speed = self.speed()?;
+        Some(self.total.saturating_sub(self.sent) as f64 / speed)
+    }
+
+    /// Share of the file sent, in percent.
+    pub fn percent(&self) -> u64 {
+        if self.total == 0 {
+            return 0;
+        }
+        self.sent * 100 / self.total
+    }
+}
+
+/// Where the last submission stands.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum SubmissionState {
+    /// No submission in flight, or its outcome was handled.
+    #[default]
+    Idle,
+    /// The file is being uploaded, `None` until the first progress event.
+    Uploading(Option<UploadProgress>),
+    /// The job was created and is being monitored.
+    Submitted {
+        /// Average upload throughput, in bytes per second.
+        upload_speed: Option<f64>,
+    },
+    /// The submission failed.
+    Failed(AppError),
+}
+
+impl SubmissionState {
+    /// Whether a submission is in flight.
+    pub fn is_in_flight(&self) -> bool {
+        matches!(self, SubmissionState::Uploading(_))
+    }
+}
+
+/// A file to submit, with the options of the submission.
+#[derive(Clone)]
+pub struct SubmissionRequest {
+    pub api_url: String,
+    /// Endpoint profile of the submission, whose options and credentials are sent.
+    pub profile_id: String,
+    pub file: Arc<dyn FileEngine>,
+    /// Size of the file, kept in the history to recognize it later.
+    pub size: Option<u64>,
+    /// Duration of the media, in seconds, for the processing estimate.
+    pub duration: Option<f64>,
+    pub output_format: OutputFormat,
+    pub diarization: DiarizationOptions,
+    pub notification: Option<NotificationTarget>,
+    /// Channels of a stereo recording to transcribe separately.
+    pub channels: ChannelSelection,
+    /// SHA-256 checksum of the file, when already computed on selection.
+    pub sha256: Option<String>,
+}
+
+impl SubmissionRequest {
+    /// The same submission options, for another file transcribed as a whole.
+    pub fn for_file(
+        &self,
+        file: Arc<dyn FileEngine>,
+        size: Option<u64>,
+        duration: Option<f64>,
+    ) -> Self {
+        SubmissionRequest {
+            file,
+            size,
+            duration,
+            channels: ChannelSelection::Mixed,
+            sha256: None,
+            ..self.clone()
+        }
+    }
+}
+
+/// Starts a submission. Ignored while another one is in flight, however fast the
+/// clicks come before the panel reflects it; returns whether it started.
+pub fn start(app_state: AppState, request: SubmissionRequest) -> bool {
+    if TASK.peek().is_some() {
+        warn!("A submission is already in flight, ignoring the new request.");
+        return false;
+    }
+    let mut submission = app_state.submission;
+    let mut ui_status = app_state.transcription_ui_status;
+    submission.set(SubmissionState::Uploading(None));
+    ui_status.set(TranscriptionUiStatus::Submitting);
+    let task = spawn_forever(async move {
+        let outcome = run(app_state, request).await;
+        *TASK.write() = None;
+        submission.set(outcome);
+    });
+    *TASK.write() = task;
+    true
+}
+
+/// Cancels the submission in flight, if any, and leaves the panel idle.
+/// An upload in flight is aborted; a job already created on the server is not affected.
+pub fn cancel(app_state: AppState) {
+    let Some(task) = TASK.write().take() else {
+        return;
+    };
+    info!("Cancelling the submission in flight.");
+    task.cancel();
+    let mut submission = app_state.submission;
+    let mut ui_status = app_state.transcription_ui_status;
+    submission.set(SubmissionState::Idle);
+    if *ui_status.peek() == TranscriptionUiStatus::Submitting {
+        ui_status.set(TranscriptionUiStatus::Idle);
+    }
+}
+
+/// Submits the file, then monitors its jobs.
+async fn run(app_state: AppState, request: SubmissionRequest) -> SubmissionState {
+    info!("Transcription submission process triggered.");
+    match submit_jobs(app_state, &request).await {
+        Ok((_, upload_speed)) => {
+            let mut ui_status = app_state.transcription_ui_status;
+            ui_status.set(TranscriptionUiStatus::Monitoring);
+            SubmissionState::Submitted { upload_speed }
+        }
+        Err(e) => failed(app_state, e),
+    }
+}
+
+/// Uploads the file, or each of its channels selected, then records the jobs in the
+/// history and the active jobs. Returns the ids of the jobs created, and the average
+/// throughput of the last upload in bytes per second. The upload progress is published
+/// in `AppState::submission`, which the caller resets.
+pub async fn submit_jobs(
+    app_state: AppState,
+    request: &SubmissionRequest,
+) -> Result<(Vec<String>, Option<f64>), AppError> {
+    let channels = request.channels.channels();
+    let parts: Vec<(Arc<dyn FileEngine>, Option<StereoChannel>)> = if channels.is_empty() {
+        vec![(request.file.clone(), None)]
+    } else {
+        info!("Splitting the channels {:?} of the file.", channels);
+        match media::channels::split(&request.file, &channels).await {
+            Ok(files) => files
+                .into_iter()
+                .zip(channels.into_iter().map(Some))
+                .collect(),
+            Err(e) => {
+                error!("Splitting the channels failed: {:?}", e);
+                return Err(e.into());
+            }
+        }
+    };
+    // The channels of a recording are recognized by the checksum of the recording.
+    let source_sha256 = match parts.first() {
+        Some((_, Some(_))) => match &request.sha256 {
+            Some(sha256) => Some(sha256.clone()),
+            None => checksum::file_sha256_hex(&request.file).await,
+        },
+        _ => None,
+    };
+
+    let mut job_ids = Vec::new();
+    let mut upload_speed = None;
+    for (file, channel) in parts {
+        let size = match channel {
+            Some(_) => match file.files().first() {
+                Some(file_name) => file.file_size(file_name).await,
+                None => None,
+            },
+            None => request.size,
+        };
+        // The checksum of the selection only holds for the file uploaded as a whole.
+        let sha256 = request.sha256.clone().filter(|_| channel.is_none());
+        let uploaded =
+            upload_then_register(upload(app_state, request, &file, sha256), |mut job| {
+                job.channel = channel;
+                job.source_sha256 = source_sha256.clone();
+                job_ids.push(job.job_id.clone());
+                register(app_state, request, job, size);
+            });
+        match uploaded.await {
+            Ok(speed) => upload_speed = speed,
+            Err(e) => {
+                error!("Job submission failed: {:?}", e);
+                return Err(e.into());
+            }
+        }
+    }
+    Ok((job_ids, upload_speed))
+}
+
+/// Awaits an upload, then registers the job it created; returns the upload throughput.
+/// Nothing is registered when the task is cancelled before the server answers: the
+/// upload is then dropped, which aborts it, so that the server creates no job either.
+async fn upload_then_register(
+    upload: impl Future<Output = Result<(TranscriptionJob, Option<f64>), ApiError>>,
+    register: impl FnOnce(TranscriptionJob),
+) -> Result<Option<f64>, ApiError> {
+    let (job, upload_speed) = upload.await?;
+    register(job);
+    Ok(upload_speed)
+}
+
+/// Uploads a file with the options of the request, publishing the progress.
+/// `sha256` is the checksum of the file, if known. Returns the job created,
+/// and the average upload throughput in bytes per second.
+async fn upload(
+    app_state: AppState,
+    request: &SubmissionRequest,
+    file: &Arc<dyn FileEngine>,
+    sha256: Option<String>,
+) -> Result<(TranscriptionJob, Option<f64>), ApiError> {
+    let submission = app_state.submission;
+    let profile = profile::profile_for(
+        &app_state.endpoint_profiles.get(),
+        Some(&request.profile_id),
+        &request.api_url,
+    );
+    let started_at = Utc::now();
+    let on_progress = move |sent, total| {
+        // Signals are `Copy`: writing through a copy keeps the callback `Fn`.
+        let mut submission = submission;
+        submission.set(SubmissionState::Uploading(Some(UploadProgress {
+            sent,
+            total,
+            started_at,
+            updated_at: Utc::now(),
+        })));
+    };
+    let job = api::submit_transcription(
+        &request.api_url,
+        &profile,
+        file,
+        sha256,
+        JobOptions {
+            output_format: request.output_format,
+            diarization: request.diarization,
+            notification: request.notification.clone(),
+        },
+        on_progress,
+    )
+    .await?;
+    let upload_speed = match *submission.peek() {
+        SubmissionState::Uploading(Some(progress)) => progress.speed(),
+        _ => None,
+    };
+    Ok((job, upload_speed))
+}
+
+/// Records a submitted job in the history and the active jobs.
+/// `size` is the size of the submitted file, kept to recognize it later.
+fn register(
+    app_state: AppState,
+    request: &SubmissionRequest,
+    mut job: TranscriptionJob,
+    size: Option<u64>,
+) {
+    job.media_duration = request.duration;
+    job.api_url = Some(request.api_url.clone());
+    job.profile_id = Some(request.profile_id.clone());
+    info!("Job submitted successfully: {}", job.job_id);
+    let mut submission_log = app_state.submission_log;
+    submission_log.set(throttle::record(
+        &submission_log.get(),
+        app_state.submission_limit(),
+        Utc::now(),
+    ));
+    let mut entry = HistoryEntry::new(request.api_url.clone(), job.clone());
+    entry.audio = job.file_name.clone().map(|file_name| AudioReference {
+        file_name,
+        size,
+        handle_stored: false,
+    });
+    let mut history = app_state.history;
+    history.set(history::record(
+        &history.get(),
+        entry,
+        &app_state.history_retention.get(),
+    ));
+    let mut active_jobs = app_state.active_jobs;
+    let mut jobs = active_jobs.get();
+    jobs.push(job);
+    active_jobs.set(jobs);
+}
+
+/// Ends a failed submission. The panel keeps the file when the user can simply
+/// submit it again.
+fn failed(app_state: AppState, error: AppError) -> SubmissionState {
+    let mut ui_status = app_state.transcription_ui_status;
+    ui_status.set(if error.is_recoverable() {
+        TranscriptionUiStatus::FileSelected
+    } else {
+        TranscriptionUiStatus::Error(error.clone())
+    });
+    SubmissionState::Failed(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::http::{Abort, AbortOnDrop};
+    use futures_util::future::{self, FutureExt};
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    /// A request recording whether it was aborted.
+    struct FakeRequest(Rc<Cell<bool>>);
+
+    impl Abort for FakeRequest {
+        fn abort(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    fn job() -> TranscriptionJob {
+        TranscriptionJob {
+            job_id: "job-1".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn cancelling_before_the_answer_aborts_the_upload_and_registers_nothing() {
+        let aborted = Rc::new(Cell::new(false));
+        let registered = RefCell::new(Vec::new());
+        let guard = AbortOnDrop::new(FakeRequest(aborted.clone()));
+        let upload = async move {
+            let _guard = guard;
+            future::pending::<Result<(TranscriptionJob, Option<f64>), ApiError>>().await
+        };
+        let mut submission = Box::pin(upload_then_register(upload, |job| {
+            registered.borrow_mut().push(job.job_id)
+        }));
+        assert!(submission.as_mut().now_or_never().is_none());
+        assert!(!aborted.get());
+
+        // Cancelling the task drops the submission while the upload is in flight.
+        drop(submission);
+        assert!(aborted.get());
+        assert!(registered.borrow().is_empty());
+    }
+
+    #[test]
+    fn an_answered_upload_registers_its_job() {
+        let aborted = Rc::new(Cell::new(false));
+        let registered = RefCell::new(Vec::new());
+        let mut guard = AbortOnDrop::new(FakeRequest(aborted.clone()));
+        let upload = async move {
+            guard.answered();
+            Ok((job(), Some(1000.0)))
+        };
+        let outcome = upload_then_register(upload, |job| registered.borrow_mut().push(job.job_id))
+            .now_or_never();
+        assert_eq!(outcome.map(Result::ok), Some(Some(Some(1000.0))));
+        assert!(!aborted.get());
+        assert_eq!(*registered.borrow(), vec!["job-1".to_string()]);
+    }
+}
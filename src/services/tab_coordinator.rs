@@ -0,0 +1,149 @@
+//! Tab coordinator
+//! When Leontine is open in several tabs, a single one, the leader, monitors the jobs
+//! and broadcasts their states to the others over a `BroadcastChannel`. The leader
+//! sends heartbeats; when they stop (the tab was closed or frozen), another tab takes
+//! over. Should two tabs lead at once, the one with the greater id steps down.
+//! The outcome of finished jobs reaches the other tabs through local storage.
+
+use crate::api::JobState;
+use crate::config::{LEADER_HEARTBEAT_MS, LEADER_TIMEOUT_MS};
+use crate::document::eval;
+use crate::services::job_manager;
+use crate::state::AppState;
+use dioxus::prelude::*;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+/// Name of the channel shared by the tabs.
+const CHANNEL_NAME: &str = "leontine-jobs";
+
+/// Joins the channel, then reports the role of this tab and the states broadcast by
+/// the leader. Browsers without `BroadcastChannel` make every tab a leader.
+const COORDINATOR_SCRIPT: &str = r#"
+    const [channelName, heartbeat, timeout] = await dioxus.recv();
+    if (!('BroadcastChannel' in window)) {
+        dioxus.send({ type: 'leader', leader: true });
+        return;
+    }
+    const id = Date.now().toString(36) + Math.random().toString(36).slice(2);
+    const channel = new BroadcastChannel(channelName);
+    window.leontineChannel = channel;
+    let leader = false;
+    let lastHeartbeat = Date.now();
+    const setLeader = (value) => {
+        if (value === leader) return;
+        leader = value;
+        dioxus.send({ type: 'leader', leader });
+        if (leader) channel.postMessage({ type: 'heartbeat', id });
+    };
+    const check = () => {
+        if (leader) {
+            channel.postMessage({ type: 'heartbeat', id });
+        } else if (Date.now() - lastHeartbeat >= timeout) {
+            setLeader(true);
+        }
+    };
+    channel.onmessage = (event) => {
+        const message = event.data;
+        switch (message.type) {
+            case 'hello':
+                if (leader) channel.postMessage({ type: 'heartbeat', id });
+                break;
+            case 'heartbeat':
+                lastHeartbeat = Date.now();
+                if (leader && message.id < id) setLeader(false);
+                break;
+            case 'resign':
+                lastHeartbeat = 0;
+                check();
+                break;
+            case 'state':
+                if (!leader) dioxus.send(message);
+                break;
+        }
+    };
+    window.addEventListener('pagehide', () => {
+        if (leader) channel.postMessage({ type: 'resign' });
+    });
+    // A leader answers the greeting at once: without answer, this tab leads.
+    channel.postMessage({ type: 'hello' });
+    lastHeartbeat = Date.now() - timeout + heartbeat / 4;
+    setTimeout(check, heartbeat / 4);
+    setInterval(check, heartbeat);
+    await new Promise(() => {});
+"#;
+
+/// A message of the coordinator script.
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Message {
+    /// This tab became the leader, or stepped down.
+    Leader { leader: bool },
+    /// The leader published the state of a job.
+    State { job_id: String, state: JobState },
+}
+
+/// A job state broadcast to the other tabs.
+#[derive(Serialize)]
+struct StateMessage<'a> {
+    r#type: &'static str,
+    job_id: &'a str,
+    state: &'a JobState,
+}
+
+/// Starts coordinating with the other tabs. Returns whether this tab leads, and so
+/// must monitor the jobs; the states broadcast by the leader are followed otherwise.
+pub fn use_tab_coordinator(app_state: AppState) -> Signal<bool> {
+    let mut is_leader = use_signal(|| false);
+
+    use_hook(move || {
+        spawn(async move {
+            let mut coordinator = eval(COORDINATOR_SCRIPT);
+            if let Err(e) = coordinator.send((CHANNEL_NAME, LEADER_HEARTBEAT_MS, LEADER_TIMEOUT_MS))
+            {
+                warn!(
+                    "Failed to start the tab coordinator, monitoring jobs here: {:?}",
+                    e
+                );
+                is_leader.set(true);
+                return;
+            }
+            while let Ok(message) = coordinator.recv::<Message>().await {
+                match message {
+                    Message::Leader { leader } => {
+                        info!(
+                            "{}",
+                            if leader {
+                                "This tab now monitors the jobs."
+                            } else {
+                                "Another tab monitors the jobs."
+                            }
+                        );
+                        is_leader.set(leader);
+                    }
+                    Message::State { job_id, state } => {
+                        job_manager::follow(app_state, &job_id, state)
+                    }
+                }
+            }
+        });
+    });
+
+    is_leader
+}
+
+/// Broadcasts the state of a job to the other tabs, from the leader.
+pub fn broadcast(job_id: &str, state: &JobState) {
+    let message = StateMessage {
+        r#type: "state",
+        job_id,
+        state,
+    };
+    let Ok(message) = serde_json::to_string(&message) else {
+        return;
+    };
+    let _ = eval(&format!(
+        "window.leontineChannel?.postMessage({});",
+        message
+    ));
+}
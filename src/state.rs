@@ -2,11 +2,74 @@
 //! This module defines the central `AppState` struct that holds all shared signals
 //! and is provided to the entire application via Dioxus context.
 
-use crate::api::{ApiError, ApiStatus, JobState, TranscriptionJob};
-use crate::hooks::persistent::UsePersistent;
-use chrono::{self, prelude::*};
+use crate::api::capabilities::Capabilities;
+use crate::api::{
+    ApiError, ApiStatus, DiarizationOptions, JobState, MaintenanceInfo, OutputFormat,
+    TranscriptionJob,
+};
+use crate::config::{
+    API_STATUS_CHECK_INTERVAL_MS, DEFAULT_API_URL, JOB_STATUS_POLL_INTERVAL_MS,
+    LOW_BANDWIDTH_INTERVAL_FACTOR, MAINTENANCE_POLL_INTERVAL_FACTOR,
+    MAX_API_STATUS_CHECK_INTERVAL_MS, MAX_JOB_STATUS_POLL_INTERVAL_MS,
+    MAX_RECOMMENDED_DURATION_SECS, MAX_STALE_STATUS_CHECKS, MIN_API_STATUS_CHECK_INTERVAL_MS,
+    MIN_JOB_STATUS_POLL_INTERVAL_MS,
+};
+use crate::consent::ConsentRecord;
+use crate::drafts::TranscriptDrafts;
+use crate::errors::AppError;
+use crate::history::{HistoryEntry, RetentionPolicy, TrashedEntry};
+use crate::hooks::indexed_db::{use_indexed_db, UseIndexedDb};
+use crate::hooks::persistent::{use_persistent, UsePersistent};
+use crate::i18n::Locale;
+use crate::layout::PanelLayout;
+use crate::media::file_types;
+use crate::postprocess::PipelineStep;
+use crate::profile::{self, EndpointProfile, EndpointProfiles};
+use crate::runtime_config::RuntimeConfig;
+use crate::services::batch::BatchItem;
+use crate::services::submission::SubmissionState;
+use crate::storage::StorageKey;
+use crate::theme::Theme;
+use crate::throttle;
+use crate::throughput::ProcessingRates;
+use crate::transcript::subtitles::SubtitleConstraints;
 use chrono::{DateTime, Utc};
 use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Intervals between the checks of the server, chosen in the settings.
+/// Both are scaled up in low-bandwidth mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PollingIntervals {
+    pub job_poll_ms: u32,
+    pub status_check_ms: u32,
+}
+
+impl Default for PollingIntervals {
+    fn default() -> Self {
+        PollingIntervals {
+            job_poll_ms: JOB_STATUS_POLL_INTERVAL_MS,
+            status_check_ms: API_STATUS_CHECK_INTERVAL_MS as u32,
+        }
+    }
+}
+
+impl PollingIntervals {
+    /// The intervals within their bounds, whatever was stored.
+    pub fn clamped(self) -> Self {
+        PollingIntervals {
+            job_poll_ms: self.job_poll_ms.clamp(
+                MIN_JOB_STATUS_POLL_INTERVAL_MS,
+                MAX_JOB_STATUS_POLL_INTERVAL_MS,
+            ),
+            status_check_ms: self.status_check_ms.clamp(
+                MIN_API_STATUS_CHECK_INTERVAL_MS,
+                MAX_API_STATUS_CHECK_INTERVAL_MS,
+            ),
+        }
+    }
+}
 
 /// Represents the possible UI states for the transcription panel.
 /// This is kept in the global state so that other components could potentially
@@ -18,8 +81,50 @@ pub enum TranscriptionUiStatus {
     FileSelected,
     Submitting,
     Monitoring,
-    Completed(String),
-    Error(String),
+    Completed {
+        job_id: String,
+        result: String,
+        /// The result after the post-processing pipeline, if any step is enabled.
+        processed: Option<String>,
+    },
+    Error(AppError),
+}
+
+impl TranscriptionUiStatus {
+    /// Whether a new file can be selected or dropped.
+    /// Submitted jobs keep being monitored while the next file is selected.
+    pub fn accepts_file(&self) -> bool {
+        matches!(
+            self,
+            TranscriptionUiStatus::Idle
+                | TranscriptionUiStatus::FileSelected
+                | TranscriptionUiStatus::Monitoring
+        )
+    }
+
+    /// Whether a job is being submitted or monitored.
+    pub fn is_busy(&self) -> bool {
+        matches!(
+            self,
+            TranscriptionUiStatus::Submitting | TranscriptionUiStatus::Monitoring
+        )
+    }
+
+    /// Whether a transcription result is displayed.
+    pub fn has_result(&self) -> bool {
+        matches!(self, TranscriptionUiStatus::Completed { .. })
+    }
+}
+
+/// Whether a new file can be selected: the API accepts submissions and no job is
+/// being submitted, nor a result or an error displayed.
+pub fn can_select_file(api: &ApiConnectionStatus, ui: &TranscriptionUiStatus) -> bool {
+    api.accepts_submissions() && ui.accepts_file()
+}
+
+/// Whether the selected file can be submitted.
+pub fn can_submit(api: &ApiConnectionStatus, ui: &TranscriptionUiStatus) -> bool {
+    api.accepts_submissions() && *ui == TranscriptionUiStatus::FileSelected
 }
 
 /// Represents the connection status of the WhisperX API endpoint.
@@ -31,8 +136,98 @@ pub enum ApiConnectionStatus {
     Pending,
     /// A successful connection has been made, and the API status is available.
     Available(ApiStatus, DateTime<Utc>),
+    /// The last checks failed, but fewer than `MAX_STALE_STATUS_CHECKS` times in a row:
+    /// the last known status is kept, and may be outdated.
+    Stale {
+        status: ApiStatus,
+        last_success: DateTime<Utc>,
+        error: ApiError,
+        failures: u32,
+    },
     /// An attempt to connect to the API failed.
     Unavailable(ApiError, DateTime<Utc>),
+    /// The server reports a maintenance: existing jobs are still monitored, but
+    /// no new submission is accepted.
+    Maintenance(MaintenanceInfo, DateTime<Utc>),
+}
+
+impl ApiConnectionStatus {
+    /// Whether the API can be used to monitor jobs: the last check succeeded, or the
+    /// failures are still considered transient.
+    pub fn is_available(&self) -> bool {
+        matches!(
+            self,
+            ApiConnectionStatus::Available(_, _)
+                | ApiConnectionStatus::Stale { .. }
+                | ApiConnectionStatus::Maintenance(_, _)
+        )
+    }
+
+    /// Whether new submissions are accepted: the last check succeeded and the server
+    /// is not under maintenance. A stale status keeps showing, but does not let files
+    /// be uploaded to a server that stopped answering.
+    pub fn accepts_submissions(&self) -> bool {
+        matches!(self, ApiConnectionStatus::Available(_, _))
+    }
+
+    /// Whether the server reports a maintenance.
+    pub fn is_maintenance(&self) -> bool {
+        matches!(self, ApiConnectionStatus::Maintenance(_, _))
+    }
+
+    /// The last known status of the API, if any.
+    pub fn api_status(&self) -> Option<&ApiStatus> {
+        match self {
+            ApiConnectionStatus::Available(status, _)
+            | ApiConnectionStatus::Stale { status, .. } => Some(status),
+            _ => None,
+        }
+    }
+
+    /// Color of the status indicator: green, amber, yellow or red.
+    pub fn color(&self) -> &'static str {
+        match self {
+            ApiConnectionStatus::Available(_, _) => "green",
+            ApiConnectionStatus::Stale { .. } | ApiConnectionStatus::Maintenance(_, _) => "amber",
+            ApiConnectionStatus::Unavailable(_, _) => "red",
+            ApiConnectionStatus::Pending => "yellow",
+        }
+    }
+
+    /// Computes the status following a check, given its result.
+    /// Failures right after a successful check keep the last known status as stale,
+    /// until `MAX_STALE_STATUS_CHECKS` consecutive checks have failed.
+    pub fn after_check(&self, result: Result<ApiStatus, ApiError>, now: DateTime<Utc>) -> Self {
+        let error = match result {
+            Ok(status) => {
+                return match status.maintenance_info() {
+                    Some(info) => ApiConnectionStatus::Maintenance(info, now),
+                    None => ApiConnectionStatus::Available(status, now),
+                }
+            }
+            Err(ApiError::Maintenance(info)) => return ApiConnectionStatus::Maintenance(info, now),
+            Err(error) => error,
+        };
+        let (status, last_success, failures) = match self {
+            ApiConnectionStatus::Available(status, checked_at) => (status, *checked_at, 1),
+            ApiConnectionStatus::Stale {
+                status,
+                last_success,
+                failures,
+                ..
+            } => (status, *last_success, failures + 1),
+            _ => return ApiConnectionStatus::Unavailable(error, now),
+        };
+        if error == ApiError::NotConfigured || failures >= MAX_STALE_STATUS_CHECKS {
+            return ApiConnectionStatus::Unavailable(error, now);
+        }
+        ApiConnectionStatus::Stale {
+            status: status.clone(),
+            last_success,
+            error,
+            failures,
+        }
+    }
 }
 
 /// The global application state.
@@ -48,14 +243,390 @@ pub struct AppState {
     // --- Persisted State ---
     /// The API URL, persisted in local storage.
     pub api_url: UsePersistent<String>,
-    /// The currently active transcription job, persisted in local storage.
-    pub active_job: UsePersistent<Option<TranscriptionJob>>,
+    /// The transcription jobs being monitored, oldest first, persisted in local storage.
+    pub active_jobs: UsePersistent<Vec<TranscriptionJob>>,
+    /// Timestamps of recent submissions, used by the client-side throttle.
+    pub submission_log: UsePersistent<Vec<DateTime<Utc>>>,
+    /// Per-endpoint options, keyed by profile id, persisted in local storage.
+    pub endpoint_profiles: UsePersistent<EndpointProfiles>,
+    /// The profile selected for the API URL, persisted in local storage.
+    /// Another profile of the URL is used when it targets another one.
+    pub profile_id: UsePersistent<Option<String>>,
+    /// Receipts of past submissions, newest first, persisted in IndexedDB.
+    pub history: UseIndexedDb<Vec<HistoryEntry>>,
+    /// Deleted history entries, most recently deleted first, persisted in IndexedDB.
+    pub trash: UseIndexedDb<Vec<TrashedEntry>>,
+    /// How long history entries are kept, persisted in local storage.
+    pub history_retention: UsePersistent<RetentionPolicy>,
+    /// Whether low-bandwidth mode is enabled, persisted in local storage.
+    pub low_bandwidth: UsePersistent<bool>,
+    /// Post-processing steps applied to completed transcripts, persisted in local storage.
+    pub postprocess_pipeline: UsePersistent<Vec<PipelineStep>>,
+    /// Limits applied to exported subtitles, persisted in local storage.
+    pub subtitle_constraints: UsePersistent<SubtitleConstraints>,
+    /// Format requested for the results of new submissions, persisted in local storage.
+    pub output_format: UsePersistent<OutputFormat>,
+    /// Speaker diarization options of new submissions, persisted in local storage.
+    pub diarization: UsePersistent<DiarizationOptions>,
+    /// Collapsed state and widths of the sidebars, persisted in local storage.
+    pub panel_layout: UsePersistent<PanelLayout>,
+    /// Intervals between job and API status checks, persisted in local storage.
+    pub polling_intervals: UsePersistent<PollingIntervals>,
+    /// Consent statements accepted before uploads, persisted in local storage.
+    pub consent_log: UsePersistent<Vec<ConsentRecord>>,
+    /// Whether finished transcriptions are announced by desktop notifications, persisted in local storage.
+    pub desktop_notifications: UsePersistent<bool>,
+    /// Transcript corrections not applied yet, by job id, persisted in local storage.
+    pub transcript_drafts: UsePersistent<TranscriptDrafts>,
+    /// Observed processing throughput of each endpoint, persisted in local storage.
+    pub processing_rates: UsePersistent<ProcessingRates>,
+    /// Color theme of the interface, persisted in local storage.
+    pub theme: UsePersistent<Theme>,
+    /// Language of the interface, `None` to follow the browser, persisted in local storage.
+    pub locale: UsePersistent<Option<Locale>>,
+    /// Label of the audio output device of the players, `None` for the default device,
+    /// persisted in local storage.
+    pub output_device: UsePersistent<Option<String>>,
+    /// File types accepted for transcription, `None` for those of the deployment,
+    /// persisted in local storage.
+    pub accepted_file_types: UsePersistent<Option<Vec<String>>>,
 
     // --- Volatile State ---
+    /// The runtime configuration of the deployment, `None` until it is loaded.
+    pub runtime_config: Signal<Option<RuntimeConfig>>,
     /// The last known connection status of the API server.
     pub api_connection_status: Signal<ApiConnectionStatus>,
-    /// The last known state of the active transcription job.
-    pub job_state: Signal<Option<Result<JobState, ApiError>>>,
+    /// The features of the API server the interface can offer, from its last status.
+    pub capabilities: Signal<Capabilities>,
+    /// The last known state of each active job, by job id.
+    pub job_states: Signal<HashMap<String, Result<JobState, ApiError>>>,
+    /// Active jobs whose monitoring stopped after repeated polling failures, until resumed.
+    pub stalled_jobs: Signal<HashSet<String>>,
     /// The current status of the transcription panel's UI.
     pub transcription_ui_status: Signal<TranscriptionUiStatus>,
+    /// Progress or outcome of the last submission, published by the submission service.
+    pub submission: Signal<SubmissionState>,
+    /// The files of the batch queue, in submission order.
+    pub batch: Signal<Vec<BatchItem>>,
+
+    // --- Derived State ---
+    /// Whether jobs can be monitored: the API answered the last status check, reports a
+    /// maintenance, or its failures are still considered transient (see
+    /// [`ApiConnectionStatus::is_available`]).
+    pub is_api_available: Memo<bool>,
+    /// Whether the server reports a maintenance.
+    pub is_maintenance: Memo<bool>,
+    /// Whether a new file can be selected: the API is reachable and no job is in progress.
+    pub can_select_file: Memo<bool>,
+    /// Whether the selected file can be submitted.
+    pub can_submit: Memo<bool>,
+    /// Whether a job is being submitted or monitored.
+    pub is_busy: Memo<bool>,
+    /// Whether a transcription result is displayed.
+    pub has_result: Memo<bool>,
+}
+
+impl AppState {
+    /// Creates the application state. Must be called once, from the `App` component,
+    /// as it creates the signals and memos backing the state, and loads the persisted values.
+    pub fn use_new() -> Self {
+        let api_url = use_persistent(StorageKey::ApiUrl, || DEFAULT_API_URL.to_string());
+        let active_jobs = use_persistent(StorageKey::ActiveJobs, Vec::new);
+        let submission_log = use_persistent(StorageKey::SubmissionLog, Vec::new);
+        let endpoint_profiles = use_persistent(StorageKey::EndpointProfiles, Default::default);
+        let profile_id = use_persistent(StorageKey::ProfileId, || None);
+        let history = use_indexed_db(StorageKey::History, Vec::new);
+        let trash = use_indexed_db(StorageKey::Trash, Vec::new);
+        let history_retention = use_persistent(StorageKey::HistoryRetention, Default::default);
+        let low_bandwidth = use_persistent(StorageKey::LowBandwidth, || false);
+        let postprocess_pipeline = use_persistent(StorageKey::PostprocessPipeline, Vec::new);
+        let subtitle_constraints =
+            use_persistent(StorageKey::SubtitleConstraints, Default::default);
+        let output_format = use_persistent(StorageKey::OutputFormat, Default::default);
+        let diarization = use_persistent(StorageKey::Diarization, Default::default);
+        let panel_layout = use_persistent(StorageKey::PanelLayout, Default::default);
+        let polling_intervals = use_persistent(StorageKey::PollingIntervals, Default::default);
+        let consent_log = use_persistent(StorageKey::ConsentLog, Vec::new);
+        let desktop_notifications = use_persistent(StorageKey::DesktopNotifications, || false);
+        let transcript_drafts = use_persistent(StorageKey::TranscriptDrafts, Default::default);
+        let processing_rates = use_persistent(StorageKey::ProcessingRates, Default::default);
+        let theme = use_persistent(StorageKey::Theme, Default::default);
+        let locale = use_persistent(StorageKey::Locale, || None);
+        let output_device = use_persistent(StorageKey::OutputDevice, || None);
+        let accepted_file_types = use_persistent(StorageKey::AcceptedFileTypes, || None);
+
+        let api_connection_status = use_signal(ApiConnectionStatus::default);
+        let transcription_ui_status = use_signal(TranscriptionUiStatus::default);
+
+        let is_api_available = use_memo(move || api_connection_status.read().is_available());
+        let is_maintenance = use_memo(move || api_connection_status.read().is_maintenance());
+        let can_select_file = use_memo(move || {
+            can_select_file(
+                &api_connection_status.read(),
+                &transcription_ui_status.read(),
+            )
+        });
+        let can_submit = use_memo(move || {
+            can_submit(
+                &api_connection_status.read(),
+                &transcription_ui_status.read(),
+            )
+        });
+        let is_busy = use_memo(move || transcription_ui_status.read().is_busy());
+        let has_result = use_memo(move || transcription_ui_status.read().has_result());
+
+        AppState {
+            api_url,
+            active_jobs,
+            submission_log,
+            endpoint_profiles,
+            profile_id,
+            history,
+            trash,
+            history_retention,
+            low_bandwidth,
+            postprocess_pipeline,
+            subtitle_constraints,
+            output_format,
+            diarization,
+            panel_layout,
+            polling_intervals,
+            consent_log,
+            desktop_notifications,
+            transcript_drafts,
+            processing_rates,
+            theme,
+            locale,
+            output_device,
+            accepted_file_types,
+            runtime_config: use_signal(|| None),
+            api_connection_status,
+            capabilities: use_signal(Capabilities::default),
+            job_states: use_signal(HashMap::new),
+            stalled_jobs: use_signal(HashSet::new),
+            transcription_ui_status,
+            submission: use_signal(SubmissionState::default),
+            batch: use_signal(Vec::new),
+            is_api_available,
+            is_maintenance,
+            can_select_file,
+            can_submit,
+            is_busy,
+            has_result,
+        }
+    }
+
+    /// Interval between API status checks, as set in the settings,
+    /// longer in low-bandwidth mode.
+    pub fn status_check_interval_ms(&self) -> u32 {
+        self.scaled_interval(self.polling_intervals.get().clamped().status_check_ms)
+    }
+
+    /// Interval between job status checks, as set in the settings,
+    /// longer in low-bandwidth mode and during a server maintenance.
+    pub fn job_poll_interval_ms(&self) -> u32 {
+        let interval_ms = self.scaled_interval(self.polling_intervals.get().clamped().job_poll_ms);
+        if *self.is_maintenance.peek() {
+            interval_ms * MAINTENANCE_POLL_INTERVAL_FACTOR
+        } else {
+            interval_ms
+        }
+    }
+
+    fn scaled_interval(&self, interval_ms: u32) -> u32 {
+        if self.low_bandwidth.get() {
+            interval_ms * LOW_BANDWIDTH_INTERVAL_FACTOR
+        } else {
+            interval_ms
+        }
+    }
+
+    /// File types accepted for transcription: those of the settings, otherwise those of
+    /// the deployment, otherwise the default ones.
+    pub fn accepted_types(&self) -> Vec<String> {
+        self.accepted_file_types
+            .get()
+            .or_else(|| self.deployment_file_types())
+            .unwrap_or_else(file_types::defaults)
+    }
+
+    /// The submission limit of the deployment, otherwise the default one.
+    pub fn submission_limit(&self) -> throttle::Limit {
+        let default = throttle::Limit::default();
+        let config = self.runtime_config.read();
+        let config = config.as_ref();
+        throttle::Limit {
+            max: config
+                .and_then(|config| config.max_submissions)
+                .unwrap_or(default.max),
+            window_secs: config
+                .and_then(|config| config.submission_window_secs)
+                .filter(|secs| *secs > 0)
+                .unwrap_or(default.window_secs),
+        }
+    }
+
+    /// Duration in seconds above which recordings are suggested to be split: the one of
+    /// the deployment, otherwise the default one.
+    pub fn max_recommended_duration(&self) -> f64 {
+        self.runtime_config
+            .read()
+            .as_ref()
+            .and_then(|config| config.max_recommended_duration_secs)
+            .filter(|secs| *secs > 0.0)
+            .unwrap_or(MAX_RECOMMENDED_DURATION_SECS)
+    }
+
+    /// File types accepted by the deployment, if it sets them.
+    pub fn deployment_file_types(&self) -> Option<Vec<String>> {
+        self.runtime_config
+            .read()
+            .as_ref()
+            .and_then(|config| config.accepted_file_types.as_ref())
+            .map(|accepted| file_types::parse(&accepted.join(",")))
+            .filter(|accepted| !accepted.is_empty())
+    }
+
+    /// The job shown by the transcription panel while monitoring: the latest submitted.
+    pub fn focused_job(&self) -> Option<TranscriptionJob> {
+        self.active_jobs.get().last().cloned()
+    }
+
+    /// Returns the id of the profile used for the configured API URL.
+    pub fn current_profile_id(&self) -> String {
+        profile::profile_id_for(
+            &self.endpoint_profiles.get(),
+            self.profile_id.get().as_deref(),
+            &self.api_url.get(),
+        )
+    }
+
+    /// Returns the profile used for the configured API URL.
+    pub fn current_profile(&self) -> EndpointProfile {
+        profile::profile_for(
+            &self.endpoint_profiles.get(),
+            self.profile_id.get().as_deref(),
+            &self.api_url.get(),
+        )
+    }
+
+    /// Switches to a named profile and its API URL.
+    pub fn select_profile(&mut self, id: &str, api_url: &str) {
+        self.profile_id.set(Some(id.to_string()));
+        self.api_url.set(api_url.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    /// A status response of a server without optional sections.
+    const STATUS_FIXTURE: &str = r#"{
+        "server": {"host": "0.0.0.0", "port": "8000", "timeout": 300, "keepalive": 5, "worker_number": 1},
+        "processing": {
+            "concurrent_mode": false, "max_concurrent_jobs": 1, "device": "cuda", "device_index": "0",
+            "default_output_format": "json", "default_sync_mode": false, "sync_timeout": 60
+        },
+        "resources": {"max_file_size": 1000000, "job_retention_hours": 24, "cleanup_interval_hours": 1},
+        "security": {"authorization_enabled": false},
+        "queue_state": {"queued_jobs": 0, "processing_jobs": 0},
+        "error": null
+    }"#;
+
+    fn status() -> ApiStatus {
+        serde_json::from_str(STATUS_FIXTURE).expect("valid status fixture")
+    }
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(secs, 0).unwrap()
+    }
+
+    fn available() -> ApiConnectionStatus {
+        ApiConnectionStatus::Available(status(), at(0))
+    }
+
+    fn unavailable() -> ApiConnectionStatus {
+        ApiConnectionStatus::Unavailable(ApiError::NotConfigured, at(0))
+    }
+
+    fn maintenance() -> ApiConnectionStatus {
+        ApiConnectionStatus::Maintenance(MaintenanceInfo::default(), at(0))
+    }
+
+    fn completed() -> TranscriptionUiStatus {
+        TranscriptionUiStatus::Completed {
+            job_id: "job".to_string(),
+            result: "text".to_string(),
+            processed: None,
+        }
+    }
+
+    #[test]
+    fn files_can_be_selected_while_idle_or_monitoring() {
+        let api = available();
+        assert!(can_select_file(&api, &TranscriptionUiStatus::Idle));
+        assert!(can_select_file(&api, &TranscriptionUiStatus::FileSelected));
+        assert!(can_select_file(&api, &TranscriptionUiStatus::Monitoring));
+        assert!(!can_select_file(&api, &TranscriptionUiStatus::Submitting));
+        assert!(!can_select_file(&api, &completed()));
+    }
+
+    #[test]
+    fn files_cannot_be_selected_without_the_api() {
+        for api in [ApiConnectionStatus::Pending, unavailable(), maintenance()] {
+            assert!(!can_select_file(&api, &TranscriptionUiStatus::Idle));
+        }
+    }
+
+    #[test]
+    fn only_a_selected_file_can_be_submitted() {
+        let api = available();
+        assert!(can_submit(&api, &TranscriptionUiStatus::FileSelected));
+        assert!(!can_submit(&api, &TranscriptionUiStatus::Idle));
+        assert!(!can_submit(&api, &TranscriptionUiStatus::Monitoring));
+        assert!(!can_submit(
+            &maintenance(),
+            &TranscriptionUiStatus::FileSelected
+        ));
+        assert!(!can_submit(
+            &unavailable(),
+            &TranscriptionUiStatus::FileSelected
+        ));
+    }
+
+    #[test]
+    fn busy_and_result_states() {
+        assert!(TranscriptionUiStatus::Submitting.is_busy());
+        assert!(TranscriptionUiStatus::Monitoring.is_busy());
+        assert!(!TranscriptionUiStatus::FileSelected.is_busy());
+        assert!(completed().has_result());
+        assert!(!TranscriptionUiStatus::Monitoring.has_result());
+    }
+
+    #[test]
+    fn maintenance_keeps_monitoring_but_not_submissions() {
+        let api = maintenance();
+        assert!(api.is_available());
+        assert!(api.is_maintenance());
+        assert!(!api.accepts_submissions());
+    }
+
+    #[test]
+    fn failed_checks_go_stale_then_unavailable() {
+        let error = ApiError::RequestFailed("timeout".to_string());
+        let mut api = available();
+        for _ in 1..MAX_STALE_STATUS_CHECKS {
+            api = api.after_check(Err(error.clone()), at(10));
+            assert!(matches!(api, ApiConnectionStatus::Stale { .. }));
+            assert!(api.api_status().is_some());
+            assert!(api.is_available());
+            assert!(!api.accepts_submissions());
+            assert!(!can_submit(&api, &TranscriptionUiStatus::FileSelected));
+        }
+        api = api.after_check(Err(error), at(20));
+        assert!(matches!(api, ApiConnectionStatus::Unavailable(_, _)));
+        assert!(api.after_check(Ok(status()), at(30)).accepts_submissions());
+    }
 }
@@ -0,0 +1,453 @@
+//! Persisted storage layout
+//! All localStorage entries of the application live under a versioned namespace.
+//! This module keeps the registry of known keys and the maintenance routines
+//! used to list, migrate and clean them. Large values live in IndexedDB under
+//! the same keys (see `hooks::indexed_db`).
+
+use crate::hooks::indexed_db;
+use dioxus::prelude::*;
+use gloo_storage::{errors::StorageError as GlooStorageError, LocalStorage, Storage};
+use std::fmt;
+
+/// Prefix shared by every key written by the application.
+pub const NAMESPACE: &str = "leontine/v1/";
+
+/// Prefix identifying keys written by any version of the application.
+const APP_PREFIX: &str = "leontine/";
+
+thread_local! {
+    /// Whether localStorage can be used, probed once per page load.
+    static AVAILABLE: bool = probe_availability();
+}
+
+/// Checks that localStorage exists and accepts writes.
+/// Some embedded webviews and privacy modes expose no storage or throw on access.
+fn probe_availability() -> bool {
+    const PROBE_KEY: &str = "leontine/probe";
+    let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+    else {
+        return false;
+    };
+    let writable = storage.set_item(PROBE_KEY, "1").is_ok();
+    let _ = storage.remove_item(PROBE_KEY);
+    writable
+}
+
+/// Whether values can be persisted in localStorage.
+/// When it returns `false`, persisted values only live in memory until the page is closed.
+pub fn is_available() -> bool {
+    AVAILABLE.with(|available| *available)
+}
+
+/// Set when a write failed because the browser storage quota is exhausted.
+/// The storage banner watches it to prompt the user to free some space.
+pub static QUOTA_EXCEEDED: GlobalSignal<bool> = Signal::global(|| false);
+
+/// Error raised when a value cannot be persisted.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StorageError {
+    /// The browser storage quota is exhausted.
+    QuotaExceeded,
+    /// The value could not be serialized.
+    Serialization(String),
+    /// The browser refused the write (blocked storage, private browsing...).
+    Unavailable(String),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageError::QuotaExceeded => write!(f, "Browser storage quota exceeded"),
+            StorageError::Serialization(msg) => write!(f, "Failed to serialize value: {}", msg),
+            StorageError::Unavailable(msg) => write!(f, "Browser storage unavailable: {}", msg),
+        }
+    }
+}
+
+impl From<GlooStorageError> for StorageError {
+    fn from(err: GlooStorageError) -> Self {
+        match err {
+            GlooStorageError::SerdeError(e) => StorageError::Serialization(e.to_string()),
+            // Firefox reports an exhausted quota under its own name.
+            GlooStorageError::JsError(e)
+                if e.name == "QuotaExceededError" || e.name == "NS_ERROR_DOM_QUOTA_REACHED" =>
+            {
+                StorageError::QuotaExceeded
+            }
+            other => StorageError::Unavailable(other.to_string()),
+        }
+    }
+}
+
+/// Handles a failed write: logs it and raises the quota prompt when relevant.
+pub fn report_write_error(key: &str, err: &StorageError) {
+    log::error!("Failed to persist '{}': {}", key, err);
+    if *err == StorageError::QuotaExceeded {
+        *QUOTA_EXCEEDED.write() = true;
+    }
+}
+
+/// A key known to the application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageKey {
+    ApiUrl,
+    ActiveJobs,
+    SubmissionLog,
+    EndpointProfiles,
+    ProfileId,
+    History,
+    Trash,
+    LowBandwidth,
+    HistoryRetention,
+    PostprocessPipeline,
+    SubtitleConstraints,
+    OutputFormat,
+    Diarization,
+    PanelLayout,
+    PollingIntervals,
+    ConsentLog,
+    DesktopNotifications,
+    TranscriptDrafts,
+    ProcessingRates,
+    Theme,
+    Locale,
+    OutputDevice,
+    AcceptedFileTypes,
+}
+
+impl StorageKey {
+    /// Every key registered by the application.
+    pub const ALL: [StorageKey; 23] = [
+        StorageKey::ApiUrl,
+        StorageKey::ActiveJobs,
+        StorageKey::SubmissionLog,
+        StorageKey::EndpointProfiles,
+        StorageKey::ProfileId,
+        StorageKey::History,
+        StorageKey::Trash,
+        StorageKey::LowBandwidth,
+        StorageKey::HistoryRetention,
+        StorageKey::PostprocessPipeline,
+        StorageKey::SubtitleConstraints,
+        StorageKey::OutputFormat,
+        StorageKey::Diarization,
+        StorageKey::PanelLayout,
+        StorageKey::PollingIntervals,
+        StorageKey::ConsentLog,
+        StorageKey::DesktopNotifications,
+        StorageKey::TranscriptDrafts,
+        StorageKey::ProcessingRates,
+        StorageKey::Theme,
+        StorageKey::Locale,
+        StorageKey::OutputDevice,
+        StorageKey::AcceptedFileTypes,
+    ];
+
+    /// The name of the key inside the namespace.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StorageKey::ApiUrl => "api-url",
+            StorageKey::ActiveJobs => "active-jobs",
+            StorageKey::SubmissionLog => "submission-log",
+            StorageKey::EndpointProfiles => "endpoint-profiles",
+            StorageKey::ProfileId => "profile-id",
+            StorageKey::History => "history",
+            StorageKey::Trash => "trash",
+            StorageKey::LowBandwidth => "low-bandwidth",
+            StorageKey::HistoryRetention => "history-retention",
+            StorageKey::PostprocessPipeline => "postprocess-pipeline",
+            StorageKey::SubtitleConstraints => "subtitle-constraints",
+            StorageKey::OutputFormat => "output-format",
+            StorageKey::Diarization => "diarization",
+            StorageKey::PanelLayout => "panel-layout",
+            StorageKey::PollingIntervals => "polling-intervals",
+            StorageKey::ConsentLog => "consent-log",
+            StorageKey::DesktopNotifications => "desktop-notifications",
+            StorageKey::TranscriptDrafts => "transcript-drafts",
+            StorageKey::ProcessingRates => "processing-rates",
+            StorageKey::Theme => "theme",
+            StorageKey::Locale => "locale",
+            StorageKey::OutputDevice => "output-device",
+            StorageKey::AcceptedFileTypes => "accepted-file-types",
+        }
+    }
+
+    /// What the entry holds, in the words of the user.
+    pub fn description(&self) -> &'static str {
+        match self {
+            StorageKey::ApiUrl => "API URL",
+            StorageKey::ActiveJobs => "Jobs being transcribed",
+            StorageKey::SubmissionLog => "Times of recent submissions",
+            StorageKey::EndpointProfiles => "Endpoint options, including credentials",
+            StorageKey::ProfileId => "Endpoint profile in use",
+            StorageKey::History => "Transcription history, with the results",
+            StorageKey::Trash => "Deleted history entries",
+            StorageKey::LowBandwidth => "Low-bandwidth mode",
+            StorageKey::HistoryRetention => "History retention",
+            StorageKey::PostprocessPipeline => "Post-processing steps",
+            StorageKey::SubtitleConstraints => "Subtitle limits",
+            StorageKey::OutputFormat => "Output format",
+            StorageKey::Diarization => "Speaker options",
+            StorageKey::PanelLayout => "Panel layout",
+            StorageKey::PollingIntervals => "Polling intervals",
+            StorageKey::ConsentLog => "Accepted upload consents",
+            StorageKey::DesktopNotifications => "Desktop notifications",
+            StorageKey::TranscriptDrafts => "Unsaved transcript corrections",
+            StorageKey::ProcessingRates => "Observed processing speed of the servers",
+            StorageKey::Theme => "Color theme",
+            StorageKey::Locale => "Interface language",
+            StorageKey::OutputDevice => "Audio output device of the players",
+            StorageKey::AcceptedFileTypes => "Accepted file types",
+        }
+    }
+
+    /// A description of the type stored under the key.
+    pub fn value_type(&self) -> &'static str {
+        match self {
+            StorageKey::ApiUrl => "String",
+            StorageKey::ActiveJobs => "Vec<TranscriptionJob>",
+            StorageKey::SubmissionLog => "Vec<DateTime<Utc>>",
+            StorageKey::EndpointProfiles => "EndpointProfiles",
+            StorageKey::ProfileId => "Option<String>",
+            StorageKey::History => "Vec<HistoryEntry>",
+            StorageKey::Trash => "Vec<TrashedEntry>",
+            StorageKey::LowBandwidth => "bool",
+            StorageKey::HistoryRetention => "RetentionPolicy",
+            StorageKey::PostprocessPipeline => "Vec<PipelineStep>",
+            StorageKey::SubtitleConstraints => "SubtitleConstraints",
+            StorageKey::OutputFormat => "OutputFormat",
+            StorageKey::Diarization => "DiarizationOptions",
+            StorageKey::PanelLayout => "PanelLayout",
+            StorageKey::PollingIntervals => "PollingIntervals",
+            StorageKey::ConsentLog => "Vec<ConsentRecord>",
+            StorageKey::DesktopNotifications => "bool",
+            StorageKey::TranscriptDrafts => "TranscriptDrafts",
+            StorageKey::ProcessingRates => "ProcessingRates",
+            StorageKey::Theme => "Theme",
+            StorageKey::Locale => "Option<Locale>",
+            StorageKey::OutputDevice => "Option<String>",
+            StorageKey::AcceptedFileTypes => "Option<Vec<String>>",
+        }
+    }
+
+    /// Keys used for the same value before the namespace was introduced.
+    fn legacy_key(&self) -> Option<&'static str> {
+        match self {
+            StorageKey::ApiUrl => Some("api_url"),
+            StorageKey::SubmissionLog => Some("leontine-submission-log"),
+            StorageKey::EndpointProfiles => Some("leontine-endpoint-profiles"),
+            StorageKey::ActiveJobs
+            | StorageKey::ProfileId
+            | StorageKey::History
+            | StorageKey::Trash
+            | StorageKey::LowBandwidth
+            | StorageKey::HistoryRetention
+            | StorageKey::PostprocessPipeline
+            | StorageKey::SubtitleConstraints
+            | StorageKey::OutputFormat
+            | StorageKey::Diarization
+            | StorageKey::PanelLayout
+            | StorageKey::PollingIntervals
+            | StorageKey::ConsentLog
+            | StorageKey::DesktopNotifications
+            | StorageKey::TranscriptDrafts
+            | StorageKey::ProcessingRates
+            | StorageKey::Theme
+            | StorageKey::Locale
+            | StorageKey::OutputDevice
+            | StorageKey::AcceptedFileTypes => None,
+        }
+    }
+
+    /// Whether the value may grow beyond what localStorage holds: it is then
+    /// stored in IndexedDB.
+    pub fn is_large(&self) -> bool {
+        matches!(self, StorageKey::History | StorageKey::Trash)
+    }
+
+    /// Finds the registered key matching a full storage key.
+    fn from_storage_key(key: &str) -> Option<StorageKey> {
+        let name = key.strip_prefix(NAMESPACE)?;
+        Self::ALL.into_iter().find(|known| known.name() == name)
+    }
+}
+
+impl fmt::Display for StorageKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", NAMESPACE, self.name())
+    }
+}
+
+/// An entry found in localStorage that belongs to the application.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredEntry {
+    /// The full storage key.
+    pub key: String,
+    /// The registered key, `None` for orphaned entries.
+    pub known: Option<StorageKey>,
+    /// The size of the stored value, in bytes.
+    pub size: usize,
+}
+
+impl StoredEntry {
+    /// Whether the entry is no longer used by the application.
+    pub fn is_orphaned(&self) -> bool {
+        self.known.is_none()
+    }
+}
+
+/// Returns every key currently present in localStorage.
+fn all_keys() -> Vec<String> {
+    let storage = LocalStorage::raw();
+    let length = storage.length().unwrap_or(0);
+    (0..length)
+        .filter_map(|index| storage.key(index).ok().flatten())
+        .collect()
+}
+
+/// Whether a key was written by the application, in any version.
+fn is_app_key(key: &str) -> bool {
+    key.starts_with(APP_PREFIX)
+        || StorageKey::ALL
+            .iter()
+            .any(|known| known.legacy_key() == Some(key))
+}
+
+/// Lists the entries belonging to the application, registered ones first,
+/// wherever they are stored.
+pub fn list_entries() -> Vec<StoredEntry> {
+    let mut entries: Vec<StoredEntry> = indexed_db::stored_keys()
+        .into_iter()
+        .map(|key| StoredEntry {
+            known: StorageKey::from_storage_key(&key),
+            size: indexed_db::stored_value(&key).map_or(0, |value| value.len()),
+            key,
+        })
+        .collect();
+    if is_available() {
+        let storage = LocalStorage::raw();
+        entries.extend(
+            all_keys()
+                .into_iter()
+                .filter(|key| is_app_key(key))
+                .map(|key| StoredEntry {
+                    known: StorageKey::from_storage_key(&key),
+                    size: storage
+                        .get_item(&key)
+                        .ok()
+                        .flatten()
+                        .map_or(0, |value| value.len()),
+                    key,
+                }),
+        );
+    }
+    entries.sort_by(|a, b| {
+        a.is_orphaned()
+            .cmp(&b.is_orphaned())
+            .then(a.key.cmp(&b.key))
+    });
+    entries
+}
+
+/// Moves values stored under legacy keys to their namespaced key.
+/// An existing namespaced value always wins over the legacy one.
+/// Returns the number of migrated entries.
+pub fn migrate_legacy_keys() -> usize {
+    if !is_available() {
+        log::warn!("localStorage is unavailable, settings will only be kept in memory");
+        return 0;
+    }
+    let storage = LocalStorage::raw();
+    let mut migrated = 0;
+    for known in StorageKey::ALL {
+        let Some(legacy_key) = known.legacy_key() else {
+            continue;
+        };
+        let Ok(Some(value)) = storage.get_item(legacy_key) else {
+            continue;
+        };
+        let key = known.to_string();
+        if matches!(storage.get_item(&key), Ok(None)) && storage.set_item(&key, &value).is_ok() {
+            migrated += 1;
+        }
+        let _ = storage.remove_item(legacy_key);
+    }
+    if migrated > 0 {
+        log::info!(
+            "Migrated {} storage entries to the {} namespace",
+            migrated,
+            NAMESPACE
+        );
+    }
+    migrated
+}
+
+/// Keys of the single active job stored before several jobs could be monitored,
+/// legacy one first.
+const SINGLE_ACTIVE_JOB_KEYS: [&str; 2] = ["leontine-active-job", "leontine/v1/active-job"];
+
+/// Moves the single active job stored by previous versions to the list of active jobs.
+/// An existing list always wins over the single job.
+pub fn migrate_single_active_job() {
+    if !is_available() {
+        return;
+    }
+    let key = StorageKey::ActiveJobs.to_string();
+    for single_key in SINGLE_ACTIVE_JOB_KEYS {
+        let job: Option<crate::api::TranscriptionJob> =
+            LocalStorage::get(single_key).ok().flatten();
+        if let Some(job) = job {
+            if LocalStorage::raw().get_item(&key).ok().flatten().is_none() {
+                log::info!(
+                    "Migrating active job {} to the list of active jobs",
+                    job.job_id
+                );
+                if let Err(e) = LocalStorage::set(&key, vec![job]) {
+                    report_write_error(&key, &e.into());
+                }
+            }
+        }
+        LocalStorage::delete(single_key);
+    }
+}
+
+/// Removes the application entries that are no longer registered.
+/// Returns the removed keys.
+pub fn clean_orphans() -> Vec<String> {
+    let orphans: Vec<String> = list_entries()
+        .into_iter()
+        .filter(StoredEntry::is_orphaned)
+        .map(|entry| entry.key)
+        .collect();
+    for key in &orphans {
+        LocalStorage::delete(key);
+    }
+    orphans
+}
+
+/// Exports every entry of the application as a JSON object, keyed by storage key.
+/// Values are kept as stored: JSON documents, or strings when they do not parse.
+pub fn export_all() -> String {
+    let storage = LocalStorage::raw();
+    let entries: serde_json::Map<String, serde_json::Value> = list_entries()
+        .into_iter()
+        .filter_map(|entry| {
+            let value = indexed_db::stored_value(&entry.key)
+                .or_else(|| storage.get_item(&entry.key).ok().flatten())?;
+            let value = serde_json::from_str(&value).unwrap_or(serde_json::Value::String(value));
+            Some((entry.key, value))
+        })
+        .collect();
+    serde_json::to_string_pretty(&entries).unwrap_or_default()
+}
+
+/// Removes every entry of the application, registered or not.
+/// Returns the removed keys.
+pub fn clear_all() -> Vec<String> {
+    let keys: Vec<String> = list_entries().into_iter().map(|entry| entry.key).collect();
+    for key in &keys {
+        LocalStorage::delete(key);
+    }
+    indexed_db::delete_all();
+    keys
+}
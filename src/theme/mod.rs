@@ -0,0 +1,55 @@
+//! Color theme
+//! The interface follows the color scheme of the system unless the user picks a light
+//! or dark theme. The theme is applied as the `data-theme` attribute of the document,
+//! which selects the colors of the stylesheet.
+
+use crate::document::eval;
+use serde::{Deserialize, Serialize};
+
+/// The theme picked by the user.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    Light,
+    Dark,
+    /// Follows `prefers-color-scheme`, which is also the theme of the first run.
+    #[default]
+    System,
+}
+
+impl Theme {
+    /// Value of the `data-theme` attribute.
+    pub fn attribute(self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+            Theme::System => "system",
+        }
+    }
+
+    /// Name of the theme, for the user.
+    pub fn label(self) -> &'static str {
+        match self {
+            Theme::Light => "Light",
+            Theme::Dark => "Dark",
+            Theme::System => "System",
+        }
+    }
+
+    /// The theme picked after this one by the header toggle.
+    pub fn next(self) -> Self {
+        match self {
+            Theme::System => Theme::Light,
+            Theme::Light => Theme::Dark,
+            Theme::Dark => Theme::System,
+        }
+    }
+}
+
+/// Applies a theme to the document.
+pub fn apply(theme: Theme) {
+    let _ = eval(&format!(
+        "document.documentElement.setAttribute('data-theme', '{}');",
+        theme.attribute()
+    ));
+}
@@ -0,0 +1,71 @@
+//! Client-side submission throttle.
+//! Keeps a log of recent submission timestamps and decides whether a new
+//! submission is allowed within the configured window.
+
+use crate::config::{SUBMISSION_RATE_LIMIT_MAX, SUBMISSION_RATE_LIMIT_WINDOW_SECS};
+use chrono::{DateTime, Duration, Utc};
+
+/// How many submissions are allowed within a window.
+/// The deployment may set it, otherwise the defaults of `config` apply.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limit {
+    /// Maximum number of submissions per window (0 disables the throttle).
+    pub max: usize,
+    /// Length of the window, in seconds.
+    pub window_secs: i64,
+}
+
+impl Default for Limit {
+    fn default() -> Self {
+        Limit {
+            max: SUBMISSION_RATE_LIMIT_MAX,
+            window_secs: SUBMISSION_RATE_LIMIT_WINDOW_SECS,
+        }
+    }
+}
+
+impl Limit {
+    /// Returns the length of the throttle window.
+    fn window(&self) -> Duration {
+        Duration::seconds(self.window_secs)
+    }
+}
+
+/// Returns only the submissions that still count against the limit at `now`.
+pub fn prune(log: &[DateTime<Utc>], limit: Limit, now: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+    log.iter()
+        .copied()
+        .filter(|submitted_at| now - *submitted_at < limit.window())
+        .collect()
+}
+
+/// Returns how long the user has to wait before submitting again,
+/// or `None` if a submission is currently allowed.
+pub fn retry_after(log: &[DateTime<Utc>], limit: Limit, now: DateTime<Utc>) -> Option<Duration> {
+    if limit.max == 0 {
+        return None;
+    }
+
+    let mut recent = prune(log, limit, now);
+    if recent.len() < limit.max {
+        return None;
+    }
+
+    // The oldest submission that keeps us at the limit decides when a slot frees up.
+    recent.sort();
+    let blocking = recent[recent.len() - limit.max];
+    Some(blocking + limit.window() - now)
+}
+
+/// Returns the log with a new submission recorded at `now`, dropping expired entries.
+pub fn record(log: &[DateTime<Utc>], limit: Limit, now: DateTime<Utc>) -> Vec<DateTime<Utc>> {
+    let mut recent = prune(log, limit, now);
+    recent.push(now);
+    recent
+}
+
+/// Formats a wait duration as a friendly `MM:SS` countdown.
+pub fn format_countdown(wait: Duration) -> String {
+    let total = wait.num_seconds().max(0);
+    format!("{:02}:{:02}", total / 60, total % 60)
+}
@@ -0,0 +1,49 @@
+//! Processing throughput
+//! The speed at which each endpoint transcribes, observed on the completed jobs, in
+//! seconds of audio per second of processing. It gives an estimate of the progress of
+//! a job being processed, the server reporting none.
+
+use crate::config::{MAX_ESTIMATED_PROGRESS, THROUGHPUT_SMOOTHING};
+use std::collections::BTreeMap;
+
+/// The observed throughput, by API URL.
+pub type ProcessingRates = BTreeMap<String, f64>;
+
+/// Returns the rates with the processing of `audio_secs` of audio in `elapsed_secs`
+/// recorded for an endpoint. Recent jobs weigh more, following the load of the server.
+pub fn record(
+    rates: &ProcessingRates,
+    api_url: &str,
+    audio_secs: f64,
+    elapsed_secs: f64,
+) -> ProcessingRates {
+    let mut rates = rates.clone();
+    if audio_secs <= 0.0 || elapsed_secs <= 0.0 {
+        return rates;
+    }
+    let observed = audio_secs / elapsed_secs;
+    let rate = match rates.get(api_url) {
+        Some(previous) => previous + THROUGHPUT_SMOOTHING * (observed - previous),
+        None => observed,
+    };
+    rates.insert(api_url.to_string(), rate);
+    rates
+}
+
+/// Estimated share of the processing done, between 0 and `MAX_ESTIMATED_PROGRESS`,
+/// after `elapsed_secs` of processing of `audio_secs` of audio at `rate`.
+pub fn estimate(rate: f64, audio_secs: f64, elapsed_secs: f64) -> f64 {
+    if rate <= 0.0 || audio_secs <= 0.0 {
+        return 0.0;
+    }
+    (elapsed_secs.max(0.0) * rate / audio_secs).min(MAX_ESTIMATED_PROGRESS)
+}
+
+/// Estimated time left, in seconds, or `None` once the estimate is overdue.
+pub fn remaining_secs(rate: f64, audio_secs: f64, elapsed_secs: f64) -> Option<f64> {
+    if rate <= 0.0 {
+        return None;
+    }
+    let remaining = audio_secs / rate - elapsed_secs;
+    (remaining > 0.0).then_some(remaining)
+}
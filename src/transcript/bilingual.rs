@@ -0,0 +1,90 @@
+//! Bilingual transcripts
+//! Aligns the segments of two transcriptions of the same recording, typically the
+//! transcript and its translation, to read or export them side by side.
+
+use super::subtitles::{self, Cue};
+use super::{segment_at, TranscriptSegment};
+
+/// A segment of the original transcript with the matching part of the translation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentPair {
+    /// Start time, in seconds.
+    pub start: f64,
+    /// End time, in seconds.
+    pub end: f64,
+    pub original: String,
+    pub translation: String,
+}
+
+/// Pairs each original segment with the translated segments whose middle falls within it.
+/// Translated segments starting before the first original one go to the first pair.
+pub fn align(
+    original: &[TranscriptSegment],
+    translation: &[TranscriptSegment],
+) -> Vec<SegmentPair> {
+    let mut translated: Vec<Vec<&str>> = vec![Vec::new(); original.len()];
+    for segment in translation {
+        let middle = (segment.start + segment.end) / 2.0;
+        if let Some(parts) = translated.get_mut(segment_at(original, middle).unwrap_or(0)) {
+            parts.push(segment.text.trim());
+        }
+    }
+    original
+        .iter()
+        .zip(translated)
+        .map(|(segment, parts)| SegmentPair {
+            start: segment.start,
+            end: segment.end,
+            original: segment.text.trim().to_string(),
+            translation: parts.join(" "),
+        })
+        .collect()
+}
+
+/// Renders the pairs as SubRip, each cue showing the original line above its translation.
+pub fn to_interleaved_srt(pairs: &[SegmentPair]) -> String {
+    let cues: Vec<Cue> = pairs
+        .iter()
+        .map(|pair| Cue {
+            start: pair.start,
+            end: pair.end,
+            lines: [&pair.original, &pair.translation]
+                .into_iter()
+                .filter(|line| !line.is_empty())
+                .cloned()
+                .collect(),
+        })
+        .collect();
+    subtitles::to_srt(&cues)
+}
+
+/// Renders the pairs as an HTML document with a two-column table,
+/// which word processors and spreadsheets open as well as browsers.
+pub fn to_two_column_html(
+    pairs: &[SegmentPair],
+    original_label: &str,
+    translation_label: &str,
+) -> String {
+    let rows: String = pairs
+        .iter()
+        .map(|pair| {
+            format!(
+                "<tr><td>{}</td><td>{}</td></tr>\n",
+                escape_html(&pair.original),
+                escape_html(&pair.translation)
+            )
+        })
+        .collect();
+    format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"></head><body>\n<table border=\"1\">\n<tr><th>{}</th><th>{}</th></tr>\n{}</table>\n</body></html>\n",
+        escape_html(original_label),
+        escape_html(translation_label),
+        rows
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
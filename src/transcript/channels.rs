@@ -0,0 +1,81 @@
+//! Channel transcripts
+//! Merges the transcripts of the channels of a stereo recording, transcribed
+//! separately, into a single transcript with a speaker per channel.
+
+use super::TranscriptSegment;
+
+/// Interleaves the segments of the channel transcripts by start time, each segment
+/// labelled with the speaker of its channel. Segments starting together keep the
+/// order of the channels.
+pub fn interleave(channels: &[(&str, &[TranscriptSegment])]) -> Vec<TranscriptSegment> {
+    let mut segments: Vec<TranscriptSegment> = channels
+        .iter()
+        .flat_map(|(speaker, segments)| {
+            segments.iter().map(|segment| TranscriptSegment {
+                speaker: Some(speaker.to_string()),
+                ..segment.clone()
+            })
+        })
+        .collect();
+    segments.sort_by(|a, b| a.start.total_cmp(&b.start));
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            start,
+            end: start + 1.0,
+            speaker: None,
+            text: text.to_string(),
+            score: None,
+            words: Vec::new(),
+        }
+    }
+
+    fn texts(segments: &[TranscriptSegment]) -> Vec<(&str, &str)> {
+        segments
+            .iter()
+            .map(|segment| {
+                (
+                    segment.speaker.as_deref().unwrap_or(""),
+                    segment.text.as_str(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn interleaves_by_start_time_with_a_speaker_per_channel() {
+        let left = [segment(0.0, "hello"), segment(4.0, "fine, thanks")];
+        let right = [segment(2.0, "hi, how are you?")];
+        let merged = interleave(&[("Left", &left), ("Right", &right)]);
+        assert_eq!(
+            texts(&merged),
+            vec![
+                ("Left", "hello"),
+                ("Right", "hi, how are you?"),
+                ("Left", "fine, thanks")
+            ]
+        );
+    }
+
+    #[test]
+    fn simultaneous_segments_keep_the_channel_order() {
+        let left = [segment(1.0, "left")];
+        let right = [segment(1.0, "right")];
+        let merged = interleave(&[("Left", &left), ("Right", &right)]);
+        assert_eq!(texts(&merged), vec![("Left", "left"), ("Right", "right")]);
+    }
+
+    #[test]
+    fn speakers_found_by_the_model_are_replaced() {
+        let mut labelled = segment(0.0, "text");
+        labelled.speaker = Some("SPEAKER_00".to_string());
+        let merged = interleave(&[("Left", &[labelled]), ("Right", &[])]);
+        assert_eq!(merged[0].speaker.as_deref(), Some("Left"));
+    }
+}
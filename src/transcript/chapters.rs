@@ -0,0 +1,145 @@
+//! Chapter detection
+//! Splits long transcripts into chapters at topic boundaries, found heuristically:
+//! a long pause between two segments, around which the vocabulary changes.
+
+use super::{format_timestamp, TranscriptSegment};
+use crate::config::{
+    CHAPTER_KEYWORD_MIN_CHARS, CHAPTER_KEYWORD_WINDOW, CHAPTER_MAX_KEYWORD_OVERLAP,
+    CHAPTER_MIN_DURATION_SECS, CHAPTER_MIN_PAUSE_SECS, CHAPTER_TITLE_KEYWORDS,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// A chapter of a transcript.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Chapter {
+    pub title: String,
+    /// Start time, in seconds.
+    pub start: f64,
+    /// Index of the first segment of the chapter.
+    pub first_segment: usize,
+}
+
+/// Detects the chapters of a transcript. A chapter starts after a pause of at least
+/// [`CHAPTER_MIN_PAUSE_SECS`], when the keywords of the segments around the pause
+/// barely overlap, and the current chapter lasts at least [`CHAPTER_MIN_DURATION_SECS`].
+/// Titles are made of the most frequent keywords of each chapter.
+pub fn detect(segments: &[TranscriptSegment]) -> Vec<Chapter> {
+    if segments.is_empty() {
+        return Vec::new();
+    }
+    let mut starts = vec![0];
+    for index in 1..segments.len() {
+        let chapter_start = starts[starts.len() - 1];
+        let pause = segments[index].start - segments[index - 1].end;
+        if pause < CHAPTER_MIN_PAUSE_SECS
+            || segments[index].start - segments[chapter_start].start < CHAPTER_MIN_DURATION_SECS
+        {
+            continue;
+        }
+        let before = keywords(
+            &segments[index
+                .saturating_sub(CHAPTER_KEYWORD_WINDOW)
+                .max(chapter_start)..index],
+        );
+        let after =
+            keywords(&segments[index..(index + CHAPTER_KEYWORD_WINDOW).min(segments.len())]);
+        if overlap(&before, &after) <= CHAPTER_MAX_KEYWORD_OVERLAP {
+            starts.push(index);
+        }
+    }
+
+    let ends = starts.iter().skip(1).copied().chain([segments.len()]);
+    starts
+        .iter()
+        .zip(ends)
+        .enumerate()
+        .map(|(number, (&first, end))| Chapter {
+            title: title(&segments[first..end])
+                .unwrap_or_else(|| format!("Chapter {}", number + 1)),
+            start: segments[first].start,
+            first_segment: first,
+        })
+        .collect()
+}
+
+/// Formats the transcript as plain text, one paragraph per chapter under its title,
+/// preceded by the table of contents when `include_contents` is set.
+pub fn to_text(
+    segments: &[TranscriptSegment],
+    chapters: &[Chapter],
+    include_contents: bool,
+) -> String {
+    let mut text = String::new();
+    if include_contents {
+        text.push_str("Contents\n\n");
+        for chapter in chapters {
+            text.push_str(&format!(
+                "{}  {}\n",
+                format_timestamp(chapter.start),
+                chapter.title
+            ));
+        }
+        text.push('\n');
+    }
+    let ends = chapters
+        .iter()
+        .skip(1)
+        .map(|chapter| chapter.first_segment)
+        .chain([segments.len()]);
+    for (chapter, end) in chapters.iter().zip(ends) {
+        let Some(chapter_segments) = segments.get(chapter.first_segment..end) else {
+            continue;
+        };
+        let paragraph: Vec<&str> = chapter_segments
+            .iter()
+            .map(|segment| segment.text.trim())
+            .collect();
+        text.push_str(&format!("{}\n\n{}\n\n", chapter.title, paragraph.join(" ")));
+    }
+    text.trim_end().to_string()
+}
+
+/// The words of the segments long enough to carry meaning, lowercased.
+/// Word length stands in for a stop word list, whatever the language.
+fn words(segments: &[TranscriptSegment]) -> impl Iterator<Item = String> + '_ {
+    segments.iter().flat_map(|segment| {
+        segment
+            .text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| word.chars().count() >= CHAPTER_KEYWORD_MIN_CHARS)
+            .map(str::to_lowercase)
+    })
+}
+
+fn keywords(segments: &[TranscriptSegment]) -> HashSet<String> {
+    words(segments).collect()
+}
+
+/// Share of keywords common to both sets (Jaccard index).
+fn overlap(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// The most frequent keywords of a chapter, the earliest first on ties.
+fn title(segments: &[TranscriptSegment]) -> Option<String> {
+    let mut counts: HashMap<String, (usize, usize)> = HashMap::new();
+    for (position, word) in words(segments).enumerate() {
+        counts.entry(word).or_insert((0, position)).0 += 1;
+    }
+    let mut ranked: Vec<(String, (usize, usize))> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1 .0.cmp(&a.1 .0).then(a.1 .1.cmp(&b.1 .1)));
+    let title = ranked
+        .into_iter()
+        .take(CHAPTER_TITLE_KEYWORDS)
+        .map(|(word, _)| word)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut chars = title.chars();
+    let first = chars.next()?;
+    Some(first.to_uppercase().chain(chars).collect())
+}
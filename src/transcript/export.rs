@@ -0,0 +1,118 @@
+//! Transcript export
+//! Converts a result into the formats offered for download, whatever format
+//! the server returned it in. Timed formats need a result with segments.
+
+use super::subtitles::{self, SubtitleConstraints};
+use super::{format_timestamp, parse_segments, speaker_turns, TranscriptSegment};
+use crate::api::OutputFormat;
+use crate::media;
+use crate::postprocess::{self, PipelineStep};
+
+/// The formats a result can be exported to. `source` is the format requested at
+/// submission, `None` for jobs submitted before the format was recorded.
+pub fn available_formats(result: &str, source: Option<OutputFormat>) -> Vec<OutputFormat> {
+    if parse_segments(result).is_some() {
+        return OutputFormat::ALL.to_vec();
+    }
+    let mut formats = vec![OutputFormat::Txt];
+    if let Some(source) = source.filter(|source| *source != OutputFormat::Txt) {
+        formats.push(source);
+    }
+    formats
+}
+
+/// Renders a result in `target` format. Plain text uses the corrected transcript
+/// when there is one; timed formats are built from the segments, with the steps of
+/// the post-processing `pipeline` applied to their text, or are the result itself
+/// when it was returned in that format.
+pub fn render(
+    result: &str,
+    processed: Option<&str>,
+    source: Option<OutputFormat>,
+    target: OutputFormat,
+    constraints: &SubtitleConstraints,
+    pipeline: &[PipelineStep],
+) -> Option<String> {
+    let segments =
+        parse_segments(result).map(|segments| postprocess::run_on_segments(pipeline, &segments));
+    match (target, segments) {
+        (OutputFormat::Txt, _) if processed.is_some() => processed.map(str::to_string),
+        (OutputFormat::Txt, Some(segments)) => Some(plain_text(&segments)),
+        (OutputFormat::Srt, Some(segments)) => {
+            Some(subtitles::to_srt(&subtitles::fit(&segments, constraints)))
+        }
+        (OutputFormat::Vtt, Some(segments)) => {
+            Some(subtitles::to_vtt(&subtitles::fit(&segments, constraints)))
+        }
+        (OutputFormat::Tsv, Some(segments)) => Some(tsv(&segments)),
+        _ if source == Some(target) || target == OutputFormat::Txt => Some(result.to_string()),
+        _ => None,
+    }
+}
+
+/// Renders a result in `target` format and downloads it, named after the job.
+pub fn download(
+    job_id: &str,
+    result: &str,
+    processed: Option<&str>,
+    source: Option<OutputFormat>,
+    target: OutputFormat,
+    constraints: &SubtitleConstraints,
+    pipeline: &[PipelineStep],
+) {
+    if let Some(text) = render(result, processed, source, target, constraints, pipeline) {
+        let file_name = format!("{}.{}", job_id, target.extension());
+        media::download(&file_name, target.mime_type(), &text);
+    }
+}
+
+/// Renders a result as Markdown under a `title` heading. Speaker turns become
+/// paragraphs led by the speaker and the time; other transcripts are plain text.
+pub fn markdown(title: &str, result: &str, processed: Option<&str>) -> String {
+    let segments = parse_segments(result);
+    let body = match (processed, segments) {
+        (Some(processed), _) => processed.to_string(),
+        (None, Some(segments)) => match speaker_turns(&segments) {
+            Some(turns) => turns
+                .iter()
+                .map(|turn| {
+                    format!(
+                        "**{}** _[{}]_: {}",
+                        turn.speaker,
+                        format_timestamp(turn.start),
+                        turn.text
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            None => plain_text(&segments),
+        },
+        (None, None) => result.to_string(),
+    };
+    format!("# {}\n\n{}\n", title, body.trim())
+}
+
+/// The text of the segments, as a single paragraph.
+pub fn plain_text(segments: &[TranscriptSegment]) -> String {
+    segments
+        .iter()
+        .map(|segment| segment.text.trim())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Tab-separated start, end (in milliseconds) and text, as written by WhisperX.
+fn tsv(segments: &[TranscriptSegment]) -> String {
+    let rows: String = segments
+        .iter()
+        .map(|segment| {
+            format!(
+                "{}\t{}\t{}\n",
+                (segment.start * 1000.0).round() as u64,
+                (segment.end * 1000.0).round() as u64,
+                segment.text.trim().replace('\t', " ")
+            )
+        })
+        .collect();
+    format!("start\tend\ttext\n{}", rows)
+}
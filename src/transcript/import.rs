@@ -0,0 +1,233 @@
+//! Transcript import
+//! Reads transcripts produced elsewhere, without their recording, so they can be
+//! viewed, corrected and exported like the results of the API. Timed formats
+//! (SubRip, WebVTT, TSV) become JSON segments; JSON results and plain text are kept as is.
+
+use super::{parse_segments, segments_to_json, TranscriptSegment};
+use crate::api::OutputFormat;
+use std::fmt;
+
+/// A transcript read from a file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedTranscript {
+    /// The transcript, as a result of the API in `output_format`.
+    pub result: String,
+    pub output_format: OutputFormat,
+}
+
+/// Error raised when a file cannot be imported.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportError {
+    /// The file extension is not one of the output formats.
+    UnsupportedFormat(String),
+    /// The file holds no transcript readable in the format of its extension.
+    NoTranscript(OutputFormat),
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::UnsupportedFormat(file_name) => write!(
+                f,
+                "{} is not a transcript: SRT, VTT, TSV, TXT or JSON files are expected",
+                file_name
+            ),
+            ImportError::NoTranscript(format) => {
+                write!(f, "No {} transcript found in the file", format.label())
+            }
+        }
+    }
+}
+
+/// Reads a transcript, in the format given by the extension of its file name.
+pub fn import(file_name: &str, content: &str) -> Result<ImportedTranscript, ImportError> {
+    let format = file_name
+        .rsplit_once('.')
+        .and_then(|(_, extension)| OutputFormat::from_extension(&extension.to_lowercase()))
+        .ok_or_else(|| ImportError::UnsupportedFormat(file_name.to_string()))?;
+    let content = content.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+
+    let segments = match format {
+        OutputFormat::Txt if !content.trim().is_empty() => {
+            return Ok(ImportedTranscript {
+                result: content.trim().to_string(),
+                output_format: OutputFormat::Txt,
+            });
+        }
+        OutputFormat::Json if parse_segments(&content).is_some() => {
+            return Ok(ImportedTranscript {
+                result: content,
+                output_format: OutputFormat::Json,
+            });
+        }
+        OutputFormat::Srt | OutputFormat::Vtt => parse_cues(&content),
+        OutputFormat::Tsv => parse_tsv(&content),
+        OutputFormat::Txt | OutputFormat::Json => Vec::new(),
+    };
+    if segments.is_empty() {
+        return Err(ImportError::NoTranscript(format));
+    }
+    Ok(ImportedTranscript {
+        result: segments_to_json(segments),
+        output_format: OutputFormat::Json,
+    })
+}
+
+/// Parses the cues of a SubRip or WebVTT file. Blocks without timing line,
+/// such as the WebVTT header, notes and styles, are skipped.
+fn parse_cues(content: &str) -> Vec<TranscriptSegment> {
+    content
+        .split("\n\n")
+        .filter_map(|block| {
+            let mut lines = block.lines().skip_while(|line| !line.contains("-->"));
+            let (start, end) = lines.next()?.split_once("-->")?;
+            let start = parse_timestamp(start.trim())?;
+            // WebVTT cue settings may follow the end time.
+            let end = parse_timestamp(end.split_whitespace().next()?)?;
+            let text: Vec<&str> = lines
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .collect();
+            let (speaker, text) = voice(&text.join(" "));
+            Some(TranscriptSegment {
+                start,
+                end,
+                speaker,
+                text: strip_tags(&text),
+                score: None,
+                words: Vec::new(),
+            })
+        })
+        .filter(|segment| !segment.text.is_empty())
+        .collect()
+}
+
+/// Parses `hh:mm:ss,mmm` (SubRip) or `[hh:]mm:ss.mmm` (WebVTT) into seconds.
+fn parse_timestamp(timestamp: &str) -> Option<f64> {
+    timestamp
+        .replace(',', ".")
+        .split(':')
+        .try_fold(0.0, |total, part| {
+            part.parse::<f64>().ok().map(|value| total * 60.0 + value)
+        })
+}
+
+/// Splits the WebVTT voice span opening a cue (`<v Speaker>text`) from its text.
+fn voice(text: &str) -> (Option<String>, String) {
+    text.strip_prefix("<v ")
+        .or_else(|| text.strip_prefix("<v."))
+        .and_then(|rest| rest.split_once('>'))
+        .map(|(speaker, text)| {
+            // Classes (`<v.loud Speaker>`) come before the speaker name.
+            let speaker = speaker.split_once(' ').map_or(speaker, |(_, name)| name);
+            (Some(speaker.trim().to_string()), text.to_string())
+        })
+        .unwrap_or_else(|| (None, text.to_string()))
+}
+
+/// Removes the formatting tags (`<i>`, `<b>`, `</v>`...) of a cue text.
+fn strip_tags(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+    stripped.trim().to_string()
+}
+
+/// Parses the rows of a TSV transcript, as written by WhisperX: start and end
+/// in milliseconds, then the text. The header row is skipped.
+fn parse_tsv(content: &str) -> Vec<TranscriptSegment> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let start = fields.next()?.trim().parse::<f64>().ok()?;
+            let end = fields.next()?.trim().parse::<f64>().ok()?;
+            let text = fields.next()?.trim().to_string();
+            Some(TranscriptSegment {
+                start: start / 1000.0,
+                end: end / 1000.0,
+                speaker: None,
+                text,
+                score: None,
+                words: Vec::new(),
+            })
+        })
+        .filter(|segment| !segment.text.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn imported_segments(file_name: &str, content: &str) -> Vec<TranscriptSegment> {
+        let imported = import(file_name, content).expect("importable transcript");
+        assert_eq!(imported.output_format, OutputFormat::Json);
+        parse_segments(&imported.result).expect("segments")
+    }
+
+    #[test]
+    fn imports_srt() {
+        let content = "\u{feff}1\r\n00:00:01,000 --> 00:00:02,500\r\n<i>Hello</i>\r\nworld\r\n\r\n2\r\n00:01:00,000 --> 00:01:01,000\r\nAgain\r\n";
+        let segments = imported_segments("talk.SRT", content);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].start, 1.0);
+        assert_eq!(segments[0].end, 2.5);
+        assert_eq!(segments[0].text, "Hello world");
+        assert_eq!(segments[1].start, 60.0);
+    }
+
+    #[test]
+    fn imports_vtt_with_voices_and_settings() {
+        let content = "WEBVTT\n\nNOTE a comment\n\n00:01.000 --> 00:02.000 align:start\n<v.loud Alice>Hi there</v>\n\n01:00:00.000 --> 01:00:01.000\nBye\n";
+        let segments = imported_segments("talk.vtt", content);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].speaker.as_deref(), Some("Alice"));
+        assert_eq!(segments[0].text, "Hi there");
+        assert_eq!(segments[1].start, 3600.0);
+        assert_eq!(segments[1].speaker, None);
+    }
+
+    #[test]
+    fn imports_tsv() {
+        let segments = imported_segments(
+            "talk.tsv",
+            "start\tend\ttext\n0\t1500\tHello\n1500\t3000\t\n",
+        );
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].end, 1.5);
+        assert_eq!(segments[0].text, "Hello");
+    }
+
+    #[test]
+    fn keeps_text_and_json_as_is() {
+        let text = import("notes.txt", "  Some notes \n").unwrap();
+        assert_eq!(text.result, "Some notes");
+        assert_eq!(text.output_format, OutputFormat::Txt);
+        let json = r#"{"segments":[{"start":0.0,"end":1.0,"text":"Hi"}]}"#;
+        assert_eq!(import("result.json", json).unwrap().result, json);
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        assert_eq!(
+            import("audio.mp3", ""),
+            Err(ImportError::UnsupportedFormat("audio.mp3".to_string()))
+        );
+        assert_eq!(
+            import("empty.srt", "not subtitles"),
+            Err(ImportError::NoTranscript(OutputFormat::Srt))
+        );
+        assert_eq!(
+            import("result.json", "{}"),
+            Err(ImportError::NoTranscript(OutputFormat::Json))
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Transcript model
+//! Parses the timestamped segments of JSON transcription results.
+//! Transcripts imported in other formats are converted to the same JSON segments.
+
+pub mod bilingual;
+pub mod channels;
+pub mod chapters;
+pub mod export;
+pub mod import;
+pub mod subtitles;
+
+use crate::config::{HIGH_CONFIDENCE_THRESHOLD, LOW_CONFIDENCE_THRESHOLD};
+use serde::{Deserialize, Serialize};
+
+/// A timestamped part of a transcript.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptSegment {
+    /// Start time, in seconds.
+    pub start: f64,
+    /// End time, in seconds.
+    pub end: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    pub text: String,
+    /// Confidence of the segment, from 0 to 1, when the model reports it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    /// Word-level timings and confidence, when the result is aligned.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordTiming>,
+}
+
+/// A word of an aligned segment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordTiming {
+    pub word: String,
+    #[serde(default)]
+    pub start: Option<f64>,
+    #[serde(default)]
+    pub end: Option<f64>,
+    /// Confidence of the word, from 0 to 1.
+    #[serde(default)]
+    pub score: Option<f64>,
+}
+
+impl TranscriptSegment {
+    /// Confidence of the segment: its own score, or the mean score of its words.
+    pub fn confidence(&self) -> Option<f64> {
+        self.score.or_else(|| {
+            let scores: Vec<f64> = self.words.iter().filter_map(|word| word.score).collect();
+            (!scores.is_empty()).then(|| scores.iter().sum::<f64>() / scores.len() as f64)
+        })
+    }
+}
+
+/// Confidence level of a segment, used to color the heatmap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfidenceLevel {
+    High,
+    Medium,
+    Low,
+}
+
+impl ConfidenceLevel {
+    /// Classifies a confidence score.
+    pub fn of(confidence: f64) -> Self {
+        if confidence < LOW_CONFIDENCE_THRESHOLD {
+            ConfidenceLevel::Low
+        } else if confidence < HIGH_CONFIDENCE_THRESHOLD {
+            ConfidenceLevel::Medium
+        } else {
+            ConfidenceLevel::High
+        }
+    }
+
+    /// CSS class of the level.
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            ConfidenceLevel::High => "confidence-high",
+            ConfidenceLevel::Medium => "confidence-medium",
+            ConfidenceLevel::Low => "confidence-low",
+        }
+    }
+}
+
+/// The part of a JSON result holding the segments.
+#[derive(Serialize, Deserialize)]
+struct SegmentedResult {
+    segments: Vec<TranscriptSegment>,
+}
+
+/// Parses the segments of a result. Returns `None` for results without segments,
+/// such as plain text outputs.
+pub fn parse_segments(result: &str) -> Option<Vec<TranscriptSegment>> {
+    serde_json::from_str::<SegmentedResult>(result)
+        .ok()
+        .map(|parsed| parsed.segments)
+        .filter(|segments| !segments.is_empty())
+}
+
+/// Renders segments as a JSON result, in the shape returned by the API.
+pub fn segments_to_json(segments: Vec<TranscriptSegment>) -> String {
+    serde_json::to_string(&SegmentedResult { segments }).unwrap_or_default()
+}
+
+/// Consecutive segments of the same speaker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeakerTurn {
+    pub speaker: String,
+    /// Start time, in seconds.
+    pub start: f64,
+    pub text: String,
+}
+
+/// Groups the segments into speaker turns. Returns `None` when no segment is
+/// labeled with a speaker; unlabeled segments continue the current turn.
+pub fn speaker_turns(segments: &[TranscriptSegment]) -> Option<Vec<SpeakerTurn>> {
+    if segments.iter().all(|segment| segment.speaker.is_none()) {
+        return None;
+    }
+    let mut turns: Vec<SpeakerTurn> = Vec::new();
+    for segment in segments {
+        let text = segment.text.trim();
+        match (turns.last_mut(), &segment.speaker) {
+            (Some(turn), speaker) if speaker.as_ref().is_none_or(|s| *s == turn.speaker) => {
+                turn.text.push(' ');
+                turn.text.push_str(text);
+            }
+            (_, speaker) => turns.push(SpeakerTurn {
+                speaker: speaker.clone().unwrap_or_default(),
+                start: segment.start,
+                text: text.to_string(),
+            }),
+        }
+    }
+    Some(turns)
+}
+
+/// Returns the indices of the `count` least confident segments, least confident first.
+/// Segments without confidence are left out.
+pub fn lowest_confidence(segments: &[TranscriptSegment], count: usize) -> Vec<usize> {
+    let mut scored: Vec<(usize, f64)> = segments
+        .iter()
+        .enumerate()
+        .filter_map(|(index, segment)| segment.confidence().map(|confidence| (index, confidence)))
+        .collect();
+    scored.sort_by(|a, b| a.1.total_cmp(&b.1));
+    scored
+        .into_iter()
+        .take(count)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Returns the index of the segment playing at `time`, or of the last one started before it.
+pub fn segment_at(segments: &[TranscriptSegment], time: f64) -> Option<usize> {
+    segments.iter().rposition(|segment| segment.start <= time)
+}
+
+/// Formats a time in seconds as a timestamp (`m:ss`, or `h:mm:ss` past an hour).
+pub fn format_timestamp(seconds: f64) -> String {
+    let total = seconds.max(0.0).floor() as u64;
+    let (hours, minutes, secs) = (total / 3600, (total % 3600) / 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, secs)
+    } else {
+        format!("{}:{:02}", minutes, secs)
+    }
+}
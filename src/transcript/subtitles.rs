@@ -0,0 +1,354 @@
+//! Subtitle export
+//! Builds SRT and WebVTT subtitles from transcript segments, fitted to the usual
+//! subtitle constraints: long cues are re-split at word boundaries, very short ones
+//! merged, and the remaining violations reported.
+
+use super::TranscriptSegment;
+use crate::config::{
+    SUBTITLE_MAX_CUE_SECS, SUBTITLE_MAX_LINES, SUBTITLE_MAX_LINE_CHARS, SUBTITLE_MIN_CUE_SECS,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Limits applied to the exported cues.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SubtitleConstraints {
+    pub max_line_chars: usize,
+    pub max_lines: usize,
+    pub max_cue_secs: f64,
+    pub min_cue_secs: f64,
+}
+
+impl Default for SubtitleConstraints {
+    fn default() -> Self {
+        SubtitleConstraints {
+            max_line_chars: SUBTITLE_MAX_LINE_CHARS,
+            max_lines: SUBTITLE_MAX_LINES,
+            max_cue_secs: SUBTITLE_MAX_CUE_SECS,
+            min_cue_secs: SUBTITLE_MIN_CUE_SECS,
+        }
+    }
+}
+
+/// A subtitle cue.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Cue {
+    pub start: f64,
+    pub end: f64,
+    pub lines: Vec<String>,
+}
+
+impl Cue {
+    fn duration(&self) -> f64 {
+        self.end - self.start
+    }
+
+    fn text(&self) -> String {
+        self.lines.join(" ")
+    }
+}
+
+/// A constraint a cue still breaks after fitting.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    LineTooLong { cue: usize, chars: usize },
+    TooManyLines { cue: usize, lines: usize },
+    TooLong { cue: usize, secs: f64 },
+    TooShort { cue: usize, secs: f64 },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::LineTooLong { cue, chars } => {
+                write!(f, "Cue {}: a line has {} characters", cue + 1, chars)
+            }
+            Violation::TooManyLines { cue, lines } => {
+                write!(f, "Cue {}: {} lines", cue + 1, lines)
+            }
+            Violation::TooLong { cue, secs } => write!(f, "Cue {}: lasts {:.1} s", cue + 1, secs),
+            Violation::TooShort { cue, secs } => {
+                write!(f, "Cue {}: lasts only {:.1} s", cue + 1, secs)
+            }
+        }
+    }
+}
+
+/// Wraps words into lines of at most `max_chars` characters.
+/// A single word longer than the limit gets a line of its own.
+fn wrap(words: &[&str], max_chars: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for word in words {
+        match lines.last_mut() {
+            Some(line) if line.chars().count() + 1 + word.chars().count() <= max_chars => {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_string()),
+        }
+    }
+    lines
+}
+
+/// Splits a segment into cues fitting the character and duration limits.
+/// Time is shared between the cues in proportion to their length.
+fn split_segment(segment: &TranscriptSegment, constraints: &SubtitleConstraints) -> Vec<Cue> {
+    let words: Vec<&str> = segment.text.split_whitespace().collect();
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    // Group words into chunks fitting the character budget of a cue.
+    let mut chunks: Vec<Vec<&str>> = vec![Vec::new()];
+    for word in words {
+        let chunk = chunks.last_mut().expect("at least one chunk");
+        let mut candidate = chunk.clone();
+        candidate.push(word);
+        if chunk.is_empty()
+            || wrap(&candidate, constraints.max_line_chars).len() <= constraints.max_lines
+        {
+            *chunk = candidate;
+        } else {
+            chunks.push(vec![word]);
+        }
+    }
+
+    // Split further when a chunk would still last too long.
+    let duration = (segment.end - segment.start).max(0.0);
+    let total_chars: usize = chunks.iter().map(|chunk| chunk.join(" ").len()).sum();
+    let mut cues = Vec::new();
+    let mut start = segment.start;
+    for chunk in chunks {
+        let share = chunk.join(" ").len() as f64 / total_chars.max(1) as f64;
+        let chunk_duration = duration * share;
+        let parts = (chunk_duration / constraints.max_cue_secs).ceil().max(1.0) as usize;
+        let per_part = chunk.len().div_ceil(parts);
+        let part_count = chunk.chunks(per_part).count();
+        for part in chunk.chunks(per_part) {
+            let end = start + chunk_duration / part_count as f64;
+            cues.push(Cue {
+                start,
+                end,
+                lines: wrap(part, constraints.max_line_chars),
+            });
+            start = end;
+        }
+    }
+    cues
+}
+
+/// Merges cues shorter than the minimum duration into the next one, when the result
+/// still fits the limits.
+fn merge_short(cues: Vec<Cue>, constraints: &SubtitleConstraints) -> Vec<Cue> {
+    let mut merged: Vec<Cue> = Vec::with_capacity(cues.len());
+    for cue in cues {
+        if let Some(previous) = merged.last_mut() {
+            if previous.duration() < constraints.min_cue_secs {
+                let text = format!("{} {}", previous.text(), cue.text());
+                let words: Vec<&str> = text.split_whitespace().collect();
+                let lines = wrap(&words, constraints.max_line_chars);
+                if lines.len() <= constraints.max_lines
+                    && cue.end - previous.start <= constraints.max_cue_secs
+                {
+                    previous.end = cue.end;
+                    previous.lines = lines;
+                    continue;
+                }
+            }
+        }
+        merged.push(cue);
+    }
+    merged
+}
+
+/// Builds cues from the segments, fitted to the constraints.
+pub fn fit(segments: &[TranscriptSegment], constraints: &SubtitleConstraints) -> Vec<Cue> {
+    let cues = segments
+        .iter()
+        .flat_map(|segment| split_segment(segment, constraints))
+        .collect();
+    merge_short(cues, constraints)
+}
+
+/// Lists the constraints the cues still break.
+pub fn validate(cues: &[Cue], constraints: &SubtitleConstraints) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for (index, cue) in cues.iter().enumerate() {
+        if let Some(chars) = cue
+            .lines
+            .iter()
+            .map(|line| line.chars().count())
+            .filter(|chars| *chars > constraints.max_line_chars)
+            .max()
+        {
+            violations.push(Violation::LineTooLong { cue: index, chars });
+        }
+        if cue.lines.len() > constraints.max_lines {
+            violations.push(Violation::TooManyLines {
+                cue: index,
+                lines: cue.lines.len(),
+            });
+        }
+        if cue.duration() > constraints.max_cue_secs + f64::EPSILON {
+            violations.push(Violation::TooLong {
+                cue: index,
+                secs: cue.duration(),
+            });
+        } else if cue.duration() < constraints.min_cue_secs {
+            violations.push(Violation::TooShort {
+                cue: index,
+                secs: cue.duration(),
+            });
+        }
+    }
+    violations
+}
+
+/// Formats a time as `hh:mm:ss` followed by the milliseconds, after `separator`.
+fn timestamp(seconds: f64, separator: char) -> String {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        millis / 3_600_000,
+        (millis / 60_000) % 60,
+        (millis / 1000) % 60,
+        separator,
+        millis % 1000
+    )
+}
+
+/// Renders cues as SubRip (SRT).
+pub fn to_srt(cues: &[Cue]) -> String {
+    cues.iter()
+        .enumerate()
+        .map(|(index, cue)| {
+            format!(
+                "{}\n{} --> {}\n{}\n",
+                index + 1,
+                timestamp(cue.start, ','),
+                timestamp(cue.end, ','),
+                cue.lines.join("\n")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders cues as WebVTT.
+pub fn to_vtt(cues: &[Cue]) -> String {
+    let body: String = cues
+        .iter()
+        .map(|cue| {
+            format!(
+                "\n{} --> {}\n{}\n",
+                timestamp(cue.start, '.'),
+                timestamp(cue.end, '.'),
+                cue.lines.join("\n")
+            )
+        })
+        .collect();
+    format!("WEBVTT\n{}", body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONSTRAINTS: SubtitleConstraints = SubtitleConstraints {
+        max_line_chars: 10,
+        max_lines: 2,
+        max_cue_secs: 5.0,
+        min_cue_secs: 1.0,
+    };
+
+    fn segment(start: f64, end: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            start,
+            end,
+            speaker: None,
+            text: text.to_string(),
+            score: None,
+            words: Vec::new(),
+        }
+    }
+
+    fn cue(start: f64, end: f64, lines: &[&str]) -> Cue {
+        Cue {
+            start,
+            end,
+            lines: lines.iter().map(|line| line.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn wraps_words_into_lines() {
+        assert_eq!(wrap(&["one", "two", "three"], 10), vec!["one two", "three"]);
+        assert_eq!(wrap(&["unbreakableword"], 10), vec!["unbreakableword"]);
+    }
+
+    #[test]
+    fn long_segments_are_split_at_word_boundaries() {
+        let text = "one two three four five six seven eight";
+        let cues = fit(&[segment(0.0, 4.0, text)], &CONSTRAINTS);
+        assert_eq!(cues.len(), 3);
+        assert_eq!(cues[0].lines, vec!["one two", "three four"]);
+        assert_eq!(cues[0].start, 0.0);
+        assert!((cues.last().unwrap().end - 4.0).abs() < 1e-9);
+        let words: Vec<String> = cues.iter().map(Cue::text).collect();
+        assert_eq!(words.join(" "), text);
+        assert!(validate(&cues, &CONSTRAINTS)
+            .iter()
+            .all(|violation| matches!(violation, Violation::TooShort { .. })));
+    }
+
+    #[test]
+    fn long_lasting_segments_are_split_in_time() {
+        let cues = fit(&[segment(0.0, 12.0, "a b c d e f")], &CONSTRAINTS);
+        assert_eq!(cues.len(), 3);
+        assert!(cues.iter().all(|cue| cue.duration() <= 5.0));
+        assert!(validate(&cues, &CONSTRAINTS).is_empty());
+    }
+
+    #[test]
+    fn short_cues_are_merged_into_the_next() {
+        let segments = [segment(0.0, 0.5, "hi"), segment(0.5, 2.0, "there you go")];
+        assert_eq!(
+            fit(&segments, &CONSTRAINTS),
+            vec![cue(0.0, 2.0, &["hi there", "you go"])]
+        );
+    }
+
+    #[test]
+    fn reports_the_remaining_violations() {
+        let cues = [
+            cue(0.0, 6.0, &["abcdefghijkl"]),
+            cue(6.0, 6.5, &["a", "b", "c"]),
+        ];
+        assert_eq!(
+            validate(&cues, &CONSTRAINTS),
+            vec![
+                Violation::LineTooLong { cue: 0, chars: 12 },
+                Violation::TooLong { cue: 0, secs: 6.0 },
+                Violation::TooManyLines { cue: 1, lines: 3 },
+                Violation::TooShort { cue: 1, secs: 0.5 },
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_srt() {
+        let cues = [cue(0.0, 1.5, &["Hello"]), cue(61.25, 3662.0, &["A", "B"])];
+        assert_eq!(
+            to_srt(&cues),
+            "1\n00:00:00,000 --> 00:00:01,500\nHello\n\n2\n00:01:01,250 --> 01:01:02,000\nA\nB\n"
+        );
+    }
+
+    #[test]
+    fn renders_vtt() {
+        assert_eq!(
+            to_vtt(&[cue(0.0, 1.5, &["Hello"])]),
+            "WEBVTT\n\n00:00:00.000 --> 00:00:01.500\nHello\n"
+        );
+    }
+}